@@ -1,17 +1,57 @@
-use crate::schema::{Artifact, Body, DecisionMeeting, DecisionMotion, DecisionVote, Meeting};
-use crate::scoring::DecisionScore;
+use crate::schema::{
+    Artifact, Body, DecisionMeeting, DecisionMotion, DecisionVote, DocType, Meeting,
+    PublicComment,
+};
+use crate::scoring::{
+    artifact_importance, compute_artifact_importance, has_scorable_evidence, ConstitutionRefs,
+    DecisionScore, EvidenceCoverage, EvidenceExcerpt, LinkedArtifact, Rubric,
+};
 use anyhow::Result;
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
 
 pub fn open(db_path: &str) -> Result<Connection> {
     let conn = Connection::open(db_path)?;
     conn.pragma_update(None, "journal_mode", "WAL")?;
     conn.pragma_update(None, "synchronous", "NORMAL")?;
+    prepare(conn)
+}
+
+/// A private database with the full schema that lives only as long as the connection, for tests
+/// and benchmarks that should not touch the disk.
+pub fn open_memory() -> Result<Connection> {
+    prepare(Connection::open_in_memory()?)
+}
+
+/// Schema setup shared by every way of opening a database: creates tables and runs the column
+/// migrations.
+fn prepare(conn: Connection) -> Result<Connection> {
     init(&conn)?;
     Ok(conn)
 }
 
+/// Copies the WAL into the main database file and truncates the WAL to zero bytes.
+pub fn checkpoint(conn: &Connection) -> Result<()> {
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+    Ok(())
+}
+
+/// Rebuilds the database file to reclaim pages freed by deletes, then truncates the WAL.
+pub fn vacuum(conn: &Connection) -> Result<()> {
+    conn.execute_batch("VACUUM")?;
+    checkpoint(conn)
+}
+
+/// Bytes on disk for the database file plus its WAL (missing files count as zero).
+pub fn file_size(db_path: &str) -> u64 {
+    [db_path.to_string(), format!("{db_path}-wal")]
+        .iter()
+        .filter_map(|path| std::fs::metadata(path).ok())
+        .map(|meta| meta.len())
+        .sum()
+}
+
 fn init(conn: &Connection) -> Result<()> {
     conn.execute_batch(
         r#"
@@ -32,11 +72,26 @@ fn init(conn: &Connection) -> Result<()> {
           body_text TEXT,
           tags_json TEXT NOT NULL,
           raw_json TEXT NOT NULL,
+          importance REAL,
+          body_text_len INTEGER,
+          supersedes_json TEXT,
+          language TEXT,
+          doc_type TEXT,
+          auto_tags_json TEXT,
           inserted_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now'))
         );
 
         CREATE INDEX IF NOT EXISTS idx_artifacts_retrieved_at ON artifacts(retrieved_at);
 
+        CREATE TABLE IF NOT EXISTS artifact_revisions (
+          artifact_id TEXT NOT NULL,
+          revision INTEGER NOT NULL,
+          retrieved_at TEXT NOT NULL,
+          body_text TEXT,
+          recorded_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now')),
+          PRIMARY KEY (artifact_id, revision)
+        );
+
         CREATE TABLE IF NOT EXISTS meetings (
           id TEXT PRIMARY KEY,
           body_id TEXT NOT NULL,
@@ -44,6 +99,8 @@ fn init(conn: &Connection) -> Result<()> {
           artifact_ids_json TEXT NOT NULL,
           motions_json TEXT NOT NULL,
           raw_json TEXT NOT NULL,
+          parent_meeting_id TEXT,
+          presiding_officer TEXT,
           inserted_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now'))
         );
 
@@ -57,6 +114,10 @@ fn init(conn: &Connection) -> Result<()> {
           moved_by TEXT,
           seconded_by TEXT,
           result TEXT,
+          amended INTEGER NOT NULL DEFAULT 0,
+          amendment_text TEXT,
+          reconsiders_motion_id TEXT,
+          agenda_ref TEXT,
           raw_json TEXT NOT NULL,
           inserted_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now'))
         );
@@ -77,6 +138,18 @@ fn init(conn: &Connection) -> Result<()> {
 
         CREATE INDEX IF NOT EXISTS idx_votes_motion_id ON votes(motion_id);
 
+        CREATE TABLE IF NOT EXISTS public_comments (
+          id TEXT PRIMARY KEY,
+          motion_id TEXT NOT NULL,
+          speaker TEXT,
+          stance TEXT,
+          summary TEXT NOT NULL,
+          raw_json TEXT NOT NULL,
+          inserted_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_public_comments_motion_id ON public_comments(motion_id);
+
         CREATE TABLE IF NOT EXISTS decision_scores (
           id TEXT PRIMARY KEY,
           meeting_id TEXT,
@@ -88,13 +161,34 @@ fn init(conn: &Connection) -> Result<()> {
           evidence_json TEXT NOT NULL,
           confidence REAL NOT NULL,
           flags_json TEXT NOT NULL,
-          computed_at TEXT NOT NULL
+          computed_at TEXT NOT NULL,
+          rubric_version TEXT,
+          contributions_json TEXT NOT NULL DEFAULT '{}',
+          weights_json TEXT NOT NULL DEFAULT '{}'
         );
 
         CREATE INDEX IF NOT EXISTS idx_decision_scores_meeting_id ON decision_scores(meeting_id);
         CREATE INDEX IF NOT EXISTS idx_decision_scores_motion_id ON decision_scores(motion_id);
         CREATE INDEX IF NOT EXISTS idx_decision_scores_vote_id ON decision_scores(vote_id);
 
+        CREATE TABLE IF NOT EXISTS decision_score_history (
+          score_id TEXT NOT NULL,
+          meeting_id TEXT,
+          motion_id TEXT,
+          vote_id TEXT,
+          overall_score REAL NOT NULL,
+          axis_json TEXT NOT NULL,
+          evidence_json TEXT NOT NULL,
+          confidence REAL NOT NULL,
+          flags_json TEXT NOT NULL,
+          computed_at TEXT NOT NULL,
+          rubric_version TEXT,
+          PRIMARY KEY (score_id, computed_at)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_decision_score_history_computed_at
+          ON decision_score_history(computed_at);
+
         CREATE TABLE IF NOT EXISTS official_drift (
           id TEXT PRIMARY KEY,
           official_name TEXT NOT NULL,
@@ -103,13 +197,32 @@ fn init(conn: &Connection) -> Result<()> {
           current_average REAL NOT NULL,
           deviation REAL NOT NULL,
           flags_json TEXT NOT NULL,
-          computed_at TEXT NOT NULL
+          computed_at TEXT NOT NULL,
+          status TEXT NOT NULL DEFAULT 'active',
+          resolved_at TEXT
         );
         "#,
     )?;
     ensure_motions_text_column(conn)?;
     ensure_motions_motion_index_column(conn)?;
+    ensure_motions_amendment_columns(conn)?;
+    ensure_motions_reconsiders_motion_id_column(conn)?;
+    ensure_motions_agenda_ref_column(conn)?;
     ensure_meetings_motions_json_column(conn)?;
+    ensure_artifacts_importance_column(conn)?;
+    ensure_artifacts_body_text_len_column(conn)?;
+    ensure_artifacts_supersedes_json_column(conn)?;
+    ensure_artifacts_language_column(conn)?;
+    ensure_artifacts_doc_type_column(conn)?;
+    ensure_artifacts_auto_tags_json_column(conn)?;
+    ensure_meetings_parent_meeting_id_column(conn)?;
+    ensure_meetings_presiding_officer_column(conn)?;
+    ensure_decision_scores_rubric_version_column(conn)?;
+    ensure_decision_scores_contributions_json_column(conn)?;
+    ensure_decision_scores_weights_json_column(conn)?;
+    ensure_decision_scores_refs_grouped(conn)?;
+    ensure_decision_score_history_backfilled(conn)?;
+    ensure_official_drift_status_columns(conn)?;
     seed_bodies(conn)?;
     Ok(())
 }
@@ -131,6 +244,71 @@ fn ensure_motions_motion_index_column(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+fn ensure_motions_amendment_columns(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "motions", "amended")? {
+        conn.execute(
+            "ALTER TABLE motions ADD COLUMN amended INTEGER NOT NULL DEFAULT 0",
+            params![],
+        )?;
+    }
+    if !column_exists(conn, "motions", "amendment_text")? {
+        conn.execute("ALTER TABLE motions ADD COLUMN amendment_text TEXT", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_artifacts_auto_tags_json_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "artifacts", "auto_tags_json")? {
+        conn.execute("ALTER TABLE artifacts ADD COLUMN auto_tags_json TEXT", params![])?;
+    }
+    Ok(())
+}
+
+/// `doc_type` holds only a type the artifact set explicitly. Rows written when the tag-derived
+/// type was stored too are cleared where the raw JSON names none, so they follow their tags.
+fn ensure_artifacts_doc_type_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "artifacts", "doc_type")? {
+        conn.execute("ALTER TABLE artifacts ADD COLUMN doc_type TEXT", params![])?;
+    }
+    conn.execute(
+        r#"
+        UPDATE artifacts SET doc_type = NULL
+        WHERE doc_type IS NOT NULL
+          AND json_valid(raw_json)
+          AND CASE WHEN json_valid(raw_json) THEN json_type(raw_json, '$.doc_type') END IS NULL
+        "#,
+        params![],
+    )?;
+    Ok(())
+}
+
+fn ensure_motions_agenda_ref_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "motions", "agenda_ref")? {
+        conn.execute("ALTER TABLE motions ADD COLUMN agenda_ref TEXT", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_motions_reconsiders_motion_id_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "motions", "reconsiders_motion_id")? {
+        conn.execute("ALTER TABLE motions ADD COLUMN reconsiders_motion_id TEXT", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_official_drift_status_columns(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "official_drift", "status")? {
+        conn.execute(
+            "ALTER TABLE official_drift ADD COLUMN status TEXT NOT NULL DEFAULT 'active'",
+            params![],
+        )?;
+    }
+    if !column_exists(conn, "official_drift", "resolved_at")? {
+        conn.execute("ALTER TABLE official_drift ADD COLUMN resolved_at TEXT", params![])?;
+    }
+    Ok(())
+}
+
 fn ensure_meetings_motions_json_column(conn: &Connection) -> Result<()> {
     if !column_exists(conn, "meetings", "motions_json")? {
         conn.execute("ALTER TABLE meetings ADD COLUMN motions_json TEXT", params![])?;
@@ -138,6 +316,142 @@ fn ensure_meetings_motions_json_column(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Rows ingested before the column existed get their importance from the stored tags and body
+/// text (already truncated if a body-size limit applied then).
+fn ensure_artifacts_importance_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "artifacts", "importance")? {
+        conn.execute("ALTER TABLE artifacts ADD COLUMN importance REAL", params![])?;
+    }
+    let mut stmt =
+        conn.prepare("SELECT id, tags_json, body_text FROM artifacts WHERE importance IS NULL")?;
+    let missing = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for (id, tags_json, body_text) in missing {
+        let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
+        conn.execute(
+            "UPDATE artifacts SET importance = ?1 WHERE id = ?2",
+            params![artifact_importance(&tags, body_text.as_deref()), id],
+        )?;
+    }
+    Ok(())
+}
+
+fn ensure_artifacts_body_text_len_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "artifacts", "body_text_len")? {
+        conn.execute("ALTER TABLE artifacts ADD COLUMN body_text_len INTEGER", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_artifacts_supersedes_json_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "artifacts", "supersedes_json")? {
+        conn.execute("ALTER TABLE artifacts ADD COLUMN supersedes_json TEXT", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_artifacts_language_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "artifacts", "language")? {
+        conn.execute("ALTER TABLE artifacts ADD COLUMN language TEXT", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_meetings_parent_meeting_id_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "meetings", "parent_meeting_id")? {
+        conn.execute("ALTER TABLE meetings ADD COLUMN parent_meeting_id TEXT", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_meetings_presiding_officer_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "meetings", "presiding_officer")? {
+        conn.execute("ALTER TABLE meetings ADD COLUMN presiding_officer TEXT", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_decision_scores_rubric_version_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "decision_scores", "rubric_version")? {
+        conn.execute("ALTER TABLE decision_scores ADD COLUMN rubric_version TEXT", params![])?;
+    }
+    Ok(())
+}
+
+fn ensure_decision_scores_contributions_json_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "decision_scores", "contributions_json")? {
+        conn.execute(
+            "ALTER TABLE decision_scores ADD COLUMN contributions_json TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        )?;
+    }
+    Ok(())
+}
+
+fn ensure_decision_scores_weights_json_column(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "decision_scores", "weights_json")? {
+        conn.execute(
+            "ALTER TABLE decision_scores ADD COLUMN weights_json TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        )?;
+    }
+    Ok(())
+}
+
+/// Axis that constitutional refs stored before they were grouped by axis are filed under; the
+/// flat list those rows hold does not say which axis each ref came from.
+pub const UNGROUPED_REFS_AXIS: &str = "ungrouped";
+
+/// Scores written before refs were grouped by axis hold `refs_json` as a flat list of refs;
+/// rewrites those rows to the grouped shape with every ref under [`UNGROUPED_REFS_AXIS`].
+fn ensure_decision_scores_refs_grouped(conn: &Connection) -> Result<()> {
+    let mut stmt =
+        conn.prepare("SELECT id, refs_json FROM decision_scores WHERE refs_json LIKE '[\"%'")?;
+    let legacy = stmt
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for (id, refs_json) in legacy {
+        let Ok(refs) = serde_json::from_str::<Vec<String>>(&refs_json) else {
+            continue;
+        };
+        let grouped: ConstitutionRefs = vec![(UNGROUPED_REFS_AXIS.to_string(), refs)];
+        conn.execute(
+            "UPDATE decision_scores SET refs_json = ?1 WHERE id = ?2",
+            params![serde_json::to_string(&grouped)?, id],
+        )?;
+    }
+    Ok(())
+}
+
+/// Databases created before the history table existed start their history from the current
+/// rows, so drift has prior weeks to compare against straight away.
+fn ensure_decision_score_history_backfilled(conn: &Connection) -> Result<()> {
+    let history_rows: i64 =
+        conn.query_row("SELECT COUNT(*) FROM decision_score_history", [], |row| row.get(0))?;
+    if history_rows == 0 {
+        conn.execute(
+            r#"
+            INSERT INTO decision_score_history (
+              score_id, meeting_id, motion_id, vote_id, overall_score, axis_json, evidence_json,
+              confidence, flags_json, computed_at, rubric_version
+            )
+            SELECT id, meeting_id, motion_id, vote_id, overall_score, axis_json, evidence_json,
+                   confidence, flags_json, computed_at, rubric_version
+            FROM decision_scores
+            "#,
+            params![],
+        )?;
+    }
+    Ok(())
+}
+
 fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool> {
     let mut stmt = conn.prepare(&format!("PRAGMA table_info({table})"))?;
     let columns = stmt.query_map([], |row| row.get::<_, String>(1))?;
@@ -167,16 +481,91 @@ fn seed_bodies(conn: &Connection) -> Result<()> {
 }
 
 pub fn upsert_artifact(conn: &Connection, artifact: &Artifact, raw_json: &Value) -> Result<()> {
+    upsert_artifact_with_storage(conn, artifact, raw_json, ArtifactStorage::default())
+}
+
+/// Storage settings for artifact rows, from the `[storage]` config section.
+#[derive(Debug, Clone, Copy)]
+pub struct ArtifactStorage {
+    /// Cap on stored `body_text`; `None` keeps it whole.
+    pub max_body_text_bytes: Option<usize>,
+    /// When false the `raw_json` column is left empty to save space.
+    pub store_raw_json: bool,
+}
+
+impl Default for ArtifactStorage {
+    fn default() -> Self {
+        Self {
+            max_body_text_bytes: None,
+            store_raw_json: true,
+        }
+    }
+}
+
+/// Upserts an artifact, capping `body_text` at `storage.max_body_text_bytes` on a UTF-8
+/// boundary. Truncated text gets a `[truncated]` marker and a `truncated` tag (also applied to
+/// the stored raw JSON body); `body_text_len` always records the full length in bytes. With
+/// `storage.store_raw_json` off, `raw_json` is stored as an empty string. When a re-ingest
+/// changes the stored `body_text`, the previous text is kept in `artifact_revisions`.
+pub fn upsert_artifact_with_storage(
+    conn: &Connection,
+    artifact: &Artifact,
+    raw_json: &Value,
+    storage: ArtifactStorage,
+) -> Result<()> {
+    let max_body_text_bytes = storage.max_body_text_bytes;
+    let importance = compute_artifact_importance(artifact);
+    let body_text_len = artifact.body_text.as_ref().map(|text| text.len() as i64);
+    let mut artifact = artifact.clone();
+    let mut raw_json = raw_json.clone();
+    if let (Some(limit), Some(text)) = (max_body_text_bytes, artifact.body_text.as_ref())
+        && text.len() > limit
+    {
+        let truncated = format!("{}{TRUNCATION_MARKER}", truncate_utf8(text, limit));
+        if let Some(object) = raw_json.as_object_mut() {
+            object.insert("body_text".to_string(), Value::String(truncated.clone()));
+        }
+        artifact.body_text = Some(truncated);
+        if !artifact.tags.iter().any(|tag| tag == "truncated") {
+            artifact.tags.push("truncated".to_string());
+        }
+    }
     let tags_json = serde_json::to_string(&artifact.tags)?;
-    let raw_json_str = serde_json::to_string(raw_json)?;
+    let supersedes_json = serde_json::to_string(&artifact.supersedes)?;
+    let raw_json_str = if storage.store_raw_json {
+        serde_json::to_string(&raw_json)?
+    } else {
+        String::new()
+    };
+
+    let previous: Option<(String, Option<String>)> = conn
+        .query_row(
+            "SELECT retrieved_at, body_text FROM artifacts WHERE id = ?1",
+            [&artifact.id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    if let Some((retrieved_at, body_text)) = previous
+        && body_text != artifact.body_text
+    {
+        conn.execute(
+            r#"
+            INSERT INTO artifact_revisions (artifact_id, revision, retrieved_at, body_text)
+            SELECT ?1, COALESCE(MAX(revision), 0) + 1, ?2, ?3
+            FROM artifact_revisions WHERE artifact_id = ?1
+            "#,
+            params![artifact.id, retrieved_at, body_text],
+        )?;
+    }
 
     conn.execute(
         r#"
         INSERT INTO artifacts (
           id, source_kind, source_value, retrieved_at,
-          title, content_type, body_text, tags_json, raw_json
+          title, content_type, body_text, tags_json, raw_json, importance, body_text_len,
+          supersedes_json, language, doc_type
         )
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
         ON CONFLICT(id) DO UPDATE SET
           source_kind=excluded.source_kind,
           source_value=excluded.source_value,
@@ -185,7 +574,13 @@ pub fn upsert_artifact(conn: &Connection, artifact: &Artifact, raw_json: &Value)
           content_type=excluded.content_type,
           body_text=excluded.body_text,
           tags_json=excluded.tags_json,
-          raw_json=excluded.raw_json
+          raw_json=excluded.raw_json,
+          importance=excluded.importance,
+          body_text_len=excluded.body_text_len,
+          supersedes_json=excluded.supersedes_json,
+          language=excluded.language,
+          doc_type=excluded.doc_type,
+          auto_tags_json=NULL
         "#,
         params![
             artifact.id,
@@ -196,18 +591,248 @@ pub fn upsert_artifact(conn: &Connection, artifact: &Artifact, raw_json: &Value)
             artifact.content_type,
             artifact.body_text,
             tags_json,
-            raw_json_str
+            raw_json_str,
+            importance,
+            body_text_len,
+            supersedes_json,
+            artifact.language,
+            artifact.doc_type.map(DocType::as_str)
         ],
     )?;
 
     Ok(())
 }
 
+const TRUNCATION_MARKER: &str = "\n[truncated]";
+
+fn truncate_utf8(text: &str, max_bytes: usize) -> &str {
+    let mut end = max_bytes.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+/// Reverse of `Artifact.supersedes`: maps each superseded artifact id to the ids of the
+/// artifacts that supersede it (sorted, deduplicated).
+pub fn artifact_superseded_by(conn: &Connection) -> Result<BTreeMap<String, Vec<String>>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, supersedes_json FROM artifacts WHERE supersedes_json IS NOT NULL ORDER BY id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+    let mut superseded_by: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for row in rows {
+        let (id, supersedes_json) = row?;
+        let supersedes: Vec<String> = serde_json::from_str(&supersedes_json).unwrap_or_default();
+        for older in supersedes {
+            let newer = superseded_by.entry(older).or_default();
+            if !newer.contains(&id) {
+                newer.push(id.clone());
+            }
+        }
+    }
+    for newer in superseded_by.values_mut() {
+        newer.sort();
+    }
+    Ok(superseded_by)
+}
+
+/// A superseded version of an artifact's `body_text`, kept when a re-ingest changed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactRevision {
+    /// 1 for the first text replaced, counting up.
+    pub revision: i64,
+    /// When the superseded version was retrieved.
+    pub retrieved_at: String,
+    pub body_text: Option<String>,
+    /// When the change was detected.
+    pub recorded_at: String,
+}
+
+/// Superseded versions of an artifact, oldest first; the current text is in `artifacts`.
+pub fn load_artifact_revisions(conn: &Connection, id: &str) -> Result<Vec<ArtifactRevision>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT revision, retrieved_at, body_text, recorded_at
+        FROM artifact_revisions
+        WHERE artifact_id = ?1
+        ORDER BY revision
+        "#,
+    )?;
+    let rows = stmt.query_map([id], |row| {
+        Ok(ArtifactRevision {
+            revision: row.get(0)?,
+            retrieved_at: row.get(1)?,
+            body_text: row.get(2)?,
+            recorded_at: row.get(3)?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
 pub fn artifact_exists(conn: &Connection, id: &str) -> Result<bool> {
     let mut stmt = conn.prepare("SELECT 1 FROM artifacts WHERE id = ?1 LIMIT 1")?;
     Ok(stmt.exists(params![id])?)
 }
 
+/// `retrieved_at` of the most recently retrieved artifact, or `None` when none are ingested.
+pub fn latest_artifact_retrieved_at(conn: &Connection) -> Result<Option<String>> {
+    Ok(conn.query_row("SELECT MAX(retrieved_at) FROM artifacts", [], |row| row.get(0))?)
+}
+
+/// Stores tags from automatic tagging. `auto_tags` are the ones the tagger added, so a forced
+/// retag can replace them without touching collector tags; importance is recomputed from the new
+/// tags. Returns false if the artifact does not exist.
+pub fn update_artifact_auto_tags(
+    conn: &Connection,
+    id: &str,
+    tags: &[String],
+    auto_tags: &[String],
+    body_text: Option<&str>,
+) -> Result<bool> {
+    let updated = conn.execute(
+        "UPDATE artifacts SET tags_json = ?2, auto_tags_json = ?3, importance = ?4 WHERE id = ?1",
+        params![
+            id,
+            serde_json::to_string(tags)?,
+            serde_json::to_string(auto_tags)?,
+            artifact_importance(tags, body_text)
+        ],
+    )?;
+    Ok(updated > 0)
+}
+
+/// Replaces an artifact's stored tags; returns false if the artifact does not exist.
+pub fn update_artifact_tags(conn: &Connection, id: &str, tags: &[String]) -> Result<bool> {
+    let tags_json = serde_json::to_string(tags)?;
+    let updated = conn.execute(
+        "UPDATE artifacts SET tags_json = ?2 WHERE id = ?1",
+        params![id, tags_json],
+    )?;
+    Ok(updated > 0)
+}
+
+/// Ingested artifacts among `artifact_ids`, in the given order; unknown ids are skipped.
+/// `source_doc_types` is the rubric's `[source_doc_types]` (see `stored_doc_type`).
+pub fn load_linked_artifacts(
+    conn: &Connection,
+    artifact_ids: &[String],
+    source_doc_types: &HashMap<String, DocType>,
+) -> Result<Vec<LinkedArtifact>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, tags_json, retrieved_at, content_type, body_text, source_kind, doc_type \
+         FROM artifacts WHERE id = ?1",
+    )?;
+    let mut artifacts = Vec::new();
+    for artifact_id in artifact_ids {
+        let mut rows = stmt.query([artifact_id.as_str()])?;
+        if let Some(row) = rows.next()? {
+            let tags_json: String = row.get(1)?;
+            let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
+            let source_kind: String = row.get(5)?;
+            let doc_type = stored_doc_type(row.get(6)?, &tags, &source_kind, source_doc_types);
+            artifacts.push(LinkedArtifact {
+                id: row.get(0)?,
+                tags,
+                retrieved_at: row.get(2)?,
+                content_type: row.get(3)?,
+                body_text: row.get(4)?,
+                source_kind,
+                trusted: true,
+                doc_type,
+            });
+        }
+    }
+    Ok(artifacts)
+}
+
+/// The `doc_type` column, which holds only a type the artifact gave explicitly; otherwise the
+/// type derived from the row's current tags and source kind (see `DocType::derive`), so
+/// retagging reclassifies it.
+pub fn stored_doc_type(
+    stored: Option<String>,
+    tags: &[String],
+    source_kind: &str,
+    source_doc_types: &HashMap<String, DocType>,
+) -> DocType {
+    stored
+        .as_deref()
+        .and_then(DocType::parse)
+        .unwrap_or_else(|| DocType::derive(tags, source_kind, source_doc_types))
+}
+
+/// How many of a meeting's motions have scorable evidence (see `has_scorable_evidence`).
+/// `None` when the meeting does not exist.
+pub fn meeting_evidence_coverage(
+    conn: &Connection,
+    meeting_id: &str,
+    rubric: &Rubric,
+) -> Result<Option<EvidenceCoverage>> {
+    let mut stmt =
+        conn.prepare("SELECT started_at, artifact_ids_json FROM meetings WHERE id = ?1")?;
+    let mut rows = stmt.query([meeting_id])?;
+    let Some(row) = rows.next()? else {
+        return Ok(None);
+    };
+    let started_at: String = row.get(0)?;
+    let artifact_ids_json: String = row.get(1)?;
+    let artifact_ids: Vec<String> = serde_json::from_str(&artifact_ids_json).unwrap_or_default();
+    let linked = load_linked_artifacts(conn, &artifact_ids, &rubric.config.source_doc_types)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT COALESCE(text, ''), amended, amendment_text FROM motions WHERE meeting_id = ?1",
+    )?;
+    let texts = stmt
+        .query_map([meeting_id], |row| {
+            let text: String = row.get(0)?;
+            let amendment: Option<String> = row.get(2)?;
+            Ok(crate::schema::amended_text(row.get(1)?, amendment.as_deref())
+                .map(str::to_string)
+                .unwrap_or(text))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    let covered = texts
+        .iter()
+        .filter(|text| has_scorable_evidence(text, &linked, &started_at, rubric))
+        .count();
+    Ok(Some(EvidenceCoverage {
+        covered,
+        total: texts.len(),
+    }))
+}
+
+/// Ids from `artifact_ids` that have no ingested artifact, in the given order.
+pub fn missing_artifacts(conn: &Connection, artifact_ids: &[String]) -> Result<Vec<String>> {
+    let mut missing = Vec::new();
+    for id in artifact_ids {
+        if !artifact_exists(conn, id)? {
+            missing.push(id.clone());
+        }
+    }
+    Ok(missing)
+}
+
+/// `(meeting_id, artifact_id)` for meeting artifact references with no ingested artifact.
+/// Scoring silently skips these, so the meeting is scored on fewer documents than it lists.
+pub fn dangling_artifact_refs(conn: &Connection) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT meetings.id, refs.value
+        FROM meetings, json_each(meetings.artifact_ids_json) AS refs
+        LEFT JOIN artifacts ON artifacts.id = refs.value
+        WHERE json_valid(meetings.artifact_ids_json)
+          AND artifacts.id IS NULL
+        ORDER BY meetings.id, refs.key
+        "#,
+    )?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<(String, String)>>>()?;
+    Ok(rows)
+}
+
 pub fn upsert_meeting(conn: &Connection, meeting: &Meeting, raw_json: &Value) -> Result<()> {
     let artifact_ids_json = serde_json::to_string(&meeting.artifact_ids)?;
     let motions_json = serde_json::to_string(&meeting.motions)?;
@@ -216,15 +841,16 @@ pub fn upsert_meeting(conn: &Connection, meeting: &Meeting, raw_json: &Value) ->
     conn.execute(
         r#"
         INSERT INTO meetings (
-          id, body_id, started_at, artifact_ids_json, motions_json, raw_json
+          id, body_id, started_at, artifact_ids_json, motions_json, raw_json, parent_meeting_id
         )
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
         ON CONFLICT(id) DO UPDATE SET
           body_id=excluded.body_id,
           started_at=excluded.started_at,
           artifact_ids_json=excluded.artifact_ids_json,
           motions_json=excluded.motions_json,
-          raw_json=excluded.raw_json
+          raw_json=excluded.raw_json,
+          parent_meeting_id=excluded.parent_meeting_id
         "#,
         params![
             meeting.id,
@@ -232,7 +858,8 @@ pub fn upsert_meeting(conn: &Connection, meeting: &Meeting, raw_json: &Value) ->
             meeting.started_at,
             artifact_ids_json,
             motions_json,
-            raw_json_str
+            raw_json_str,
+            meeting.parent_meeting_id
         ],
     )?;
     Ok(())
@@ -243,6 +870,11 @@ pub fn meeting_exists(conn: &Connection, id: &str) -> Result<bool> {
     Ok(stmt.exists(params![id])?)
 }
 
+pub fn motion_exists(conn: &Connection, id: &str) -> Result<bool> {
+    let mut stmt = conn.prepare("SELECT 1 FROM motions WHERE id = ?1 LIMIT 1")?;
+    Ok(stmt.exists(params![id])?)
+}
+
 pub fn upsert_decision_meeting(
     conn: &Connection,
     meeting: &DecisionMeeting,
@@ -263,15 +895,18 @@ pub fn upsert_decision_meeting(
     conn.execute(
         r#"
         INSERT INTO meetings (
-          id, body_id, started_at, artifact_ids_json, motions_json, raw_json
+          id, body_id, started_at, artifact_ids_json, motions_json, raw_json, parent_meeting_id,
+          presiding_officer
         )
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
         ON CONFLICT(id) DO UPDATE SET
           body_id=excluded.body_id,
           started_at=excluded.started_at,
           artifact_ids_json=excluded.artifact_ids_json,
           motions_json=excluded.motions_json,
-          raw_json=excluded.raw_json
+          raw_json=excluded.raw_json,
+          parent_meeting_id=excluded.parent_meeting_id,
+          presiding_officer=excluded.presiding_officer
         "#,
         params![
             meeting.id,
@@ -279,7 +914,9 @@ pub fn upsert_decision_meeting(
             meeting.started_at,
             artifact_ids_json,
             motions_json,
-            raw_json_str
+            raw_json_str,
+            meeting.parent_meeting_id,
+            meeting.presiding_officer
         ],
     )?;
     Ok(())
@@ -294,9 +931,10 @@ pub fn upsert_motion(
     conn.execute(
         r#"
         INSERT INTO motions (
-          id, meeting_id, motion_index, text, moved_by, seconded_by, result, raw_json
+          id, meeting_id, motion_index, text, moved_by, seconded_by, result, amended,
+          amendment_text, agenda_ref, raw_json
         )
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
         ON CONFLICT(id) DO UPDATE SET
           meeting_id=excluded.meeting_id,
           motion_index=excluded.motion_index,
@@ -304,6 +942,9 @@ pub fn upsert_motion(
           moved_by=excluded.moved_by,
           seconded_by=excluded.seconded_by,
           result=excluded.result,
+          amended=excluded.amended,
+          amendment_text=excluded.amendment_text,
+          agenda_ref=excluded.agenda_ref,
           raw_json=excluded.raw_json
         "#,
         params![
@@ -314,12 +955,29 @@ pub fn upsert_motion(
             motion.moved_by,
             motion.seconded_by,
             motion.result,
+            motion.amended,
+            motion.amendment_text,
+            motion.agenda_ref,
             raw_json_str
         ],
     )?;
     Ok(())
 }
 
+/// Links a motion to the earlier motion it reconsiders, or clears the link with `None`. The
+/// link is derived at scoring time, so `upsert_motion` leaves it alone.
+pub fn set_motion_reconsiders(
+    conn: &Connection,
+    motion_id: &str,
+    reconsiders_motion_id: Option<&str>,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE motions SET reconsiders_motion_id = ?2 WHERE id = ?1",
+        params![motion_id, reconsiders_motion_id],
+    )?;
+    Ok(())
+}
+
 pub fn upsert_vote(
     conn: &Connection,
     vote: &DecisionVote,
@@ -358,19 +1016,92 @@ pub fn upsert_vote(
     Ok(())
 }
 
+/// `(vote_id, motion_id)` for stored votes whose motion row does not exist.
+pub fn dangling_votes(conn: &Connection) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT votes.id, votes.motion_id
+        FROM votes
+        LEFT JOIN motions ON motions.id = votes.motion_id
+        WHERE motions.id IS NULL
+        ORDER BY votes.id
+        "#,
+    )?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<(String, String)>>>()?;
+    Ok(rows)
+}
+
+/// `(score_id, motion_id)` for stored scores whose motion row does not exist.
+pub fn orphaned_scores(conn: &Connection) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT decision_scores.id, decision_scores.motion_id
+        FROM decision_scores
+        LEFT JOIN motions ON motions.id = decision_scores.motion_id
+        WHERE decision_scores.motion_id IS NOT NULL
+          AND motions.id IS NULL
+        ORDER BY decision_scores.id
+        "#,
+    )?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<(String, String)>>>()?;
+    Ok(rows)
+}
+
+pub fn upsert_public_comment(
+    conn: &Connection,
+    comment: &PublicComment,
+    raw_json: &Value,
+) -> Result<()> {
+    let raw_json_str = serde_json::to_string(raw_json)?;
+    conn.execute(
+        r#"
+        INSERT INTO public_comments (id, motion_id, speaker, stance, summary, raw_json)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        ON CONFLICT(id) DO UPDATE SET
+          motion_id=excluded.motion_id,
+          speaker=excluded.speaker,
+          stance=excluded.stance,
+          summary=excluded.summary,
+          raw_json=excluded.raw_json
+        "#,
+        params![
+            comment.id,
+            comment.motion_id,
+            comment.speaker,
+            comment.stance,
+            comment.summary,
+            raw_json_str
+        ],
+    )?;
+    Ok(())
+}
+
+/// Stores the score under its stable id, replacing any earlier computation; its JSON columns hold
+/// the matching fields of the canonical score JSON. The computation is also recorded in
+/// `decision_score_history` keyed by `(id, computed_at)`: rerunning the same window updates its
+/// history row, while a different window adds one and leaves earlier windows intact.
 pub fn upsert_decision_score(conn: &Connection, score: &DecisionScore) -> Result<()> {
-    let axis_json = serde_json::to_string(&score.axis_scores)?;
-    let refs_json = serde_json::to_string(&score.constitutional_refs)?;
-    let evidence_json = serde_json::to_string(&score.evidence)?;
-    let flags_json = serde_json::to_string(&score.flags)?;
+    let canonical = score.canonical_json()?;
+    let column = |field: &str| canonical[field].to_string();
+    let axis_json = column("axis_scores");
+    let refs_json = column("constitutional_refs");
+    let evidence_json = column("evidence");
+    let flags_json = column("flags");
+    let contributions_json = column("contributions");
+    let weights_json = column("axis_weights");
 
     conn.execute(
         r#"
         INSERT INTO decision_scores (
           id, meeting_id, motion_id, vote_id, overall_score, axis_json, refs_json,
-          evidence_json, confidence, flags_json, computed_at
+          evidence_json, confidence, flags_json, computed_at, rubric_version, contributions_json,
+          weights_json
         )
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
         ON CONFLICT(id) DO UPDATE SET
           meeting_id=excluded.meeting_id,
           motion_id=excluded.motion_id,
@@ -381,7 +1112,10 @@ pub fn upsert_decision_score(conn: &Connection, score: &DecisionScore) -> Result
           evidence_json=excluded.evidence_json,
           confidence=excluded.confidence,
           flags_json=excluded.flags_json,
-          computed_at=excluded.computed_at
+          computed_at=excluded.computed_at,
+          rubric_version=excluded.rubric_version,
+          contributions_json=excluded.contributions_json,
+          weights_json=excluded.weights_json
         "#,
         params![
             score.id,
@@ -394,12 +1128,50 @@ pub fn upsert_decision_score(conn: &Connection, score: &DecisionScore) -> Result
             evidence_json,
             score.confidence,
             flags_json,
-            score.computed_at
+            score.computed_at,
+            score.rubric_version,
+            contributions_json,
+            weights_json
+        ],
+    )?;
+    conn.execute(
+        r#"
+        INSERT INTO decision_score_history (
+          score_id, meeting_id, motion_id, vote_id, overall_score, axis_json, evidence_json,
+          confidence, flags_json, computed_at, rubric_version
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+        ON CONFLICT(score_id, computed_at) DO UPDATE SET
+          meeting_id=excluded.meeting_id,
+          motion_id=excluded.motion_id,
+          vote_id=excluded.vote_id,
+          overall_score=excluded.overall_score,
+          axis_json=excluded.axis_json,
+          evidence_json=excluded.evidence_json,
+          confidence=excluded.confidence,
+          flags_json=excluded.flags_json,
+          rubric_version=excluded.rubric_version
+        "#,
+        params![
+            score.id,
+            score.meeting_id,
+            score.motion_id,
+            score.vote_id,
+            score.overall_score,
+            axis_json,
+            evidence_json,
+            score.confidence,
+            flags_json,
+            score.computed_at,
+            score.rubric_version
         ],
     )?;
     Ok(())
 }
 
+/// Records drift on one official's axis as `active`. Re-detecting drift under an existing id
+/// reactivates a row that `resolve_official_drift` had resolved.
+#[allow(clippy::too_many_arguments)]
 pub fn upsert_official_drift(
     conn: &Connection,
     id: &str,
@@ -425,7 +1197,9 @@ pub fn upsert_official_drift(
           current_average=excluded.current_average,
           deviation=excluded.deviation,
           flags_json=excluded.flags_json,
-          computed_at=excluded.computed_at
+          computed_at=excluded.computed_at,
+          status='active',
+          resolved_at=NULL
         "#,
         params![
             id,
@@ -440,3 +1214,916 @@ pub fn upsert_official_drift(
     )?;
     Ok(())
 }
+
+/// Marks the official's active drift rows on `axis` computed at or before `resolved_at` as
+/// `resolved`, once the axis is back within the drift threshold. Returns the rows resolved.
+pub fn resolve_official_drift(
+    conn: &Connection,
+    official_name: &str,
+    axis: &str,
+    resolved_at: &str,
+) -> Result<usize> {
+    let resolved = conn.execute(
+        r#"
+        UPDATE official_drift
+        SET status = 'resolved', resolved_at = ?3
+        WHERE official_name = ?1
+          AND axis = ?2
+          AND status = 'active'
+          AND datetime(computed_at) <= datetime(?3)
+        "#,
+        params![official_name, axis, resolved_at],
+    )?;
+    Ok(resolved)
+}
+
+/// Calls `f` with every stored artifact, in id order, rebuilt from its columns. Rows are read
+/// one at a time, so callers can stream a table of any size.
+pub fn for_each_artifact(
+    conn: &Connection,
+    mut f: impl FnMut(Artifact) -> Result<()>,
+) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT id, source_kind, source_value, retrieved_at, title, body_text, content_type,
+               tags_json, COALESCE(supersedes_json, '[]'), language, doc_type
+        FROM artifacts
+        ORDER BY id
+        "#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        f(Artifact {
+            id: row.get(0)?,
+            source: crate::schema::SourceRef {
+                kind: row.get(1)?,
+                value: row.get(2)?,
+                retrieved_at: row.get(3)?,
+            },
+            title: row.get(4)?,
+            body_text: row.get(5)?,
+            content_type: row.get(6)?,
+            tags: json_column(row, 7)?,
+            supersedes: json_column(row, 8)?,
+            language: row.get(9)?,
+            doc_type: row.get::<_, Option<String>>(10)?.as_deref().and_then(DocType::parse),
+        })?;
+    }
+    Ok(())
+}
+
+/// Calls `f` with every stored meeting, in id order. `source` comes from the raw JSON, since
+/// it has no column of its own.
+pub fn for_each_meeting(
+    conn: &Connection,
+    mut f: impl FnMut(Meeting) -> Result<()>,
+) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT id, body_id, started_at, artifact_ids_json, COALESCE(motions_json, '[]'),
+               parent_meeting_id, raw_json
+        FROM meetings
+        ORDER BY id
+        "#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let raw_json: Value = json_column(row, 6)?;
+        f(Meeting {
+            id: row.get(0)?,
+            body_id: row.get(1)?,
+            started_at: row.get(2)?,
+            artifact_ids: json_column(row, 3)?,
+            motions: json_column(row, 4)?,
+            parent_meeting_id: row.get(5)?,
+            source: raw_json
+                .get("source")
+                .and_then(|source| serde_json::from_value(source.clone()).ok()),
+        })?;
+    }
+    Ok(())
+}
+
+/// Calls `f` with every current decision score, in id order.
+pub fn for_each_decision_score(
+    conn: &Connection,
+    mut f: impl FnMut(DecisionScore) -> Result<()>,
+) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT id, meeting_id, motion_id, vote_id, overall_score, axis_json, contributions_json,
+               refs_json, evidence_json, confidence, flags_json, computed_at, rubric_version,
+               weights_json
+        FROM decision_scores
+        ORDER BY id
+        "#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        f(DecisionScore {
+            id: row.get(0)?,
+            meeting_id: row.get(1)?,
+            motion_id: row.get(2)?,
+            vote_id: row.get(3)?,
+            overall_score: row.get(4)?,
+            axis_scores: json_column(row, 5)?,
+            contributions: json_column(row, 6)?,
+            axis_weights: json_column(row, 13)?,
+            constitutional_refs: json_column(row, 7)?,
+            evidence: json_column(row, 8)?,
+            confidence: row.get(9)?,
+            flags: json_column(row, 10)?,
+            computed_at: row.get(11)?,
+            rubric_version: row.get(12)?,
+        })?;
+    }
+    Ok(())
+}
+
+/// A JSON text column parsed into `T`; unparseable JSON reads as `T::default()`.
+fn json_column<T: serde::de::DeserializeOwned + Default>(
+    row: &rusqlite::Row,
+    index: usize,
+) -> rusqlite::Result<T> {
+    Ok(serde_json::from_str(&row.get::<_, String>(index)?).unwrap_or_default())
+}
+
+/// How `merge_database` rewrites a column so ids from one source cannot collide with another's.
+#[derive(Debug, Clone, Copy)]
+enum MergeRewrite {
+    /// The column is an id.
+    Id,
+    /// A score id, `motion:<motion id>` or `vote:<vote id>:<official>`; the tag goes after the
+    /// kind so merged ids keep the scheme rescoring produces.
+    ScoreId,
+    /// A JSON array of ids.
+    IdList,
+    /// A raw JSON object; see `RAW_JSON_ID_KEYS` and `RAW_JSON_ID_LIST_KEYS`.
+    RawJson,
+    /// A JSON array of objects, each rewritten like `RawJson`.
+    RawJsonList,
+    /// A JSON array of evidence entries; see `EVIDENCE_ID_PREFIXES` and `EvidenceExcerpt`.
+    Evidence,
+}
+
+/// Tables copied by `merge_database`, in dependency order, with their id columns. Body ids
+/// are county-specific already and are kept as-is.
+const MERGE_TABLES: &[(&str, &[(&str, MergeRewrite)])] = &[
+    (
+        "artifacts",
+        &[
+            ("id", MergeRewrite::Id),
+            ("supersedes_json", MergeRewrite::IdList),
+            ("raw_json", MergeRewrite::RawJson),
+        ],
+    ),
+    ("artifact_revisions", &[("artifact_id", MergeRewrite::Id)]),
+    (
+        "meetings",
+        &[
+            ("id", MergeRewrite::Id),
+            ("parent_meeting_id", MergeRewrite::Id),
+            ("artifact_ids_json", MergeRewrite::IdList),
+            ("motions_json", MergeRewrite::RawJsonList),
+            ("raw_json", MergeRewrite::RawJson),
+        ],
+    ),
+    (
+        "motions",
+        &[
+            ("id", MergeRewrite::Id),
+            ("meeting_id", MergeRewrite::Id),
+            ("reconsiders_motion_id", MergeRewrite::Id),
+            ("raw_json", MergeRewrite::RawJson),
+        ],
+    ),
+    (
+        "votes",
+        &[
+            ("id", MergeRewrite::Id),
+            ("motion_id", MergeRewrite::Id),
+            ("raw_json", MergeRewrite::RawJson),
+        ],
+    ),
+    (
+        "public_comments",
+        &[
+            ("id", MergeRewrite::Id),
+            ("motion_id", MergeRewrite::Id),
+            ("raw_json", MergeRewrite::RawJson),
+        ],
+    ),
+    (
+        "decision_scores",
+        &[
+            ("id", MergeRewrite::ScoreId),
+            ("meeting_id", MergeRewrite::Id),
+            ("motion_id", MergeRewrite::Id),
+            ("vote_id", MergeRewrite::Id),
+            ("evidence_json", MergeRewrite::Evidence),
+        ],
+    ),
+    (
+        "decision_score_history",
+        &[
+            ("score_id", MergeRewrite::ScoreId),
+            ("meeting_id", MergeRewrite::Id),
+            ("motion_id", MergeRewrite::Id),
+            ("vote_id", MergeRewrite::Id),
+            ("evidence_json", MergeRewrite::Evidence),
+        ],
+    ),
+    ("official_drift", &[("id", MergeRewrite::Id)]),
+];
+
+const RAW_JSON_ID_KEYS: &[&str] = &["id", "meeting_id", "motion_id", "parent_meeting_id"];
+const RAW_JSON_ID_LIST_KEYS: &[&str] = &["artifact_ids", "supersedes"];
+/// Evidence entries ending in an id, with how many `:`-separated fields precede it.
+const EVIDENCE_ID_PREFIXES: &[(&str, usize)] =
+    &[("reconsidered_by", 0), ("untrusted_source", 1), ("not_decision_record", 1)];
+
+/// Rows `merge_database` copied from one source.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MergeCounts {
+    /// Bodies the target did not have yet.
+    pub bodies: usize,
+    /// Rows copied per table, in `MERGE_TABLES` order.
+    pub tables: Vec<(&'static str, usize)>,
+    /// Source bodies whose id the target already has with a different name, kind, or
+    /// jurisdiction; the target's row is kept.
+    pub body_conflicts: Vec<String>,
+}
+
+/// Copies every row of the database at `source_path` into `target`, prefixing ids (and the ids
+/// stored inside JSON columns) with `<tag>-` so several county databases can share one target.
+/// Bodies are merged by id, so the seeded body present in every database is stored once. Rows
+/// already merged under the same tag are replaced. The source is opened read-only.
+pub fn merge_database(target: &Connection, source_path: &str, tag: &str) -> Result<MergeCounts> {
+    let source =
+        Connection::open_with_flags(source_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let tx = target.unchecked_transaction()?;
+    let mut counts = MergeCounts::default();
+
+    let mut bodies =
+        source.prepare("SELECT id, name, kind, jurisdiction FROM bodies ORDER BY id")?;
+    let bodies = bodies.query_map([], |row| {
+        Ok(Body {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            kind: row.get(2)?,
+            jurisdiction: row.get(3)?,
+        })
+    })?;
+    for body in bodies {
+        let body = body?;
+        let existing: Option<(String, String, String)> = tx
+            .query_row(
+                "SELECT name, kind, jurisdiction FROM bodies WHERE id = ?1",
+                [&body.id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+        match existing {
+            None => {
+                tx.execute(
+                    "INSERT INTO bodies (id, name, kind, jurisdiction) VALUES (?1, ?2, ?3, ?4)",
+                    params![body.id, body.name, body.kind, body.jurisdiction],
+                )?;
+                counts.bodies += 1;
+            }
+            Some(existing) if existing != (body.name, body.kind, body.jurisdiction) => {
+                counts.body_conflicts.push(body.id);
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (table, rewrites) in MERGE_TABLES {
+        let copied = if table_exists(&source, table)? {
+            merge_table(&tx, &source, table, rewrites, tag)?
+        } else {
+            0
+        };
+        counts.tables.push((table, copied));
+    }
+    // A source that predates grouped refs is read raw, so its copied rows need the same upgrade.
+    ensure_decision_scores_refs_grouped(&tx)?;
+    tx.commit()?;
+    Ok(counts)
+}
+
+fn table_exists(conn: &Connection, table: &str) -> Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+        [table],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+/// Copies whichever of the table's columns the source has; the target, being current, has
+/// them all.
+fn merge_table(
+    target: &Connection,
+    source: &Connection,
+    table: &str,
+    rewrites: &[(&str, MergeRewrite)],
+    tag: &str,
+) -> Result<usize> {
+    use rusqlite::types::Value as SqlValue;
+
+    let mut select = source.prepare(&format!("SELECT * FROM {table}"))?;
+    let columns: Vec<String> = select.column_names().into_iter().map(str::to_string).collect();
+    let placeholders = (1..=columns.len()).map(|index| format!("?{index}")).collect::<Vec<_>>();
+    let mut insert = target.prepare(&format!(
+        "INSERT OR REPLACE INTO {table} ({}) VALUES ({})",
+        columns.join(", "),
+        placeholders.join(", ")
+    ))?;
+    let column_rewrites: Vec<Option<MergeRewrite>> = columns
+        .iter()
+        .map(|column| {
+            rewrites.iter().find(|(name, _)| name == column).map(|(_, rewrite)| *rewrite)
+        })
+        .collect();
+
+    let mut rows = select.query([])?;
+    let mut copied = 0;
+    while let Some(row) = rows.next()? {
+        let mut values = Vec::with_capacity(columns.len());
+        for (index, rewrite) in column_rewrites.iter().enumerate() {
+            let value: SqlValue = row.get(index)?;
+            values.push(match (rewrite, value) {
+                (Some(rewrite), SqlValue::Text(text)) => {
+                    SqlValue::Text(rewrite_merged_column(*rewrite, &text, tag))
+                }
+                (_, value) => value,
+            });
+        }
+        insert.execute(rusqlite::params_from_iter(values))?;
+        copied += 1;
+    }
+    Ok(copied)
+}
+
+fn rewrite_merged_column(rewrite: MergeRewrite, text: &str, tag: &str) -> String {
+    let prefix = |id: &str| format!("{tag}-{id}");
+    let prefix_list = |value: &mut Value| {
+        if let Some(ids) = value.as_array_mut() {
+            for id in ids {
+                if let Some(text) = id.as_str() {
+                    *id = Value::String(prefix(text));
+                }
+            }
+        }
+    };
+    let prefix_object = |value: &mut Value| {
+        if let Some(object) = value.as_object_mut() {
+            for key in RAW_JSON_ID_KEYS {
+                if let Some(Value::String(id)) = object.get(*key) {
+                    let id = prefix(id);
+                    object.insert(key.to_string(), Value::String(id));
+                }
+            }
+            for key in RAW_JSON_ID_LIST_KEYS {
+                if let Some(ids) = object.get_mut(*key) {
+                    prefix_list(ids);
+                }
+            }
+        }
+    };
+    match rewrite {
+        MergeRewrite::Id => return prefix(text),
+        MergeRewrite::ScoreId => {
+            return match text.split_once(':') {
+                Some((kind, id)) => format!("{kind}:{}", prefix(id)),
+                None => prefix(text),
+            };
+        }
+        _ => {}
+    }
+    // Unparseable JSON is copied unchanged rather than failing the merge.
+    let Ok(mut value) = serde_json::from_str::<Value>(text) else {
+        return text.to_string();
+    };
+    match rewrite {
+        MergeRewrite::Id | MergeRewrite::ScoreId => {}
+        MergeRewrite::IdList => prefix_list(&mut value),
+        MergeRewrite::RawJson => prefix_object(&mut value),
+        MergeRewrite::RawJsonList => {
+            if let Some(objects) = value.as_array_mut() {
+                objects.iter_mut().for_each(prefix_object);
+            }
+        }
+        MergeRewrite::Evidence => {
+            if let Some(entries) = value.as_array_mut() {
+                for entry in entries {
+                    if let Some(rewritten) = entry.as_str().and_then(|text| {
+                        rewrite_evidence_entry(text, &prefix)
+                    }) {
+                        *entry = Value::String(rewritten);
+                    }
+                }
+            }
+        }
+    }
+    value.to_string()
+}
+
+/// An evidence entry with its id prefixed, or `None` when it names no id.
+fn rewrite_evidence_entry(entry: &str, prefix: &dyn Fn(&str) -> String) -> Option<String> {
+    if let Some(mut excerpt) = EvidenceExcerpt::from_evidence(entry) {
+        excerpt.artifact_id = prefix(&excerpt.artifact_id);
+        return Some(excerpt.to_evidence());
+    }
+    let (name, rest) = entry.split_once(':')?;
+    let (_, fields) = EVIDENCE_ID_PREFIXES.iter().find(|(known, _)| *known == name)?;
+    // Ids may themselves contain `:`, so everything after the leading fields is the id.
+    let mut parts = rest.splitn(fields + 1, ':');
+    let leading = parts.by_ref().take(*fields).collect::<Vec<_>>();
+    let id = parts.next()?;
+    let mut rewritten = vec![name];
+    rewritten.extend(leading);
+    let id = prefix(id);
+    rewritten.push(&id);
+    Some(rewritten.join(":"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::SourceRef;
+    use serde_json::json;
+
+    /// A bare url artifact; tests override the fields they care about.
+    fn artifact(id: &str) -> Artifact {
+        Artifact {
+            id: id.to_string(),
+            source: SourceRef {
+                kind: "url".to_string(),
+                value: format!("https://example.com/{id}.pdf"),
+                retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            },
+            title: None,
+            body_text: None,
+            content_type: None,
+            tags: Vec::new(),
+            supersedes: Vec::new(),
+            language: None,
+            doc_type: None,
+        }
+    }
+
+    #[test]
+    fn oversized_body_text_is_truncated_on_char_boundary() {
+        let conn = open_memory().unwrap();
+        // "é" is two bytes, so a 5-byte cap must back off to a char boundary.
+        let body = "é".repeat(1000);
+        let artifact = Artifact {
+            body_text: Some(body.clone()),
+            tags: vec!["budget".to_string()],
+            ..artifact("big")
+        };
+        let raw = serde_json::to_value(&artifact).unwrap();
+        let storage = ArtifactStorage {
+            max_body_text_bytes: Some(5),
+            ..ArtifactStorage::default()
+        };
+        upsert_artifact_with_storage(&conn, &artifact, &raw, storage).unwrap();
+
+        let (stored, tags_json, raw_json, len): (String, String, String, i64) = conn
+            .query_row(
+                r#"
+                SELECT body_text, tags_json, raw_json, body_text_len
+                FROM artifacts
+                WHERE id = 'big'
+                "#,
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .unwrap();
+        assert_eq!(stored, "éé\n[truncated]");
+        assert_eq!(len, body.len() as i64);
+        let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap();
+        assert_eq!(tags, vec!["budget".to_string(), "truncated".to_string()]);
+        let raw: Value = serde_json::from_str(&raw_json).unwrap();
+        assert_eq!(raw["body_text"], json!("éé\n[truncated]"));
+        assert_eq!(raw["tags"], json!(["budget"]));
+    }
+
+    #[test]
+    fn raw_json_is_dropped_when_storage_disables_it() {
+        let conn = open_memory().unwrap();
+        let artifact = |id: &str| Artifact {
+            title: Some("Agenda".to_string()),
+            body_text: Some("Road contract".to_string()),
+            tags: vec!["budget".to_string()],
+            ..artifact(id)
+        };
+        let dropped = ArtifactStorage {
+            store_raw_json: false,
+            ..ArtifactStorage::default()
+        };
+        let kept = artifact("kept");
+        let raw = serde_json::to_value(&kept).unwrap();
+        upsert_artifact_with_storage(&conn, &kept, &raw, ArtifactStorage::default()).unwrap();
+        let lean = artifact("lean");
+        let raw = serde_json::to_value(&lean).unwrap();
+        upsert_artifact_with_storage(&conn, &lean, &raw, dropped).unwrap();
+
+        let stored = |id: &str| -> (String, String) {
+            conn.query_row(
+                "SELECT raw_json, body_text FROM artifacts WHERE id = ?1",
+                [id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+        };
+        let (raw_json, body) = stored("kept");
+        let raw: Value = serde_json::from_str(&raw_json).unwrap();
+        assert_eq!(raw["id"], json!("kept"));
+        assert_eq!(body, "Road contract");
+        let (raw_json, body) = stored("lean");
+        assert_eq!(raw_json, "");
+        assert_eq!(body, "Road contract");
+    }
+
+    #[test]
+    fn checkpoint_truncates_wal() {
+        let path = std::env::temp_dir()
+            .join(format!("larue-db-checkpoint-{}.db", std::process::id()));
+        let db_path = path.to_str().unwrap();
+        let _ = std::fs::remove_file(&path);
+        let conn = open(db_path).unwrap();
+        for index in 0..50 {
+            let motion = DecisionMotion {
+                id: format!("m1-{index}"),
+                meeting_id: "m1".to_string(),
+                index,
+                text: "Approve the road contract".repeat(20),
+                moved_by: None,
+                seconded_by: None,
+                result: None,
+                amended: false,
+                amendment_text: None,
+                agenda_ref: None,
+            };
+            upsert_motion(&conn, &motion, &json!({})).unwrap();
+        }
+        let wal = format!("{db_path}-wal");
+        assert!(std::fs::metadata(&wal).unwrap().len() > 0);
+
+        checkpoint(&conn).unwrap();
+        assert_eq!(std::fs::metadata(&wal).unwrap().len(), 0);
+        vacuum(&conn).unwrap();
+        assert_eq!(file_size(db_path), std::fs::metadata(&path).unwrap().len());
+
+        drop(conn);
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&wal);
+    }
+
+    #[test]
+    fn dangling_votes_are_found() {
+        let conn = open_memory().unwrap();
+        let motion = DecisionMotion {
+            id: "m1-a".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 0,
+            text: "Approve the road contract".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: None,
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        };
+        upsert_motion(&conn, &motion, &json!({})).unwrap();
+        for (id, motion_id) in [("v-a", "m1-a"), ("v-x", "m1-missing")] {
+            let vote = DecisionVote {
+                id: id.to_string(),
+                motion_id: motion_id.to_string(),
+                vote_type: None,
+                outcome: None,
+                ayes: Vec::new(),
+                nays: Vec::new(),
+                abstain: Vec::new(),
+            };
+            upsert_vote(&conn, &vote, &json!({})).unwrap();
+        }
+
+        assert_eq!(
+            dangling_votes(&conn).unwrap(),
+            vec![("v-x".to_string(), "m1-missing".to_string())]
+        );
+    }
+
+    #[test]
+    fn meetings_referencing_missing_artifacts_are_found() {
+        let conn = open_memory().unwrap();
+        upsert_artifact(&conn, &artifact("a1"), &json!({})).unwrap();
+        let meeting = Meeting {
+            id: "m1".to_string(),
+            body_id: "larue-fiscal-court".to_string(),
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            artifact_ids: vec!["a1".to_string(), "a-missing".to_string()],
+            motions: Vec::new(),
+            parent_meeting_id: None,
+            source: None,
+        };
+        upsert_meeting(&conn, &meeting, &json!({})).unwrap();
+
+        assert_eq!(
+            missing_artifacts(&conn, &meeting.artifact_ids).unwrap(),
+            vec!["a-missing".to_string()]
+        );
+        assert_eq!(
+            dangling_artifact_refs(&conn).unwrap(),
+            vec![("m1".to_string(), "a-missing".to_string())]
+        );
+    }
+
+    #[test]
+    fn merged_databases_keep_ids_apart_and_bodies_deduplicated() {
+        let dir = std::env::temp_dir().join(format!("larue-db-merge-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let excerpt = |artifact_id: &str| EvidenceExcerpt {
+            tag: "roads".to_string(),
+            artifact_id: artifact_id.to_string(),
+            text: "Pave it".to_string(),
+        };
+        let county = |name: &str, body_id: &str, body_name: &str| {
+            let path = dir.join(format!("{name}.db"));
+            let conn = open(path.to_str().unwrap()).unwrap();
+            conn.execute(
+                "INSERT OR IGNORE INTO bodies (id, name, kind, jurisdiction) \
+                 VALUES (?1, ?2, 'fiscal_court', 'KY')",
+                params![body_id, body_name],
+            )
+            .unwrap();
+            let mut artifact = Artifact {
+                title: Some(format!("{name} agenda")),
+                ..artifact("a1")
+            };
+            upsert_artifact(&conn, &artifact, &json!({"id": "a1"})).unwrap();
+            artifact.id = "a2".to_string();
+            artifact.supersedes = vec!["a1".to_string()];
+            upsert_artifact(&conn, &artifact, &json!({"id": "a2", "supersedes": ["a1"]})).unwrap();
+            let meeting = DecisionMeeting {
+                id: "m1".to_string(),
+                body_id: body_id.to_string(),
+                body_name: None,
+                started_at: "2024-03-04T18:00:00Z".to_string(),
+                meeting_type: None,
+                artifact_ids: vec!["a2".to_string()],
+                parent_meeting_id: None,
+                presiding_officer: None,
+            };
+            upsert_decision_meeting(&conn, &meeting, &json!({"id": "m1"}), &[]).unwrap();
+            let motion = DecisionMotion {
+                id: "m1-a".to_string(),
+                meeting_id: "m1".to_string(),
+                index: 0,
+                text: "Approve the road contract".to_string(),
+                moved_by: None,
+                seconded_by: None,
+                result: None,
+                amended: false,
+                amendment_text: None,
+                agenda_ref: None,
+            };
+            upsert_motion(&conn, &motion, &json!({})).unwrap();
+            let reconsideration = DecisionMotion {
+                id: "m1-b".to_string(),
+                index: 1,
+                text: "Reconsider item 1".to_string(),
+                ..motion
+            };
+            upsert_motion(&conn, &reconsideration, &json!({})).unwrap();
+            set_motion_reconsiders(&conn, "m1-b", Some("m1-a")).unwrap();
+            // Meetings ingested from minutes may carry motion ids in motions_json.
+            conn.execute(
+                r#"UPDATE meetings SET motions_json = '[{"motion_id":"m1-a","text":"Pave"}]'"#,
+                [],
+            )
+            .unwrap();
+            upsert_decision_score(
+                &conn,
+                &DecisionScore {
+                    id: "motion:m1-a".to_string(),
+                    meeting_id: Some("m1".to_string()),
+                    motion_id: Some("m1-a".to_string()),
+                    vote_id: None,
+                    overall_score: 1.0,
+                    axis_scores: Default::default(),
+                    contributions: Default::default(),
+                    axis_weights: Default::default(),
+                    constitutional_refs: Vec::new(),
+                    evidence: vec![
+                        "tag:roads".to_string(),
+                        excerpt("a2").to_evidence(),
+                        "reconsidered_by:m1-b".to_string(),
+                        "untrusted_source:social_media:a1".to_string(),
+                        "not_decision_record:agenda:a1".to_string(),
+                    ],
+                    confidence: 0.6,
+                    flags: Vec::new(),
+                    computed_at: "2024-03-05T00:00:00Z".to_string(),
+                    rubric_version: None,
+                },
+            )
+            .unwrap();
+            path
+        };
+        let larue = county("larue", "larue-fiscal-court", "LaRue County Fiscal Court");
+        let hardin = county("hardin", "hardin-fiscal-court", "Hardin County Fiscal Court");
+        // Same id as the seeded body, different details: the target keeps its own.
+        let conn = open(hardin.to_str().unwrap()).unwrap();
+        conn.execute("UPDATE bodies SET name = 'Renamed' WHERE id = 'larue-fiscal-court'", [])
+            .unwrap();
+        drop(conn);
+
+        let target_path = dir.join("combined.db");
+        let target = open(target_path.to_str().unwrap()).unwrap();
+        let counts = merge_database(&target, larue.to_str().unwrap(), "larue").unwrap();
+        assert_eq!(counts.bodies, 0);
+        assert!(counts.body_conflicts.is_empty());
+        assert!(counts.tables.contains(&("artifacts", 2)));
+        let counts = merge_database(&target, hardin.to_str().unwrap(), "hardin").unwrap();
+        assert_eq!(counts.bodies, 1);
+        assert_eq!(counts.body_conflicts, vec!["larue-fiscal-court"]);
+        // Re-merging a source replaces its rows instead of duplicating them.
+        merge_database(&target, hardin.to_str().unwrap(), "hardin").unwrap();
+
+        let strings = |sql: &str| {
+            let mut stmt = target.prepare(sql).unwrap();
+            stmt.query_map([], |row| row.get::<_, String>(0))
+                .unwrap()
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .unwrap()
+        };
+        assert_eq!(
+            strings("SELECT id FROM artifacts ORDER BY id"),
+            vec!["hardin-a1", "hardin-a2", "larue-a1", "larue-a2"]
+        );
+        let larue_a2 =
+            "SELECT supersedes_json || ' ' || raw_json FROM artifacts WHERE id = 'larue-a2'";
+        assert_eq!(
+            strings(larue_a2),
+            vec![r#"["larue-a1"] {"id":"larue-a2","supersedes":["larue-a1"]}"#]
+        );
+        assert_eq!(
+            strings(
+                "SELECT id || ' ' || body_id || ' ' || artifact_ids_json FROM meetings \
+                 ORDER BY 1 DESC"
+            ),
+            vec![
+                r#"larue-m1 larue-fiscal-court ["larue-a2"]"#,
+                r#"hardin-m1 hardin-fiscal-court ["hardin-a2"]"#,
+            ]
+        );
+        assert_eq!(
+            strings(
+                "SELECT id || ' ' || meeting_id || ' ' || COALESCE(reconsiders_motion_id, '-') \
+                 FROM motions ORDER BY id"
+            ),
+            vec![
+                "hardin-m1-a hardin-m1 -",
+                "hardin-m1-b hardin-m1 hardin-m1-a",
+                "larue-m1-a larue-m1 -",
+                "larue-m1-b larue-m1 larue-m1-a",
+            ]
+        );
+        assert_eq!(
+            strings("SELECT motions_json FROM meetings WHERE id = 'hardin-m1'"),
+            vec![r#"[{"motion_id":"hardin-m1-a","text":"Pave"}]"#]
+        );
+        let evidence: Vec<String> = serde_json::from_str(
+            &strings("SELECT evidence_json FROM decision_scores WHERE id = 'motion:hardin-m1-a'")
+                [0],
+        )
+        .unwrap();
+        assert_eq!(
+            evidence,
+            vec![
+                "tag:roads".to_string(),
+                excerpt("hardin-a2").to_evidence(),
+                "reconsidered_by:hardin-m1-b".to_string(),
+                "untrusted_source:social_media:hardin-a1".to_string(),
+                "not_decision_record:agenda:hardin-a1".to_string(),
+            ]
+        );
+        assert_eq!(
+            strings("SELECT score_id || ' ' || motion_id FROM decision_score_history ORDER BY 1"),
+            vec!["motion:hardin-m1-a hardin-m1-a", "motion:larue-m1-a larue-m1-a"]
+        );
+        assert_eq!(
+            strings("SELECT id || ' ' || name FROM bodies ORDER BY id"),
+            vec![
+                "hardin-fiscal-court Hardin County Fiscal Court",
+                "larue-fiscal-court LaRue County Fiscal Court",
+            ]
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn legacy_flat_refs_are_grouped_on_open() {
+        let path = std::env::temp_dir()
+            .join(format!("larue-db-legacy-refs-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        {
+            let legacy = Connection::open(&path).unwrap();
+            legacy
+                .execute_batch(
+                    r#"
+                    CREATE TABLE decision_scores (
+                      id TEXT PRIMARY KEY,
+                      meeting_id TEXT,
+                      motion_id TEXT,
+                      vote_id TEXT,
+                      overall_score REAL NOT NULL,
+                      axis_json TEXT NOT NULL,
+                      refs_json TEXT NOT NULL,
+                      evidence_json TEXT NOT NULL,
+                      confidence REAL NOT NULL,
+                      flags_json TEXT NOT NULL,
+                      computed_at TEXT NOT NULL
+                    );
+                    INSERT INTO decision_scores VALUES (
+                      's1', 'm1', 'm1-a', NULL, 0.5, '{}',
+                      '["US Const. Amend. I","KY §2"]', '[]', 0.8, '[]',
+                      '2024-03-01T00:00:00Z'
+                    );
+                    "#,
+                )
+                .unwrap();
+        }
+
+        let conn = open(path.to_str().unwrap()).unwrap();
+        let mut scores = Vec::new();
+        for_each_decision_score(&conn, |score| {
+            scores.push(score);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(scores.len(), 1);
+        assert_eq!(
+            scores[0].constitutional_refs,
+            vec![(
+                UNGROUPED_REFS_AXIS.to_string(),
+                vec!["US Const. Amend. I".to_string(), "KY §2".to_string()]
+            )]
+        );
+
+        drop(conn);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn importance_is_backfilled_for_rows_ingested_before_the_column() {
+        let path = std::env::temp_dir()
+            .join(format!("larue-db-legacy-importance-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        {
+            let legacy = Connection::open(&path).unwrap();
+            legacy
+                .execute_batch(
+                    r#"
+                    CREATE TABLE artifacts (
+                      id TEXT PRIMARY KEY,
+                      source_kind TEXT NOT NULL,
+                      source_value TEXT NOT NULL,
+                      retrieved_at TEXT NOT NULL,
+                      title TEXT,
+                      content_type TEXT,
+                      body_text TEXT,
+                      tags_json TEXT NOT NULL,
+                      raw_json TEXT NOT NULL,
+                      inserted_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+                    );
+                    INSERT INTO artifacts (
+                      id, source_kind, source_value, retrieved_at, body_text, tags_json, raw_json
+                    ) VALUES (
+                      'old', 'url', 'https://example.com/old.pdf', '2024-03-01T00:00:00Z',
+                      'Paving bid of $250,000', '["roads","budget"]', '{}'
+                    );
+                    "#,
+                )
+                .unwrap();
+        }
+
+        let conn = open(path.to_str().unwrap()).unwrap();
+        let importance: Option<f64> = conn
+            .query_row("SELECT importance FROM artifacts WHERE id = 'old'", [], |row| row.get(0))
+            .unwrap();
+        let tags = vec!["roads".to_string(), "budget".to_string()];
+        assert_eq!(importance, Some(artifact_importance(&tags, Some("Paving bid of $250,000"))));
+        assert!(importance.unwrap() > 0.0);
+
+        drop(conn);
+        let _ = std::fs::remove_file(&path);
+    }
+}
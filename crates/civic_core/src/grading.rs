@@ -0,0 +1,152 @@
+use crate::scoring::RubricConfig;
+
+/// Letter grade shown for provisional officials under `insufficient_policy = "withhold"`.
+pub const WITHHELD_GRADE: &str = "N/A";
+
+/// Maps a raw rubric score onto 0-100. Without a rubric config the score is assumed to already be
+/// on that scale; a degenerate floor/ceiling range yields the rubric's neutral score.
+pub fn normalize_score(score: f64, rubric_config: Option<&RubricConfig>) -> f64 {
+    let Some(config) = rubric_config else {
+        return score.clamp(0.0, 100.0);
+    };
+    let floor = config.general.score_floor;
+    let ceiling = config.general.score_ceiling;
+    if (ceiling - floor).abs() < f64::EPSILON {
+        return config.general.neutral_score;
+    }
+    let normalized = ((score - floor) / (ceiling - floor)) * 100.0;
+    normalized.clamp(0.0, 100.0)
+}
+
+/// Clamped 0-100 score and its letter grade. Each band's lower bound is inclusive (90.0 is an
+/// `A-`); NaN passes through as the numeric score and grades `F`.
+pub fn score_to_grade(score: f64) -> (f64, String) {
+    let numeric = score.clamp(0.0, 100.0);
+    let grade = match numeric {
+        n if n >= 97.0 => "A+",
+        n if n >= 93.0 => "A",
+        n if n >= 90.0 => "A-",
+        n if n >= 87.0 => "B+",
+        n if n >= 83.0 => "B",
+        n if n >= 80.0 => "B-",
+        n if n >= 77.0 => "C+",
+        n if n >= 73.0 => "C",
+        n if n >= 70.0 => "C-",
+        n if n >= 67.0 => "D+",
+        n if n >= 63.0 => "D",
+        n if n >= 60.0 => "D-",
+        _ => "F",
+    };
+    (numeric, grade.to_string())
+}
+
+/// Orders letter grades from `F` (and anything unrecognized, including `WITHHELD_GRADE`) at 0 up
+/// to `A+` at 12.
+pub fn grade_rank(grade: &str) -> i32 {
+    match grade {
+        "A+" => 12,
+        "A" => 11,
+        "A-" => 10,
+        "B+" => 9,
+        "B" => 8,
+        "B-" => 7,
+        "C+" => 6,
+        "C" => 5,
+        "C-" => 4,
+        "D+" => 3,
+        "D" => 2,
+        "D-" => 1,
+        _ => 0,
+    }
+}
+
+/// CSS band (`a`..`f`, or `withheld`) for a letter grade; `+`/`-` modifiers share their band.
+pub fn grade_class(grade: &str) -> &'static str {
+    if grade == WITHHELD_GRADE {
+        return "withheld";
+    }
+    match grade.chars().next().unwrap_or('F') {
+        'A' => "a",
+        'B' => "b",
+        'C' => "c",
+        'D' => "d",
+        _ => "f",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rubric_config(floor: f64, ceiling: f64, neutral: f64) -> RubricConfig {
+        let mut config: RubricConfig = toml::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../rubric/rubric_config.toml"
+        )))
+        .unwrap();
+        config.general.score_floor = floor;
+        config.general.score_ceiling = ceiling;
+        config.general.neutral_score = neutral;
+        config
+    }
+
+    #[test]
+    fn band_lower_bounds_are_inclusive() {
+        let grade = |score| score_to_grade(score).1;
+        assert_eq!(grade(100.0), "A+");
+        assert_eq!(grade(97.0), "A+");
+        assert_eq!(grade(96.99), "A");
+        assert_eq!(grade(90.0), "A-");
+        assert_eq!(grade(89.999), "B+");
+        assert_eq!(grade(80.0), "B-");
+        assert_eq!(grade(70.0), "C-");
+        assert_eq!(grade(60.0), "D-");
+        assert_eq!(grade(59.99), "F");
+        assert_eq!(grade(0.0), "F");
+    }
+
+    #[test]
+    fn out_of_range_and_nan_scores() {
+        assert_eq!(score_to_grade(-12.5), (0.0, "F".to_string()));
+        assert_eq!(score_to_grade(140.0), (100.0, "A+".to_string()));
+        assert_eq!(score_to_grade(f64::INFINITY), (100.0, "A+".to_string()));
+        let (numeric, letter) = score_to_grade(f64::NAN);
+        assert!(numeric.is_nan());
+        assert_eq!(letter, "F");
+
+        assert_eq!(normalize_score(-5.0, None), 0.0);
+        assert_eq!(normalize_score(105.0, None), 100.0);
+        assert!(normalize_score(f64::NAN, None).is_nan());
+    }
+
+    #[test]
+    fn normalize_maps_rubric_range_onto_percent() {
+        let config = rubric_config(-100.0, 100.0, 0.0);
+        assert_eq!(normalize_score(-100.0, Some(&config)), 0.0);
+        assert_eq!(normalize_score(0.0, Some(&config)), 50.0);
+        assert_eq!(normalize_score(80.0, Some(&config)), 90.0);
+        assert_eq!(normalize_score(250.0, Some(&config)), 100.0);
+        assert_eq!(normalize_score(-250.0, Some(&config)), 0.0);
+        assert_eq!(score_to_grade(normalize_score(80.0, Some(&config))).1, "A-");
+
+        let flat = rubric_config(10.0, 10.0, 42.0);
+        assert_eq!(normalize_score(99.0, Some(&flat)), 42.0);
+    }
+
+    #[test]
+    fn ranks_and_classes_follow_letter_bands() {
+        let letters = ["F", "D-", "D", "D+", "C-", "C", "C+", "B-", "B", "B+", "A-", "A", "A+"];
+        for (rank, letter) in letters.iter().enumerate() {
+            assert_eq!(grade_rank(letter), rank as i32);
+        }
+        assert_eq!(grade_rank(WITHHELD_GRADE), 0);
+        assert_eq!(grade_rank("Z"), 0);
+
+        assert_eq!(grade_class("A+"), "a");
+        assert_eq!(grade_class("B-"), "b");
+        assert_eq!(grade_class("D"), "d");
+        assert_eq!(grade_class("F"), "f");
+        assert_eq!(grade_class(""), "f");
+        assert_eq!(grade_class(WITHHELD_GRADE), "withheld");
+    }
+}
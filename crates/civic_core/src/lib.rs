@@ -1,6 +1,11 @@
 pub mod schema;
 pub mod db;
+pub mod grading;
+pub mod paths;
+pub mod progress;
 pub mod scoring;
+pub mod tags;
+pub mod timezone;
 
 
 pub fn add(left: u64, right: u64) -> u64 {
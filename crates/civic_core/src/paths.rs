@@ -0,0 +1,112 @@
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// Validate a record id for use as a file name stem. Ids come from collector output and the
+/// database, so anything that could leave the target directory (path separators, `..`, NUL or
+/// other control characters) is rejected rather than rewritten, which keeps file names stable
+/// and collision-free.
+pub fn safe_filename(id: &str) -> Result<String> {
+    let unsafe_char = id
+        .chars()
+        .any(|ch| ch == '/' || ch == '\\' || ch.is_control());
+    if id.trim().is_empty() || unsafe_char || id.contains("..") {
+        return Err(anyhow!("Unsafe id for a file name: {id:?}"));
+    }
+    Ok(id.to_string())
+}
+
+/// Write `contents` to `path` without ever exposing a partially written file: the bytes go to a
+/// temporary sibling in the same directory, which is synced and then renamed over the target.
+/// A reader (or a crash) sees either the previous file or the complete new one.
+pub fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> Result<()> {
+    write_atomic_with(path, |writer| Ok(writer.write_all(contents.as_ref())?))
+}
+
+/// `write_atomic` for output produced incrementally: `write` streams into a buffered writer on
+/// the temporary file, and the target is only replaced if it returns `Ok`.
+pub fn write_atomic_with(
+    path: impl AsRef<Path>,
+    write: impl FnOnce(&mut dyn Write) -> Result<()>,
+) -> Result<()> {
+    let path = path.as_ref();
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow!("Cannot write to {}: no file name", path.display()))?;
+    let tmp_path = dir.join(format!(".{name}.tmp-{}", std::process::id()));
+    let result = (|| -> Result<()> {
+        let mut writer = std::io::BufWriter::new(fs::File::create(&tmp_path)?);
+        write(&mut writer)?;
+        let file = writer.into_inner().map_err(|err| err.into_error())?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result.with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Lowercase hex SHA-256 of a file's contents, read in a streaming fashion.
+pub fn file_sha256(path: impl AsRef<Path>) -> Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let path = path.as_ref();
+    let mut file =
+        fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_traversal_ids() {
+        assert!(safe_filename("../../etc/x").is_err());
+        assert!(safe_filename("..").is_err());
+        assert!(safe_filename("a\\..\\b").is_err());
+        assert!(safe_filename("nested/id").is_err());
+        assert!(safe_filename("").is_err());
+        assert!(safe_filename("bad\0id").is_err());
+        assert_eq!(
+            safe_filename("ky_public_notice:road bids").unwrap(),
+            "ky_public_notice:road bids"
+        );
+    }
+
+    #[test]
+    fn write_atomic_replaces_target_without_leftovers() {
+        let dir = std::env::temp_dir().join(format!("larue-write-atomic-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("index.html");
+        write_atomic(&target, "old page").unwrap();
+
+        // A failed write (the temp name is taken by a directory) leaves the old file intact.
+        let blocker = dir.join(format!(".index.html.tmp-{}", std::process::id()));
+        fs::create_dir(&blocker).unwrap();
+        assert!(write_atomic(&target, "new page that never lands").is_err());
+        assert_eq!(fs::read_to_string(&target).unwrap(), "old page");
+        fs::remove_dir(&blocker).unwrap();
+
+        write_atomic(&target, "new page").unwrap();
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new page");
+        let names: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(names, vec![std::ffi::OsString::from("index.html")]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,83 @@
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Loops shorter than this finish quickly enough that progress lines are just noise.
+const MIN_TOTAL: usize = 100;
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Suppress progress output for the rest of the process (the CLI's `--quiet`).
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Count-based progress reporter for long loops. Prints to stderr roughly every 5% of `total`,
+/// and only when stderr is a terminal, so piped output and final summaries are unchanged.
+pub struct Progress {
+    label: String,
+    total: usize,
+    done: usize,
+    every: usize,
+    started: Instant,
+    enabled: bool,
+}
+
+impl Progress {
+    pub fn new(label: &str, total: usize) -> Self {
+        let enabled =
+            total >= MIN_TOTAL && !QUIET.load(Ordering::Relaxed) && std::io::stderr().is_terminal();
+        Self {
+            label: label.to_string(),
+            total,
+            done: 0,
+            every: (total / 20).max(1),
+            started: Instant::now(),
+            enabled,
+        }
+    }
+
+    /// Record one processed item.
+    pub fn tick(&mut self) {
+        self.done += 1;
+        if self.enabled && self.done.is_multiple_of(self.every) && self.done < self.total {
+            eprintln!(
+                "{}",
+                progress_line(&self.label, self.done, self.total, self.started.elapsed())
+            );
+        }
+    }
+}
+
+fn progress_line(label: &str, done: usize, total: usize, elapsed: Duration) -> String {
+    let percent = done * 100 / total.max(1);
+    let remaining = total.saturating_sub(done) as f64;
+    let eta_secs = if done == 0 {
+        0
+    } else {
+        (elapsed.as_secs_f64() / done as f64 * remaining).round() as u64
+    };
+    let eta = if eta_secs >= 60 {
+        format!("{}m{:02}s", eta_secs / 60, eta_secs % 60)
+    } else {
+        format!("{eta_secs}s")
+    };
+    format!("{label}: {done}/{total} ({percent}%), ETA {eta}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_line_reports_count_and_eta() {
+        assert_eq!(
+            progress_line("ingest-dir", 250, 1000, Duration::from_secs(30)),
+            "ingest-dir: 250/1000 (25%), ETA 1m30s"
+        );
+        assert_eq!(
+            progress_line("score-weekly", 90, 100, Duration::from_secs(9)),
+            "score-weekly: 90/100 (90%), ETA 1s"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SourceRef {
@@ -16,6 +17,96 @@ pub struct Artifact {
     pub body_text: Option<String>, // extracted plain text (if available)
     pub content_type: Option<String>, // "text/html", "application/pdf", etc.
     pub tags: Vec<String>,         // lightweight labels from collectors/parsers
+    #[serde(default)]
+    pub supersedes: Vec<String>,   // ids of older artifacts this one amends or replaces
+    #[serde(default)]
+    pub language: Option<String>,  // ISO 639-1 code; missing means DEFAULT_ARTIFACT_LANGUAGE
+    #[serde(default)]
+    pub doc_type: Option<DocType>, // missing means derived when read (see DocType::derive)
+}
+
+/// Language assumed for artifacts that do not set `language`.
+pub const DEFAULT_ARTIFACT_LANGUAGE: &str = "en";
+
+impl Artifact {
+    pub fn language(&self) -> &str {
+        self.language.as_deref().unwrap_or(DEFAULT_ARTIFACT_LANGUAGE)
+    }
+}
+
+/// Doc type for artifacts of a `source.kind` that no tag classifies, unless the rubric's
+/// `[source_doc_types]` maps the kind.
+pub const DEFAULT_SOURCE_DOC_TYPES: &[(&str, DocType)] = &[("public_notice", DocType::Notice)];
+
+/// What kind of record an artifact is. Only minutes (and unclassified documents) record
+/// decisions; agendas announce upcoming items and notices announce hearings or bids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DocType {
+    Agenda,
+    Minutes,
+    Notice,
+    Other,
+}
+
+impl DocType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DocType::Agenda => "agenda",
+            DocType::Minutes => "minutes",
+            DocType::Notice => "notice",
+            DocType::Other => "other",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "agenda" => Some(DocType::Agenda),
+            "minutes" => Some(DocType::Minutes),
+            "notice" => Some(DocType::Notice),
+            "other" => Some(DocType::Other),
+            _ => None,
+        }
+    }
+
+    /// The type named by the (canonical) `minutes`, `agenda`, or `notice`/`public_notice` tags,
+    /// checked in that order. Tag aliases in `rubric/tag_aliases.yaml` can map collector labels
+    /// such as "packet" onto these.
+    pub fn from_tags(tags: &[String]) -> Option<Self> {
+        let has = |names: &[&str]| tags.iter().any(|tag| names.contains(&tag.as_str()));
+        if has(&["minutes"]) {
+            Some(DocType::Minutes)
+        } else if has(&["agenda"]) {
+            Some(DocType::Agenda)
+        } else if has(&["notice", "public_notice"]) {
+            Some(DocType::Notice)
+        } else {
+            None
+        }
+    }
+
+    /// Type of an artifact without an explicit `doc_type`: its tags decide, then its source kind
+    /// through `source_doc_types` and `DEFAULT_SOURCE_DOC_TYPES`; anything else is `Other`.
+    pub fn derive(
+        tags: &[String],
+        source_kind: &str,
+        source_doc_types: &HashMap<String, DocType>,
+    ) -> Self {
+        Self::from_tags(tags)
+            .or_else(|| source_doc_types.get(source_kind).copied())
+            .or_else(|| {
+                DEFAULT_SOURCE_DOC_TYPES
+                    .iter()
+                    .find(|(kind, _)| *kind == source_kind)
+                    .map(|(_, doc_type)| *doc_type)
+            })
+            .unwrap_or(DocType::Other)
+    }
+
+    /// Whether scoring may take the artifact as evidence of what a meeting decided.
+    pub fn is_decision_record(self) -> bool {
+        matches!(self, DocType::Minutes | DocType::Other)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -39,6 +130,31 @@ pub struct Meeting {
     pub started_at: String,
     pub artifact_ids: Vec<String>,
     pub motions: Vec<Motion>,
+    pub parent_meeting_id: Option<String>, // committee meetings feeding a full-body meeting
+    pub source: Option<SourceRef>,         // where the minutes were published, if known
+}
+
+impl Meeting {
+    /// Builds the minutes artifact for a meeting with an embedded `source` and adds its id to
+    /// `artifact_ids`, so collectors do not have to coordinate the link themselves.
+    pub fn link_minutes_artifact(&mut self) -> Option<Artifact> {
+        let source = self.source.clone()?;
+        let artifact = Artifact {
+            id: format!("{}-minutes", self.id),
+            source,
+            title: Some(format!("Minutes: {} {}", self.body_id, self.started_at)),
+            body_text: None,
+            content_type: None,
+            tags: vec!["minutes".to_string()],
+            supersedes: Vec::new(),
+            language: None,
+            doc_type: Some(DocType::Minutes),
+        };
+        if !self.artifact_ids.contains(&artifact.id) {
+            self.artifact_ids.push(artifact.id.clone());
+        }
+        Some(artifact)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -49,6 +165,10 @@ pub struct DecisionMeeting {
     pub started_at: String,
     pub meeting_type: Option<String>,
     pub artifact_ids: Vec<String>,
+    pub parent_meeting_id: Option<String>,
+    /// Chair (e.g. the county judge/executive) who breaks tied votes.
+    #[serde(default)]
+    pub presiding_officer: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -60,6 +180,25 @@ pub struct DecisionMotion {
     pub moved_by: Option<String>,
     pub seconded_by: Option<String>,
     pub result: Option<String>,
+    #[serde(default)]
+    pub amended: bool, // minutes record "motion as amended"
+    #[serde(default)]
+    pub amendment_text: Option<String>,
+    /// Agenda item number the minutes cite (e.g. "4.2" for "Item 4.2 approved"); when the
+    /// motion text is blank, scoring looks the item's description up in the linked agenda.
+    #[serde(default)]
+    pub agenda_ref: Option<String>,
+}
+
+/// Amendment wording to score in place of the original motion, if the motion was amended and
+/// the amendment text is not blank.
+pub fn amended_text(amended: bool, amendment_text: Option<&str>) -> Option<&str> {
+    amendment_text.filter(|text| amended && !text.trim().is_empty())
+}
+
+/// Suffix appended after motion text in reports and notes.
+pub fn amended_marker(amended: bool) -> &'static str {
+    if amended { " (amended)" } else { "" }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -73,9 +212,178 @@ pub struct DecisionVote {
     pub abstain: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PublicComment {
+    pub id: String,
+    pub motion_id: String,
+    pub speaker: Option<String>,
+    pub stance: Option<String>, // e.g. "for", "against", "neutral"
+    pub summary: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DecisionBundle {
     pub meeting: DecisionMeeting,
     pub motions: Vec<DecisionMotion>,
     pub votes: Vec<DecisionVote>,
+    #[serde(default)]
+    pub public_comments: Vec<PublicComment>,
+}
+
+/// Comparison key for motion text: trimmed, inner whitespace collapsed, lowercased.
+pub fn normalize_motion_text(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+impl DecisionBundle {
+    /// Drops motions that repeat an earlier motion's index and normalized text, so a parser that
+    /// emits the same motion twice does not double-count it in scoring. Votes on a dropped motion
+    /// move to the kept motion unless it already has votes; public comments always move.
+    /// Returns `(dropped_id, kept_id)` pairs.
+    pub fn dedup_motions(&mut self) -> Vec<(String, String)> {
+        let mut seen: HashMap<(usize, String), String> = HashMap::new();
+        let mut merged = Vec::new();
+        self.motions.retain(|motion| {
+            let key = (motion.index, normalize_motion_text(&motion.text));
+            match seen.get(&key) {
+                Some(kept) => {
+                    merged.push((motion.id.clone(), kept.clone()));
+                    false
+                }
+                None => {
+                    seen.insert(key, motion.id.clone());
+                    true
+                }
+            }
+        });
+
+        for (dropped, kept) in &merged {
+            if dropped == kept {
+                continue;
+            }
+            let kept_has_votes = self.votes.iter().any(|vote| vote.motion_id == *kept);
+            if kept_has_votes {
+                self.votes.retain(|vote| vote.motion_id != *dropped);
+            }
+            for vote in &mut self.votes {
+                if vote.motion_id == *dropped {
+                    vote.motion_id = kept.clone();
+                }
+            }
+            for comment in &mut self.public_comments {
+                if comment.motion_id == *dropped {
+                    comment.motion_id = kept.clone();
+                }
+            }
+        }
+        merged
+    }
+
+    /// Votes whose `motion_id` matches no motion in this bundle. They would be stored but never
+    /// scored, since vote loading joins on motions.
+    pub fn dangling_votes(&self) -> Vec<&DecisionVote> {
+        self.votes
+            .iter()
+            .filter(|vote| !self.motions.iter().any(|motion| motion.id == vote.motion_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn motion(id: &str, index: usize, text: &str) -> DecisionMotion {
+        DecisionMotion {
+            id: id.to_string(),
+            meeting_id: "m1".to_string(),
+            index,
+            text: text.to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        }
+    }
+
+    fn vote(id: &str, motion_id: &str) -> DecisionVote {
+        DecisionVote {
+            id: id.to_string(),
+            motion_id: motion_id.to_string(),
+            vote_type: None,
+            outcome: None,
+            ayes: vec!["Smith".to_string()],
+            nays: Vec::new(),
+            abstain: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn duplicate_motions_are_merged() {
+        let mut bundle = DecisionBundle {
+            meeting: DecisionMeeting {
+                id: "m1".to_string(),
+                body_id: "larue-fiscal-court".to_string(),
+                body_name: None,
+                started_at: "2024-03-04T18:00:00Z".to_string(),
+                meeting_type: None,
+                artifact_ids: Vec::new(),
+                parent_meeting_id: None,
+                presiding_officer: None,
+            },
+            motions: vec![
+                motion("m1-a", 0, "Approve the  road contract"),
+                motion("m1-b", 0, "  approve the road\ncontract "),
+                motion("m1-c", 1, "Approve the road contract"),
+            ],
+            votes: vec![vote("v-a", "m1-a"), vote("v-b", "m1-b")],
+            public_comments: vec![PublicComment {
+                id: "c1".to_string(),
+                motion_id: "m1-b".to_string(),
+                speaker: None,
+                stance: None,
+                summary: "Supports paving".to_string(),
+            }],
+        };
+
+        let merged = bundle.dedup_motions();
+
+        assert_eq!(merged, vec![("m1-b".to_string(), "m1-a".to_string())]);
+        let ids: Vec<&str> = bundle.motions.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["m1-a", "m1-c"]);
+        let votes: Vec<&str> = bundle.votes.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(votes, vec!["v-a"]);
+        assert_eq!(bundle.public_comments[0].motion_id, "m1-a");
+        assert_eq!(
+            normalize_motion_text("  Approve\tthe   ROAD contract "),
+            "approve the road contract"
+        );
+    }
+
+    #[test]
+    fn dangling_votes_are_reported() {
+        let bundle = DecisionBundle {
+            meeting: DecisionMeeting {
+                id: "m1".to_string(),
+                body_id: "larue-fiscal-court".to_string(),
+                body_name: None,
+                started_at: "2024-03-04T18:00:00Z".to_string(),
+                meeting_type: None,
+                artifact_ids: Vec::new(),
+                parent_meeting_id: None,
+                presiding_officer: None,
+            },
+            motions: vec![motion("m1-a", 0, "Approve the road contract")],
+            votes: vec![vote("v-a", "m1-a"), vote("v-x", "m1-missing")],
+            public_comments: Vec::new(),
+        };
+
+        let dangling: Vec<&str> = bundle.dangling_votes().iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(dangling, vec!["v-x"]);
+    }
 }
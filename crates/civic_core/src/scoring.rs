@@ -1,51 +1,178 @@
+use crate::schema::{Artifact, DocType};
+use crate::tags::{TagAliases, TagRules};
 use anyhow::{anyhow, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
 
 #[derive(Debug, Clone)]
 pub struct ScoreResult {
     pub overall_score: f64,
     pub axis_scores: HashMap<String, f64>,
-    pub constitutional_refs: Vec<String>,
+    /// Each axis's signed, weighted share of the overall score, before clamping.
+    pub contributions: HashMap<String, f64>,
+    pub constitutional_refs: ConstitutionRefs,
     pub evidence: Vec<String>,
     pub confidence: f64,
     pub flags: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+/// A stored score. `canonical_json` is the one JSON shape for it: the database's JSON columns,
+/// the NDJSON export, and the score audit log are all built from it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DecisionScore {
     pub id: String,
     pub meeting_id: Option<String>,
     pub motion_id: Option<String>,
     pub vote_id: Option<String>,
     pub overall_score: f64,
+    #[serde(serialize_with = "serialize_sorted")]
     pub axis_scores: HashMap<String, f64>,
-    pub constitutional_refs: Vec<String>,
+    #[serde(serialize_with = "serialize_sorted")]
+    pub contributions: HashMap<String, f64>,
+    /// Polarity-signed rubric weight applied to each scored axis, so `overall_score` can be
+    /// reproduced as the clamped sum of `axis_scores * axis_weights`.
+    #[serde(default, serialize_with = "serialize_sorted")]
+    pub axis_weights: HashMap<String, f64>,
+    pub constitutional_refs: ConstitutionRefs,
     pub evidence: Vec<String>,
     pub confidence: f64,
     pub flags: Vec<String>,
     pub computed_at: String,
+    pub rubric_version: Option<String>, // Rubric::version_hash of the rubric that produced it
+}
+
+impl DecisionScore {
+    /// Every field under its struct name, with object keys (axis names included) sorted, so the
+    /// same score always serializes to the same bytes.
+    pub fn canonical_json(&self) -> Result<Value> {
+        Ok(serde_json::to_value(self)?)
+    }
 }
 
+fn serialize_sorted<S: serde::Serializer>(
+    map: &HashMap<String, f64>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// Constitutional references grouped by axis, in canonical axis order; each axis's refs are
+/// sorted US before KY and deduplicated.
+pub type ConstitutionRefs = Vec<(String, Vec<String>)>;
+
 #[derive(Debug, Clone)]
 pub struct LinkedArtifact {
     pub id: String,
     pub tags: Vec<String>,
+    pub retrieved_at: String,
+    pub content_type: Option<String>,
+    /// Source text for evidence excerpts; scores do not depend on it.
+    pub body_text: Option<String>,
+    pub source_kind: String,
+    /// Cleared for source kinds the caller does not trust; such an artifact's tags are left out
+    /// of scoring and noted in evidence as `untrusted_source:<kind>:<artifact id>`.
+    pub trusted: bool,
+    /// Agendas and notices are not decision evidence: their tags are left out of scoring and
+    /// noted as `not_decision_record:<doc type>:<artifact id>`.
+    pub doc_type: DocType,
+}
+
+/// The sentence of a linked artifact that matched an issue tag, kept in a score's evidence as
+/// `excerpt:` followed by this struct as JSON, so readers can see what triggered the tag. JSON
+/// keeps artifact ids containing `:` (such as `ky_public_notice:<query>`) intact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvidenceExcerpt {
+    pub tag: String,
+    pub artifact_id: String,
+    pub text: String,
+}
+
+impl EvidenceExcerpt {
+    pub fn to_evidence(&self) -> String {
+        let json = serde_json::to_string(self).expect("excerpt fields are plain strings");
+        format!("excerpt:{json}")
+    }
+
+    /// Also reads the older `excerpt:<tag>:<artifact id>:<text>` form stored before excerpts
+    /// were JSON-encoded.
+    pub fn from_evidence(entry: &str) -> Option<Self> {
+        let payload = entry.strip_prefix("excerpt:")?;
+        if payload.starts_with('{') {
+            return serde_json::from_str(payload).ok();
+        }
+        let mut parts = payload.splitn(3, ':');
+        Some(Self {
+            tag: parts.next()?.to_string(),
+            artifact_id: parts.next()?.to_string(),
+            text: parts.next()?.to_string(),
+        })
+    }
+}
+
+impl LinkedArtifact {
+    /// Text recovered by OCR from a scanned PDF, which is more error-prone than born-digital
+    /// text.
+    pub fn is_ocr_sourced(&self) -> bool {
+        let is_pdf = self
+            .content_type
+            .as_deref()
+            .is_some_and(|value| value.to_ascii_lowercase().contains("pdf"));
+        is_pdf && self.tags.iter().any(|tag| tag == "ocr")
+    }
+}
+
+/// Motions in a meeting with scorable evidence, out of all its motions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvidenceCoverage {
+    pub covered: usize,
+    pub total: usize,
+}
+
+impl EvidenceCoverage {
+    /// Whole percent covered; `None` for a meeting without motions.
+    pub fn percent(&self) -> Option<usize> {
+        (self.total > 0).then(|| (self.covered * 100 + self.total / 2) / self.total)
+    }
+
+    /// "Evidence coverage: 50% (1 of 2 motions)", or `None` without motions.
+    pub fn label(&self) -> Option<String> {
+        let percent = self.percent()?;
+        let noun = if self.total == 1 { "motion" } else { "motions" };
+        Some(format!(
+            "Evidence coverage: {percent}% ({} of {} {noun})",
+            self.covered, self.total
+        ))
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Rubric {
     pub config: RubricConfig,
+    /// Canonical axis list: the `weights.yaml` `axis_weights` keys in file order. Scoring,
+    /// constitution references, and per-axis site rendering all iterate this list.
+    pub axes: Vec<String>,
     pub axis_weights: HashMap<String, f64>,
+    /// Issue tags that put a motion on each axis (`weights.yaml` `axis_tags`).
+    pub axis_tags: HashMap<String, Vec<String>>,
+    pub axis_polarity: HashMap<String, AxisPolarity>,
     pub scoring_rules: ScoringRules,
     pub evidence_rules: EvidenceRules,
     pub bias_controls: BiasControls,
     pub us_constitution: HashMap<String, Vec<String>>,
     pub ky_constitution: HashMap<String, Vec<String>>,
     pub rubric_tags: Vec<String>,
+    pub tag_aliases: TagAliases,
+    /// Native tagger phrases (`tag_rules.yaml`, optional here), used only to find excerpts.
+    pub tag_rules: TagRules,
+    /// SHA-256 over the rubric input files; changes whenever any scoring input changes.
+    pub version_hash: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -53,6 +180,37 @@ pub struct RubricConfig {
     pub general: RubricGeneral,
     pub evidence: RubricEvidence,
     pub output: RubricOutput,
+    /// `[axis_bounds.<axis>]` ranges overriding the general floor/ceiling for one axis.
+    #[serde(default)]
+    pub axis_bounds: HashMap<String, AxisBounds>,
+    /// `[source_doc_types]`: doc type per artifact `source.kind` for artifacts that neither set
+    /// `doc_type` nor carry a classifying tag; checked before `DEFAULT_SOURCE_DOC_TYPES`.
+    #[serde(default)]
+    pub source_doc_types: HashMap<String, DocType>,
+}
+
+impl RubricConfig {
+    /// The `(floor, ceiling)` an axis score is clamped to before weighting; ends not set in
+    /// `axis_bounds` fall back to `general.score_floor` / `general.score_ceiling`.
+    pub fn axis_range(&self, axis: &str) -> (f64, f64) {
+        let bounds = self.axis_bounds.get(axis);
+        (
+            bounds
+                .and_then(|bounds| bounds.floor)
+                .unwrap_or(self.general.score_floor),
+            bounds
+                .and_then(|bounds| bounds.ceiling)
+                .unwrap_or(self.general.score_ceiling),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AxisBounds {
+    #[serde(default)]
+    pub floor: Option<f64>,
+    #[serde(default)]
+    pub ceiling: Option<f64>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -66,6 +224,16 @@ pub struct RubricGeneral {
 pub struct RubricEvidence {
     pub minimum_confidence: f64,
     pub unknown_penalty: f64,
+    /// Days after which an artifact's tag contribution is halved; unset disables decay.
+    #[serde(default)]
+    pub freshness_half_life_days: Option<f64>,
+    /// Confidence multiplier for scores whose only linked artifacts are OCR'd PDFs.
+    #[serde(default = "default_ocr_confidence_factor")]
+    pub ocr_confidence_factor: f64,
+}
+
+fn default_ocr_confidence_factor() -> f64 {
+    0.5
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -74,9 +242,32 @@ pub struct RubricOutput {
     pub include_axis_breakdown: bool,
 }
 
+/// Whether a high raw score on an axis raises (`positive`) or lowers (`negative`) the overall
+/// score. Axes missing from `weights.yaml` `axis_polarity` are positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AxisPolarity {
+    #[default]
+    Positive,
+    Negative,
+}
+
+impl AxisPolarity {
+    pub fn sign(self) -> f64 {
+        match self {
+            AxisPolarity::Positive => 1.0,
+            AxisPolarity::Negative => -1.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct WeightsFile {
     axis_weights: HashMap<String, f64>,
+    #[serde(default)]
+    axis_polarity: HashMap<String, AxisPolarity>,
+    #[serde(default)]
+    axis_tags: HashMap<String, Vec<String>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -91,6 +282,20 @@ pub struct ScoringRules {
     pub abstain_penalty: f64,
     pub absent_penalty: f64,
     pub unknown_motion_penalty: f64,
+    pub unanimity_modifier: f64,
+    pub close_vote_modifier: f64,
+    /// Weight of a vote in an official's grade by `DecisionVote.vote_type` (e.g. procedural
+    /// votes count less than final ones); unlisted types weigh 1.0.
+    pub vote_type_weights: HashMap<String, f64>,
+}
+
+impl ScoringRules {
+    pub fn vote_type_weight(&self, vote_type: Option<&str>) -> f64 {
+        vote_type
+            .and_then(|vote_type| self.vote_type_weights.get(vote_type))
+            .copied()
+            .unwrap_or(1.0)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -102,17 +307,32 @@ pub enum VoteEffect {
 #[derive(Debug, Clone, Deserialize)]
 struct ScoringRulesFile {
     rules: HashMap<String, ScoringRuleEntry>,
+    #[serde(default)]
+    vote_type_weights: HashMap<String, f64>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 struct ScoringRuleEntry {
     effect: Option<String>,
     penalty: Option<f64>,
+    modifier: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct EvidenceRules {
     pub minimum_confidence: f64,
+    /// Per-axis overrides of `minimum_confidence` (`axis_minimum_confidence` in
+    /// `evidence_rules.yaml`) for axes that need stronger evidence.
+    pub axis_minimum_confidence: HashMap<String, f64>,
+}
+
+impl EvidenceRules {
+    pub fn minimum_confidence_for(&self, axis: &str) -> f64 {
+        self.axis_minimum_confidence
+            .get(axis)
+            .copied()
+            .unwrap_or(self.minimum_confidence)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -128,11 +348,15 @@ struct EvidenceRequirements {
 #[derive(Debug, Clone, Deserialize)]
 struct EvidenceMotionRequirements {
     minimum_confidence: f64,
+    #[serde(default)]
+    axis_minimum_confidence: HashMap<String, f64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct BiasControls {
     pub spending_bias_penalty: f64,
+    /// Apply the spending penalty once per matching spending tag instead of once per motion.
+    pub spending_bias_per_tag: bool,
     pub drift_threshold: f64,
     pub drift_window: usize,
 }
@@ -149,6 +373,7 @@ struct BiasControlEntry {
     modifier: Option<f64>,
     threshold: Option<f64>,
     window: Option<usize>,
+    per_tag: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -158,21 +383,77 @@ struct ConstitutionMapEntry {
     principles: Option<Vec<String>>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
-enum ConstitutionMapValue {
-    Entry(ConstitutionMapEntry),
-    Score(f64),
-}
-
+/// A constitution map: the file's `version` scalar, which is accepted but not read, and one
+/// entry per axis.
 #[derive(Debug, Clone, Deserialize)]
 struct ConstitutionMapFile {
+    #[serde(default, rename = "version")]
+    _version: Option<serde::de::IgnoredAny>,
     #[serde(flatten)]
-    axes: HashMap<String, ConstitutionMapValue>,
+    axes: HashMap<String, ConstitutionMapEntry>,
+}
+
+/// Modification time and length of each rubric input file; `None` for missing optional files.
+type RubricFingerprint = Vec<Option<(SystemTime, u64)>>;
+
+type RubricCache = Mutex<HashMap<PathBuf, (RubricFingerprint, Arc<Rubric>)>>;
+
+fn rubric_cache() -> &'static RubricCache {
+    static CACHE: OnceLock<RubricCache> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+#[cfg(test)]
+thread_local! {
+    static RUBRIC_PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+fn rubric_fingerprint(dir: &Path) -> RubricFingerprint {
+    RUBRIC_INPUT_FILES
+        .iter()
+        .map(|name| {
+            let meta = fs::metadata(dir.join(name)).ok()?;
+            Some((meta.modified().ok()?, meta.len()))
+        })
+        .collect()
 }
 
 impl Rubric {
+    /// Loads the rubric once per process and directory, reparsing only when an input file's
+    /// modification time or size changes. Safe to call from multiple threads.
+    pub fn load_cached(path: &Path) -> Result<Arc<Self>> {
+        let key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let fingerprint = rubric_fingerprint(&key);
+        let mut cache = rubric_cache()
+            .lock()
+            .map_err(|_| anyhow!("Rubric cache lock poisoned"))?;
+        if let Some((cached_fingerprint, rubric)) = cache.get(&key)
+            && *cached_fingerprint == fingerprint
+        {
+            return Ok(Arc::clone(rubric));
+        }
+        let rubric = Arc::new(Self::load_from_dir(&key)?);
+        cache.insert(key, (fingerprint, Arc::clone(&rubric)));
+        Ok(rubric)
+    }
+
+    /// A body's own rubric: `<base>/<body_id>/` when that directory holds a
+    /// `rubric_config.toml`, replacing the base rubric as a whole (every input file is read from
+    /// it). `None` means the body is scored under the base rubric. Cached like `load_cached`.
+    pub fn load_for_body(base: &Path, body_id: &str) -> Result<Option<Arc<Self>>> {
+        let Ok(dir_name) = crate::paths::safe_filename(body_id) else {
+            return Ok(None);
+        };
+        let dir = base.join(dir_name);
+        if !dir.join("rubric_config.toml").is_file() {
+            return Ok(None);
+        }
+        Self::load_cached(&dir).map(Some)
+    }
+
     pub fn load_from_dir(path: &Path) -> Result<Self> {
+        #[cfg(test)]
+        RUBRIC_PARSES.with(|count| count.set(count.get() + 1));
         let config_path = path.join("rubric_config.toml");
         let config_str = fs::read_to_string(&config_path)?;
         let config: RubricConfig = toml::from_str(&config_str)?;
@@ -180,6 +461,15 @@ impl Rubric {
         let weights_path = path.join("weights.yaml");
         let weights_str = fs::read_to_string(&weights_path)?;
         let weights: WeightsFile = serde_yaml::from_str(&weights_str)?;
+        let axes = axis_order(&weights_str)?;
+        for axis in weights.axis_polarity.keys().chain(weights.axis_tags.keys()) {
+            if !weights.axis_weights.contains_key(axis) {
+                return Err(anyhow!(
+                    "Axis {axis} in {} has no entry in axis_weights",
+                    weights_path.display()
+                ));
+            }
+        }
 
         let scoring_rules_path = path.join("scoring_rules.yaml");
         let scoring_rules_str = fs::read_to_string(&scoring_rules_path)?;
@@ -189,6 +479,15 @@ impl Rubric {
         let evidence_rules_path = path.join("evidence_rules.yaml");
         let evidence_rules_str = fs::read_to_string(&evidence_rules_path)?;
         let evidence_file: EvidenceRulesFile = serde_yaml::from_str(&evidence_rules_str)?;
+        let motion_requirements = evidence_file.requirements.motion_scoring;
+        for axis in motion_requirements.axis_minimum_confidence.keys() {
+            if !weights.axis_weights.contains_key(axis) {
+                return Err(anyhow!(
+                    "Axis {axis} in {} has no entry in axis_weights",
+                    evidence_rules_path.display()
+                ));
+            }
+        }
 
         let bias_controls_path = path.join("bias_controls.yaml");
         let bias_controls_str = fs::read_to_string(&bias_controls_path)?;
@@ -201,20 +500,85 @@ impl Rubric {
 
         let us_constitution = load_constitution_map(&path.join("us_constitution_map.yaml"))?;
         let ky_constitution = load_constitution_map(&path.join("kentucky_constitution_map.yaml"))?;
+        let tag_aliases = TagAliases::load_from_dir(path)?;
+        let tag_rules = if path.join("tag_rules.yaml").exists() {
+            TagRules::load_from_dir(path)?
+        } else {
+            TagRules::default()
+        };
+        let version_hash = rubric_version_hash(path)?;
 
         Ok(Self {
             config,
+            axes,
             axis_weights: weights.axis_weights,
+            axis_tags: weights.axis_tags,
+            axis_polarity: weights.axis_polarity,
             scoring_rules,
             evidence_rules: EvidenceRules {
-                minimum_confidence: evidence_file.requirements.motion_scoring.minimum_confidence,
+                minimum_confidence: motion_requirements.minimum_confidence,
+                axis_minimum_confidence: motion_requirements.axis_minimum_confidence,
             },
             bias_controls,
             us_constitution,
             ky_constitution,
             rubric_tags: tags_file.tags,
+            tag_aliases,
+            tag_rules,
+            version_hash,
         })
     }
+
+    /// Canonical axes whose `axis_tags` list contains `tag`, in axis-list order.
+    pub fn axes_for_tag(&self, tag: &str) -> Vec<&str> {
+        self.axes
+            .iter()
+            .filter(|axis| {
+                self.axis_tags
+                    .get(*axis)
+                    .is_some_and(|tags| tags.iter().any(|mapped| mapped == tag))
+            })
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Files read by `Rubric::load_from_dir`; `tag_aliases.yaml` and `tag_rules.yaml` are optional.
+const RUBRIC_INPUT_FILES: &[&str] = &[
+    "rubric_config.toml",
+    "weights.yaml",
+    "scoring_rules.yaml",
+    "evidence_rules.yaml",
+    "bias_controls.yaml",
+    "tags.yaml",
+    "us_constitution_map.yaml",
+    "kentucky_constitution_map.yaml",
+    "tag_aliases.yaml",
+    "tag_rules.yaml",
+];
+
+/// Stable hash of the rubric input files (name and contents, in a fixed order). Documentation
+/// such as the rubric README does not affect it.
+pub fn rubric_version_hash(dir: &Path) -> Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    for name in RUBRIC_INPUT_FILES {
+        let path = dir.join(name);
+        if !path.exists() {
+            continue;
+        }
+        hasher.update(name.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(fs::read(&path)?);
+        hasher.update([0u8]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Short form of a rubric version hash for display ("scored under rubric 1a2b3c4d5e6f").
+pub fn short_rubric_version(hash: &str) -> &str {
+    &hash[..hash.len().min(12)]
 }
 
 fn parse_scoring_rules(file: ScoringRulesFile) -> Result<ScoringRules> {
@@ -243,6 +607,16 @@ fn parse_scoring_rules(file: ScoringRulesFile) -> Result<ScoringRules> {
         .get("unknown_motion")
         .and_then(|entry| entry.penalty)
         .unwrap_or(0.0);
+    let unanimity = file
+        .rules
+        .get("unanimous_vote")
+        .and_then(|entry| entry.modifier)
+        .unwrap_or(1.0);
+    let close_vote = file
+        .rules
+        .get("close_vote")
+        .and_then(|entry| entry.modifier)
+        .unwrap_or(1.0);
 
     Ok(ScoringRules {
         vote_yes_effect: parse_vote_effect(vote_yes)?,
@@ -250,6 +624,9 @@ fn parse_scoring_rules(file: ScoringRulesFile) -> Result<ScoringRules> {
         abstain_penalty: abstain,
         absent_penalty: absent,
         unknown_motion_penalty: unknown,
+        unanimity_modifier: unanimity,
+        close_vote_modifier: close_vote,
+        vote_type_weights: file.vote_type_weights,
     })
 }
 
@@ -262,11 +639,9 @@ fn parse_vote_effect(value: &str) -> Result<VoteEffect> {
 }
 
 fn parse_bias_controls(file: &BiasControlsFile) -> BiasControls {
-    let spending = file
-        .controls
-        .get("spending_bias")
-        .and_then(|entry| entry.penalty)
-        .unwrap_or(0.0);
+    let spending_bias = file.controls.get("spending_bias");
+    let spending = spending_bias.and_then(|entry| entry.penalty).unwrap_or(0.0);
+    let spending_per_tag = spending_bias.and_then(|entry| entry.per_tag).unwrap_or(false);
     let drift_threshold = file
         .controls
         .get("drift_threshold")
@@ -279,6 +654,7 @@ fn parse_bias_controls(file: &BiasControlsFile) -> BiasControls {
         .unwrap_or(20);
     BiasControls {
         spending_bias_penalty: spending,
+        spending_bias_per_tag: spending_per_tag,
         drift_threshold,
         drift_window,
     }
@@ -289,13 +665,6 @@ fn load_constitution_map(path: &PathBuf) -> Result<HashMap<String, Vec<String>>>
     let parsed: ConstitutionMapFile = serde_yaml::from_str(&raw)?;
     let mut map = HashMap::new();
     for (axis, entry) in parsed.axes {
-        let entry = match entry {
-            ConstitutionMapValue::Entry(entry) => entry,
-            ConstitutionMapValue::Score(_) => {
-                map.insert(axis, Vec::new());
-                continue;
-            }
-        };
         let mut refs = Vec::new();
         if let Some(amendments) = entry.amendments {
             refs.extend(amendments.into_iter().map(|value| format!("Amendment {value}")));
@@ -314,9 +683,10 @@ fn load_constitution_map(path: &PathBuf) -> Result<HashMap<String, Vec<String>>>
 pub fn compute_motion_score(
     motion_text: &str,
     linked_artifacts: &[LinkedArtifact],
+    meeting_started_at: &str,
     rubric: &Rubric,
 ) -> ScoreResult {
-    let (issue_tags, evidence) = collect_issue_tags(linked_artifacts, rubric);
+    let (issue_tags, evidence) = collect_issue_tags(linked_artifacts, meeting_started_at, rubric);
     let mut axis_scores: HashMap<String, f64> = HashMap::new();
     let mut flags = Vec::new();
     let mut evidence_list = evidence;
@@ -334,13 +704,42 @@ pub fn compute_motion_score(
         &mut axis_scores,
         &mut evidence_list,
     );
+    clamp_axis_scores(&mut axis_scores, &rubric.config, &mut evidence_list);
 
-    let mut overall_score = weighted_overall(&axis_scores, &rubric.axis_weights);
+    let mut contributions =
+        weighted_contributions(&axis_scores, &rubric.axis_weights, &rubric.axis_polarity);
+    let mut overall_score = contributions.values().sum();
 
-    if axis_scores.values().all(|value| value.abs() < f64::EPSILON) {
+    // "No document" is reported separately from "the document had nothing scorable"; an agenda
+    // alone is no record of the decision.
+    if !linked_artifacts.iter().any(|artifact| artifact.doc_type.is_decision_record()) {
+        flags.push("no_source_artifacts".to_string());
+        overall_score = rubric.config.general.neutral_score;
+        confidence = 0.0;
+        contributions.clear();
+    } else if axis_scores.values().all(|value| value.abs() < f64::EPSILON) {
         flags.push("insufficient_evidence".to_string());
         overall_score = rubric.config.general.neutral_score;
         confidence = 0.0;
+        contributions.clear();
+    }
+    if confidence > 0.0 && linked_artifacts.iter().all(LinkedArtifact::is_ocr_sourced) {
+        flags.push("ocr_evidence".to_string());
+        confidence *= rubric.config.evidence.ocr_confidence_factor;
+    }
+    // Scores already neutralized for missing evidence carry zero confidence and their own flag.
+    if confidence > 0.0 {
+        let mut weak_axes: Vec<&String> = axis_scores
+            .keys()
+            .filter(|axis| confidence < rubric.evidence_rules.minimum_confidence_for(axis))
+            .collect();
+        weak_axes.sort();
+        flags.extend(weak_axes.into_iter().map(|axis| format!("below_axis_confidence:{axis}")));
+    }
+    // Blank text usually means the parser lost the motion; the artifact tags alone may not be
+    // about it.
+    if motion_text.trim().is_empty() {
+        flags.push("empty_motion_text".to_string());
     }
 
     overall_score = clamp_score(
@@ -350,7 +749,7 @@ pub fn compute_motion_score(
     );
     overall_score = round_score(overall_score, rubric.config.output.rounding);
 
-    for value in axis_scores.values_mut() {
+    for value in axis_scores.values_mut().chain(contributions.values_mut()) {
         *value = round_score(*value, rubric.config.output.rounding);
     }
 
@@ -359,6 +758,7 @@ pub fn compute_motion_score(
     ScoreResult {
         overall_score,
         axis_scores,
+        contributions,
         constitutional_refs,
         evidence: evidence_list,
         confidence,
@@ -366,11 +766,25 @@ pub fn compute_motion_score(
     }
 }
 
+/// A motion has scorable evidence when a linked artifact carries an issue or axis tag and the
+/// motion has text. Artifacts are linked per meeting, so blank-text motions are the ones whose
+/// tags cannot be tied to what was decided (see `empty_motion_text`).
+pub fn has_scorable_evidence(
+    motion_text: &str,
+    linked_artifacts: &[LinkedArtifact],
+    meeting_started_at: &str,
+    rubric: &Rubric,
+) -> bool {
+    !motion_text.trim().is_empty()
+        && !collect_issue_tags(linked_artifacts, meeting_started_at, rubric).0.is_empty()
+}
+
 pub fn compute_vote_score(vote: &Value, rubric: &Rubric) -> ScoreResult {
     let mut score = ScoreResult {
         overall_score: rubric.config.general.neutral_score,
         axis_scores: HashMap::new(),
-        constitutional_refs: Vec::new(),
+        contributions: HashMap::new(),
+        constitutional_refs: ConstitutionRefs::new(),
         evidence: vec!["vote_without_motion".to_string()],
         confidence: 0.0,
         flags: vec!["insufficient_evidence".to_string()],
@@ -389,10 +803,11 @@ pub fn compute_vote_score(vote: &Value, rubric: &Rubric) -> ScoreResult {
 pub fn compute_vote_score_with_motion(
     motion_score: &ScoreResult,
     vote_choice: VoteChoice,
+    tally: &VoteTally,
     rubric: &Rubric,
 ) -> ScoreResult {
     let mut axis_scores = motion_score.axis_scores.clone();
-    let evidence = vec![format!("vote_choice:{vote_choice}")];
+    let mut evidence = vec![format!("vote_choice:{vote_choice}")];
     let mut flags = Vec::new();
 
     match vote_choice {
@@ -408,7 +823,29 @@ pub fn compute_vote_score_with_motion(
         }
     }
 
-    let mut overall_score = weighted_overall(&axis_scores, &rubric.axis_weights);
+    if tally.is_unanimous() {
+        evidence.push("unanimous".to_string());
+        apply_modifier(&mut axis_scores, rubric.scoring_rules.unanimity_modifier);
+    } else if tally.is_close() {
+        evidence.push("close_vote".to_string());
+        apply_modifier(&mut axis_scores, rubric.scoring_rules.close_vote_modifier);
+    }
+    if tally.ayes + tally.nays > 0 {
+        evidence.push(format!("vote_tally:{}-{}", tally.ayes, tally.nays));
+    }
+    // Receipts are built from vote scores, so they carry the motion's excerpts.
+    evidence.extend(
+        motion_score
+            .evidence
+            .iter()
+            .filter(|entry| entry.starts_with("excerpt:"))
+            .cloned(),
+    );
+    clamp_axis_scores(&mut axis_scores, &rubric.config, &mut evidence);
+
+    let mut contributions =
+        weighted_contributions(&axis_scores, &rubric.axis_weights, &rubric.axis_polarity);
+    let mut overall_score = contributions.values().sum();
     overall_score = clamp_score(
         overall_score,
         rubric.config.general.score_floor,
@@ -416,26 +853,84 @@ pub fn compute_vote_score_with_motion(
     );
     overall_score = round_score(overall_score, rubric.config.output.rounding);
 
-    for value in axis_scores.values_mut() {
+    for value in axis_scores.values_mut().chain(contributions.values_mut()) {
         *value = round_score(*value, rubric.config.output.rounding);
     }
 
     let constitutional_refs = build_constitution_refs(&axis_scores, rubric);
 
-    if axis_scores.values().all(|value| value.abs() < f64::EPSILON) {
+    if motion_score.flags.iter().any(|flag| flag == "no_source_artifacts") {
+        flags.push("no_source_artifacts".to_string());
+    } else if axis_scores.values().all(|value| value.abs() < f64::EPSILON) {
         flags.push("insufficient_evidence".to_string());
     }
+    if motion_score.flags.iter().any(|flag| flag == "empty_motion_text") {
+        flags.push("empty_motion_text".to_string());
+    }
+    let mut confidence = 1.0;
+    if motion_score.flags.iter().any(|flag| flag == "ocr_evidence") {
+        flags.push("ocr_evidence".to_string());
+        confidence *= rubric.config.evidence.ocr_confidence_factor;
+    }
+    flags.extend(
+        motion_score
+            .flags
+            .iter()
+            .filter(|flag| flag.starts_with("below_axis_confidence:"))
+            .cloned(),
+    );
 
     ScoreResult {
         overall_score,
         axis_scores,
+        contributions,
         constitutional_refs,
         evidence,
-        confidence: 1.0,
+        confidence,
         flags,
     }
 }
 
+/// Aye/nay counts for one recorded vote; abstentions do not count toward the margin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VoteTally {
+    pub ayes: usize,
+    pub nays: usize,
+}
+
+impl VoteTally {
+    pub fn margin(&self) -> usize {
+        self.ayes.abs_diff(self.nays)
+    }
+
+    pub fn is_unanimous(&self) -> bool {
+        (self.ayes == 0) != (self.nays == 0)
+    }
+
+    pub fn is_tie(&self) -> bool {
+        self.ayes > 0 && self.ayes == self.nays
+    }
+
+    /// Decided by a single vote, or tied.
+    pub fn is_close(&self) -> bool {
+        self.ayes + self.nays > 1 && self.margin() <= 1
+    }
+
+    /// Display label such as "Unanimous 7-0", "Split 4-3", or "Tied 3-3".
+    pub fn label(&self) -> Option<String> {
+        let counts = format!("{}-{}", self.ayes, self.nays);
+        if self.ayes + self.nays == 0 {
+            None
+        } else if self.is_unanimous() {
+            Some(format!("Unanimous {counts}"))
+        } else if self.is_tie() {
+            Some(format!("Tied {counts}"))
+        } else {
+            Some(format!("Split {counts}"))
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum VoteChoice {
     Aye,
@@ -456,28 +951,131 @@ impl std::fmt::Display for VoteChoice {
     }
 }
 
+/// Continuous importance signal for an artifact, combining issue tags, dollar amounts found in
+/// the extracted text, and document length. Higher means more likely to matter to readers.
+pub fn compute_artifact_importance(artifact: &Artifact) -> f64 {
+    artifact_importance(&artifact.tags, artifact.body_text.as_deref())
+}
+
+/// `compute_artifact_importance` from an artifact's tags and body text alone, for stored rows.
+pub fn artifact_importance(tags: &[String], body_text: Option<&str>) -> f64 {
+    let issue_tags = tags
+        .iter()
+        .filter(|tag| is_issue_tag(tag))
+        .count()
+        .min(5) as f64;
+    let body = body_text.unwrap_or("");
+    let largest_amount = extract_dollar_amounts(body)
+        .into_iter()
+        .fold(0.0, f64::max);
+    let money = (largest_amount + 1.0).log10();
+    let length = (body.chars().count() as f64 + 1.0).log10() / 2.0;
+    round_score(issue_tags + money + length, 2)
+}
+
+/// Dollar amounts written as `$N`, allowing thousands separators and cents (e.g. `$1,250.50`).
+pub fn extract_dollar_amounts(text: &str) -> Vec<f64> {
+    let mut amounts = Vec::new();
+    let mut rest = text;
+    while let Some(pos) = rest.find('$') {
+        rest = &rest[pos + 1..];
+        let digits: String = rest
+            .chars()
+            .take_while(|ch| ch.is_ascii_digit() || *ch == ',' || *ch == '.')
+            .collect();
+        let cleaned = digits.trim_end_matches(['.', ',']).replace(',', "");
+        if let Ok(value) = cleaned.parse::<f64>() {
+            amounts.push(value);
+        }
+    }
+    amounts
+}
+
+/// Issue tags paired with the freshest evidence weight among the artifacts carrying them.
+/// Each tag also gets an excerpt from the first carrying artifact whose text mentions it.
 fn collect_issue_tags(
     linked_artifacts: &[LinkedArtifact],
+    meeting_started_at: &str,
     rubric: &Rubric,
-) -> (Vec<String>, Vec<String>) {
-    let mut tags = Vec::new();
+) -> (Vec<(String, f64)>, Vec<String>) {
+    let mut tags: Vec<(String, f64)> = Vec::new();
     let mut evidence = Vec::new();
+    let mut excerpts: Vec<EvidenceExcerpt> = Vec::new();
     for artifact in linked_artifacts {
-        for tag in &artifact.tags {
-            if is_issue_tag(tag) && !tags.contains(tag) {
-                tags.push(tag.to_string());
-                evidence.push(format!("tag:{tag}"));
+        if !artifact.trusted {
+            evidence.push(format!("untrusted_source:{}:{}", artifact.source_kind, artifact.id));
+            continue;
+        }
+        if !artifact.doc_type.is_decision_record() {
+            evidence.push(format!(
+                "not_decision_record:{}:{}",
+                artifact.doc_type.as_str(),
+                artifact.id
+            ));
+            continue;
+        }
+        let freshness = evidence_freshness(
+            &artifact.retrieved_at,
+            meeting_started_at,
+            rubric.config.evidence.freshness_half_life_days,
+        );
+        for tag in &rubric.tag_aliases.canonicalize(&artifact.tags) {
+            if is_issue_tag(tag) || !rubric.axes_for_tag(tag).is_empty() {
+                match tags.iter_mut().find(|(existing, _)| existing == tag) {
+                    Some((_, weight)) => *weight = weight.max(freshness),
+                    None => {
+                        tags.push((tag.to_string(), freshness));
+                        evidence.push(format!("tag:{tag}"));
+                    }
+                }
+                if !excerpts.iter().any(|excerpt| &excerpt.tag == tag)
+                    && let Some(text) = artifact
+                        .body_text
+                        .as_deref()
+                        .and_then(|body| rubric.tag_rules.excerpt(tag, body))
+                {
+                    excerpts.push(EvidenceExcerpt {
+                        tag: tag.to_string(),
+                        artifact_id: artifact.id.clone(),
+                        text,
+                    });
+                }
             }
             if rubric.rubric_tags.iter().any(|rubric_tag| rubric_tag == tag) {
                 evidence.push(format!("rubric_tag:{tag}"));
             }
         }
     }
+    for (tag, weight) in &tags {
+        if *weight < 1.0 {
+            evidence.push(format!("freshness:{tag}:{weight:.2}"));
+        }
+    }
+    evidence.extend(excerpts.iter().map(EvidenceExcerpt::to_evidence));
     (tags, evidence)
 }
 
+/// Exponential decay by the retrieval-to-meeting gap; 1.0 when disabled or unparseable.
+fn evidence_freshness(
+    retrieved_at: &str,
+    meeting_started_at: &str,
+    half_life_days: Option<f64>,
+) -> f64 {
+    let Some(half_life) = half_life_days.filter(|value| *value > 0.0) else {
+        return 1.0;
+    };
+    let (Ok(retrieved), Ok(meeting)) = (
+        OffsetDateTime::parse(retrieved_at, &Rfc3339),
+        OffsetDateTime::parse(meeting_started_at, &Rfc3339),
+    ) else {
+        return 1.0;
+    };
+    let gap_days = (meeting - retrieved).abs().as_seconds_f64() / 86_400.0;
+    0.5f64.powf(gap_days / half_life)
+}
+
 fn apply_tag_axis_scores(
-    issue_tags: &[String],
+    issue_tags: &[(String, f64)],
     motion_text: &str,
     rubric: &Rubric,
     axis_scores: &mut HashMap<String, f64>,
@@ -485,65 +1083,142 @@ fn apply_tag_axis_scores(
 ) {
     let spending_keywords = ["appropriation", "budget", "tax", "bond", "contract", "bid"];
     let lowered = motion_text.to_lowercase();
-    for tag in issue_tags {
-        let axes = tag_axes(tag);
-        for axis in axes {
+    let spending_motion = !lowered.trim().is_empty()
+        && spending_keywords.iter().any(|keyword| lowered.contains(keyword));
+    // Unless `per_tag` is set, the penalty applies once, weighted by the freshest spending tag.
+    let mut spending_tag: Option<(&str, f64)> = None;
+    for (tag, freshness) in issue_tags {
+        for axis in rubric.axes_for_tag(tag) {
             let entry = axis_scores.entry(axis.to_string()).or_insert(0.0);
-            if axis == "fiscal_restraint"
-                && spending_keywords.iter().any(|keyword| lowered.contains(keyword))
-            {
-                *entry += rubric.bias_controls.spending_bias_penalty;
+            if !spending_motion || axis != "fiscal_restraint" {
+                continue;
+            }
+            if rubric.bias_controls.spending_bias_per_tag {
+                *entry += rubric.bias_controls.spending_bias_penalty * freshness;
                 evidence.push(format!("spending_bias:{tag}"));
+            } else if spending_tag.is_none_or(|(_, freshest)| *freshness > freshest) {
+                spending_tag = Some((tag, *freshness));
             }
         }
     }
-}
-
-fn tag_axes(tag: &str) -> Vec<&'static str> {
-    match tag {
-        "budget" | "tax" | "bond" | "appropriation" | "contract" | "bid" | "procurement" => {
-            vec!["fiscal_restraint"]
-        }
-        "zoning" | "rezoning" | "variance" | "land_sale" | "eminent_domain" => {
-            vec!["property_rights"]
-        }
-        "transparency" | "ordinance" => vec!["transparency"],
-        _ => Vec::new(),
+    if let Some((tag, freshness)) = spending_tag {
+        *axis_scores.entry("fiscal_restraint".to_string()).or_insert(0.0) +=
+            rubric.bias_controls.spending_bias_penalty * freshness;
+        evidence.push(format!("spending_bias:{tag}"));
     }
 }
 
-fn weighted_overall(axis_scores: &HashMap<String, f64>, weights: &HashMap<String, f64>) -> f64 {
+/// Each raw axis score scaled by its weight and signed by its polarity; these sum to the
+/// unclamped overall score.
+fn weighted_contributions(
+    axis_scores: &HashMap<String, f64>,
+    weights: &HashMap<String, f64>,
+    polarity: &HashMap<String, AxisPolarity>,
+) -> HashMap<String, f64> {
     axis_scores
         .iter()
-        .map(|(axis, score)| score * weights.get(axis).copied().unwrap_or(1.0))
-        .sum()
+        .map(|(axis, score)| (axis.clone(), score * signed_weight(axis, weights, polarity)))
+        .collect()
+}
+
+/// The polarity-signed weight `rubric` applies to each axis in `axis_scores`; the snapshot
+/// stored as `DecisionScore::axis_weights`.
+pub fn applied_axis_weights(
+    axis_scores: &HashMap<String, f64>,
+    rubric: &Rubric,
+) -> HashMap<String, f64> {
+    axis_scores
+        .keys()
+        .map(|axis| {
+            let weight = signed_weight(axis, &rubric.axis_weights, &rubric.axis_polarity);
+            (axis.clone(), weight)
+        })
+        .collect()
+}
+
+fn signed_weight(
+    axis: &str,
+    weights: &HashMap<String, f64>,
+    polarity: &HashMap<String, AxisPolarity>,
+) -> f64 {
+    let weight = weights.get(axis).copied().unwrap_or(1.0);
+    weight * polarity.get(axis).copied().unwrap_or_default().sign()
 }
 
 fn clamp_score(value: f64, floor: f64, ceiling: f64) -> f64 {
     value.max(floor).min(ceiling)
 }
 
+/// Clamps each axis score to its `RubricConfig::axis_range`, so one runaway axis cannot swamp
+/// the weighted overall. Clamped axes are noted as `axis_clamped:<axis>`, in axis name order.
+fn clamp_axis_scores(
+    axis_scores: &mut HashMap<String, f64>,
+    config: &RubricConfig,
+    evidence: &mut Vec<String>,
+) {
+    let mut clamped = Vec::new();
+    for (axis, value) in axis_scores.iter_mut() {
+        let (floor, ceiling) = config.axis_range(axis);
+        let bounded = clamp_score(*value, floor, ceiling);
+        if bounded != *value {
+            *value = bounded;
+            clamped.push(format!("axis_clamped:{axis}"));
+        }
+    }
+    clamped.sort();
+    evidence.extend(clamped);
+}
+
 fn round_score(value: f64, decimals: u32) -> f64 {
     let factor = 10f64.powi(decimals as i32);
     (value * factor).round() / factor
 }
 
-fn build_constitution_refs(axis_scores: &HashMap<String, f64>, rubric: &Rubric) -> Vec<String> {
-    let mut refs = Vec::new();
-    for (axis, score) in axis_scores {
+fn build_constitution_refs(
+    axis_scores: &HashMap<String, f64>,
+    rubric: &Rubric,
+) -> ConstitutionRefs {
+    let mut grouped = ConstitutionRefs::new();
+    for axis in &rubric.axes {
+        let Some(score) = axis_scores.get(axis) else {
+            continue;
+        };
         if score.abs() < f64::EPSILON {
             continue;
         }
-        if let Some(us_refs) = rubric.us_constitution.get(axis) {
-            refs.extend(us_refs.iter().map(|value| format!("US {value}")));
-        }
-        if let Some(ky_refs) = rubric.ky_constitution.get(axis) {
-            refs.extend(ky_refs.iter().map(|value| format!("KY {value}")));
+        let mut us_refs: Vec<String> = rubric
+            .us_constitution
+            .get(axis)
+            .map(|refs| refs.iter().map(|value| format!("US {value}")).collect())
+            .unwrap_or_default();
+        let mut ky_refs: Vec<String> = rubric
+            .ky_constitution
+            .get(axis)
+            .map(|refs| refs.iter().map(|value| format!("KY {value}")).collect())
+            .unwrap_or_default();
+        us_refs.sort();
+        us_refs.dedup();
+        ky_refs.sort();
+        ky_refs.dedup();
+        us_refs.extend(ky_refs);
+        if !us_refs.is_empty() {
+            grouped.push((axis.clone(), us_refs));
         }
     }
-    refs.sort();
-    refs.dedup();
-    refs
+    grouped
+}
+
+/// `axis_weights` keys in the order they appear in `weights.yaml`.
+fn axis_order(weights_yaml: &str) -> Result<Vec<String>> {
+    let value: serde_yaml::Value = serde_yaml::from_str(weights_yaml)?;
+    let axes = value
+        .get("axis_weights")
+        .and_then(serde_yaml::Value::as_mapping)
+        .ok_or_else(|| anyhow!("weights.yaml is missing axis_weights"))?
+        .keys()
+        .filter_map(|key| key.as_str().map(str::to_string))
+        .collect();
+    Ok(axes)
 }
 
 fn apply_vote_effect(axis_scores: &mut HashMap<String, f64>, effect: &VoteEffect) {
@@ -557,6 +1232,12 @@ fn apply_vote_effect(axis_scores: &mut HashMap<String, f64>, effect: &VoteEffect
     }
 }
 
+fn apply_modifier(axis_scores: &mut HashMap<String, f64>, modifier: f64) {
+    for value in axis_scores.values_mut() {
+        *value *= modifier;
+    }
+}
+
 fn apply_flat_penalty(axis_scores: &mut HashMap<String, f64>, penalty: f64) {
     if axis_scores.is_empty() {
         return;
@@ -566,32 +1247,620 @@ fn apply_flat_penalty(axis_scores: &mut HashMap<String, f64>, penalty: f64) {
     }
 }
 
+/// Scoring also treats `transparency` as an issue tag.
 fn is_issue_tag(tag: &str) -> bool {
-    matches!(
-        tag,
-        "zoning"
-            | "rezoning"
-            | "variance"
-            | "planning_commission"
-            | "budget"
-            | "tax"
-            | "bond"
-            | "appropriation"
-            | "contract"
-            | "bid"
-            | "procurement"
-            | "election"
-            | "clerk"
-            | "ballot"
-            | "school_board"
-            | "curriculum"
-            | "policy"
-            | "lawsuit"
-            | "settlement"
-            | "ordinance"
-            | "public_safety"
-            | "land_sale"
-            | "eminent_domain"
-            | "transparency"
-    )
+    crate::tags::is_issue_tag(tag) || tag == "transparency"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::SourceRef;
+
+    fn artifact(body_text: &str, tags: &[&str]) -> Artifact {
+        Artifact {
+            id: "a1".to_string(),
+            source: SourceRef {
+                kind: "url".to_string(),
+                value: "https://example.com".to_string(),
+                retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            },
+            title: None,
+            body_text: Some(body_text.to_string()),
+            content_type: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            supersedes: Vec::new(),
+            language: None,
+            doc_type: None,
+        }
+    }
+
+    #[test]
+    fn extracts_dollar_amounts_with_separators() {
+        let amounts = extract_dollar_amounts("Approve $1,250.50 and $300. Not 400.");
+        assert_eq!(amounts, vec![1250.5, 300.0]);
+    }
+
+    #[test]
+    fn dollar_amounts_raise_importance() {
+        let with_money = artifact("Approved a road contract for $2,500,000.", &["contract"]);
+        let without_money = artifact("Approved a road contract for resurfacing.", &["contract"]);
+        let with_score = compute_artifact_importance(&with_money);
+        let without_score = compute_artifact_importance(&without_money);
+        assert!(with_score > without_score + 5.0);
+    }
+
+    fn linked(tags: &[&str], retrieved_at: &str) -> LinkedArtifact {
+        LinkedArtifact {
+            id: "a1".to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            retrieved_at: retrieved_at.to_string(),
+            content_type: Some("text/html".to_string()),
+            body_text: None,
+            source_kind: "url".to_string(),
+            trusted: true,
+            doc_type: DocType::Other,
+        }
+    }
+
+    fn repo_rubric(half_life_days: Option<f64>) -> Rubric {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let mut rubric = Rubric::load_from_dir(&dir).unwrap();
+        rubric.config.evidence.freshness_half_life_days = half_life_days;
+        rubric
+    }
+
+    #[test]
+    fn stale_evidence_contributes_less_than_fresh() {
+        let rubric = repo_rubric(Some(30.0));
+        let meeting = "2024-06-03T18:00:00Z";
+        let motion = "Approve the road contract";
+        let fresh = compute_motion_score(
+            motion,
+            &[linked(&["contract"], "2024-06-01T00:00:00Z")],
+            meeting,
+            &rubric,
+        );
+        let stale = compute_motion_score(
+            motion,
+            &[linked(&["contract"], "2024-01-01T00:00:00Z")],
+            meeting,
+            &rubric,
+        );
+        let fresh_axis = fresh.axis_scores["fiscal_restraint"];
+        let stale_axis = stale.axis_scores["fiscal_restraint"];
+        assert!(fresh_axis < 0.0);
+        assert!(stale_axis.abs() < fresh_axis.abs());
+        assert!(stale.evidence.iter().any(|item| item.starts_with("freshness:contract:")));
+    }
+
+    #[test]
+    fn decay_disabled_without_half_life() {
+        let rubric = repo_rubric(None);
+        let meeting = "2024-06-03T18:00:00Z";
+        let motion = "Approve the road contract";
+        let fresh = compute_motion_score(
+            motion,
+            &[linked(&["contract"], "2024-06-01T00:00:00Z")],
+            meeting,
+            &rubric,
+        );
+        let stale = compute_motion_score(
+            motion,
+            &[linked(&["contract"], "2020-01-01T00:00:00Z")],
+            meeting,
+            &rubric,
+        );
+        assert_eq!(fresh.axis_scores, stale.axis_scores);
+    }
+
+    #[test]
+    fn missing_artifacts_flagged_separately_from_insufficient_evidence() {
+        let rubric = repo_rubric(None);
+        let meeting = "2024-06-03T18:00:00Z";
+        let no_docs = compute_motion_score("Approve minutes", &[], meeting, &rubric);
+        assert_eq!(no_docs.flags, vec!["no_source_artifacts".to_string()]);
+
+        let untagged = [linked(&["public_notice"], "2024-06-01T00:00:00Z")];
+        let no_content = compute_motion_score("Approve minutes", &untagged, meeting, &rubric);
+        assert_eq!(no_content.flags, vec!["insufficient_evidence".to_string()]);
+
+        let tally = VoteTally { ayes: 5, nays: 0 };
+        let vote = compute_vote_score_with_motion(&no_docs, VoteChoice::Aye, &tally, &rubric);
+        assert_eq!(vote.flags, vec!["no_source_artifacts".to_string()]);
+    }
+
+    #[test]
+    fn empty_motion_text_is_flagged_on_motion_and_votes() {
+        let rubric = repo_rubric(None);
+        let meeting = "2024-06-03T18:00:00Z";
+        let artifacts = [linked(&["contract"], "2024-06-01T00:00:00Z")];
+        let blank = compute_motion_score("  \n\t ", &artifacts, meeting, &rubric);
+        assert!(blank.flags.contains(&"empty_motion_text".to_string()));
+        assert!(!blank.evidence.iter().any(|item| item.starts_with("spending_bias:")));
+
+        let worded = compute_motion_score("Approve road contract", &artifacts, meeting, &rubric);
+        assert!(!worded.flags.contains(&"empty_motion_text".to_string()));
+
+        let tally = VoteTally { ayes: 5, nays: 0 };
+        let vote = compute_vote_score_with_motion(&blank, VoteChoice::Aye, &tally, &rubric);
+        assert!(vote.flags.contains(&"empty_motion_text".to_string()));
+    }
+
+    #[test]
+    fn matched_sentence_is_kept_as_excerpt_on_motion_and_votes() {
+        let rubric = repo_rubric(None);
+        let meeting = "2024-06-03T18:00:00Z";
+        let mut minutes = linked(&["budget", "contract"], "2024-06-01T00:00:00Z");
+        minutes.body_text = Some(
+            "Call to order. Magistrates reviewed the\n  fiscal year budget: $2.4M for roads! \
+             Adjourned."
+                .to_string(),
+        );
+        let score = compute_motion_score("Adopt the budget", &[minutes], meeting, &rubric);
+
+        let excerpts = score
+            .evidence
+            .iter()
+            .filter_map(|entry| EvidenceExcerpt::from_evidence(entry))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            excerpts,
+            vec![EvidenceExcerpt {
+                tag: "budget".to_string(),
+                artifact_id: "a1".to_string(),
+                text: "Magistrates reviewed the fiscal year budget: $2.4M for roads".to_string(),
+            }]
+        );
+        let stored = score.evidence.iter().find_map(|entry| entry.strip_prefix("excerpt:"));
+        assert_eq!(
+            serde_json::from_str::<Value>(stored.unwrap()).unwrap(),
+            serde_json::json!({
+                "tag": "budget",
+                "artifact_id": "a1",
+                "text": "Magistrates reviewed the fiscal year budget: $2.4M for roads",
+            })
+        );
+
+        let tally = VoteTally { ayes: 5, nays: 0 };
+        let vote = compute_vote_score_with_motion(&score, VoteChoice::Aye, &tally, &rubric);
+        assert!(vote.evidence.contains(&excerpts[0].to_evidence()));
+
+        let untexted = [linked(&["budget"], "2024-06-01T00:00:00Z")];
+        let score = compute_motion_score("Adopt the budget", &untexted, meeting, &rubric);
+        assert!(!score.evidence.iter().any(|entry| entry.starts_with("excerpt:")));
+    }
+
+    #[test]
+    fn excerpts_round_trip_artifact_ids_containing_colons() {
+        let excerpt = EvidenceExcerpt {
+            tag: "zoning".to_string(),
+            artifact_id: "ky_public_notice:larue zoning".to_string(),
+            text: "Hearing at 6:00 p.m.: rezoning of Main St.".to_string(),
+        };
+        let entry = excerpt.to_evidence();
+        assert!(entry.starts_with("excerpt:{"));
+        assert_eq!(EvidenceExcerpt::from_evidence(&entry), Some(excerpt));
+
+        let legacy = "excerpt:roads:a2:Pave it: all of it";
+        let legacy = EvidenceExcerpt::from_evidence(legacy).unwrap();
+        assert_eq!(legacy.artifact_id, "a2");
+        assert_eq!(legacy.text, "Pave it: all of it");
+        assert_eq!(EvidenceExcerpt::from_evidence("tag:roads"), None);
+    }
+
+    #[test]
+    fn spending_bias_applies_once_per_motion_unless_per_tag() {
+        let mut rubric = repo_rubric(None);
+        let artifacts = [linked(&["budget", "tax", "bond"], "2024-06-01T00:00:00Z")];
+        let score = |rubric: &Rubric| {
+            compute_motion_score(
+                "Adopt the budget and tax levy",
+                &artifacts,
+                "2024-06-03T18:00:00Z",
+                rubric,
+            )
+        };
+        let penalty = rubric.bias_controls.spending_bias_penalty;
+        assert!(!rubric.bias_controls.spending_bias_per_tag);
+        assert!(penalty < 0.0);
+
+        let once = score(&rubric);
+        assert_eq!(once.axis_scores["fiscal_restraint"], penalty);
+        let spending = |score: &ScoreResult| {
+            score
+                .evidence
+                .iter()
+                .filter(|entry| entry.starts_with("spending_bias:"))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(spending(&once), vec!["spending_bias:budget"]);
+
+        rubric.bias_controls.spending_bias_per_tag = true;
+        let per_tag = score(&rubric);
+        assert_eq!(per_tag.axis_scores["fiscal_restraint"], penalty * 3.0);
+        assert_eq!(spending(&per_tag).len(), 3);
+    }
+
+    #[test]
+    fn tag_aliases_score_as_canonical_tag() {
+        let rubric = repo_rubric(None);
+        let meeting = "2024-06-03T18:00:00Z";
+        let alias = compute_motion_score(
+            "Approve rezoning request",
+            &[linked(&["re-zoning"], "2024-06-01T00:00:00Z")],
+            meeting,
+            &rubric,
+        );
+        let canonical = compute_motion_score(
+            "Approve rezoning request",
+            &[linked(&["rezoning"], "2024-06-01T00:00:00Z")],
+            meeting,
+            &rubric,
+        );
+        assert!(alias.evidence.contains(&"tag:rezoning".to_string()));
+        assert_eq!(alias.evidence, canonical.evidence);
+        assert_eq!(alias.axis_scores, canonical.axis_scores);
+    }
+
+    fn contract_motion(rubric: &Rubric) -> ScoreResult {
+        compute_motion_score(
+            "Approve the road contract",
+            &[linked(&["contract"], "2024-06-01T00:00:00Z")],
+            "2024-06-03T18:00:00Z",
+            rubric,
+        )
+    }
+
+    #[test]
+    fn constitution_refs_are_grouped_by_axis_in_weights_order() {
+        let rubric = repo_rubric(None);
+        assert_eq!(rubric.axes.first().map(String::as_str), Some("free_speech"));
+        let axis_scores = HashMap::from([
+            ("fiscal_restraint".to_string(), -0.5),
+            ("transparency".to_string(), 1.0),
+            ("property_rights".to_string(), 0.0),
+        ]);
+
+        let refs = build_constitution_refs(&axis_scores, &rubric);
+
+        let axes: Vec<&str> = refs.iter().map(|(axis, _)| axis.as_str()).collect();
+        assert_eq!(axes, vec!["transparency", "fiscal_restraint"]);
+        assert_eq!(
+            refs[1].1,
+            vec![
+                "US Principle taxation_with_representation",
+                "KY Section §171",
+                "KY Section §172",
+            ]
+        );
+    }
+
+    #[test]
+    fn negative_polarity_axis_lowers_overall_when_raw_score_is_high() {
+        let weights = HashMap::from([("transparency".to_string(), 1.5)]);
+        let axis_scores = HashMap::from([("transparency".to_string(), 2.0)]);
+        let positive = weighted_contributions(&axis_scores, &weights, &HashMap::new());
+        let negative = weighted_contributions(
+            &axis_scores,
+            &weights,
+            &HashMap::from([("transparency".to_string(), AxisPolarity::Negative)]),
+        );
+        assert_eq!(positive.values().sum::<f64>(), 3.0);
+        assert_eq!(negative.values().sum::<f64>(), -3.0);
+    }
+
+    #[test]
+    fn axis_polarity_flips_motion_overall_but_not_axis_scores() {
+        let mut rubric = repo_rubric(None);
+        assert_eq!(
+            rubric.axis_polarity.get("fiscal_restraint").copied(),
+            Some(AxisPolarity::Positive)
+        );
+        let positive = contract_motion(&rubric);
+        rubric
+            .axis_polarity
+            .insert("fiscal_restraint".to_string(), AxisPolarity::Negative);
+        let negative = contract_motion(&rubric);
+
+        assert!(positive.overall_score < 0.0);
+        assert_eq!(negative.overall_score, -positive.overall_score);
+        assert_eq!(negative.axis_scores, positive.axis_scores);
+    }
+
+    #[test]
+    fn ocr_only_evidence_lowers_confidence() {
+        let rubric = repo_rubric(None);
+        let html = contract_motion(&rubric);
+        let mut scanned = linked(&["contract", "ocr"], "2024-06-01T00:00:00Z");
+        scanned.content_type = Some("application/pdf".to_string());
+        let ocr = compute_motion_score(
+            "Approve the road contract",
+            &[scanned.clone()],
+            "2024-06-03T18:00:00Z",
+            &rubric,
+        );
+
+        assert!(!html.flags.contains(&"ocr_evidence".to_string()));
+        assert!(ocr.flags.contains(&"ocr_evidence".to_string()));
+        assert_eq!(
+            ocr.confidence,
+            html.confidence * rubric.config.evidence.ocr_confidence_factor
+        );
+        assert!(ocr.confidence < html.confidence);
+        assert_eq!(ocr.overall_score, html.overall_score);
+
+        let mixed = compute_motion_score(
+            "Approve the road contract",
+            &[scanned, linked(&["contract"], "2024-06-01T00:00:00Z")],
+            "2024-06-03T18:00:00Z",
+            &rubric,
+        );
+        assert_eq!(mixed.confidence, html.confidence);
+
+        let tally = VoteTally { ayes: 3, nays: 2 };
+        let vote = compute_vote_score_with_motion(&ocr, VoteChoice::Aye, &tally, &rubric);
+        assert!(vote.flags.contains(&"ocr_evidence".to_string()));
+        assert!(vote.confidence < 1.0);
+    }
+
+    #[test]
+    fn stricter_axis_confidence_requirement_flags_only_that_axis() {
+        let mut rubric = repo_rubric(None);
+        let artifacts = [linked(&["contract", "transparency"], "2024-06-01T00:00:00Z")];
+        let score = |rubric: &Rubric| {
+            compute_motion_score(
+                "Approve the records contract",
+                &artifacts,
+                "2024-06-03T18:00:00Z",
+                rubric,
+            )
+        };
+        let default = score(&rubric);
+        assert!(default.axis_scores.contains_key("transparency"));
+        assert!(default.axis_scores.contains_key("fiscal_restraint"));
+        assert_eq!(rubric.evidence_rules.minimum_confidence_for("transparency"), 0.6);
+        assert!(!default.flags.iter().any(|flag| flag.starts_with("below_axis_confidence")));
+
+        rubric
+            .evidence_rules
+            .axis_minimum_confidence
+            .insert("transparency".to_string(), 0.8);
+        let strict = score(&rubric);
+        assert_eq!(strict.flags, vec!["below_axis_confidence:transparency".to_string()]);
+        assert_eq!(strict.overall_score, default.overall_score);
+
+        let tally = VoteTally { ayes: 3, nays: 2 };
+        let vote = compute_vote_score_with_motion(&strict, VoteChoice::Aye, &tally, &rubric);
+        assert!(vote.flags.contains(&"below_axis_confidence:transparency".to_string()));
+    }
+
+    #[test]
+    fn contributions_sum_to_overall_within_rounding() {
+        let rubric = repo_rubric(None);
+        let motion = contract_motion(&rubric);
+        let vote = compute_vote_score_with_motion(
+            &motion,
+            VoteChoice::Aye,
+            &VoteTally { ayes: 3, nays: 2 },
+            &rubric,
+        );
+        let step = 10f64.powi(-(rubric.config.output.rounding as i32));
+        let tolerance = step * rubric.axes.len() as f64;
+
+        for score in [&motion, &vote] {
+            assert!(!score.contributions.is_empty());
+            assert_eq!(
+                score.contributions.keys().collect::<std::collections::BTreeSet<_>>(),
+                score.axis_scores.keys().collect()
+            );
+            let total: f64 = score.contributions.values().sum();
+            assert!((total - score.overall_score).abs() <= tolerance, "{total} vs {score:?}");
+        }
+        assert!(
+            compute_vote_score(&serde_json::json!({}), &rubric)
+                .contributions
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn cached_rubric_is_parsed_once_until_inputs_change() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let copy = std::env::temp_dir().join(format!("larue-rubric-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&copy);
+        fs::create_dir_all(&copy).unwrap();
+        for name in RUBRIC_INPUT_FILES {
+            if repo.join(name).exists() {
+                fs::copy(repo.join(name), copy.join(name)).unwrap();
+            }
+        }
+        let parses = || RUBRIC_PARSES.with(|count| count.get());
+        let before = parses();
+
+        let first = Rubric::load_cached(&copy).unwrap();
+        for _ in 0..4 {
+            let again = Rubric::load_cached(&copy).unwrap();
+            assert!(Arc::ptr_eq(&first, &again));
+        }
+        assert_eq!(parses() - before, 1);
+
+        let weights = fs::read_to_string(copy.join("weights.yaml")).unwrap();
+        fs::write(copy.join("weights.yaml"), format!("{weights}\n# retuned\n")).unwrap();
+        let reloaded = Rubric::load_cached(&copy).unwrap();
+        assert!(!Arc::ptr_eq(&first, &reloaded));
+        assert_ne!(reloaded.version_hash, first.version_hash);
+        assert_eq!(parses() - before, 2);
+
+        let _ = fs::remove_dir_all(&copy);
+    }
+
+    #[test]
+    fn rubric_version_hash_tracks_input_contents() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let copy = std::env::temp_dir().join(format!("larue-rubric-hash-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&copy);
+        fs::create_dir_all(&copy).unwrap();
+        for name in RUBRIC_INPUT_FILES {
+            if repo.join(name).exists() {
+                fs::copy(repo.join(name), copy.join(name)).unwrap();
+            }
+        }
+
+        let original = rubric_version_hash(&repo).unwrap();
+        assert_eq!(rubric_version_hash(&repo).unwrap(), original);
+        assert_eq!(rubric_version_hash(&copy).unwrap(), original);
+        assert_eq!(Rubric::load_from_dir(&copy).unwrap().version_hash, original);
+
+        fs::write(copy.join("README.md"), "notes only").unwrap();
+        assert_eq!(rubric_version_hash(&copy).unwrap(), original);
+
+        let weights = fs::read_to_string(copy.join("weights.yaml")).unwrap();
+        let tuned = weights.replace("transparency: 1.4", "transparency: 2.0");
+        assert_ne!(tuned, weights);
+        fs::write(copy.join("weights.yaml"), tuned).unwrap();
+        let retuned = rubric_version_hash(&copy).unwrap();
+        assert_ne!(retuned, original);
+
+        // Tagger phrases feed evidence excerpts, so they are part of the version too.
+        let rules = fs::read_to_string(copy.join("tag_rules.yaml")).unwrap();
+        fs::write(copy.join("tag_rules.yaml"), format!("{rules}\n# reworded\n")).unwrap();
+        assert_ne!(rubric_version_hash(&copy).unwrap(), retuned);
+
+        let _ = fs::remove_dir_all(&copy);
+    }
+
+    #[test]
+    fn unanimous_vote_applies_unanimity_modifier() {
+        let mut rubric = repo_rubric(None);
+        rubric.scoring_rules.unanimity_modifier = 0.5;
+        let motion = contract_motion(&rubric);
+        let tally = VoteTally { ayes: 7, nays: 0 };
+        let vote = compute_vote_score_with_motion(&motion, VoteChoice::Aye, &tally, &rubric);
+        assert_eq!(tally.label().as_deref(), Some("Unanimous 7-0"));
+        assert!(vote.evidence.contains(&"unanimous".to_string()));
+        assert_eq!(
+            vote.axis_scores["fiscal_restraint"],
+            motion.axis_scores["fiscal_restraint"] * 0.5
+        );
+    }
+
+    #[test]
+    fn split_vote_by_one_applies_close_vote_modifier() {
+        let mut rubric = repo_rubric(None);
+        rubric.scoring_rules.close_vote_modifier = 2.0;
+        let motion = contract_motion(&rubric);
+        let tally = VoteTally { ayes: 4, nays: 3 };
+        let vote = compute_vote_score_with_motion(&motion, VoteChoice::Aye, &tally, &rubric);
+        assert_eq!(tally.label().as_deref(), Some("Split 4-3"));
+        assert_eq!(tally.margin(), 1);
+        assert!(vote.evidence.contains(&"close_vote".to_string()));
+        assert!(!vote.evidence.contains(&"unanimous".to_string()));
+        assert_eq!(
+            vote.axis_scores["fiscal_restraint"],
+            motion.axis_scores["fiscal_restraint"] * 2.0
+        );
+
+        let wide = VoteTally { ayes: 5, nays: 2 };
+        let wide_vote = compute_vote_score_with_motion(&motion, VoteChoice::Aye, &wide, &rubric);
+        assert!(!wide.is_close());
+        assert_eq!(wide_vote.axis_scores, motion.axis_scores);
+    }
+
+    #[test]
+    fn axis_scores_are_clamped_before_weighting() {
+        let mut rubric = repo_rubric(None);
+        rubric.scoring_rules.close_vote_modifier = 1000.0;
+        let motion = contract_motion(&rubric);
+        let raw = motion.axis_scores["fiscal_restraint"];
+        assert!(raw < 0.0 && raw * 1000.0 < rubric.config.general.score_floor);
+        let weight = rubric.axis_weights["fiscal_restraint"];
+
+        let tally = VoteTally { ayes: 4, nays: 3 };
+        let vote = compute_vote_score_with_motion(&motion, VoteChoice::Aye, &tally, &rubric);
+        assert_eq!(vote.axis_scores["fiscal_restraint"], rubric.config.general.score_floor);
+        assert_eq!(
+            vote.contributions["fiscal_restraint"],
+            round_score(rubric.config.general.score_floor * weight, rubric.config.output.rounding)
+        );
+        assert!(vote.evidence.contains(&"axis_clamped:fiscal_restraint".to_string()));
+
+        rubric.config.axis_bounds.insert(
+            "fiscal_restraint".to_string(),
+            AxisBounds {
+                floor: Some(-0.5),
+                ceiling: None,
+            },
+        );
+        assert_eq!(rubric.config.axis_range("fiscal_restraint"), (-0.5, 100.0));
+        let bounded = contract_motion(&rubric);
+        assert!(raw < -0.5);
+        assert_eq!(bounded.axis_scores["fiscal_restraint"], -0.5);
+        assert_eq!(bounded.contributions["fiscal_restraint"], round_score(-0.5 * weight, 1));
+        assert!(bounded.evidence.contains(&"axis_clamped:fiscal_restraint".to_string()));
+        assert!(!motion.evidence.iter().any(|item| item.starts_with("axis_clamped:")));
+    }
+
+    #[test]
+    fn tied_vote_is_close_but_not_unanimous() {
+        let rubric = repo_rubric(None);
+        let motion = contract_motion(&rubric);
+        let tally = VoteTally { ayes: 3, nays: 3 };
+        let vote = compute_vote_score_with_motion(&motion, VoteChoice::Nay, &tally, &rubric);
+        assert!(tally.is_tie());
+        assert!(!tally.is_unanimous());
+        assert_eq!(tally.label().as_deref(), Some("Tied 3-3"));
+        assert!(vote.evidence.contains(&"close_vote".to_string()));
+        assert!(vote.evidence.contains(&"vote_tally:3-3".to_string()));
+    }
+
+    #[test]
+    fn issue_tags_raise_importance() {
+        let tagged = artifact("Notice of hearing.", &["zoning", "variance"]);
+        let untagged = artifact("Notice of hearing.", &["public_notice"]);
+        assert!(compute_artifact_importance(&tagged) > compute_artifact_importance(&untagged));
+    }
+
+    #[test]
+    fn decision_score_canonical_json_round_trips() {
+        let score = DecisionScore {
+            id: "vote:v1:adams".to_string(),
+            meeting_id: Some("m1".to_string()),
+            motion_id: Some("m1-a".to_string()),
+            vote_id: Some("v1".to_string()),
+            overall_score: 12.5,
+            axis_scores: HashMap::from([
+                ("transparency".to_string(), 20.0),
+                ("due_process".to_string(), -5.0),
+                ("fiscal_restraint".to_string(), 7.5),
+            ]),
+            contributions: HashMap::from([("transparency".to_string(), 28.0)]),
+            axis_weights: HashMap::from([("transparency".to_string(), 1.4)]),
+            constitutional_refs: vec![("transparency".to_string(), vec!["KY §2".to_string()])],
+            evidence: vec!["official:Adams".to_string()],
+            confidence: 0.8,
+            flags: Vec::new(),
+            computed_at: "2024-03-05T00:00:00Z".to_string(),
+            rubric_version: Some("abc123".to_string()),
+        };
+
+        let json = score.canonical_json().unwrap();
+        let text = json.to_string();
+        assert!(text.contains(
+            r#""axis_scores":{"due_process":-5.0,"fiscal_restraint":7.5,"transparency":20.0}"#
+        ));
+        assert_eq!(serde_json::from_str::<DecisionScore>(&text).unwrap(), score);
+        assert_eq!(score.clone().canonical_json().unwrap().to_string(), text);
+
+        let conn = crate::db::open_memory().unwrap();
+        crate::db::upsert_decision_score(&conn, &score).unwrap();
+        let mut stored = Vec::new();
+        crate::db::for_each_decision_score(&conn, |row| {
+            stored.push(row);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(stored, vec![score]);
+    }
 }
@@ -0,0 +1,335 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// Canonical issue tags: the topics reports count and taggers may assign.
+pub const ISSUE_TAGS: &[&str] = &[
+    "zoning",
+    "rezoning",
+    "variance",
+    "planning_commission",
+    "budget",
+    "tax",
+    "bond",
+    "appropriation",
+    "contract",
+    "bid",
+    "procurement",
+    "election",
+    "clerk",
+    "ballot",
+    "school_board",
+    "curriculum",
+    "policy",
+    "lawsuit",
+    "settlement",
+    "ordinance",
+    "public_safety",
+    "land_sale",
+    "eminent_domain",
+];
+
+/// Marker tag recording that an artifact has been through issue tagging; taggers skip marked
+/// artifacts unless forced.
+pub const ISSUE_TAG_MARKER: &str = "issue_tagged";
+
+pub fn is_issue_tag(tag: &str) -> bool {
+    ISSUE_TAGS.contains(&tag)
+}
+
+/// Alias → canonical tag mapping loaded from `rubric/tag_aliases.yaml`.
+#[derive(Debug, Clone, Default)]
+pub struct TagAliases {
+    aliases: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagAliasesFile {
+    #[serde(default)]
+    aliases: HashMap<String, String>,
+}
+
+impl TagAliases {
+    /// Loads `tag_aliases.yaml` from the rubric directory; a missing file yields no aliases.
+    pub fn load_from_dir(path: &Path) -> Result<Self> {
+        let aliases_path = path.join("tag_aliases.yaml");
+        if !aliases_path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = fs::read_to_string(&aliases_path)?;
+        let file: TagAliasesFile = serde_yaml::from_str(&raw)?;
+        Ok(Self::from_map(file.aliases))
+    }
+
+    pub fn from_map(aliases: HashMap<String, String>) -> Self {
+        Self {
+            aliases: aliases
+                .into_iter()
+                .map(|(alias, canonical)| (normalize(&alias), normalize(&canonical)))
+                .collect(),
+        }
+    }
+
+    pub fn canonical(&self, tag: &str) -> String {
+        let normalized = normalize(tag);
+        self.aliases.get(&normalized).cloned().unwrap_or(normalized)
+    }
+
+    /// Canonicalizes every tag, dropping duplicates while keeping first-seen order.
+    pub fn canonicalize(&self, tags: &[String]) -> Vec<String> {
+        let mut canonical: Vec<String> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            let tag = self.canonical(tag);
+            if !canonical.contains(&tag) {
+                canonical.push(tag);
+            }
+        }
+        canonical
+    }
+}
+
+fn normalize(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+/// Per-tag weights from `rubric/tag_importance.yaml` for ranking issue tags; unlisted tags
+/// weigh 1.0, so a missing file ranks by raw count.
+#[derive(Debug, Clone, Default)]
+pub struct TagImportance {
+    weights: HashMap<String, f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagImportanceFile {
+    #[serde(default)]
+    weights: HashMap<String, f64>,
+}
+
+/// An issue tag's raw count and its importance-weighted score (`count * weight`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedTag {
+    pub tag: String,
+    pub count: usize,
+    pub weighted: f64,
+}
+
+impl TagImportance {
+    /// Loads `tag_importance.yaml` from the rubric directory; a missing file yields equal weights.
+    pub fn load_from_dir(path: &Path) -> Result<Self> {
+        let importance_path = path.join("tag_importance.yaml");
+        if !importance_path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = fs::read_to_string(&importance_path)?;
+        let file: TagImportanceFile = serde_yaml::from_str(&raw)?;
+        Ok(Self::from_map(file.weights))
+    }
+
+    pub fn from_map(weights: HashMap<String, f64>) -> Self {
+        Self {
+            weights: weights
+                .into_iter()
+                .map(|(tag, weight)| (normalize(&tag), weight))
+                .collect(),
+        }
+    }
+
+    pub fn weight(&self, tag: &str) -> f64 {
+        self.weights.get(&normalize(tag)).copied().unwrap_or(1.0)
+    }
+
+    /// Ranks tags by weighted score, then raw count, then name.
+    pub fn rank<'a>(
+        &self,
+        counts: impl IntoIterator<Item = (&'a String, &'a usize)>,
+    ) -> Vec<RankedTag> {
+        let mut ranked: Vec<RankedTag> = counts
+            .into_iter()
+            .map(|(tag, count)| RankedTag {
+                tag: tag.clone(),
+                count: *count,
+                weighted: *count as f64 * self.weight(tag),
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.weighted
+                .partial_cmp(&a.weighted)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.count.cmp(&a.count))
+                .then_with(|| a.tag.cmp(&b.tag))
+        });
+        ranked
+    }
+}
+
+/// Keyword → issue tag rules loaded from `rubric/tag_rules.yaml` for the native tagger.
+#[derive(Debug, Clone, Default)]
+pub struct TagRules {
+    /// Tag → phrases, each phrase pre-split into lowercase words.
+    rules: BTreeMap<String, Vec<Vec<String>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagRulesFile {
+    #[serde(default)]
+    rules: BTreeMap<String, Vec<String>>,
+}
+
+impl TagRules {
+    /// Loads `tag_rules.yaml` from the rubric directory; the file is required.
+    pub fn load_from_dir(path: &Path) -> Result<Self> {
+        let rules_path = path.join("tag_rules.yaml");
+        let raw = fs::read_to_string(&rules_path)
+            .map_err(|err| anyhow::anyhow!("Cannot read {}: {err}", rules_path.display()))?;
+        let file: TagRulesFile = serde_yaml::from_str(&raw)?;
+        Self::from_map(file.rules)
+    }
+
+    /// Rejects rules for tags outside `ISSUE_TAGS`.
+    pub fn from_map(rules: BTreeMap<String, Vec<String>>) -> Result<Self> {
+        let mut parsed = BTreeMap::new();
+        for (tag, phrases) in rules {
+            let tag = normalize(&tag);
+            if !is_issue_tag(&tag) {
+                bail!("tag_rules.yaml: `{tag}` is not a canonical issue tag");
+            }
+            let phrases: Vec<Vec<String>> = phrases
+                .iter()
+                .map(|phrase| words(phrase))
+                .filter(|phrase| !phrase.is_empty())
+                .collect();
+            parsed.insert(tag, phrases);
+        }
+        Ok(Self { rules: parsed })
+    }
+
+    /// Issue tags, sorted, with at least one phrase appearing in `text` as whole words.
+    pub fn matches(&self, text: &str) -> Vec<String> {
+        let text = words(text);
+        self.rules
+            .iter()
+            .filter(|(_, phrases)| {
+                phrases.iter().any(|phrase| {
+                    text.windows(phrase.len()).any(|window| window == phrase.as_slice())
+                })
+            })
+            .map(|(tag, _)| tag.clone())
+            .collect()
+    }
+
+    /// First sentence of `text` containing one of `tag`'s phrases (or the tag name itself when
+    /// it has no rules), whitespace-collapsed and capped at `EXCERPT_MAX_CHARS`.
+    pub fn excerpt(&self, tag: &str, text: &str) -> Option<String> {
+        let fallback = [words(&tag.replace('_', " "))];
+        let phrases = match self.rules.get(tag) {
+            Some(phrases) if !phrases.is_empty() => phrases.as_slice(),
+            _ => fallback.as_slice(),
+        };
+        let sentence = sentences(text)
+            .into_iter()
+            .find(|sentence| {
+                let sentence = words(sentence);
+                phrases.iter().filter(|phrase| !phrase.is_empty()).any(|phrase| {
+                    sentence.windows(phrase.len()).any(|window| window == phrase.as_slice())
+                })
+            })?;
+        let sentence = sentence.split_whitespace().collect::<Vec<_>>().join(" ");
+        if sentence.chars().count() <= EXCERPT_MAX_CHARS {
+            return Some(sentence);
+        }
+        let cut: String = sentence.chars().take(EXCERPT_MAX_CHARS).collect();
+        Some(format!("{}…", cut.trim_end()))
+    }
+}
+
+/// Longest evidence excerpt kept, in characters.
+pub const EXCERPT_MAX_CHARS: usize = 200;
+
+/// Splits on `.`, `!`, or `?` followed by whitespace (so `$2.4M` stays whole) and on blank lines.
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        let next = chars.peek().map(|(_, next)| *next);
+        let end = match ch {
+            '.' | '!' | '?' => next.is_none_or(char::is_whitespace),
+            '\n' => next == Some('\n'),
+            _ => false,
+        };
+        if end {
+            sentences.push(&text[start..index]);
+            start = index + ch.len_utf8();
+        }
+    }
+    sentences.push(&text[start..]);
+    sentences
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weighted_tag_outranks_more_frequent_one() {
+        let counts = BTreeMap::from([
+            ("policy".to_string(), 3),
+            ("eminent_domain".to_string(), 1),
+            ("budget".to_string(), 3),
+        ]);
+
+        let equal = TagImportance::default().rank(&counts);
+        let tags: Vec<&str> = equal.iter().map(|ranked| ranked.tag.as_str()).collect();
+        assert_eq!(tags, vec!["budget", "policy", "eminent_domain"]);
+
+        let importance = TagImportance::from_map(HashMap::from([
+            ("Eminent_Domain".to_string(), 4.0),
+            ("policy".to_string(), 0.5),
+        ]));
+        let weighted = importance.rank(&counts);
+        let tags: Vec<&str> = weighted.iter().map(|ranked| ranked.tag.as_str()).collect();
+        assert_eq!(tags, vec!["eminent_domain", "budget", "policy"]);
+        assert_eq!(weighted[0].count, 1);
+        assert_eq!(weighted[0].weighted, 4.0);
+    }
+
+    #[test]
+    fn tag_rules_match_whole_word_phrases() {
+        let rules = TagRules::from_map(BTreeMap::from([
+            ("zoning".to_string(), vec!["zone change".to_string()]),
+            ("bid".to_string(), vec!["bid".to_string()]),
+            ("clerk".to_string(), vec!["clerk's office".to_string()]),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            rules.matches("Approve the ZONE\n change; see the Clerk's Office."),
+            vec!["clerk", "zoning"]
+        );
+        assert!(rules.matches("Forbidden morbid zone changes").is_empty());
+
+        let unknown = TagRules::from_map(BTreeMap::from([(
+            "weather".to_string(),
+            vec!["rain".to_string()],
+        )]));
+        assert!(unknown.is_err());
+
+        let repo = TagRules::load_from_dir(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric"),
+        )
+        .unwrap();
+        assert_eq!(
+            repo.matches("Planning & Zoning hearing"),
+            vec!["planning_commission", "zoning"]
+        );
+    }
+}
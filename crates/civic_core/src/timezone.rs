@@ -0,0 +1,167 @@
+use anyhow::{anyhow, Result};
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Duration, Month, OffsetDateTime, Time, UtcOffset, Weekday};
+
+/// IANA zones we can resolve without a tz database: (name, standard offset hours, observes US DST).
+/// Only US zones whose rules are a fixed standard offset plus the current US DST schedule are
+/// listed; any other name is rejected rather than resolved with the wrong rules, and historical
+/// rule changes (e.g. before the 2007 US DST extension) are not modelled.
+const KNOWN_ZONES: &[(&str, i8, bool)] = &[
+    ("UTC", 0, false),
+    ("Etc/UTC", 0, false),
+    ("America/New_York", -5, true),
+    ("America/Detroit", -5, true),
+    ("America/Kentucky/Louisville", -5, true),
+    ("America/Kentucky/Monticello", -5, true),
+    ("America/Indiana/Indianapolis", -5, true),
+    ("America/Chicago", -6, true),
+    ("America/Indiana/Knox", -6, true),
+    ("America/Denver", -7, true),
+    ("America/Phoenix", -7, false),
+    ("America/Los_Angeles", -8, true),
+    ("America/Anchorage", -9, true),
+    ("Pacific/Honolulu", -10, false),
+];
+
+/// Reporting time zone used for window boundaries and display. Storage stays in UTC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportingZone {
+    pub name: String,
+    standard_offset_hours: i8,
+    observes_us_dst: bool,
+}
+
+impl ReportingZone {
+    pub fn utc() -> Self {
+        Self {
+            name: "UTC".to_string(),
+            standard_offset_hours: 0,
+            observes_us_dst: false,
+        }
+    }
+
+    /// Looks up one of `KNOWN_ZONES`; other IANA names are rejected with the supported list.
+    pub fn from_name(name: &str) -> Result<Self> {
+        KNOWN_ZONES
+            .iter()
+            .find(|(known, _, _)| *known == name)
+            .map(|(known, offset, dst)| Self {
+                name: known.to_string(),
+                standard_offset_hours: *offset,
+                observes_us_dst: *dst,
+            })
+            .ok_or_else(|| {
+                let supported = KNOWN_ZONES
+                    .iter()
+                    .map(|(known, _, _)| *known)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow!(
+                    "Unsupported time zone {name} (only these US zones are built in): {supported}"
+                )
+            })
+    }
+
+    /// UTC offset in effect at the given instant (US rules: second Sunday of March to first
+    /// Sunday of November, switching at 02:00 local time).
+    pub fn offset_at(&self, instant: OffsetDateTime) -> UtcOffset {
+        let standard = self.standard_offset();
+        if !self.observes_us_dst {
+            return standard;
+        }
+        let daylight = self.daylight_offset();
+        let year = instant.to_offset(standard).year();
+        let two_am = Time::from_hms(2, 0, 0).expect("valid time");
+        let (Some(start), Some(end)) = (
+            nth_sunday(year, Month::March, 2),
+            nth_sunday(year, Month::November, 1),
+        ) else {
+            return standard;
+        };
+        let dst_start = start.with_time(two_am).assume_offset(standard);
+        let dst_end = end.with_time(two_am).assume_offset(daylight);
+        if instant >= dst_start && instant < dst_end {
+            daylight
+        } else {
+            standard
+        }
+    }
+
+    pub fn to_local(&self, instant: OffsetDateTime) -> OffsetDateTime {
+        instant.to_offset(self.offset_at(instant))
+    }
+
+    /// The UTC instant at which the given local calendar date begins.
+    pub fn local_midnight(&self, date: Date) -> OffsetDateTime {
+        let standard = self.standard_offset();
+        let candidate = date.midnight().assume_offset(standard);
+        let offset = self.offset_at(candidate);
+        date.midnight()
+            .assume_offset(offset)
+            .to_offset(UtcOffset::UTC)
+    }
+
+    /// Render an RFC 3339 timestamp in this zone; unparseable values are returned unchanged.
+    pub fn display(&self, timestamp: &str) -> String {
+        let Ok(parsed) = OffsetDateTime::parse(timestamp, &Rfc3339) else {
+            return timestamp.to_string();
+        };
+        self.to_local(parsed)
+            .format(&Rfc3339)
+            .unwrap_or_else(|_| timestamp.to_string())
+    }
+
+    fn standard_offset(&self) -> UtcOffset {
+        UtcOffset::from_hms(self.standard_offset_hours, 0, 0).unwrap_or(UtcOffset::UTC)
+    }
+
+    fn daylight_offset(&self) -> UtcOffset {
+        UtcOffset::from_hms(self.standard_offset_hours + 1, 0, 0).unwrap_or(UtcOffset::UTC)
+    }
+}
+
+fn nth_sunday(year: i32, month: Month, n: u8) -> Option<Date> {
+    let first = Date::from_calendar_date(year, month, 1).ok()?;
+    let days_until_sunday = (7 + 6 - first.weekday().number_days_from_monday()) % 7;
+    let first_sunday = first + Duration::days(days_until_sunday as i64);
+    debug_assert_eq!(first_sunday.weekday(), Weekday::Sunday);
+    Some(first_sunday + Duration::weeks(n as i64 - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn evening_meeting_stays_on_local_date_near_midnight() {
+        let zone = ReportingZone::from_name("America/Chicago").unwrap();
+        // Monday 2024-03-04 18:30 CST is already Tuesday in UTC.
+        let meeting = datetime!(2024-03-05 00:30 UTC);
+        assert_eq!(zone.to_local(meeting).date(), date!(2024 - 03 - 04));
+
+        // The window for Monday ends at local midnight, which is 06:00 UTC.
+        let window_end = zone.local_midnight(date!(2024 - 03 - 05));
+        assert_eq!(window_end, datetime!(2024-03-05 06:00 UTC));
+        assert!(meeting < window_end);
+        assert!(meeting >= ReportingZone::utc().local_midnight(date!(2024 - 03 - 05)));
+    }
+
+    #[test]
+    fn daylight_saving_shifts_offset() {
+        let zone = ReportingZone::from_name("America/Chicago").unwrap();
+        let winter = zone.offset_at(datetime!(2024-01-15 12:00 UTC));
+        let summer = zone.offset_at(datetime!(2024-07-15 12:00 UTC));
+        assert_eq!(winter.whole_hours(), -6);
+        assert_eq!(summer.whole_hours(), -5);
+        assert_eq!(
+            zone.local_midnight(date!(2024 - 07 - 16)),
+            datetime!(2024-07-16 05:00 UTC)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_zone() {
+        assert!(ReportingZone::from_name("Mars/Olympus_Mons").is_err());
+    }
+}
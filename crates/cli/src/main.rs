@@ -1,13 +1,28 @@
 use anyhow::{anyhow, Result};
-use clap::{Parser, Subcommand};
-use civic_core::scoring::{DecisionScore, LinkedArtifact, Rubric, ScoreResult, VoteChoice};
+use clap::{Parser, Subcommand, ValueEnum};
+use civic_core::db::ArtifactStorage;
+use civic_core::grading::{grade_class, grade_rank, normalize_score, score_to_grade, WITHHELD_GRADE};
+use civic_core::paths::{safe_filename, write_atomic};
+use civic_core::progress::Progress;
+use civic_core::schema::{DocType, PublicComment};
+use civic_core::scoring::{
+    applied_axis_weights, ConstitutionRefs, DecisionScore, EvidenceCoverage, EvidenceExcerpt,
+    LinkedArtifact, Rubric, ScoreResult, VoteChoice, VoteTally,
+};
+use civic_core::tags::{is_issue_tag, TagImportance, TagRules, ISSUE_TAG_MARKER};
+use civic_core::timezone::ReportingZone;
+use obsidian::vault::{VaultLayout, VaultPaths};
+use rusqlite::OptionalExtension;
 use schemars::schema_for;
 use serde::Deserialize;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 use std::path::Path;
+#[cfg(feature = "python-collectors")]
 use std::process::Command;
+use std::process::ExitCode;
+use std::sync::OnceLock;
 use time::format_description::well_known::Rfc3339;
 use time::format_description::FormatItem;
 use time::{Duration, Month, OffsetDateTime};
@@ -16,6 +31,10 @@ use time::{Duration, Month, OffsetDateTime};
 #[command(name = "larue")]
 #[command(about = "LaRue Civic Intelligence CLI", long_about = None)]
 struct Cli {
+    /// Suppress progress output on stderr for long-running commands
+    #[arg(long, global = true)]
+    quiet: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -27,15 +46,29 @@ enum Commands {
         #[command(subcommand)]
         command: SchemaCommands,
     },
+    /// Inspect the config file
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
 
     /// Ingest a single Artifact JSON file into SQLite
     Ingest {
         /// Path to an artifact JSON file matching the canonical schema
-        artifact_json: PathBuf,
+        #[arg(required_unless_present = "stdin", conflicts_with = "stdin")]
+        artifact_json: Option<PathBuf>,
+
+        /// Read the artifact JSON from stdin instead of a file
+        #[arg(long)]
+        stdin: bool,
+
+        /// Optional config file path
+        #[arg(long)]
+        config: Option<PathBuf>,
 
         /// SQLite DB path
-        #[arg(long, default_value = "civic.db")]
-        db: String,
+        #[arg(long)]
+        db: Option<String>,
     },
     /// Ingest all Artifact JSON files in a directory into SQLite
     IngestDir {
@@ -49,15 +82,72 @@ enum Commands {
         /// SQLite DB path
         #[arg(long)]
         db: Option<String>,
+
+        /// Checkpoint and truncate the SQLite WAL when done
+        #[arg(long)]
+        checkpoint: bool,
+    },
+    /// Remap legacy artifact JSON shapes and ingest them into SQLite
+    ImportLegacy {
+        /// Directory containing legacy artifact JSON files
+        #[arg(long)]
+        dir: PathBuf,
+
+        /// Optional config file path
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// SQLite DB path
+        #[arg(long)]
+        db: Option<String>,
+
+        /// TOML mapping of legacy field paths to canonical field paths
+        #[arg(long)]
+        mapping: PathBuf,
+    },
+    /// Copy several county databases into one, prefixing each source's ids with its tag
+    MergeDb {
+        /// Target SQLite DB (created if missing)
+        #[arg(long)]
+        into: String,
+
+        /// Source DB as TAG=PATH, or PATH to use the file stem as the tag (repeatable)
+        #[arg(long = "from", required = true)]
+        from: Vec<String>,
     },
     /// Ingest a single Meeting JSON file into SQLite
     IngestMeeting {
         /// Path to a meeting JSON file matching the canonical schema
         meeting_json: PathBuf,
 
+        /// Optional config file path
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// SQLite DB path
+        #[arg(long)]
+        db: Option<String>,
+
+        /// Create a minutes artifact from the meeting's `source` and link it to the meeting
+        #[arg(long)]
+        link_minutes: bool,
+
+        /// Fail if the meeting references an artifact id that has not been ingested
+        #[arg(long)]
+        strict_links: bool,
+    },
+    /// Print an artifact's recorded text revisions and a diff between consecutive versions
+    ArtifactHistory {
+        /// Artifact id
+        id: String,
+
+        /// Optional config file path
+        #[arg(long)]
+        config: Option<PathBuf>,
+
         /// SQLite DB path
-        #[arg(long, default_value = "civic.db")]
-        db: String,
+        #[arg(long)]
+        db: Option<String>,
     },
     /// Build/update an Obsidian vault from the SQLite database
     BuildVault {
@@ -73,11 +163,31 @@ enum Commands {
         #[arg(long)]
         vault: Option<PathBuf>,
     },
+    /// Write the weekly score report, decision notes, and official summaries as one Markdown file
+    ExportVaultBundle {
+        /// Optional config file path
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// SQLite DB path
+        #[arg(long)]
+        db: Option<String>,
+
+        /// Output Markdown file
+        #[arg(long)]
+        out: PathBuf,
+    },
     /// Run the weekly pipeline: collect -> ingest-dir -> build-vault
     RunWeekly {
         /// Config file path
         #[arg(long)]
         config: PathBuf,
+        /// Break an existing lock if it is older than storage.lock_stale_minutes
+        #[arg(long)]
+        force: bool,
+        /// Checkpoint and truncate the SQLite WAL when done
+        #[arg(long)]
+        checkpoint: bool,
     },
     /// Extract normalized text into Artifact JSONs
     ExtractText {
@@ -94,39 +204,246 @@ enum Commands {
         #[arg(long)]
         force: bool,
     },
+    /// Tag stored artifacts from rubric/tag_rules.yaml keywords, without the Python tagger
+    TagNative {
+        /// Config file path
+        #[arg(long)]
+        config: PathBuf,
+        /// Force re-tagging of previously tagged artifacts
+        #[arg(long)]
+        force: bool,
+    },
     /// Ingest parsed decision JSON files into SQLite
     IngestDecisions {
         /// Config file path
         #[arg(long)]
         config: PathBuf,
+        /// Reject decision files whose meeting references an artifact id that has not been ingested
+        #[arg(long)]
+        strict_links: bool,
     },
     /// Score weekly decisions using the rubric
     ScoreWeekly {
         /// Config file path
         #[arg(long)]
         config: PathBuf,
-        /// Override report date (YYYY-MM-DD)
+        /// Override report date (YYYY-MM-DD; MM-DD-YYYY and / or . separators also accepted)
         #[arg(long)]
         date: Option<String>,
+        /// Append a JSONL audit record per score to <out_dir>/audit/scores-<date>.jsonl
+        #[arg(long)]
+        audit: bool,
+        /// Score meetings on N worker threads; results and database writes keep meeting order
+        #[arg(long, default_value_t = 1)]
+        parallel: usize,
+        /// Fail instead of warning when too few linked artifacts have extracted text
+        /// (scoring.min_extracted_fraction)
+        #[arg(long)]
+        require_extraction: bool,
     },
     /// Export static site bundle
     ExportSite {
         /// Config file path
         #[arg(long)]
         config: PathBuf,
+        /// Write the site here instead of <out_dir>/site (inputs still come from out_dir)
+        #[arg(long)]
+        output_dir: Option<PathBuf>,
+        /// Warn when the newest artifact is older than this many days
+        /// (overrides reporting.max_staleness_days)
+        #[arg(long)]
+        max_age: Option<u32>,
+        /// Read page.html/nav.html/footer.html from here instead of the built-in templates
+        /// (overrides site.template_dir)
+        #[arg(long)]
+        template_dir: Option<PathBuf>,
+    },
+    /// Stream a table as newline-delimited JSON, one typed record per line
+    ExportNdjson {
+        /// Config file path
+        #[arg(long)]
+        config: PathBuf,
+        /// Table to export
+        #[arg(long, value_enum)]
+        table: NdjsonTable,
+        /// Output file
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Export bodies, meetings, motions, votes, officials, and artifacts as a relationship graph
+    ExportGraph {
+        /// Config file path
+        #[arg(long)]
+        config: PathBuf,
+        /// Output format
+        #[arg(long, value_enum, default_value = "dot")]
+        format: GraphFormat,
+        /// Output file
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Check an exported site against its manifest.sha256 (written when site.sign is on)
+    VerifySite {
+        /// Site directory containing manifest.sha256
+        #[arg(long)]
+        dir: PathBuf,
+    },
+    /// Serve the exported static site over HTTP for local preview
+    Serve {
+        /// Config file path
+        #[arg(long)]
+        config: PathBuf,
+        /// Port to listen on (localhost only)
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+        /// Serve the existing <out_dir>/site without re-exporting first
+        #[arg(long)]
+        no_export: bool,
     },
     /// Generate a weekly report (last 7 days) from the database
     ReportWeekly {
         /// Config file path
         #[arg(long)]
         config: PathBuf,
+        /// Override report date (YYYY-MM-DD; MM-DD-YYYY and / or . separators also accepted)
+        #[arg(long)]
+        date: Option<String>,
+        /// Warn when the newest artifact is older than this many days
+        /// (overrides reporting.max_staleness_days)
+        #[arg(long)]
+        max_age: Option<u32>,
+        /// Embed each motion's recorded votes (name lists and tally) in the report JSON
+        #[arg(long)]
+        include_votes: bool,
+    },
+    /// Re-run the Rust pipeline stages (ingest -> score -> report -> vault -> site) against an
+    /// archived out/ snapshot and a fresh database, without running any collectors
+    Replay {
+        /// Config file path (storage paths are overridden for the replay)
+        #[arg(long)]
+        config: PathBuf,
+        /// Archived out/ directory containing artifacts/ and decisions/
+        #[arg(long)]
+        out: PathBuf,
+        /// SQLite DB path (default: <work_dir>/civic.db)
+        #[arg(long)]
+        db: Option<String>,
+        /// Where replay output is written (default: a new temp directory)
+        #[arg(long)]
+        work_dir: Option<PathBuf>,
+        /// Rubric directory the replayed decisions are scored with
+        #[arg(long, default_value = "rubric")]
+        rubric: PathBuf,
+        /// Report date to replay (YYYY-MM-DD); defaults to today
+        #[arg(long)]
+        date: Option<String>,
+    },
+    /// Weekly digest generation (AI digest is a placeholder; --email writes an HTML email body)
+    DigestWeekly {
+        /// Config file path
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Write an inline-styled HTML email body to <out_dir>/digests/<date>.html
+        #[arg(long, requires = "config")]
+        email: bool,
+    },
+    /// List officials whose letter grade changed between the two most recent weekly reports
+    GradeChanges {
+        /// Config file path
+        #[arg(long)]
+        config: PathBuf,
+        /// Compare this report week (YYYY-MM-DD) with the one before it instead of the latest
+        #[arg(long)]
+        week: Option<String>,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Rank issue tags across the weekly reports in a date range, with each one's trend
+    TopIssues {
+        /// Config file path
+        #[arg(long)]
+        config: PathBuf,
+        /// Only include reports dated on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        from: Option<String>,
+        /// Only include reports dated on or before this date (YYYY-MM-DD)
+        #[arg(long)]
+        to: Option<String>,
+        /// Number of issues to list
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// List data-quality flags on stored scores, grouped by flag
+    Flags {
+        /// Config file path
+        #[arg(long)]
+        config: PathBuf,
+        /// Only show this flag (e.g. insufficient_evidence)
+        #[arg(long)]
+        flag: Option<String>,
+        /// Only show scores for meetings in the week ending on this date (YYYY-MM-DD)
+        #[arg(long)]
+        week: Option<String>,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the database for inconsistencies (e.g. votes whose motion is missing)
+    Doctor {
+        /// Optional config file path
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// SQLite DB path
+        #[arg(long)]
+        db: Option<String>,
+    },
+    /// Run VACUUM to reclaim space in the SQLite database (e.g. after prunes)
+    Vacuum {
+        /// Optional config file path
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// SQLite DB path
+        #[arg(long)]
+        db: Option<String>,
     },
-    /// Placeholder for weekly AI digest generation
-    DigestWeekly,
     /// Placeholder for publishing artifacts (e.g., Web3/static)
     Publish,
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+enum NdjsonTable {
+    /// Current decision scores (`DecisionScore`)
+    Scores,
+    /// Ingested artifacts (`Artifact`)
+    Artifacts,
+    /// Stored meetings (`Meeting`)
+    Meetings,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum GraphFormat {
+    /// GraphViz DOT (`dot -Tsvg graph.dot`)
+    Dot,
+    /// `{"nodes": [...], "edges": [...]}`
+    Json,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Validate types, unknown keys, referenced paths, and enabled sources; fail on hard errors
+    Check {
+        /// Config file path
+        #[arg(long)]
+        config: PathBuf,
+    },
+}
+
 #[derive(Subcommand)]
 enum SchemaCommands {
     /// Export JSON Schema files for canonical types
@@ -134,39 +451,248 @@ enum SchemaCommands {
         /// Output directory (default: ./schemas)
         #[arg(long, default_value = "schemas")]
         out_dir: PathBuf,
+        /// Compare generated schemas against out_dir without writing; fail if any are stale
+        #[arg(long)]
+        check: bool,
     },
 }
 
-fn main() -> Result<()> {
+/// Failure categories reported as distinct process exit codes (documented in the README), so
+/// scripts wrapping the CLI can tell a bad config from a failed collector or a busy run lock.
+/// Anything uncategorized exits 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FailureKind {
+    Config,
+    Collector,
+    Database,
+    Validation,
+    /// Another `run-weekly` holds the run lock; retrying later may succeed.
+    Busy,
+}
+
+impl FailureKind {
+    fn exit_code(self) -> u8 {
+        match self {
+            FailureKind::Config => 2,
+            FailureKind::Collector => 3,
+            FailureKind::Database => 4,
+            FailureKind::Validation => 5,
+            FailureKind::Busy => 6,
+        }
+    }
+
+    fn error(self, message: impl std::fmt::Display) -> anyhow::Error {
+        anyhow::Error::new(Failure {
+            kind: self,
+            message: message.to_string(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Failure {
+    kind: FailureKind,
+    message: String,
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Failure {}
+
+/// Exit code for an error: the first categorized cause wins; SQLite errors count as database
+/// failures even when they were not tagged explicitly.
+fn exit_code_for(err: &anyhow::Error) -> u8 {
+    for cause in err.chain() {
+        if let Some(failure) = cause.downcast_ref::<Failure>() {
+            return failure.kind.exit_code();
+        }
+        if cause.is::<rusqlite::Error>() {
+            return FailureKind::Database.exit_code();
+        }
+    }
+    1
+}
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
+    civic_core::progress::set_quiet(cli.quiet);
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err:?}");
+            ExitCode::from(exit_code_for(&err))
+        }
+    }
+}
 
+fn run(cli: Cli) -> Result<()> {
     match cli.command {
         Commands::Schema { command } => match command {
-            SchemaCommands::Export { out_dir } => schema_export(out_dir),
+            SchemaCommands::Export { out_dir, check } => {
+                if check {
+                    schema_check(out_dir)
+                } else {
+                    schema_export(out_dir)
+                }
+            }
         },
-        Commands::Ingest { artifact_json, db } => ingest_artifact(artifact_json, &db),
-        Commands::IngestDir { dir, config, db } => {
-            let config = config.as_ref().map(load_config).transpose()?;
-            let storage = resolve_storage(config.as_ref());
-            let db_path = db.unwrap_or(storage.db_path);
-            ingest_dir(dir, &db_path)
+        Commands::Config { command } => match command {
+            ConfigCommands::Check { config } => config_check(config),
+        },
+        Commands::Ingest {
+            artifact_json,
+            stdin,
+            config,
+            db,
+        } => {
+            let storage = resolve_command_storage(config.as_ref(), db)?;
+            let db_path = storage.db_path;
+            match artifact_json {
+                Some(path) if !stdin => ingest_artifact(path, &db_path, storage.artifacts),
+                _ => {
+                    let stdin = std::io::stdin().lock();
+                    ingest_artifact_from(stdin, "stdin", &db_path, storage.artifacts).map(|_| ())
+                }
+            }
+        }
+        Commands::IngestDir {
+            dir,
+            config,
+            db,
+            checkpoint,
+        } => {
+            let storage = resolve_command_storage(config.as_ref(), db)?;
+            let db_path = storage.db_path;
+            ingest_dir(dir, &db_path, storage.artifacts)?;
+            if checkpoint {
+                checkpoint_db(&db_path)?;
+            }
+            Ok(())
+        }
+        Commands::ImportLegacy {
+            dir,
+            config,
+            db,
+            mapping,
+        } => {
+            let storage = resolve_command_storage(config.as_ref(), db)?;
+            import_legacy(dir, &storage.db_path, storage.artifacts, mapping)
+        }
+        Commands::MergeDb { into, from } => merge_db(&into, &from),
+        Commands::IngestMeeting {
+            meeting_json,
+            config,
+            db,
+            link_minutes,
+            strict_links,
+        } => {
+            let storage = resolve_command_storage(config.as_ref(), db)?;
+            ingest_meeting(
+                meeting_json,
+                &storage.db_path,
+                storage.artifacts,
+                link_minutes,
+                strict_links,
+            )
+        }
+        Commands::ArtifactHistory { id, config, db } => {
+            artifact_history(&resolve_command_storage(config.as_ref(), db)?.db_path, &id)
         }
-        Commands::IngestMeeting { meeting_json, db } => ingest_meeting(meeting_json, &db),
         Commands::BuildVault { config, db, vault } => {
             let config = config.as_ref().map(load_config).transpose()?;
             let storage = resolve_storage(config.as_ref());
             let db_path = db.unwrap_or(storage.db_path);
             let vault_path = vault.unwrap_or(storage.vault_path);
-            build_vault(&db_path, vault_path)
+            build_vault(&db_path, vault_path, config.as_ref())
+        }
+        Commands::ExportVaultBundle { config, db, out } => {
+            let config = config.as_ref().map(load_config).transpose()?;
+            let db_path = db.unwrap_or_else(|| resolve_storage(config.as_ref()).db_path);
+            export_vault_bundle(&db_path, &out, config.as_ref())
         }
-        Commands::RunWeekly { config } => run_weekly(config),
+        Commands::RunWeekly {
+            config,
+            force,
+            checkpoint,
+        } => run_weekly(config, force, checkpoint),
         Commands::ExtractText { config } => extract_text(config),
         Commands::TagArtifacts { config, force } => tag_artifacts(config, force),
-        Commands::IngestDecisions { config } => ingest_decisions(config),
-        Commands::ScoreWeekly { config, date } => score_weekly(config, date),
-        Commands::ExportSite { config } => export_site(config),
-        Commands::ReportWeekly { config } => report_weekly(config),
-        Commands::DigestWeekly => digest_weekly(),
+        Commands::TagNative { config, force } => tag_native(config, force),
+        Commands::IngestDecisions {
+            config,
+            strict_links,
+        } => ingest_decisions(config, strict_links),
+        Commands::ScoreWeekly {
+            config,
+            date,
+            audit,
+            parallel,
+            require_extraction,
+        } => score_weekly(
+            config,
+            Path::new("rubric"),
+            date,
+            audit,
+            parallel,
+            require_extraction,
+        ),
+        Commands::ExportSite {
+            config,
+            output_dir,
+            max_age,
+            template_dir,
+        } => export_site(config, output_dir, max_age, template_dir),
+        Commands::ExportNdjson { config, table, out } => export_ndjson(config, table, out),
+        Commands::ExportGraph {
+            config,
+            format,
+            out,
+        } => export_graph(config, format, out),
+        Commands::VerifySite { dir } => verify_site(&dir),
+        Commands::Serve {
+            config,
+            port,
+            no_export,
+        } => serve_site(config, port, no_export),
+        Commands::ReportWeekly {
+            config,
+            date,
+            max_age,
+            include_votes,
+        } => report_weekly(config, date, max_age, include_votes),
+        Commands::Replay {
+            config,
+            out,
+            db,
+            work_dir,
+            rubric,
+            date,
+        } => replay(config, out, db, work_dir, &rubric, date),
+        Commands::DigestWeekly { config, email } => digest_weekly(config, email),
+        Commands::GradeChanges { config, week, json } => list_grade_changes(config, week, json),
+        Commands::TopIssues {
+            config,
+            from,
+            to,
+            top,
+            json,
+        } => list_top_issues(config, from, to, top, json),
+        Commands::Flags {
+            config,
+            flag,
+            week,
+            json,
+        } => list_flags(config, flag, week, json),
+        Commands::Doctor { config, db } => {
+            doctor(&resolve_command_storage(config.as_ref(), db)?.db_path)
+        }
+        Commands::Vacuum { config, db } => {
+            vacuum_db(&resolve_command_storage(config.as_ref(), db)?.db_path)
+        }
         Commands::Publish => publish_placeholder(),
     }
 }
@@ -175,9 +701,10 @@ fn main() -> Result<()> {
 struct Config {
     storage: Option<StorageConfig>,
     sources: Option<SourcesConfig>,
-    ai: Option<AiConfig>,
-    publish: Option<PublishConfig>,
     site: Option<SiteConfig>,
+    reporting: Option<ReportingConfig>,
+    scoring: Option<ScoringConfig>,
+    vault: Option<VaultConfig>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -185,6 +712,9 @@ struct StorageConfig {
     db_path: Option<String>,
     vault_path: Option<String>,
     out_dir: Option<String>,
+    lock_stale_minutes: Option<u64>,
+    max_body_text_bytes: Option<usize>,
+    store_raw_json: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -196,35 +726,124 @@ struct SourcesConfig {
 #[derive(Debug, Deserialize)]
 struct SourceConfig {
     enabled: Option<bool>,
-    base_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 struct WaybackConfig {
     enabled: Option<bool>,
     urls: Option<Vec<String>>,
-    rate_limit_seconds: Option<f32>,
-    limit_per_run: Option<usize>,
-    include_subpaths: Option<bool>,
-    high_impact_url_keywords: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
-struct AiConfig {
-    enabled: Option<bool>,
-    provider: Option<String>,
+struct SiteConfig {
+    enable_commentary: Option<bool>,
+    commentary_style: Option<String>,
+    disclaimer: Option<String>,
+    /// Directory with page.html/nav.html/footer.html overriding the built-in templates.
+    template_dir: Option<PathBuf>,
+    /// Decimal places shown for normalized (0-100) scores and grade deltas.
+    display_decimals: Option<usize>,
+    /// Fixed commentary template index (modulo the set size) in place of the per-official hash.
+    commentary_seed_override: Option<u64>,
+    /// Templated sentences per official commentary (1-3): grade, then trend, then top issue.
+    commentary_sentences: Option<usize>,
+    /// Newest artifacts listed on `artifacts/index.html`.
+    artifacts_index_limit: Option<usize>,
+    /// Margin around `TREND_THRESHOLD` a delta must clear to enter or leave a Rising/Falling
+    /// trend.
+    trend_hysteresis: Option<f64>,
+    /// Write `manifest.sha256` with every exported file's hash (checked by `verify-site`).
+    sign: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
-struct PublishConfig {
-    enabled: Option<bool>,
-    provider: Option<String>,
+struct ReportingConfig {
+    timezone: Option<String>,
+    top_n: Option<usize>,
+    collapse_superseded: Option<bool>,
+    max_staleness_days: Option<u32>,
+    /// Report category per artifact `source.kind`, merged over `DEFAULT_ARTIFACT_CATEGORIES`.
+    artifact_categories: Option<HashMap<String, String>>,
+    /// ISO 639-1 codes to include in reports; unset includes every language.
+    languages: Option<Vec<String>>,
+    /// Leave `insufficient_evidence` scores (parked at the neutral score) out of averages.
+    exclude_insufficient_from_average: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
-struct SiteConfig {
-    enable_commentary: Option<bool>,
-    commentary_style: Option<String>,
+struct VaultConfig {
+    index_dir: Option<String>,
+    artifacts_dir: Option<String>,
+    meetings_dir: Option<String>,
+    reports_dir: Option<String>,
+    officials_dir: Option<String>,
+    official_notes: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ScoringConfig {
+    committee_vote_weight: Option<f64>,
+    min_votes_for_grade: Option<usize>,
+    insufficient_policy: Option<InsufficientPolicy>,
+    /// When set, each meeting an official missed counts as a vote scored at minus this value.
+    absence_grade_penalty: Option<f64>,
+    /// Artifacts first ingested less than this many hours ago are left out of scoring.
+    min_evidence_age_hours: Option<u32>,
+    /// Weight of a presiding officer's tie-breaking vote in their grade.
+    tie_breaker_weight: Option<f64>,
+    /// Artifact source kinds whose tags may influence scores; unset trusts every kind.
+    trusted_source_kinds: Option<Vec<String>>,
+    /// Link motions that reconsider an earlier motion in the same meeting.
+    detect_reconsiderations: Option<bool>,
+    /// Case-insensitive phrases marking a reconsideration motion.
+    reconsideration_patterns: Option<Vec<String>>,
+    /// Drop a reconsidered motion's vote scores from grades in favor of the reconsideration's.
+    reconsideration_supersedes: Option<bool>,
+    /// Warn when fewer of the window's linked artifacts than this have extracted text.
+    min_extracted_fraction: Option<f64>,
+}
+
+/// How officials with fewer than `min_votes_for_grade` scored votes are graded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum InsufficientPolicy {
+    /// Show the grade with a "Provisional" badge.
+    #[default]
+    Badge,
+    /// Replace the letter grade with `WITHHELD_GRADE`.
+    Withhold,
+}
+
+#[derive(Debug)]
+struct ResolvedScoring {
+    committee_vote_weight: f64,
+    min_votes_for_grade: usize,
+    insufficient_policy: InsufficientPolicy,
+    absence_grade_penalty: Option<f64>,
+    min_evidence_age_hours: u32,
+    tie_breaker_weight: f64,
+    trusted_source_kinds: Option<Vec<String>>,
+    /// Empty unless `detect_reconsiderations` is on.
+    reconsideration_patterns: Vec<String>,
+    reconsideration_supersedes: bool,
+    min_extracted_fraction: f64,
+}
+
+impl Default for ResolvedScoring {
+    fn default() -> Self {
+        Self {
+            committee_vote_weight: 1.0,
+            min_votes_for_grade: 1,
+            insufficient_policy: InsufficientPolicy::default(),
+            absence_grade_penalty: None,
+            min_evidence_age_hours: 0,
+            tie_breaker_weight: 1.0,
+            trusted_source_kinds: None,
+            reconsideration_patterns: Vec::new(),
+            reconsideration_supersedes: true,
+            min_extracted_fraction: 0.5,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -232,16 +851,37 @@ struct ResolvedStorage {
     db_path: String,
     vault_path: PathBuf,
     out_dir: PathBuf,
+    artifacts: ArtifactStorage,
 }
 
 fn load_config(path: &PathBuf) -> Result<Config> {
     ensure_config_path(path)?;
-    let raw = fs::read_to_string(path)?;
-    let config = toml::from_str(&raw)?;
+    let raw = fs::read_to_string(path).map_err(|err| {
+        FailureKind::Config.error(format!("Failed to read config {}: {err}", path.display()))
+    })?;
+    let config = toml::from_str(&raw).map_err(|err| {
+        FailureKind::Config.error(format!("Invalid config {}: {err}", path.display()))
+    })?;
+    // Zones outside the built-in US list are rejected up front, not when a report first needs one.
+    resolve_reporting_zone(&config)?;
     warn_missing_config_keys(&config);
     Ok(config)
 }
 
+/// Storage for commands taking `--config` and `--db`: the DB path is `--db`, else the config's
+/// `storage.db_path`, else `civic.db`.
+fn resolve_command_storage(
+    config: Option<&PathBuf>,
+    db: Option<String>,
+) -> Result<ResolvedStorage> {
+    let config = config.map(load_config).transpose()?;
+    let mut storage = resolve_storage(config.as_ref());
+    if let Some(db) = db {
+        storage.db_path = db;
+    }
+    Ok(storage)
+}
+
 fn resolve_storage(config: Option<&Config>) -> ResolvedStorage {
     let storage = config.and_then(|cfg| cfg.storage.as_ref());
     let db_path = storage
@@ -257,31 +897,205 @@ fn resolve_storage(config: Option<&Config>) -> ResolvedStorage {
         db_path,
         vault_path: PathBuf::from(vault_path),
         out_dir: PathBuf::from(out_dir),
+        artifacts: ArtifactStorage {
+            max_body_text_bytes: storage.and_then(|value| value.max_body_text_bytes),
+            store_raw_json: storage.and_then(|value| value.store_raw_json).unwrap_or(true),
+        },
     }
 }
 
-fn ensure_config_path(path: &Path) -> Result<()> {
-    if !path.exists() {
-        return Err(anyhow!(
-            "Config file not found: {}. Tip: cp config.example.toml config.toml",
-            path.display()
-        ));
+fn resolve_vault_layout(config: Option<&Config>) -> VaultLayout {
+    let vault = config.and_then(|cfg| cfg.vault.as_ref());
+    let defaults = VaultLayout::default();
+    VaultLayout {
+        index_dir: vault
+            .and_then(|value| value.index_dir.clone())
+            .unwrap_or(defaults.index_dir),
+        artifacts_dir: vault
+            .and_then(|value| value.artifacts_dir.clone())
+            .unwrap_or(defaults.artifacts_dir),
+        meetings_dir: vault
+            .and_then(|value| value.meetings_dir.clone())
+            .unwrap_or(defaults.meetings_dir),
+        reports_dir: vault
+            .and_then(|value| value.reports_dir.clone())
+            .unwrap_or(defaults.reports_dir),
+        officials_dir: vault
+            .and_then(|value| value.officials_dir.clone())
+            .unwrap_or(defaults.officials_dir),
     }
-    Ok(())
 }
 
-fn warn_missing_config_keys(config: &Config) {
-    let mut missing = Vec::new();
-    let storage = config.storage.as_ref();
-    if storage
-        .and_then(|value| value.db_path.as_ref())
-        .is_none()
-    {
-        missing.push("storage.db_path");
+fn official_notes_enabled(config: Option<&Config>) -> bool {
+    config
+        .and_then(|cfg| cfg.vault.as_ref())
+        .and_then(|vault| vault.official_notes)
+        .unwrap_or(false)
+}
+
+fn resolve_scoring(config: Option<&Config>) -> ResolvedScoring {
+    let scoring = config.and_then(|cfg| cfg.scoring.as_ref());
+    let defaults = ResolvedScoring::default();
+    ResolvedScoring {
+        committee_vote_weight: scoring
+            .and_then(|value| value.committee_vote_weight)
+            .unwrap_or(defaults.committee_vote_weight)
+            .max(0.0),
+        min_votes_for_grade: scoring
+            .and_then(|value| value.min_votes_for_grade)
+            .unwrap_or(defaults.min_votes_for_grade),
+        insufficient_policy: scoring
+            .and_then(|value| value.insufficient_policy)
+            .unwrap_or(defaults.insufficient_policy),
+        absence_grade_penalty: scoring
+            .and_then(|value| value.absence_grade_penalty)
+            .map(|penalty| penalty.max(0.0)),
+        min_evidence_age_hours: scoring
+            .and_then(|value| value.min_evidence_age_hours)
+            .unwrap_or(defaults.min_evidence_age_hours),
+        tie_breaker_weight: scoring
+            .and_then(|value| value.tie_breaker_weight)
+            .unwrap_or(defaults.tie_breaker_weight)
+            .max(0.0),
+        trusted_source_kinds: scoring.and_then(|value| value.trusted_source_kinds.clone()),
+        reconsideration_patterns: if scoring
+            .and_then(|value| value.detect_reconsiderations)
+            .unwrap_or(false)
+        {
+            scoring
+                .and_then(|value| value.reconsideration_patterns.clone())
+                .unwrap_or_else(|| {
+                    DEFAULT_RECONSIDERATION_PATTERNS.map(str::to_string).to_vec()
+                })
+        } else {
+            Vec::new()
+        },
+        reconsideration_supersedes: scoring
+            .and_then(|value| value.reconsideration_supersedes)
+            .unwrap_or(defaults.reconsideration_supersedes),
+        min_extracted_fraction: scoring
+            .and_then(|value| value.min_extracted_fraction)
+            .unwrap_or(defaults.min_extracted_fraction)
+            .clamp(0.0, 1.0),
     }
-    if storage
-        .and_then(|value| value.vault_path.as_ref())
-        .is_none()
+}
+
+fn resolve_reporting_zone(config: &Config) -> Result<ReportingZone> {
+    match config
+        .reporting
+        .as_ref()
+        .and_then(|reporting| reporting.timezone.as_deref())
+    {
+        Some(name) => ReportingZone::from_name(name)
+            .map_err(|err| FailureKind::Config.error(format!("reporting.timezone: {err}"))),
+        None => Ok(ReportingZone::utc()),
+    }
+}
+
+fn resolve_report_top_n(config: Option<&Config>) -> Result<usize> {
+    let top_n = config
+        .and_then(|cfg| cfg.reporting.as_ref())
+        .and_then(|reporting| reporting.top_n)
+        .unwrap_or(3);
+    if top_n == 0 {
+        return Err(FailureKind::Config.error("reporting.top_n must be at least 1"));
+    }
+    Ok(top_n)
+}
+
+fn resolve_exclude_insufficient(config: Option<&Config>) -> bool {
+    config
+        .and_then(|cfg| cfg.reporting.as_ref())
+        .and_then(|reporting| reporting.exclude_insufficient_from_average)
+        .unwrap_or(false)
+}
+
+fn resolve_max_staleness_days(config: Option<&Config>, max_age: Option<u32>) -> Result<u32> {
+    let days = max_age
+        .or_else(|| {
+            config
+                .and_then(|cfg| cfg.reporting.as_ref())
+                .and_then(|reporting| reporting.max_staleness_days)
+        })
+        .unwrap_or(14);
+    if days == 0 {
+        return Err(FailureKind::Config.error("reporting.max_staleness_days must be at least 1"));
+    }
+    Ok(days)
+}
+
+/// Report groupings for the `source.kind` values the collectors emit.
+const DEFAULT_ARTIFACT_CATEGORIES: &[(&str, &str)] = &[
+    ("public_notice", "Legal Notices"),
+    ("url", "Web"),
+    ("rss", "Web"),
+    ("file", "Documents"),
+];
+
+/// Category for source kinds with no mapping.
+const OTHER_ARTIFACT_CATEGORY: &str = "Other";
+
+fn resolve_artifact_categories(config: Option<&Config>) -> HashMap<String, String> {
+    let mut categories: HashMap<String, String> = DEFAULT_ARTIFACT_CATEGORIES
+        .iter()
+        .map(|(kind, category)| (kind.to_string(), category.to_string()))
+        .collect();
+    if let Some(overrides) = config
+        .and_then(|cfg| cfg.reporting.as_ref())
+        .and_then(|reporting| reporting.artifact_categories.as_ref())
+    {
+        categories.extend(overrides.clone());
+    }
+    categories
+}
+
+/// `reporting.languages`, lowercased. Artifacts without a `language` count as
+/// `DEFAULT_ARTIFACT_LANGUAGE`.
+fn resolve_report_languages(config: Option<&Config>) -> Result<Option<Vec<String>>> {
+    let Some(languages) = config
+        .and_then(|cfg| cfg.reporting.as_ref())
+        .and_then(|reporting| reporting.languages.as_ref())
+    else {
+        return Ok(None);
+    };
+    let languages: Vec<String> =
+        languages.iter().map(|code| code.trim().to_ascii_lowercase()).collect();
+    for code in &languages {
+        if !is_language_code(code) {
+            return Err(FailureKind::Config.error(format!(
+                "reporting.languages: {code:?} is not a two-letter ISO 639-1 code"
+            )));
+        }
+    }
+    Ok(Some(languages))
+}
+
+fn is_language_code(code: &str) -> bool {
+    code.len() == 2 && code.chars().all(|ch| ch.is_ascii_lowercase())
+}
+
+fn ensure_config_path(path: &Path) -> Result<()> {
+    if !path.exists() {
+        return Err(FailureKind::Config.error(format!(
+            "Config file not found: {}. Tip: cp config.example.toml config.toml",
+            path.display()
+        )));
+    }
+    Ok(())
+}
+
+fn warn_missing_config_keys(config: &Config) {
+    let mut missing = Vec::new();
+    let storage = config.storage.as_ref();
+    if storage
+        .and_then(|value| value.db_path.as_ref())
+        .is_none()
+    {
+        missing.push("storage.db_path");
+    }
+    if storage
+        .and_then(|value| value.vault_path.as_ref())
+        .is_none()
     {
         missing.push("storage.vault_path");
     }
@@ -299,42 +1113,294 @@ fn warn_missing_config_keys(config: &Config) {
     }
 }
 
-fn schema_export(out_dir: PathBuf) -> Result<()> {
-    fs::create_dir_all(&out_dir)?;
+/// Keys each config table accepts, by dotted table path (`""` is the top level). Tables not
+/// listed here, like `tagging.tag_min_hits`, are free-form and their keys are not checked.
+/// Includes keys only the Python workers read (`project`, `sources.*.query`, `tagging`).
+const KNOWN_CONFIG_KEYS: &[(&str, &[&str])] = &[
+    (
+        "",
+        &[
+            "project", "storage", "vault", "sources", "ai", "publish", "scoring", "reporting",
+            "site", "tagging",
+        ],
+    ),
+    ("project", &["jurisdiction", "county", "state"]),
+    (
+        "storage",
+        &[
+            "db_path", "vault_path", "out_dir", "lock_stale_minutes", "max_body_text_bytes",
+            "store_raw_json",
+        ],
+    ),
+    (
+        "vault",
+        &[
+            "index_dir", "artifacts_dir", "meetings_dir", "reports_dir", "officials_dir",
+            "official_notes",
+        ],
+    ),
+    ("sources", &["ky_public_notice", "larue_fiscal_court", "wayback", "school_board"]),
+    ("sources.ky_public_notice", &["enabled", "query", "tags", "base_url"]),
+    ("sources.larue_fiscal_court", &["enabled", "base_url"]),
+    (
+        "sources.wayback",
+        &[
+            "enabled", "urls", "rate_limit_seconds", "limit_per_run", "include_subpaths",
+            "high_impact_url_keywords",
+        ],
+    ),
+    ("sources.school_board", &["enabled", "base_url"]),
+    ("ai", &["enabled", "provider"]),
+    ("publish", &["enabled", "provider"]),
+    (
+        "scoring",
+        &[
+            "committee_vote_weight", "min_votes_for_grade", "insufficient_policy",
+            "absence_grade_penalty", "min_evidence_age_hours", "tie_breaker_weight",
+            "trusted_source_kinds", "detect_reconsiderations", "reconsideration_patterns",
+            "reconsideration_supersedes", "min_extracted_fraction",
+        ],
+    ),
+    (
+        "reporting",
+        &[
+            "timezone", "top_n", "collapse_superseded", "max_staleness_days",
+            "artifact_categories", "languages", "exclude_insufficient_from_average",
+        ],
+    ),
+    (
+        "site",
+        &[
+            "enable_commentary", "commentary_style", "disclaimer", "template_dir",
+            "display_decimals", "commentary_seed_override", "commentary_sentences",
+            "artifacts_index_limit", "trend_hysteresis", "sign",
+        ],
+    ),
+    (
+        "tagging",
+        &[
+            "enabled", "min_hits_default", "min_hits_broad", "broad_tags", "tag_min_hits", "rules",
+            "keywords",
+        ],
+    ),
+];
+
+/// Problems found by `config check`: errors make the command fail, warnings do not.
+#[derive(Debug, Default)]
+struct ConfigReport {
+    errors: Vec<String>,
+    warnings: Vec<String>,
+}
 
-    let artifact_schema = schema_for!(civic_core::schema::Artifact);
-    fs::write(
-        out_dir.join("Artifact.schema.json"),
-        serde_json::to_string_pretty(&artifact_schema)?,
-    )?;
+fn config_check(config_path: PathBuf) -> Result<()> {
+    ensure_config_path(&config_path)?;
+    let raw = fs::read_to_string(&config_path).map_err(|err| {
+        FailureKind::Config.error(format!("Failed to read config {}: {err}", config_path.display()))
+    })?;
+    let report = check_config(&raw, Path::new("."));
+    for warning in &report.warnings {
+        eprintln!("warning: {warning}");
+    }
+    for error in &report.errors {
+        eprintln!("error: {error}");
+    }
+    if !report.errors.is_empty() {
+        return Err(FailureKind::Config.error(format!(
+            "{} has {} error(s)",
+            config_path.display(),
+            report.errors.len()
+        )));
+    }
+    println!(
+        "Config OK: {} ({} warning(s))",
+        config_path.display(),
+        report.warnings.len()
+    );
+    Ok(())
+}
 
-    let source_schema = schema_for!(civic_core::schema::SourceRef);
-    fs::write(
-        out_dir.join("SourceRef.schema.json"),
-        serde_json::to_string_pretty(&source_schema)?,
-    )?;
+/// Validates a config file's contents. Scripts, the rubric, and relative paths resolve against
+/// `root`, the directory the pipeline runs from.
+fn check_config(raw: &str, root: &Path) -> ConfigReport {
+    let mut report = ConfigReport::default();
+    let value: toml::Value = match toml::from_str(raw) {
+        Ok(value) => value,
+        Err(err) => {
+            report.errors.push(format!("invalid TOML: {err}"));
+            return report;
+        }
+    };
+    if let Some(table) = value.as_table() {
+        check_unknown_keys("", table, &mut report);
+    }
+    let config: Config = match toml::from_str(raw) {
+        Ok(config) => config,
+        Err(err) => {
+            report.errors.push(format!("invalid value: {err}"));
+            return report;
+        }
+    };
 
-    let body_schema = schema_for!(civic_core::schema::Body);
-    fs::write(
-        out_dir.join("Body.schema.json"),
-        serde_json::to_string_pretty(&body_schema)?,
-    )?;
+    let resolved = [
+        resolve_reporting_zone(&config).map(|_| ()),
+        resolve_report_top_n(Some(&config)).map(|_| ()),
+        resolve_max_staleness_days(Some(&config), None).map(|_| ()),
+        resolve_report_languages(Some(&config)).map(|_| ()),
+    ];
+    for err in resolved.into_iter().filter_map(|result| result.err()) {
+        report.errors.push(err.to_string());
+    }
 
-    let meeting_schema = schema_for!(civic_core::schema::Meeting);
-    fs::write(
-        out_dir.join("Meeting.schema.json"),
-        serde_json::to_string_pretty(&meeting_schema)?,
-    )?;
+    if let Err(err) = Rubric::load_from_dir(&root.join("rubric")) {
+        report.errors.push(format!("rubric directory {}: {err:#}", root.join("rubric").display()));
+    }
+    let site = resolve_site_config(config.site.as_ref());
+    if let Some(template_dir) = &site.template_dir
+        && !root.join(template_dir).is_dir()
+    {
+        report.errors.push(format!(
+            "site.template_dir: {} is not a directory",
+            template_dir.display()
+        ));
+    }
+
+    let mut scripts = vec![
+        "workers/collectors/ky_public_notice_larue.py",
+        "workers/parsers/extract_text.py",
+        "workers/parsers/tag_artifacts.py",
+        "workers/parsers/parse_meeting_minutes.py",
+    ];
+    if fiscal_court_enabled(&config) {
+        scripts.push("workers/collectors/larue_fiscal_court_agendas.py");
+    }
+    if wayback_enabled(&config) {
+        scripts.push("workers/collectors/wayback_backfill.py");
+        let urls = config
+            .sources
+            .as_ref()
+            .and_then(|sources| sources.wayback.as_ref())
+            .and_then(|wayback| wayback.urls.as_ref());
+        if urls.is_none_or(|urls| urls.iter().all(|url| url.trim().is_empty())) {
+            report
+                .errors
+                .push("sources.wayback is enabled but has no urls".to_string());
+        }
+    }
+    for script in scripts {
+        if !root.join(script).is_file() {
+            report.errors.push(format!("script not found: {script}"));
+        }
+    }
+
+    let storage = config.storage.as_ref();
+    for (key, value) in [
+        ("db_path", storage.and_then(|value| value.db_path.as_ref())),
+        ("vault_path", storage.and_then(|value| value.vault_path.as_ref())),
+        ("out_dir", storage.and_then(|value| value.out_dir.as_ref())),
+    ] {
+        if value.is_none() {
+            report
+                .warnings
+                .push(format!("storage.{key} is not set; the default will be used"));
+        }
+    }
+    report
+}
 
+fn check_unknown_keys(path: &str, table: &toml::Table, report: &mut ConfigReport) {
+    let Some((_, known)) = KNOWN_CONFIG_KEYS.iter().find(|(table_path, _)| *table_path == path)
+    else {
+        return;
+    };
+    for (key, value) in table {
+        let key_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        if !known.contains(&key.as_str()) {
+            report.warnings.push(format!("unknown key `{key_path}` is ignored"));
+        } else if let Some(nested) = value.as_table() {
+            check_unknown_keys(&key_path, nested, report);
+        }
+    }
+}
+
+fn generated_schemas() -> Result<Vec<(&'static str, String)>> {
+    Ok(vec![
+        (
+            "Artifact.schema.json",
+            serde_json::to_string_pretty(&schema_for!(civic_core::schema::Artifact))?,
+        ),
+        (
+            "SourceRef.schema.json",
+            serde_json::to_string_pretty(&schema_for!(civic_core::schema::SourceRef))?,
+        ),
+        (
+            "Body.schema.json",
+            serde_json::to_string_pretty(&schema_for!(civic_core::schema::Body))?,
+        ),
+        (
+            "Meeting.schema.json",
+            serde_json::to_string_pretty(&schema_for!(civic_core::schema::Meeting))?,
+        ),
+    ])
+}
+
+fn schema_export(out_dir: PathBuf) -> Result<()> {
+    fs::create_dir_all(&out_dir)?;
+    for (filename, contents) in generated_schemas()? {
+        write_atomic(out_dir.join(filename), contents)?;
+    }
     println!("Exported schemas to {}", out_dir.display());
     Ok(())
 }
 
-fn ingest_artifact(path: PathBuf, db_path: &str) -> Result<()> {
-    let raw = fs::read_to_string(&path)?;
-    let raw_json: serde_json::Value = serde_json::from_str(&raw)?;
+fn schema_check(out_dir: PathBuf) -> Result<()> {
+    let mut stale = Vec::new();
+    for (filename, expected) in generated_schemas()? {
+        let path = out_dir.join(filename);
+        let Ok(actual) = fs::read(&path) else {
+            stale.push(format!("{}: missing", path.display()));
+            continue;
+        };
+        if actual == expected.as_bytes() {
+            continue;
+        }
+        let actual = String::from_utf8_lossy(&actual);
+        let first_diff = expected
+            .lines()
+            .zip(actual.lines())
+            .position(|(left, right)| left != right)
+            .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+        stale.push(format!(
+            "{}: differs from generated schema (first difference at line {}; {} vs {} lines)",
+            path.display(),
+            first_diff + 1,
+            actual.lines().count(),
+            expected.lines().count()
+        ));
+    }
+    if stale.is_empty() {
+        println!("OK: schemas in {} are up to date", out_dir.display());
+        return Ok(());
+    }
+    for entry in &stale {
+        eprintln!("{entry}");
+    }
+    Err(FailureKind::Validation.error(format!(
+        "{} schema file(s) are stale; run `larue schema export --out-dir {}`",
+        stale.len(),
+        out_dir.display()
+    )))
+}
+
+fn ingest_artifact(path: PathBuf, db_path: &str, storage: ArtifactStorage) -> Result<()> {
+    let raw = fs::read(&path)?;
+    let raw_json = decode_artifact_json(&raw, &path.display().to_string())?;
     let conn = civic_core::db::open(db_path)?;
-    let artifact_id = ingest_artifact_json(&conn, raw_json)?;
+    let rubric = ingest_rubric();
+    let artifact_id = ingest_artifact_json(&conn, raw_json, storage, rubric.as_deref())?;
 
     println!(
         "Ingested artifact id={} into db={}",
@@ -344,30 +1410,91 @@ fn ingest_artifact(path: PathBuf, db_path: &str) -> Result<()> {
     Ok(())
 }
 
+/// `ingest --stdin`: one artifact JSON document read to the end of `reader`.
+fn ingest_artifact_from(
+    mut reader: impl std::io::Read,
+    source: &str,
+    db_path: &str,
+    storage: ArtifactStorage,
+) -> Result<String> {
+    let mut raw = Vec::new();
+    reader.read_to_end(&mut raw)?;
+    let raw_json = decode_artifact_json(&raw, source)?;
+    let conn = civic_core::db::open(db_path)?;
+    let rubric = ingest_rubric();
+    let artifact_id = ingest_artifact_json(&conn, raw_json, storage, rubric.as_deref())?;
+    println!("Ingested artifact id={artifact_id} into db={db_path}");
+    Ok(artifact_id)
+}
+
+/// Tag added to artifacts whose `body_text` held invalid UTF-8 and was decoded lossily.
+const LOSSY_DECODE_TAG: &str = "lossy_decode";
+
+/// Parses artifact JSON bytes. Invalid UTF-8 is tolerated only inside `body_text` (PDF
+/// extraction sometimes yields Latin-1): those bytes become U+FFFD, the artifact is tagged
+/// `lossy_decode`, and a warning names `source`. Anywhere else it is a validation error.
+fn decode_artifact_json(raw: &[u8], source: &str) -> Result<serde_json::Value> {
+    let invalid_json = |err: serde_json::Error| {
+        FailureKind::Validation.error(format!("Invalid artifact JSON in {source}: {err}"))
+    };
+    if let Ok(text) = std::str::from_utf8(raw) {
+        return serde_json::from_str(text).map_err(invalid_json);
+    }
+    let mut raw_json: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(raw)).map_err(invalid_json)?;
+    let Some(object) = raw_json.as_object_mut() else {
+        return Err(FailureKind::Validation.error(format!("Invalid UTF-8 in {source}")));
+    };
+    let body_text = object.remove("body_text");
+    if serde_json::to_string(object)?.contains('\u{FFFD}') {
+        return Err(FailureKind::Validation.error(format!(
+            "Invalid UTF-8 outside body_text in {source}"
+        )));
+    }
+    if let Some(body_text) = body_text {
+        object.insert("body_text".to_string(), body_text);
+    }
+    if let Some(tags) = object.get_mut("tags").and_then(|tags| tags.as_array_mut())
+        && !tags.iter().any(|tag| tag == LOSSY_DECODE_TAG)
+    {
+        tags.push(LOSSY_DECODE_TAG.into());
+    }
+    eprintln!("Warning: invalid UTF-8 in body_text of {source}; replaced with U+FFFD");
+    Ok(raw_json)
+}
+
 // Keep validation lightweight for v1; expand later.
 fn validate_artifact(a: &civic_core::schema::Artifact) -> Result<()> {
     if a.id.trim().is_empty() {
-        return Err(anyhow!("Artifact.id must not be empty"));
+        return Err(FailureKind::Validation.error("Artifact.id must not be empty"));
     }
     if a.source.kind.trim().is_empty() {
-        return Err(anyhow!("Artifact.source.kind must not be empty"));
+        return Err(FailureKind::Validation.error("Artifact.source.kind must not be empty"));
     }
     if a.source.value.trim().is_empty() {
-        return Err(anyhow!("Artifact.source.value must not be empty"));
+        return Err(FailureKind::Validation.error("Artifact.source.value must not be empty"));
     }
     if a.source.retrieved_at.trim().is_empty() {
-        return Err(anyhow!("Artifact.source.retrieved_at must not be empty"));
+        return Err(FailureKind::Validation.error("Artifact.source.retrieved_at must not be empty"));
+    }
+    if let Some(language) = &a.language
+        && !is_language_code(language)
+    {
+        return Err(FailureKind::Validation.error(format!(
+            "Artifact.language must be a lowercase ISO 639-1 code, got {language:?}"
+        )));
     }
     Ok(())
 }
 
-fn ingest_dir(dir: PathBuf, db_path: &str) -> Result<()> {
+fn ingest_dir(dir: PathBuf, db_path: &str, storage: ArtifactStorage) -> Result<()> {
     if !dir.exists() {
         println!("No artifacts directory found at {}", dir.display());
         return Ok(());
     }
 
     let conn = civic_core::db::open(db_path)?;
+    let rubric = ingest_rubric();
 
     let mut ingested = 0usize;
     let mut failed = 0usize;
@@ -378,7 +1505,9 @@ fn ingest_dir(dir: PathBuf, db_path: &str) -> Result<()> {
         .collect::<Vec<_>>();
     entries.sort_by_key(|entry| entry.path());
 
+    let mut progress = Progress::new("ingest-dir", entries.len());
     for entry in entries {
+        progress.tick();
         let path = entry.path();
         if !path.is_file() {
             continue;
@@ -395,7 +1524,7 @@ fn ingest_dir(dir: PathBuf, db_path: &str) -> Result<()> {
             skipped += 1;
             continue;
         }
-        let raw = match fs::read_to_string(&path) {
+        let raw = match fs::read(&path) {
             Ok(raw) => raw,
             Err(err) => {
                 failed += 1;
@@ -403,7 +1532,7 @@ fn ingest_dir(dir: PathBuf, db_path: &str) -> Result<()> {
                 continue;
             }
         };
-        let raw_json: serde_json::Value = match serde_json::from_str(&raw) {
+        let raw_json = match decode_artifact_json(&raw, &path.display().to_string()) {
             Ok(raw_json) => raw_json,
             Err(err) => {
                 failed += 1;
@@ -428,7 +1557,7 @@ fn ingest_dir(dir: PathBuf, db_path: &str) -> Result<()> {
             skipped += 1;
             continue;
         }
-        match ingest_artifact_json(&conn, raw_json) {
+        match ingest_artifact_json(&conn, raw_json, storage, rubric.as_deref()) {
             Ok(_) => ingested += 1,
             Err(err) => {
                 failed += 1;
@@ -447,199 +1576,702 @@ fn ingest_dir(dir: PathBuf, db_path: &str) -> Result<()> {
     Ok(())
 }
 
-fn ingest_meeting(path: PathBuf, db_path: &str) -> Result<()> {
-    let raw = fs::read_to_string(&path)?;
-    let raw_json: serde_json::Value = serde_json::from_str(&raw)?;
-    let meeting: civic_core::schema::Meeting =
-        serde_json::from_value(raw_json.clone()).map_err(|e| anyhow!("Schema mismatch: {e}"))?;
-    validate_meeting(&meeting)?;
-    let conn = civic_core::db::open(db_path)?;
-    civic_core::db::upsert_meeting(&conn, &meeting, &raw_json)?;
-    println!("Ingested meeting id={} into db={}", meeting.id, db_path);
-    Ok(())
-}
-
-fn validate_meeting(meeting: &civic_core::schema::Meeting) -> Result<()> {
-    if meeting.id.trim().is_empty() {
-        return Err(anyhow!("Meeting.id must not be empty"));
-    }
-    if meeting.body_id.trim().is_empty() {
-        return Err(anyhow!("Meeting.body_id must not be empty"));
-    }
-    if meeting.started_at.trim().is_empty() {
-        return Err(anyhow!("Meeting.started_at must not be empty"));
-    }
-    Ok(())
-}
-
-fn ingest_artifact_json(
-    conn: &rusqlite::Connection,
-    raw_json: serde_json::Value,
-) -> Result<String> {
-    let artifact: civic_core::schema::Artifact =
-        serde_json::from_value(raw_json.clone()).map_err(|e| anyhow!("Schema mismatch: {e}"))?;
-
-    validate_artifact(&artifact)?;
-    civic_core::db::upsert_artifact(conn, &artifact, &raw_json)?;
-    Ok(artifact.id)
+/// Field moves applied to legacy artifact JSON. Keys and values are dotted paths
+/// (e.g. `url = "source.value"`); `defaults` fills paths that are still missing afterwards.
+#[derive(Debug, Deserialize)]
+struct LegacyMapping {
+    #[serde(default)]
+    rename: BTreeMap<String, String>,
+    #[serde(default)]
+    defaults: BTreeMap<String, serde_json::Value>,
 }
 
-fn ingest_meeting_dir(dir: PathBuf, db_path: &str) -> Result<()> {
+fn import_legacy(
+    dir: PathBuf,
+    db_path: &str,
+    storage: ArtifactStorage,
+    mapping_path: PathBuf,
+) -> Result<()> {
+    let mapping_raw = fs::read_to_string(&mapping_path)
+        .map_err(|err| {
+            let path = mapping_path.display();
+            FailureKind::Config.error(format!("Failed to read mapping {path}: {err}"))
+        })?;
+    let mapping: LegacyMapping = toml::from_str(&mapping_raw).map_err(|err| {
+        FailureKind::Config.error(format!("Invalid mapping {}: {err}", mapping_path.display()))
+    })?;
     if !dir.exists() {
-        return Ok(());
+        return Err(FailureKind::Config.error(format!(
+            "Legacy directory not found: {}",
+            dir.display()
+        )));
     }
 
     let conn = civic_core::db::open(db_path)?;
-    let mut ingested = 0usize;
+    let rubric = ingest_rubric();
+    let mut remapped = 0usize;
     let mut failed = 0usize;
-    let mut skipped = 0usize;
 
-    for entry in fs::read_dir(&dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
-            skipped += 1;
-            continue;
-        }
-        let raw = match fs::read_to_string(&path) {
-            Ok(raw) => raw,
-            Err(err) => {
-                failed += 1;
-                eprintln!("Failed to read meeting {}: {err}", path.display());
-                continue;
-            }
-        };
-        let raw_json: serde_json::Value = match serde_json::from_str(&raw) {
-            Ok(raw_json) => raw_json,
+    let mut entries = fs::read_dir(&dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file() && path.extension().and_then(|ext| ext.to_str()) == Some("json")
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    for path in entries {
+        let raw_json: serde_json::Value = match fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|raw| serde_json::from_str(&raw).map_err(anyhow::Error::from))
+        {
+            Ok(value) => value,
             Err(err) => {
                 failed += 1;
-                eprintln!("Failed to parse meeting {}: {err}", path.display());
-                continue;
-            }
-        };
-        let meeting_id = match raw_json.get("id").and_then(|value| value.as_str()) {
-            Some(value) => value,
-            None => {
-                failed += 1;
-                eprintln!("Missing meeting id in {}", path.display());
+                eprintln!("Failed to read {}: {err}", path.display());
                 continue;
             }
         };
-        if civic_core::db::meeting_exists(&conn, meeting_id)? {
-            skipped += 1;
-            continue;
-        }
-        let meeting: civic_core::schema::Meeting = match serde_json::from_value(raw_json.clone()) {
-            Ok(meeting) => meeting,
+        let mapped = apply_legacy_mapping(raw_json, &mapping);
+        match ingest_artifact_json(&conn, mapped, storage, rubric.as_deref()) {
+            Ok(_) => remapped += 1,
             Err(err) => {
                 failed += 1;
-                eprintln!("Meeting schema mismatch in {}: {err}", path.display());
-                continue;
+                eprintln!("Failed to import {}: {err}", path.display());
             }
-        };
-        if let Err(err) = validate_meeting(&meeting) {
-            failed += 1;
-            eprintln!("Meeting validation failed in {}: {err}", path.display());
-            continue;
-        }
-        if let Err(err) = civic_core::db::upsert_meeting(&conn, &meeting, &raw_json) {
-            failed += 1;
-            eprintln!("Failed to ingest meeting {}: {err}", path.display());
-            continue;
         }
-        ingested += 1;
     }
 
     println!(
-        "Ingested {} meetings, {} failed, {} skipped in {}",
-        ingested,
+        "Remapped {} legacy artifacts, {} failed in {}",
+        remapped,
         failed,
-        skipped,
         dir.display()
     );
     Ok(())
 }
 
-// Build/update an Obsidian vault from the sqlite database. Will be expanded further.
-fn build_vault(db_path: &str, vault: PathBuf) -> Result<()> {
+fn artifact_history(db_path: &str, id: &str) -> Result<()> {
     let conn = civic_core::db::open(db_path)?;
-    obsidian::vault::build_vault(&conn, &vault)?;
-    println!("Vault updated at {}", vault.display());
+    print!("{}", render_artifact_history(&conn, id)?);
     Ok(())
 }
 
-fn run_weekly(config_path: PathBuf) -> Result<()> {
-    ensure_config_path(&config_path)?;
-    let python = find_python_interpreter()?;
-    let collector_path = Path::new("workers/collectors/ky_public_notice_larue.py");
-    if !collector_path.exists() {
-        return Err(anyhow!(
-            "Collector script not found: {}",
-            collector_path.display()
-        ));
-    }
-
-    let config = load_config(&config_path)?;
-    let storage = resolve_storage(Some(&config));
+/// Lists each superseded version of the artifact's text and the current one, then a unified
+/// diff between each consecutive pair.
+fn render_artifact_history(conn: &rusqlite::Connection, id: &str) -> Result<String> {
+    let current: Option<(String, Option<String>)> = conn
+        .query_row(
+            "SELECT retrieved_at, body_text FROM artifacts WHERE id = ?1",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    let Some((retrieved_at, body_text)) = current else {
+        return Err(FailureKind::Validation.error(format!("Unknown artifact id: {id}")));
+    };
+    let mut versions: Vec<(String, String, Option<String>)> =
+        civic_core::db::load_artifact_revisions(conn, id)?
+            .into_iter()
+            .map(|revision| {
+                let label = format!(
+                    "revision {} (retrieved {}, replaced {})",
+                    revision.revision, revision.retrieved_at, revision.recorded_at
+                );
+                (label, revision.retrieved_at, revision.body_text)
+            })
+            .collect();
+    versions.push((format!("current (retrieved {retrieved_at})"), retrieved_at, body_text));
 
-    let output = Command::new(&python)
-        .arg(collector_path)
-        .arg("--config")
-        .arg(&config_path)
-        .output()?;
+    let mut out = format!("Artifact {id}: {} revision(s)\n", versions.len() - 1);
+    for (label, _, _) in &versions {
+        out.push_str(&format!("  {label}\n"));
+    }
+    for pair in versions.windows(2) {
+        let (old, new) = (&pair[0], &pair[1]);
+        let old_text = old.2.as_deref().unwrap_or("");
+        let new_text = new.2.as_deref().unwrap_or("");
+        let diff = similar::TextDiff::from_lines(old_text, new_text)
+            .unified_diff()
+            .missing_newline_hint(false)
+            .header(&format!("{id}@{}", old.1), &format!("{id}@{}", new.1))
+            .to_string();
+        out.push('\n');
+        out.push_str(&diff);
+        if !diff.ends_with('\n') {
+            out.push('\n');
+        }
+    }
+    Ok(out)
+}
 
-    if !output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        eprintln!("Collector failed with status {}", output.status);
-        if !stdout.is_empty() {
-            eprintln!("Collector stdout:\n{stdout}");
+fn merge_db(into: &str, from: &[String]) -> Result<()> {
+    let sources = parse_merge_sources(from)?;
+    let target_path = fs::canonicalize(into).ok();
+    for (tag, path) in &sources {
+        if !path.is_file() {
+            return Err(FailureKind::Database
+                .error(format!("Source database not found: {}", path.display())));
         }
-        if !stderr.is_empty() {
-            eprintln!("Collector stderr:\n{stderr}");
+        if target_path.is_some() && fs::canonicalize(path).ok() == target_path {
+            return Err(FailureKind::Validation
+                .error(format!("Source {tag} is the target database {into}")));
         }
-        return Err(anyhow!("Collector exited with failure"));
     }
 
-    if fiscal_court_enabled(&config) {
-        run_fiscal_court_collector(&python, &config_path)?;
+    let conn = civic_core::db::open(into)?;
+    for (tag, path) in &sources {
+        let path_str = path.to_str().ok_or_else(|| {
+            FailureKind::Validation.error(format!("Non-UTF-8 path: {}", path.display()))
+        })?;
+        let counts = civic_core::db::merge_database(&conn, path_str, tag)?;
+        let tables = counts
+            .tables
+            .iter()
+            .map(|(table, copied)| format!("{copied} {table}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "Merged {} as {tag}: {} new bodies, {tables}",
+            path.display(),
+            counts.bodies
+        );
+        for body_id in &counts.body_conflicts {
+            eprintln!(
+                "Body {body_id} in {} differs from the one in {into}; kept the existing row",
+                path.display()
+            );
+        }
     }
+    Ok(())
+}
 
-    if wayback_enabled(&config) {
-        run_wayback_collector(&python, &config_path)?;
+/// `TAG=PATH` or `PATH` (tagged by file stem). Tags become id prefixes, so they are limited to
+/// ASCII letters, digits, `-`, and `_`, and must be unique.
+fn parse_merge_sources(from: &[String]) -> Result<Vec<(String, PathBuf)>> {
+    let mut sources: Vec<(String, PathBuf)> = Vec::new();
+    for value in from {
+        let (tag, path) = match value.split_once('=') {
+            Some((tag, path)) => (tag.to_string(), PathBuf::from(path)),
+            None => {
+                let path = PathBuf::from(value);
+                let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("");
+                (stem.to_string(), path)
+            }
+        };
+        let valid = !tag.is_empty()
+            && tag.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+        if !valid {
+            return Err(FailureKind::Validation.error(format!(
+                "Invalid merge source tag {tag:?} for {value}; use TAG=PATH with letters, digits, \
+                 '-' or '_'"
+            )));
+        }
+        if sources.iter().any(|(existing, _)| *existing == tag) {
+            return Err(FailureKind::Validation.error(format!(
+                "Merge source tag {tag} is used twice; give each source its own TAG=PATH"
+            )));
+        }
+        sources.push((tag, path));
+    }
+    Ok(sources)
+}
+
+fn apply_legacy_mapping(
+    mut value: serde_json::Value,
+    mapping: &LegacyMapping,
+) -> serde_json::Value {
+    for (from, to) in &mapping.rename {
+        if let Some(moved) = take_json_path(&mut value, from) {
+            set_json_path(&mut value, to, moved);
+        }
+    }
+    for (path, default) in &mapping.defaults {
+        if get_json_path(&value, path).is_none() {
+            set_json_path(&mut value, path, default.clone());
+        }
     }
+    value
+}
 
-    let artifacts_dir = storage.out_dir.join("artifacts");
-    ingest_dir(artifacts_dir.clone(), &storage.db_path)?;
+fn get_json_path<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    path.split('.').try_fold(value, |current, key| current.get(key))
+}
+
+fn take_json_path(value: &mut serde_json::Value, path: &str) -> Option<serde_json::Value> {
+    let (parent, key) = match path.rsplit_once('.') {
+        Some((parent, key)) => (
+            parent
+                .split('.')
+                .try_fold(&mut *value, |current, key| current.get_mut(key))?,
+            key,
+        ),
+        None => (value, path),
+    };
+    parent.as_object_mut()?.remove(key)
+}
+
+fn set_json_path(value: &mut serde_json::Value, path: &str, new_value: serde_json::Value) {
+    let mut current = value;
+    let mut keys = path.split('.').peekable();
+    while let Some(key) = keys.next() {
+        if !current.is_object() {
+            *current = serde_json::Value::Object(serde_json::Map::new());
+        }
+        let object = current.as_object_mut().expect("object ensured above");
+        if keys.peek().is_none() {
+            object.insert(key.to_string(), new_value);
+            return;
+        }
+        current = object
+            .entry(key.to_string())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+    }
+}
+
+fn ingest_meeting(
+    path: PathBuf,
+    db_path: &str,
+    storage: ArtifactStorage,
+    link_minutes: bool,
+    strict_links: bool,
+) -> Result<()> {
+    let raw = fs::read_to_string(&path)?;
+    let raw_json: serde_json::Value = serde_json::from_str(&raw)?;
+    let mut meeting: civic_core::schema::Meeting =
+        serde_json::from_value(raw_json.clone()).map_err(|e| {
+            FailureKind::Validation.error(format!("Schema mismatch: {e}"))
+        })?;
+    validate_meeting(&meeting)?;
+    let conn = civic_core::db::open(db_path)?;
+    if link_minutes {
+        match meeting.link_minutes_artifact() {
+            Some(artifact) => {
+                let artifact_json = serde_json::to_value(&artifact)?;
+                let rubric = ingest_rubric();
+                let artifact_id =
+                    ingest_artifact_json(&conn, artifact_json, storage, rubric.as_deref())?;
+                println!("Linked minutes artifact id={artifact_id} to meeting id={}", meeting.id);
+            }
+            None => eprintln!(
+                "Warning: --link-minutes ignored; meeting {} has no source",
+                meeting.id
+            ),
+        }
+    }
+    check_artifact_links(&conn, &meeting.id, &meeting.artifact_ids, strict_links)?;
+    civic_core::db::upsert_meeting(&conn, &meeting, &raw_json)?;
+    println!("Ingested meeting id={} into db={}", meeting.id, db_path);
+    Ok(())
+}
+
+/// Unknown artifact ids are skipped by scoring; warn about them, or fail in strict mode.
+fn check_artifact_links(
+    conn: &rusqlite::Connection,
+    meeting_id: &str,
+    artifact_ids: &[String],
+    strict: bool,
+) -> Result<()> {
+    let missing = civic_core::db::missing_artifacts(conn, artifact_ids)?;
+    if missing.is_empty() {
+        return Ok(());
+    }
+    let message = format!(
+        "meeting {meeting_id} references unknown artifact(s): {}",
+        missing.join(", ")
+    );
+    if strict {
+        return Err(FailureKind::Validation.error(message));
+    }
+    eprintln!("Warning: {message}");
+    Ok(())
+}
+
+fn validate_meeting(meeting: &civic_core::schema::Meeting) -> Result<()> {
+    if meeting.id.trim().is_empty() {
+        return Err(FailureKind::Validation.error("Meeting.id must not be empty"));
+    }
+    if meeting.body_id.trim().is_empty() {
+        return Err(FailureKind::Validation.error("Meeting.body_id must not be empty"));
+    }
+    if meeting.started_at.trim().is_empty() {
+        return Err(FailureKind::Validation.error("Meeting.started_at must not be empty"));
+    }
+    Ok(())
+}
+
+/// The repo rubric, whose tag aliases canonicalize ingested tags. Ingest still runs without it;
+/// tags are then stored as given.
+fn ingest_rubric() -> Option<std::sync::Arc<Rubric>> {
+    Rubric::load_cached(Path::new("rubric"))
+        .inspect_err(|err| {
+            eprintln!("Warning: failed to load rubric; tags are stored without aliasing: {err}")
+        })
+        .ok()
+}
+
+fn ingest_artifact_json(
+    conn: &rusqlite::Connection,
+    raw_json: serde_json::Value,
+    storage: ArtifactStorage,
+    rubric: Option<&Rubric>,
+) -> Result<String> {
+    let mut artifact: civic_core::schema::Artifact =
+        serde_json::from_value(raw_json.clone()).map_err(|e| {
+            FailureKind::Validation.error(format!("Schema mismatch: {e}"))
+        })?;
+
+    validate_artifact(&artifact)?;
+    // raw_json keeps the collector's original tags; the tags column stores canonical ones.
+    if let Some(rubric) = rubric {
+        artifact.tags = rubric.tag_aliases.canonicalize(&artifact.tags);
+    }
+    civic_core::db::upsert_artifact_with_storage(conn, &artifact, &raw_json, storage)?;
+    Ok(artifact.id)
+}
 
-    if let Err(err) = extract_text(config_path.clone()) {
-        eprintln!("Warning: extract-text failed: {err}");
+// Build/update an Obsidian vault from the sqlite database. Will be expanded further.
+fn build_vault(db_path: &str, vault: PathBuf, config: Option<&Config>) -> Result<()> {
+    let layout = resolve_vault_layout(config);
+    let top_n = resolve_report_top_n(config)?;
+    let exclude_insufficient = resolve_exclude_insufficient(config);
+    let conn = civic_core::db::open(db_path)?;
+    let rubric = Rubric::load_cached(Path::new("rubric")).ok();
+    obsidian::vault::build_vault(
+        &conn,
+        &vault,
+        &layout,
+        top_n,
+        exclude_insufficient,
+        rubric.as_deref(),
+    )?;
+    if official_notes_enabled(config) {
+        let storage = resolve_storage(config);
+        let reports = load_week_reports(&storage.out_dir)?;
+        let scoring = resolve_scoring(config);
+        let notes = official_notes(&conn, &reports, rubric.as_deref(), &scoring)?;
+        obsidian::vault::write_official_notes(&vault, &layout, &notes)?;
     }
+    println!("Vault updated at {}", vault.display());
+    Ok(())
+}
 
-    if let Err(err) = tag_artifacts(config_path.clone(), false) {
-        eprintln!("Warning: tag-artifacts failed: {err}");
+fn export_vault_bundle(db_path: &str, out: &Path, config: Option<&Config>) -> Result<()> {
+    let layout = resolve_vault_layout(config);
+    let top_n = resolve_report_top_n(config)?;
+    let conn = civic_core::db::open(db_path)?;
+    let storage = resolve_storage(config);
+    let reports = load_week_reports(&storage.out_dir)?;
+    let rubric = Rubric::load_cached(Path::new("rubric")).ok();
+    let scoring = resolve_scoring(config);
+    let notes = official_notes(&conn, &reports, rubric.as_deref(), &scoring)?;
+    let bundle = obsidian::bundle::build_vault_bundle(
+        &conn,
+        &layout,
+        top_n,
+        resolve_exclude_insufficient(config),
+        rubric.as_deref(),
+        &notes,
+    )?;
+    if let Some(parent) = out.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
     }
+    write_atomic(out, bundle)?;
+    println!("Vault bundle written to {}", out.display());
+    Ok(())
+}
+
+fn run_weekly(config_path: PathBuf, force: bool, checkpoint: bool) -> Result<()> {
+    ensure_config_path(&config_path)?;
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let stale_minutes = config
+        .storage
+        .as_ref()
+        .and_then(|value| value.lock_stale_minutes)
+        .unwrap_or(360);
+    let _lock = RunLock::acquire(&storage.out_dir.join(".larue.lock"), force, stale_minutes)?;
+
+    #[cfg(feature = "python-collectors")]
+    let python = run_collectors(&config, &config_path)?;
+    #[cfg(not(feature = "python-collectors"))]
+    println!(
+        "Built without the python-collectors feature: skipping collectors, extract-text, \
+         tag-artifacts, and parse-meetings; using existing JSON in {} and the native tagger",
+        storage.out_dir.display()
+    );
+
+    let artifacts_dir = storage.out_dir.join("artifacts");
+    ingest_dir(
+        artifacts_dir.clone(),
+        &storage.db_path,
+        storage.artifacts,
+    )?;
 
-    if let Err(err) = parse_meetings(&python, &config_path, &storage) {
-        eprintln!("Warning: parse-meetings failed: {err}");
+    #[cfg(feature = "python-collectors")]
+    {
+        if let Err(err) = extract_text(config_path.clone()) {
+            eprintln!("Warning: extract-text failed: {err}");
+        }
+        if let Err(err) = tag_artifacts(config_path.clone(), false) {
+            eprintln!("Warning: tag-artifacts failed: {err}");
+        }
+        if let Err(err) = parse_meetings(&python, &config_path, &storage) {
+            eprintln!("Warning: parse-meetings failed: {err}");
+        }
+    }
+    #[cfg(not(feature = "python-collectors"))]
+    if let Err(err) = tag_native(config_path.clone(), false) {
+        eprintln!("Warning: tag-native failed: {err}");
     }
 
-    if let Err(err) = ingest_decisions(config_path.clone()) {
+    if let Err(err) = ingest_decisions(config_path.clone(), false) {
         eprintln!("Warning: ingest-decisions failed: {err}");
     }
 
-    if let Err(err) = score_weekly(config_path.clone(), None) {
+    if let Err(err) = score_weekly(config_path.clone(), Path::new("rubric"), None, false, 1, false)
+    {
         eprintln!("Warning: score-weekly failed: {err}");
     }
 
-    report_weekly(config_path.clone())?;
-    build_vault(&storage.db_path, storage.vault_path)?;
-    if let Err(err) = export_site(config_path.clone()) {
+    report_weekly(config_path.clone(), None, None, false)?;
+    build_vault(&storage.db_path, storage.vault_path, Some(&config))?;
+    if let Err(err) = export_site(config_path.clone(), None, None, None) {
+        eprintln!("Warning: export-site failed: {err}");
+    }
+    if checkpoint {
+        checkpoint_db(&storage.db_path)?;
+    }
+    Ok(())
+}
+
+/// Runs the public-notice collector and any enabled optional collectors, returning the Python
+/// interpreter for the later Python stages.
+#[cfg(feature = "python-collectors")]
+fn run_collectors(config: &Config, config_path: &PathBuf) -> Result<String> {
+    let python = find_python_interpreter()?;
+    let collector_path = Path::new("workers/collectors/ky_public_notice_larue.py");
+    if !collector_path.exists() {
+        return Err(FailureKind::Collector.error(format!(
+            "Collector script not found: {}",
+            collector_path.display()
+        )));
+    }
+
+    let output = Command::new(&python)
+        .arg(collector_path)
+        .arg("--config")
+        .arg(config_path)
+        .output()?;
+
+    if !output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eprintln!("Collector failed with status {}", output.status);
+        if !stdout.is_empty() {
+            eprintln!("Collector stdout:\n{stdout}");
+        }
+        if !stderr.is_empty() {
+            eprintln!("Collector stderr:\n{stderr}");
+        }
+        return Err(FailureKind::Collector.error("Collector exited with failure"));
+    }
+
+    if fiscal_court_enabled(config) {
+        run_fiscal_court_collector(&python, config_path)?;
+    }
+
+    if wayback_enabled(config) {
+        run_wayback_collector(&python, config_path)?;
+    }
+    Ok(python)
+}
+
+fn checkpoint_db(db_path: &str) -> Result<()> {
+    let before = civic_core::db::file_size(db_path);
+    let conn = civic_core::db::open(db_path)?;
+    civic_core::db::checkpoint(&conn)?;
+    let after = civic_core::db::file_size(db_path);
+    println!("Checkpointed {db_path}: {before} -> {after} bytes (database + WAL)");
+    Ok(())
+}
+
+fn vacuum_db(db_path: &str) -> Result<()> {
+    let before = civic_core::db::file_size(db_path);
+    let conn = civic_core::db::open(db_path)?;
+    civic_core::db::vacuum(&conn)?;
+    let after = civic_core::db::file_size(db_path);
+    println!("Vacuumed {db_path}: {before} -> {after} bytes (database + WAL)");
+    Ok(())
+}
+
+/// Replays the Rust side of `run-weekly` against an archived `out/` snapshot. The snapshot's
+/// `artifacts/` and `decisions/` are copied into the work directory so the archive is never
+/// modified, and a derived config points every stage at the work directory and replay DB. The
+/// replay DB must not exist yet, so scores never mix with an earlier run's rows.
+fn replay(
+    config_path: PathBuf,
+    snapshot: PathBuf,
+    db: Option<String>,
+    work_dir: Option<PathBuf>,
+    rubric_dir: &Path,
+    date: Option<String>,
+) -> Result<()> {
+    ensure_config_path(&config_path)?;
+    load_config(&config_path)?;
+    if !snapshot.is_dir() {
+        return Err(FailureKind::Config.error(format!(
+            "Snapshot directory not found: {}",
+            snapshot.display()
+        )));
+    }
+    let work_dir = work_dir.unwrap_or_else(|| {
+        std::env::temp_dir().join(format!("larue-replay-{}", std::process::id()))
+    });
+    let db_path = db.unwrap_or_else(|| work_dir.join("civic.db").display().to_string());
+    if Path::new(&db_path).exists() {
+        return Err(FailureKind::Config.error(format!(
+            "Replay database already exists: {db_path}; remove it or pass a new --db"
+        )));
+    }
+    let out_dir = work_dir.join("out");
+    fs::create_dir_all(&out_dir)?;
+    for name in ["artifacts", "decisions"] {
+        let source = snapshot.join(name);
+        if source.is_dir() {
+            copy_json_files(&source, &out_dir.join(name))?;
+        }
+    }
+    let replay_config = work_dir.join("replay.toml");
+    write_replay_config(&config_path, &replay_config, &out_dir, &db_path, &work_dir)?;
+    let config = load_config(&replay_config)?;
+    let storage = resolve_storage(Some(&config));
+
+    ingest_dir(
+        storage.out_dir.join("artifacts"),
+        &storage.db_path,
+        storage.artifacts,
+    )?;
+    if let Err(err) = ingest_decisions(replay_config.clone(), false) {
+        eprintln!("Warning: ingest-decisions failed: {err}");
+    }
+    score_weekly(replay_config.clone(), rubric_dir, date.clone(), false, 1, false)?;
+    report_weekly(replay_config.clone(), date, None, false)?;
+    build_vault(&storage.db_path, storage.vault_path, Some(&config))?;
+    if let Err(err) = export_site(replay_config, None, None, None) {
         eprintln!("Warning: export-site failed: {err}");
     }
+    println!(
+        "Replayed {} into {} (db={db_path})",
+        snapshot.display(),
+        work_dir.display()
+    );
+    Ok(())
+}
+
+/// Writes a copy of the config with `storage.out_dir`, `db_path`, and `vault_path` redirected.
+fn write_replay_config(
+    config_path: &Path,
+    dest: &Path,
+    out_dir: &Path,
+    db_path: &str,
+    work_dir: &Path,
+) -> Result<()> {
+    let raw = fs::read_to_string(config_path)?;
+    let mut value: toml::Value = toml::from_str(&raw).map_err(|err| {
+        FailureKind::Config.error(format!("Invalid config {}: {err}", config_path.display()))
+    })?;
+    let table = value
+        .as_table_mut()
+        .ok_or_else(|| FailureKind::Config.error("Config root must be a table"))?;
+    let storage = table
+        .entry("storage")
+        .or_insert_with(|| toml::Value::Table(toml::map::Map::new()))
+        .as_table_mut()
+        .ok_or_else(|| FailureKind::Config.error("[storage] must be a table"))?;
+    storage.insert("out_dir".to_string(), out_dir.display().to_string().into());
+    storage.insert("db_path".to_string(), db_path.into());
+    storage.insert(
+        "vault_path".to_string(),
+        work_dir.join("vault").display().to_string().into(),
+    );
+    write_atomic(dest, toml::to_string(&value)?)?;
+    Ok(())
+}
+
+fn copy_json_files(source: &Path, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(source)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) == Some("json")
+            && let Some(name) = path.file_name()
+        {
+            fs::copy(&path, dest.join(name))?;
+        }
+    }
     Ok(())
 }
 
+/// Exclusive lockfile held for the duration of a `run-weekly`; removed on drop so early
+/// returns and errors still release it.
+struct RunLock {
+    path: PathBuf,
+}
+
+impl RunLock {
+    fn acquire(path: &Path, force: bool, stale_minutes: u64) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        loop {
+            match fs::OpenOptions::new().write(true).create_new(true).open(path) {
+                Ok(mut file) => {
+                    use std::io::Write;
+                    let now = OffsetDateTime::now_utc().format(&Rfc3339)?;
+                    writeln!(file, "pid={} started_at={now}", std::process::id())?;
+                    return Ok(Self {
+                        path: path.to_path_buf(),
+                    });
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let holder = fs::read_to_string(path).unwrap_or_default();
+                    let age_minutes = fs::metadata(path)
+                        .and_then(|meta| meta.modified())
+                        .ok()
+                        .and_then(|modified| modified.elapsed().ok())
+                        .map(|elapsed| elapsed.as_secs() / 60)
+                        .unwrap_or(0);
+                    if force && age_minutes >= stale_minutes {
+                        eprintln!(
+                            "Breaking stale lock {} ({age_minutes} minutes old): {}",
+                            path.display(),
+                            holder.trim()
+                        );
+                        fs::remove_file(path)?;
+                        continue;
+                    }
+                    return Err(FailureKind::Busy.error(format!(
+                        "Another run-weekly holds the lock {} ({}; {age_minutes} minutes old). \
+                         Use --force to break a lock older than {stale_minutes} minutes.",
+                        path.display(),
+                        holder.trim()
+                    )));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+impl Drop for RunLock {
+    fn drop(&mut self) {
+        if let Err(err) = fs::remove_file(&self.path) {
+            eprintln!("Failed to release lock {}: {err}", self.path.display());
+        }
+    }
+}
+
 fn fiscal_court_enabled(config: &Config) -> bool {
     config
         .sources
@@ -658,13 +2290,14 @@ fn wayback_enabled(config: &Config) -> bool {
         .unwrap_or(false)
 }
 
+#[cfg(feature = "python-collectors")]
 fn run_fiscal_court_collector(python: &str, config_path: &PathBuf) -> Result<()> {
     let collector_path = Path::new("workers/collectors/larue_fiscal_court_agendas.py");
     if !collector_path.exists() {
-        return Err(anyhow!(
+        return Err(FailureKind::Collector.error(format!(
             "Collector script not found: {}",
             collector_path.display()
-        ));
+        )));
     }
 
     let output = Command::new(python)
@@ -683,11 +2316,12 @@ fn run_fiscal_court_collector(python: &str, config_path: &PathBuf) -> Result<()>
         if !stderr.is_empty() {
             eprintln!("Collector stderr:\n{stderr}");
         }
-        return Err(anyhow!("Fiscal court collector exited with failure"));
+        return Err(FailureKind::Collector.error("Fiscal court collector exited with failure"));
     }
     Ok(())
 }
 
+#[cfg(feature = "python-collectors")]
 fn parse_meetings(
     python: &str,
     config_path: &PathBuf,
@@ -695,10 +2329,10 @@ fn parse_meetings(
 ) -> Result<()> {
     let parser_path = Path::new("workers/parsers/parse_meeting_minutes.py");
     if !parser_path.exists() {
-        return Err(anyhow!(
+        return Err(FailureKind::Collector.error(format!(
             "Meeting parser script not found: {}",
             parser_path.display()
-        ));
+        )));
     }
 
     let artifacts_dir = storage.out_dir.join("artifacts");
@@ -720,18 +2354,19 @@ fn parse_meetings(
         if !stderr.is_empty() {
             eprintln!("Parser stderr:\n{stderr}");
         }
-        return Err(anyhow!("Meeting parser exited with failure"));
+        return Err(FailureKind::Collector.error("Meeting parser exited with failure"));
     }
     Ok(())
 }
 
+#[cfg(feature = "python-collectors")]
 fn run_wayback_collector(python: &str, config_path: &PathBuf) -> Result<()> {
     let collector_path = Path::new("workers/collectors/wayback_backfill.py");
     if !collector_path.exists() {
-        return Err(anyhow!(
+        return Err(FailureKind::Collector.error(format!(
             "Collector script not found: {}",
             collector_path.display()
-        ));
+        )));
     }
 
     let output = Command::new(python)
@@ -750,38 +2385,59 @@ fn run_wayback_collector(python: &str, config_path: &PathBuf) -> Result<()> {
         if !stderr.is_empty() {
             eprintln!("Collector stderr:\n{stderr}");
         }
-        return Err(anyhow!("Wayback collector exited with failure"));
+        return Err(FailureKind::Collector.error("Wayback collector exited with failure"));
     }
     Ok(())
 }
 
+#[cfg(feature = "python-collectors")]
 fn find_python_interpreter() -> Result<String> {
     match Command::new("python3").arg("--version").output() {
         Ok(_) => return Ok("python3".to_string()),
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
         Err(err) => {
-            return Err(anyhow!("Failed to check python3: {err}"));
+            return Err(FailureKind::Collector.error(format!("Failed to check python3: {err}")));
         }
     }
 
     match Command::new("python").arg("--version").output() {
         Ok(_) => Ok("python".to_string()),
-        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Err(anyhow!(
-            "Python interpreter not found. Install python3 or ensure python is on PATH."
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Err(FailureKind::Collector.error(
+            "Python interpreter not found. Install python3 or ensure python is on PATH.",
         )),
-        Err(err) => Err(anyhow!("Failed to check python: {err}")),
+        Err(err) => Err(FailureKind::Collector.error(format!("Failed to check python: {err}"))),
     }
 }
 
+/// Error for Python-backed commands in a build without the `python-collectors` feature.
+#[cfg(not(feature = "python-collectors"))]
+fn python_collectors_disabled(command: &str, hint: &str) -> anyhow::Error {
+    FailureKind::Collector.error(format!(
+        "{command} runs a Python worker, but this build was compiled without the \
+         python-collectors feature{hint}"
+    ))
+}
+
+#[cfg(not(feature = "python-collectors"))]
+fn extract_text(_config_path: PathBuf) -> Result<()> {
+    Err(python_collectors_disabled("extract-text", ""))
+}
+
+#[cfg(not(feature = "python-collectors"))]
+fn tag_artifacts(_config_path: PathBuf, _force: bool) -> Result<()> {
+    Err(python_collectors_disabled("tag-artifacts", "; use tag-native instead"))
+}
+
+#[cfg(feature = "python-collectors")]
 fn extract_text(config_path: PathBuf) -> Result<()> {
     ensure_config_path(&config_path)?;
     let python = find_python_interpreter()?;
     let extractor_path = Path::new("workers/parsers/extract_text.py");
     if !extractor_path.exists() {
-        return Err(anyhow!(
+        return Err(FailureKind::Collector.error(format!(
             "Text extraction script not found: {}",
             extractor_path.display()
-        ));
+        )));
     }
 
     let config = load_config(&config_path)?;
@@ -806,7 +2462,7 @@ fn extract_text(config_path: PathBuf) -> Result<()> {
         if !stderr.is_empty() {
             eprintln!("Extractor stderr:\n{stderr}");
         }
-        return Err(anyhow!("Text extraction exited with failure"));
+        return Err(FailureKind::Collector.error("Text extraction exited with failure"));
     }
 
     println!(
@@ -816,15 +2472,16 @@ fn extract_text(config_path: PathBuf) -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "python-collectors")]
 fn tag_artifacts(config_path: PathBuf, force: bool) -> Result<()> {
     ensure_config_path(&config_path)?;
     let python = find_python_interpreter()?;
     let tagger_path = Path::new("workers/parsers/tag_artifacts.py");
     if !tagger_path.exists() {
-        return Err(anyhow!(
+        return Err(FailureKind::Collector.error(format!(
             "Tagging script not found: {}",
             tagger_path.display()
-        ));
+        )));
     }
 
     let config = load_config(&config_path)?;
@@ -854,7 +2511,7 @@ fn tag_artifacts(config_path: PathBuf, force: bool) -> Result<()> {
         if !stderr.is_empty() {
             eprintln!("Tagger stderr:\n{stderr}");
         }
-        return Err(anyhow!("Tagging exited with failure"));
+        return Err(FailureKind::Collector.error("Tagging exited with failure"));
     }
 
     println!(
@@ -864,7 +2521,93 @@ fn tag_artifacts(config_path: PathBuf, force: bool) -> Result<()> {
     Ok(())
 }
 
-fn ingest_decisions(config_path: PathBuf) -> Result<()> {
+fn tag_native(config_path: PathBuf, force: bool) -> Result<()> {
+    ensure_config_path(&config_path)?;
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let rules = TagRules::load_from_dir(Path::new("rubric"))
+        .map_err(|err| FailureKind::Config.error(format!("Invalid tag rules: {err}")))?;
+    let conn = civic_core::db::open(&storage.db_path)?;
+    let counts = retag_artifacts(&conn, &rules, force)?;
+    println!(
+        "processed={} tagged={} skipped={} forced={}",
+        counts.processed, counts.tagged, counts.skipped, counts.forced
+    );
+    Ok(())
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct TagCounts {
+    processed: usize,
+    tagged: usize,
+    skipped: usize,
+    forced: usize,
+}
+
+/// Adds rule-matched issue tags plus `ISSUE_TAG_MARKER` to each stored artifact with text.
+/// Marked artifacts are skipped unless `force`, which first drops the tags an earlier run added;
+/// other tags are never removed. Importance is recomputed with the tags, in one transaction.
+fn retag_artifacts(
+    conn: &rusqlite::Connection,
+    rules: &TagRules,
+    force: bool,
+) -> Result<TagCounts> {
+    let mut stmt = conn.prepare(
+        "SELECT id, COALESCE(title, ''), COALESCE(body_text, ''), tags_json, \
+         COALESCE(auto_tags_json, '[]') FROM artifacts ORDER BY id",
+    )?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let tx = conn.unchecked_transaction()?;
+    let mut counts = TagCounts::default();
+    let mut progress = Progress::new("tag-native", rows.len());
+    for (id, title, body_text, tags_json, auto_tags_json) in rows {
+        progress.tick();
+        if title.trim().is_empty() && body_text.trim().is_empty() {
+            counts.skipped += 1;
+            continue;
+        }
+        let mut tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
+        if tags.iter().any(|tag| tag == ISSUE_TAG_MARKER) {
+            if !force {
+                counts.skipped += 1;
+                continue;
+            }
+            counts.forced += 1;
+            let auto_tags: Vec<String> =
+                serde_json::from_str(&auto_tags_json).unwrap_or_default();
+            tags.retain(|tag| !auto_tags.contains(tag));
+        }
+        counts.processed += 1;
+
+        let matched = rules.matches(&format!("{title}\n{body_text}"));
+        if !matched.is_empty() {
+            counts.tagged += 1;
+        }
+        let mut added = Vec::new();
+        for tag in matched.into_iter().chain([ISSUE_TAG_MARKER.to_string()]) {
+            if !tags.contains(&tag) {
+                tags.push(tag.clone());
+                added.push(tag);
+            }
+        }
+        civic_core::db::update_artifact_auto_tags(&tx, &id, &tags, &added, Some(&body_text))?;
+    }
+    tx.commit()?;
+    Ok(counts)
+}
+
+fn ingest_decisions(config_path: PathBuf, strict_links: bool) -> Result<()> {
     ensure_config_path(&config_path)?;
     let config = load_config(&config_path)?;
     let storage = resolve_storage(Some(&config));
@@ -889,8 +2632,11 @@ fn ingest_decisions(config_path: PathBuf) -> Result<()> {
     let conn = civic_core::db::open(&storage.db_path)?;
     let mut ingested = 0usize;
     let mut failed = 0usize;
+    let mut rejected = 0usize;
 
+    let mut progress = Progress::new("ingest-decisions", decision_files.len());
     for path in decision_files {
+        progress.tick();
         let raw = match fs::read_to_string(&path) {
             Ok(raw) => raw,
             Err(err) => {
@@ -907,7 +2653,7 @@ fn ingest_decisions(config_path: PathBuf) -> Result<()> {
                 continue;
             }
         };
-        let decision: civic_core::schema::DecisionBundle = match serde_json::from_value(raw_json.clone()) {
+        let mut decision: civic_core::schema::DecisionBundle = match serde_json::from_value(raw_json.clone()) {
             Ok(decision) => decision,
             Err(err) => {
                 failed += 1;
@@ -915,6 +2661,32 @@ fn ingest_decisions(config_path: PathBuf) -> Result<()> {
                 continue;
             }
         };
+        for (dropped, kept) in decision.dedup_motions() {
+            eprintln!(
+                "Warning: merged duplicate motion {dropped} into {kept} in {}",
+                path.display()
+            );
+        }
+        for motion in decision.motions.iter().filter(|motion| motion.text.trim().is_empty()) {
+            eprintln!(
+                "Warning: motion {} in {} has no text; its scores will be flagged \
+                 empty_motion_text",
+                motion.id,
+                path.display()
+            );
+        }
+
+        if let Err(err) = check_artifact_links(
+            &conn,
+            &decision.meeting.id,
+            &decision.meeting.artifact_ids,
+            strict_links,
+        ) {
+            failed += 1;
+            rejected += 1;
+            eprintln!("Rejected {}: {err}", path.display());
+            continue;
+        }
 
         if let Err(err) = civic_core::db::upsert_decision_meeting(
             &conn,
@@ -934,13 +2706,35 @@ fn ingest_decisions(config_path: PathBuf) -> Result<()> {
                 eprintln!("Failed to ingest motion {}: {err}", motion.id);
             }
         }
+        let dangling: Vec<String> =
+            decision.dangling_votes().iter().map(|vote| vote.id.clone()).collect();
         for vote in &decision.votes {
+            // A vote may follow up on a motion an earlier bundle already stored.
+            if dangling.contains(&vote.id)
+                && !civic_core::db::motion_exists(&conn, &vote.motion_id)?
+            {
+                eprintln!(
+                    "Warning: skipping vote {} in {}: motion {} is not in the bundle or the \
+                     database",
+                    vote.id,
+                    path.display(),
+                    vote.motion_id
+                );
+                continue;
+            }
             let vote_json = serde_json::to_value(vote)?;
             if let Err(err) = civic_core::db::upsert_vote(&conn, vote, &vote_json) {
                 failed += 1;
                 eprintln!("Failed to ingest vote {}: {err}", vote.id);
             }
         }
+        for comment in &decision.public_comments {
+            let comment_json = serde_json::to_value(comment)?;
+            if let Err(err) = civic_core::db::upsert_public_comment(&conn, comment, &comment_json) {
+                failed += 1;
+                eprintln!("Failed to ingest public comment {}: {err}", comment.id);
+            }
+        }
         ingested += 1;
     }
 
@@ -950,131 +2744,664 @@ fn ingest_decisions(config_path: PathBuf) -> Result<()> {
         failed,
         decisions_dir.display()
     );
+    if rejected > 0 {
+        return Err(FailureKind::Validation.error(format!(
+            "{rejected} decision file(s) reference unknown artifacts"
+        )));
+    }
     Ok(())
 }
 
-fn score_weekly(config_path: PathBuf, date: Option<String>) -> Result<()> {
+fn score_weekly(
+    config_path: PathBuf,
+    rubric_dir: &Path,
+    date: Option<String>,
+    audit: bool,
+    parallel: usize,
+    require_extraction: bool,
+) -> Result<()> {
     ensure_config_path(&config_path)?;
     let config = load_config(&config_path)?;
     let storage = resolve_storage(Some(&config));
-    let rubric = Rubric::load_from_dir(Path::new("rubric"))?;
+    let scoring = resolve_scoring(Some(&config));
+    let rubric = Rubric::load_cached(rubric_dir)?;
+    let rubric_hash = audit.then(|| rubric.version_hash.clone());
 
-    let (_date_str, window_start, window_end) = resolve_window(date)?;
+    let zone = resolve_reporting_zone(&config)?;
+    let window = resolve_window(date, &zone)?;
     let conn = civic_core::db::open(&storage.db_path)?;
 
-    let meetings = load_meetings_in_window(&conn, &window_start, &window_end)?;
+    let meetings = load_meetings_in_window(&conn, &window)?;
     if meetings.is_empty() {
-        println!("motions_scored=0 votes_scored=0 insufficient=0 flagged=0");
+        println!(
+            "motions_scored=0 votes_scored=0 insufficient=0 no_sources=0 empty_text=0 flagged=0"
+        );
         return Ok(());
     }
 
-    let mut motion_scores: HashMap<String, ScoreResult> = HashMap::new();
+    let computed_at = window.end_rfc3339()?;
+    let mut progress = Progress::new("score-weekly", meetings.len());
+    let mut inputs = Vec::with_capacity(meetings.len());
+    let mut deferred = HashSet::new();
+    let mut reconsiderations = 0;
+    for meeting in meetings {
+        progress.tick();
+        let mut artifacts = load_linked_artifacts(&conn, &meeting, &rubric)?;
+        if scoring.min_evidence_age_hours > 0 {
+            deferred.extend(defer_fresh_artifacts(
+                &conn,
+                &mut artifacts,
+                scoring.min_evidence_age_hours,
+                OffsetDateTime::now_utc(),
+            )?);
+        }
+        if let Some(trusted_kinds) = &scoring.trusted_source_kinds {
+            distrust_source_kinds(&mut artifacts, trusted_kinds);
+        }
+        let mut motions = load_motions_for_meeting(&conn, &meeting.id)?;
+        reconsiderations +=
+            link_reconsiderations(&conn, &mut motions, &scoring.reconsideration_patterns)?;
+        inputs.push(MeetingScoringInput {
+            artifacts,
+            motions,
+            votes: load_votes_for_meeting(&conn, &meeting.id)?,
+            body_rubric: Rubric::load_for_body(rubric_dir, &meeting.body_id)?,
+            meeting,
+        });
+    }
+    if reconsiderations > 0 {
+        println!("Linked {reconsiderations} reconsideration motion(s) to the motions they reopen");
+    }
+    if let Some(message) = extraction_shortfall(&inputs, scoring.min_extracted_fraction) {
+        if require_extraction {
+            return Err(FailureKind::Validation.error(message));
+        }
+        eprintln!("Warning: {message}");
+    }
+    if !deferred.is_empty() {
+        println!(
+            "Deferred {} artifact(s) first seen within the last {} hour(s) to a later run",
+            deferred.len(),
+            scoring.min_evidence_age_hours
+        );
+    }
+
+    let mut tally = ScoreTally::default();
     let mut scores_to_write: Vec<DecisionScore> = Vec::new();
-    let mut motions_scored = 0usize;
-    let mut votes_scored = 0usize;
-    let mut insufficient = 0usize;
-    let mut flagged = 0usize;
-    let computed_at = window_end.clone();
-
-    for meeting in &meetings {
-        let artifacts = load_linked_artifacts(&conn, meeting)?;
-        let motions = load_motions_for_meeting(&conn, &meeting.id)?;
-        for motion in motions {
-            let score = civic_core::scoring::compute_motion_score(
-                &motion.text,
-                &artifacts,
-                &rubric,
+    let mut audit_records: Vec<serde_json::Value> = Vec::new();
+    let scored = score_meetings(&inputs, &rubric, &computed_at, rubric_hash.as_deref(), parallel);
+    for meeting in scored {
+        tally.add(&meeting.tally);
+        scores_to_write.extend(meeting.scores);
+        audit_records.extend(meeting.audit_records);
+    }
+
+    for score in &scores_to_write {
+        civic_core::db::upsert_decision_score(&conn, score)?;
+    }
+
+    let drift_flags = detect_drift(&conn, &rubric, &window, &computed_at)?;
+    for score in drift_flags.updated_scores {
+        civic_core::db::upsert_decision_score(&conn, &score)?;
+    }
+    if drift_flags.resolved > 0 {
+        println!("Resolved {} drift flag(s) now back within threshold", drift_flags.resolved);
+    }
+
+    if audit {
+        let audit_path = append_score_audit(&storage.out_dir, &window.date, &audit_records)?;
+        println!(
+            "Appended {} audit records to {}",
+            audit_records.len(),
+            audit_path.display()
+        );
+    }
+
+    println!(
+        "motions_scored={} votes_scored={} insufficient={} no_sources={} empty_text={} flagged={}",
+        tally.motions_scored,
+        tally.votes_scored,
+        tally.insufficient,
+        tally.no_sources,
+        tally.empty_text,
+        tally.flagged
+    );
+    Ok(())
+}
+
+/// Scoring reads tags that text extraction produces, so a window whose linked artifacts mostly
+/// lack text scores mostly `insufficient_evidence`. Describes the shortfall when fewer than
+/// `min_fraction` of the distinct linked artifacts have text (a `text_extracted` tag or a
+/// non-empty `body_text`); `None` when coverage is fine or nothing is linked.
+fn extraction_shortfall(inputs: &[MeetingScoringInput], min_fraction: f64) -> Option<String> {
+    let mut seen = HashSet::new();
+    let mut extracted = 0;
+    for artifact in inputs.iter().flat_map(|input| &input.artifacts) {
+        if !seen.insert(artifact.id.as_str()) {
+            continue;
+        }
+        let has_text = artifact.tags.iter().any(|tag| tag == "text_extracted")
+            || artifact.body_text.as_deref().is_some_and(|text| !text.trim().is_empty());
+        if has_text {
+            extracted += 1;
+        }
+    }
+    let total = seen.len();
+    if total == 0 || extracted as f64 >= min_fraction * total as f64 {
+        return None;
+    }
+    Some(format!(
+        "only {extracted} of {total} linked artifact(s) have extracted text (minimum {:.0}%); \
+         run extract-text before scoring",
+        min_fraction * 100.0
+    ))
+}
+
+/// Everything scoring one meeting reads from the database, loaded up front so the scoring
+/// itself can run on worker threads without a connection.
+struct MeetingScoringInput {
+    meeting: MeetingWindowRow,
+    artifacts: Vec<LinkedArtifact>,
+    motions: Vec<MotionRow>,
+    votes: Vec<VoteRow>,
+    /// The meeting body's `rubric/<body_id>/` rubric; `None` scores under the base rubric.
+    body_rubric: Option<std::sync::Arc<Rubric>>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct ScoreTally {
+    motions_scored: usize,
+    votes_scored: usize,
+    insufficient: usize,
+    no_sources: usize,
+    empty_text: usize,
+    flagged: usize,
+}
+
+impl ScoreTally {
+    fn add(&mut self, other: &ScoreTally) {
+        self.motions_scored += other.motions_scored;
+        self.votes_scored += other.votes_scored;
+        self.insufficient += other.insufficient;
+        self.no_sources += other.no_sources;
+        self.empty_text += other.empty_text;
+        self.flagged += other.flagged;
+    }
+}
+
+struct MeetingScores {
+    scores: Vec<DecisionScore>,
+    audit_records: Vec<serde_json::Value>,
+    tally: ScoreTally,
+}
+
+/// Scores each meeting, splitting the meetings into contiguous chunks across `parallel` threads.
+/// Results come back in input order, so the output matches a sequential run exactly.
+fn score_meetings(
+    inputs: &[MeetingScoringInput],
+    rubric: &Rubric,
+    computed_at: &str,
+    rubric_hash: Option<&str>,
+    parallel: usize,
+) -> Vec<MeetingScores> {
+    let workers = parallel.clamp(1, inputs.len().max(1));
+    if workers == 1 {
+        return inputs
+            .iter()
+            .map(|input| score_meeting(input, rubric, computed_at, rubric_hash))
+            .collect();
+    }
+    let chunk_size = inputs.len().div_ceil(workers);
+    std::thread::scope(|scope| {
+        let handles = inputs
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|input| score_meeting(input, rubric, computed_at, rubric_hash))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+/// Motion scores for one meeting, then each official's vote score derived from its motion's.
+fn score_meeting(
+    input: &MeetingScoringInput,
+    rubric: &Rubric,
+    computed_at: &str,
+    rubric_hash: Option<&str>,
+) -> MeetingScores {
+    let (rubric, rubric_hash) = match &input.body_rubric {
+        Some(body_rubric) => (
+            body_rubric.as_ref(),
+            rubric_hash.map(|_| body_rubric.version_hash.as_str()),
+        ),
+        None => (rubric, rubric_hash),
+    };
+    let meeting = &input.meeting;
+    // Each motion's score and the index of its `DecisionScore` in `scores`.
+    let mut motion_scores: HashMap<&str, (ScoreResult, usize)> = HashMap::new();
+    let mut scores = Vec::new();
+    let mut audit_records = Vec::new();
+    let mut tally = ScoreTally::default();
+    let reconsidered_by: HashMap<&str, &str> = input
+        .motions
+        .iter()
+        .filter_map(|motion| Some((motion.reconsiders_motion_id.as_deref()?, motion.id.as_str())))
+        .collect();
+    for motion in &input.motions {
+        let agenda_text = motion
+            .scored_text()
+            .trim()
+            .is_empty()
+            .then(|| agenda_item_text(&input.artifacts, motion.agenda_ref.as_deref()?))
+            .flatten();
+        let motion_text = agenda_text.as_deref().unwrap_or(motion.scored_text());
+        let mut score = civic_core::scoring::compute_motion_score(
+            motion_text,
+            &input.artifacts,
+            &meeting.started_at,
+            rubric,
+        );
+        if agenda_text.is_some() {
+            score.evidence.push(RESOLVED_FROM_AGENDA_TAG.to_string());
+        } else if motion_text != motion.text {
+            score.evidence.push("scored_amended".to_string());
+        }
+        if let Some(by) = reconsidered_by.get(motion.id.as_str()) {
+            score.evidence.push(format!("{RECONSIDERED_BY_PREFIX}{by}"));
+        }
+        if score.flags.iter().any(|flag| flag == "insufficient_evidence") {
+            tally.insufficient += 1;
+        }
+        if score.flags.iter().any(|flag| flag == "no_source_artifacts") {
+            tally.no_sources += 1;
+        }
+        if score.flags.iter().any(|flag| flag == "empty_motion_text") {
+            tally.empty_text += 1;
+        }
+        if !score.flags.is_empty() {
+            tally.flagged += 1;
+        }
+        tally.motions_scored += 1;
+        let decision = DecisionScore {
+            id: format!("motion:{}", motion.id),
+            meeting_id: Some(meeting.id.clone()),
+            motion_id: Some(motion.id.clone()),
+            vote_id: None,
+            overall_score: score.overall_score,
+            axis_scores: score.axis_scores.clone(),
+            contributions: score.contributions.clone(),
+            axis_weights: applied_axis_weights(&score.axis_scores, rubric),
+            constitutional_refs: score.constitutional_refs.clone(),
+            evidence: score.evidence.clone(),
+            confidence: score.confidence,
+            flags: score.flags.clone(),
+            computed_at: computed_at.to_string(),
+            rubric_version: Some(rubric.version_hash.clone()),
+        };
+        if let Some(rubric_hash) = rubric_hash {
+            audit_records.push(serde_json::json!({
+                "score_id": decision.id,
+                "computed_at": computed_at,
+                "meeting_id": meeting.id,
+                "motion_id": motion.id,
+                "inputs": {
+                    "motion_text": motion_text,
+                    "meeting_started_at": meeting.started_at,
+                    "linked_artifacts": input.artifacts.iter().map(|artifact| {
+                        serde_json::json!({
+                            "id": artifact.id,
+                            "tags": artifact.tags,
+                            "retrieved_at": artifact.retrieved_at,
+                            "content_type": artifact.content_type,
+                        })
+                    }).collect::<Vec<_>>(),
+                    "rubric_hash": rubric_hash,
+                },
+                "result": decision,
+            }));
+        }
+        motion_scores.insert(motion.id.as_str(), (score, scores.len()));
+        scores.push(decision);
+    }
+
+    for vote in &input.votes {
+        let Some((motion_score, motion_index)) = motion_scores.get(vote.motion_id.as_str()) else {
+            continue;
+        };
+        let vote_type_weight = rubric
+            .scoring_rules
+            .vote_type_weight(vote.vote_type.as_deref());
+        let tie_breaker = tie_breaker(vote, meeting.presiding_officer.as_deref());
+        let mut choices = vote.choices.clone();
+        let mut vote_tally = vote.tally;
+        if let Some((officer, choice)) = &tie_breaker
+            && !choices.iter().any(|(name, _)| name == officer)
+        {
+            // The minutes recorded only the tied roll; count the chair's deciding vote too.
+            choices.push((officer.clone(), *choice));
+            match choice {
+                VoteChoice::Aye => vote_tally.ayes += 1,
+                _ => vote_tally.nays += 1,
+            }
+        }
+        for (name, choice) in &choices {
+            let choice = *choice;
+            let mut score = civic_core::scoring::compute_vote_score_with_motion(
+                motion_score,
+                choice,
+                &vote_tally,
+                rubric,
             );
+            score.evidence.push(format!("official:{name}"));
+            if tie_breaker.as_ref().is_some_and(|(officer, _)| officer == name) {
+                score.evidence.push(TIE_BREAKER_TAG.to_string());
+            }
+            if let Some(by) = reconsidered_by.get(vote.motion_id.as_str()) {
+                score.evidence.push(format!("{RECONSIDERED_BY_PREFIX}{by}"));
+            }
+            if let Some(item) =
+                vote_type_weight_evidence(vote.vote_type.as_deref(), vote_type_weight)
+            {
+                score.evidence.push(item);
+            }
+            let score_id = format!("vote:{}:{}", vote.id, slugify(name));
             if score.flags.iter().any(|flag| flag == "insufficient_evidence") {
-                insufficient += 1;
+                tally.insufficient += 1;
+            }
+            if score.flags.iter().any(|flag| flag == "no_source_artifacts") {
+                tally.no_sources += 1;
             }
             if !score.flags.is_empty() {
-                flagged += 1;
+                tally.flagged += 1;
             }
-            motions_scored += 1;
-            motion_scores.insert(motion.id.clone(), score.clone());
-            scores_to_write.push(DecisionScore {
-                id: format!("motion:{}", motion.id),
+            tally.votes_scored += 1;
+            let decision = DecisionScore {
+                id: score_id,
                 meeting_id: Some(meeting.id.clone()),
-                motion_id: Some(motion.id.clone()),
-                vote_id: None,
+                motion_id: Some(vote.motion_id.clone()),
+                vote_id: Some(vote.id.clone()),
                 overall_score: score.overall_score,
                 axis_scores: score.axis_scores.clone(),
+                contributions: score.contributions.clone(),
+                axis_weights: applied_axis_weights(&score.axis_scores, rubric),
                 constitutional_refs: score.constitutional_refs.clone(),
                 evidence: score.evidence.clone(),
                 confidence: score.confidence,
                 flags: score.flags.clone(),
-                computed_at: computed_at.clone(),
-            });
-        }
-
-        let votes = load_votes_for_meeting(&conn, &meeting.id)?;
-        for vote in votes {
-            let Some(motion_score) = motion_scores.get(&vote.motion_id) else {
-                continue;
+                computed_at: computed_at.to_string(),
+                rubric_version: Some(rubric.version_hash.clone()),
             };
-            let mut per_vote_scores = Vec::new();
-            for (name, choice) in vote.choices {
-                let mut score =
-                    civic_core::scoring::compute_vote_score_with_motion(motion_score, choice, &rubric);
-                score.evidence.push(format!("official:{name}"));
-                let score_id = format!("vote:{}:{}", vote.id, slugify(&name));
-                if score.flags.iter().any(|flag| flag == "insufficient_evidence") {
-                    insufficient += 1;
-                }
-                if !score.flags.is_empty() {
-                    flagged += 1;
-                }
-                votes_scored += 1;
-                per_vote_scores.push((score_id, name, score));
-            }
-
-            for (score_id, _name, score) in per_vote_scores {
-                scores_to_write.push(DecisionScore {
-                    id: score_id,
-                    meeting_id: Some(meeting.id.clone()),
-                    motion_id: Some(vote.motion_id.clone()),
-                    vote_id: Some(vote.id.clone()),
-                    overall_score: score.overall_score,
-                    axis_scores: score.axis_scores.clone(),
-                    constitutional_refs: score.constitutional_refs.clone(),
-                    evidence: score.evidence.clone(),
-                    confidence: score.confidence,
-                    flags: score.flags.clone(),
-                    computed_at: computed_at.clone(),
-                });
+            if let Some(rubric_hash) = rubric_hash {
+                audit_records.push(serde_json::json!({
+                    "score_id": decision.id,
+                    "computed_at": computed_at,
+                    "meeting_id": meeting.id,
+                    "motion_id": vote.motion_id,
+                    "vote_id": vote.id,
+                    "inputs": {
+                        "official": name,
+                        "vote_choice": choice.to_string(),
+                        "vote_tally": {
+                            "ayes": vote_tally.ayes,
+                            "nays": vote_tally.nays,
+                        },
+                        "motion_score": scores[*motion_index],
+                        "rubric_hash": rubric_hash,
+                    },
+                    "result": decision,
+                }));
             }
+            scores.push(decision);
         }
     }
+    MeetingScores {
+        scores,
+        audit_records,
+        tally,
+    }
+}
 
-    for score in &scores_to_write {
-        civic_core::db::upsert_decision_score(&conn, score)?;
+/// Appends one JSON line per computed score to `<out_dir>/audit/scores-<date>.jsonl`.
+fn append_score_audit(
+    out_dir: &Path,
+    date_str: &str,
+    records: &[serde_json::Value],
+) -> Result<PathBuf> {
+    use std::io::Write;
+
+    let audit_dir = out_dir.join("audit");
+    fs::create_dir_all(&audit_dir)?;
+    let audit_path = audit_dir.join(format!("scores-{date_str}.jsonl"));
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&audit_path)?;
+    for record in records {
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
     }
+    Ok(audit_path)
+}
 
-    let drift_flags = detect_drift(
-        &conn,
-        &rubric,
-        &window_start,
-        &window_end,
-        &computed_at,
-    )?;
-    for score in drift_flags.updated_scores {
-        civic_core::db::upsert_decision_score(&conn, &score)?;
+fn export_ndjson(config_path: PathBuf, table: NdjsonTable, out: PathBuf) -> Result<()> {
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let conn = civic_core::db::open(&storage.db_path)?;
+    let mut written = 0;
+    civic_core::paths::write_atomic_with(&out, |writer| {
+        written = write_ndjson(&conn, table, writer)?;
+        Ok(())
+    })?;
+    println!("Wrote {written} records to {}", out.display());
+    Ok(())
+}
+
+/// Writes each row of `table` as one JSON object per line, reading and writing a row at a
+/// time. Records keep their nested fields (axis maps, tag and id arrays) as JSON.
+fn write_ndjson(
+    conn: &rusqlite::Connection,
+    table: NdjsonTable,
+    writer: &mut dyn std::io::Write,
+) -> Result<usize> {
+    use civic_core::db::{for_each_artifact, for_each_decision_score, for_each_meeting};
+    let mut written = 0;
+    let mut line = |record: serde_json::Value| -> Result<()> {
+        serde_json::to_writer(&mut *writer, &record)?;
+        writer.write_all(b"\n")?;
+        written += 1;
+        Ok(())
+    };
+    match table {
+        NdjsonTable::Scores => {
+            for_each_decision_score(conn, |score| line(score.canonical_json()?))?
+        }
+        NdjsonTable::Artifacts => {
+            for_each_artifact(conn, |artifact| line(serde_json::to_value(artifact)?))?
+        }
+        NdjsonTable::Meetings => {
+            for_each_meeting(conn, |meeting| line(serde_json::to_value(meeting)?))?
+        }
     }
+    Ok(written)
+}
 
+fn export_graph(config_path: PathBuf, format: GraphFormat, out: PathBuf) -> Result<()> {
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let conn = civic_core::db::open(&storage.db_path)?;
+    let graph = build_relationship_graph(&conn)?;
+    let contents = match format {
+        GraphFormat::Dot => graph.to_dot(),
+        GraphFormat::Json => serde_json::to_string_pretty(&graph)?,
+    };
+    write_atomic(&out, contents)?;
     println!(
-        "motions_scored={} votes_scored={} insufficient={} flagged={}",
-        motions_scored, votes_scored, insufficient, flagged
+        "Wrote {} nodes and {} edges to {}",
+        graph.nodes.len(),
+        graph.edges.len(),
+        out.display()
     );
     Ok(())
 }
 
-fn export_site(config_path: PathBuf) -> Result<()> {
+#[derive(serde::Serialize)]
+struct GraphNode {
+    /// `<kind>:<id>`, unique across kinds.
+    id: String,
+    kind: &'static str,
+    label: String,
+}
+
+#[derive(serde::Serialize)]
+struct GraphEdge {
+    from: String,
+    to: String,
+    kind: String,
+}
+
+#[derive(Default, serde::Serialize)]
+struct RelationshipGraph {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    #[serde(skip)]
+    seen: HashSet<String>,
+}
+
+impl RelationshipGraph {
+    /// Adds the node unless one with the same id exists; returns its id either way.
+    fn node(&mut self, kind: &'static str, id: &str, label: &str) -> String {
+        let node_id = format!("{kind}:{id}");
+        if self.seen.insert(node_id.clone()) {
+            self.nodes.push(GraphNode {
+                id: node_id.clone(),
+                kind,
+                label: label.to_string(),
+            });
+        }
+        node_id
+    }
+
+    fn edge(&mut self, from: &str, to: &str, kind: impl Into<String>) {
+        self.edges.push(GraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind: kind.into(),
+        });
+    }
+
+    fn to_dot(&self) -> String {
+        let quote = |value: &str| {
+            let escaped = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("\"{escaped}\"")
+        };
+        let mut dot = String::from("digraph larue {\n  rankdir=LR;\n");
+        for node in &self.nodes {
+            let shape = match node.kind {
+                "body" => "box",
+                "motion" => "note",
+                "vote" => "diamond",
+                "artifact" => "folder",
+                _ => "ellipse",
+            };
+            dot.push_str(&format!(
+                "  {} [label={}, shape={shape}];\n",
+                quote(&node.id),
+                quote(&node.label)
+            ));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "  {} -> {} [label={}];\n",
+                quote(&edge.from),
+                quote(&edge.to),
+                quote(&edge.kind)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Bodies -> meetings -> motions -> votes -> officials (edges labeled with each official's
+/// choice), plus artifacts -> the meetings that link them. Bodies appear only once they hold a
+/// meeting; links to artifacts that were never ingested are dropped.
+fn build_relationship_graph(conn: &rusqlite::Connection) -> Result<RelationshipGraph> {
+    let mut graph = RelationshipGraph::default();
+    let mut artifact_nodes = HashMap::new();
+    civic_core::db::for_each_artifact(conn, |artifact| {
+        let label = artifact.title.as_deref().unwrap_or(&artifact.id).to_string();
+        let node = graph.node("artifact", &artifact.id, &label);
+        artifact_nodes.insert(artifact.id, node);
+        Ok(())
+    })?;
+    let mut stmt = conn.prepare("SELECT id, name FROM bodies")?;
+    let body_names = stmt
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?
+        .collect::<rusqlite::Result<HashMap<_, _>>>()?;
+    let mut meetings = Vec::new();
+    civic_core::db::for_each_meeting(conn, |meeting| {
+        meetings.push(meeting);
+        Ok(())
+    })?;
+    for meeting in meetings {
+        let body_name = body_names.get(&meeting.body_id).unwrap_or(&meeting.body_id);
+        let body = graph.node("body", &meeting.body_id, body_name);
+        let label = format!("{} {}", body_name, meeting.started_at);
+        let meeting_node = graph.node("meeting", &meeting.id, &label);
+        graph.edge(&body, &meeting_node, "held");
+        for artifact_id in &meeting.artifact_ids {
+            if let Some(artifact) = artifact_nodes.get(artifact_id) {
+                graph.edge(artifact, &meeting_node, "documents");
+            }
+        }
+        let votes = load_votes_for_meeting(conn, &meeting.id)?;
+        for motion in load_motions_for_meeting(conn, &meeting.id)? {
+            let motion_node = graph.node("motion", &motion.id, &motion.text);
+            graph.edge(&meeting_node, &motion_node, "considered");
+            for vote in votes.iter().filter(|vote| vote.motion_id == motion.id) {
+                let label = vote.tally.label().unwrap_or_else(|| vote.id.clone());
+                let vote_node = graph.node("vote", &vote.id, &label);
+                graph.edge(&motion_node, &vote_node, "voted");
+                for (name, choice) in &vote.choices {
+                    let official = graph.node("official", &slugify(name), name);
+                    graph.edge(&vote_node, &official, choice.to_string());
+                }
+            }
+        }
+    }
+    Ok(graph)
+}
+
+fn export_site(
+    config_path: PathBuf,
+    output_dir: Option<PathBuf>,
+    max_age: Option<u32>,
+    template_dir: Option<PathBuf>,
+) -> Result<()> {
     ensure_config_path(&config_path)?;
     let config = load_config(&config_path)?;
     let storage = resolve_storage(Some(&config));
     let site = resolve_site_config(config.site.as_ref());
-    let rubric = Rubric::load_from_dir(Path::new("rubric")).ok();
+    let templates = SiteTemplates::load(template_dir.or(site.template_dir.clone()).as_deref())?;
+    let zone = resolve_reporting_zone(&config)?;
+    let scoring = resolve_scoring(Some(&config));
+    let max_staleness_days = resolve_max_staleness_days(Some(&config), max_age)?;
+    let rubric = Rubric::load_cached(Path::new("rubric")).ok();
 
     let mut reports = load_week_reports(&storage.out_dir)?;
     let (latest_date, window_start, window_end) = if let Some(report) = reports.last() {
@@ -1084,7 +3411,9 @@ fn export_site(config_path: PathBuf) -> Result<()> {
             report.window_end.clone(),
         )
     } else {
-        resolve_window(None)?
+        let window = resolve_window(None, &zone)?;
+        let (start, end) = window.bounds()?;
+        (window.date, start, end)
     };
     if reports.is_empty() {
         reports.push(build_placeholder_report(&latest_date, &window_start, &window_end));
@@ -1092,22 +3421,44 @@ fn export_site(config_path: PathBuf) -> Result<()> {
     let latest_report = reports.last();
 
     let conn = civic_core::db::open(&storage.db_path)?;
+    let stale = stale_data(&conn, max_staleness_days, OffsetDateTime::now_utc())?;
+    if let Some(stale) = &stale {
+        warn_stale_data(stale, max_staleness_days);
+    }
+    let orphaned = civic_core::db::orphaned_scores(&conn)?;
+    if !orphaned.is_empty() {
+        eprintln!(
+            "Warning: {} score(s) reference missing motions and are excluded from official \
+             grades (run `larue doctor` for details)",
+            orphaned.len()
+        );
+    }
     let mut official_stats = load_official_summaries(
         &conn,
         &window_start,
         &window_end,
-        rubric.as_ref(),
+        rubric.as_deref(),
         latest_report,
         &latest_date,
+        &scoring,
     )?;
     let previous_average = if reports.len() > 1 {
         let previous_report = &reports[reports.len() - 2];
-        load_official_averages(&conn, &previous_report.window_start, &previous_report.window_end)?
+        load_official_averages(
+            &conn,
+            &previous_report.window_start,
+            &previous_report.window_end,
+            rubric.as_deref(),
+            &scoring,
+        )?
     } else {
         HashMap::new()
     };
 
     for summary in &mut official_stats {
+        for receipt in &mut summary.receipts {
+            receipt.meeting_date = zone.display(&receipt.meeting_date);
+        }
         summary.delta = summary.average_score
             - previous_average
                 .get(&summary.name)
@@ -1118,6 +3469,9 @@ fn export_site(config_path: PathBuf) -> Result<()> {
             .copied()
             .unwrap_or(summary.average_score);
         let prior_grade = score_to_grade(normalize_score(prior_score, rubric.as_ref().map(|rub| &rub.config)));
+        if summary.letter_grade == WITHHELD_GRADE {
+            continue;
+        }
         summary.commentary = build_commentary_line(
             &summary.id,
             &latest_date,
@@ -1130,7 +3484,32 @@ fn export_site(config_path: PathBuf) -> Result<()> {
         );
     }
 
-    let site_dir = storage.out_dir.join("site");
+    // Without hysteresis a trend depends on this week's delta alone, so history is skipped.
+    let hysteresis = site.trend_hysteresis.unwrap_or(0.0);
+    let prior_trends = if hysteresis > 0.0 && reports.len() > 2 {
+        let mut weekly_averages = Vec::new();
+        for report in &reports[..reports.len() - 1] {
+            weekly_averages.push(load_official_averages(
+                &conn,
+                &report.window_start,
+                &report.window_end,
+                rubric.as_deref(),
+                &scoring,
+            )?);
+        }
+        carry_trends(&weekly_averages, hysteresis)
+    } else {
+        HashMap::new()
+    };
+    for summary in &mut official_stats {
+        let previous = prior_trends.get(&summary.name).copied().unwrap_or_default();
+        summary.trend = next_trend(previous, summary.delta, hysteresis);
+    }
+
+    let site_dir = output_dir.unwrap_or_else(|| storage.out_dir.join("site"));
+    fs::create_dir_all(&site_dir).map_err(|err| {
+        anyhow!("Cannot create site output directory {}: {err}", site_dir.display())
+    })?;
     let assets_dir = site_dir.join("assets");
     let stockade_dir = site_dir.join("stockade");
     let officials_dir = site_dir.join("officials");
@@ -1146,95 +3525,459 @@ fn export_site(config_path: PathBuf) -> Result<()> {
 
     write_site_assets(&assets_dir)?;
     copy_report_jsons(&storage.out_dir, &reports_dir)?;
-    export_artifact_jsons(&storage.out_dir, &artifacts_dir)?;
+    let exported_artifacts = export_artifact_jsons(&storage.out_dir, &artifacts_dir)?;
 
-    let home_html = render_home_page(latest_report, &latest_date, &official_stats);
-    fs::write(site_dir.join("index.html"), home_html)?;
+    let disclaimer = site.disclaimer.as_deref().unwrap_or(DEFAULT_DISCLAIMER);
+    let decimals = site.display_decimals.unwrap_or(DEFAULT_DISPLAY_DECIMALS);
+    let last_updated = stale.map(|stale| zone.display(&stale.last_retrieved_at));
+    let movers = biggest_movers(&official_stats, &previous_average);
+    let home_html = render_home_page(
+        latest_report,
+        &latest_date,
+        &official_stats,
+        &movers,
+        last_updated.as_deref(),
+        disclaimer,
+        &templates,
+        decimals,
+    );
+    write_atomic(site_dir.join("index.html"), home_html)?;
 
-    let stockade_html = render_stockade_page(&official_stats, &latest_date);
-    fs::write(stockade_dir.join("index.html"), stockade_html)?;
+    let rubric_versions = load_rubric_versions(&conn, &window_start, &window_end)?;
+    let stockade_html =
+        render_stockade_page(
+            &official_stats,
+            &latest_date,
+            &rubric_versions,
+            disclaimer,
+            &templates,
+            decimals,
+        );
+    write_atomic(stockade_dir.join("index.html"), stockade_html)?;
 
-    let officials_index = render_officials_index(&official_stats, &latest_date);
-    fs::write(officials_dir.join("index.html"), officials_index)?;
+    let officials_index =
+        render_officials_index(&official_stats, &latest_date, disclaimer, &templates, decimals);
+    write_atomic(officials_dir.join("index.html"), officials_index)?;
 
+    let axes = rubric
+        .as_ref()
+        .map(|rubric| rubric.axes.clone())
+        .unwrap_or_default();
     for official in &official_stats {
-        let detail_html = render_official_detail(official, &latest_date);
-        fs::write(
-            officials_dir.join(format!("{}.html", official.id)),
+        let detail_html = render_official_detail(
+            official,
+            &axes,
+            &latest_date,
+            disclaimer,
+            &templates,
+            decimals,
+        );
+        write_atomic(
+            officials_dir.join(format!("{}.html", safe_filename(&official.id)?)),
             detail_html,
         )?;
     }
 
     for report in &reports {
-        let week_html = render_week_page(report, &latest_date);
-        fs::write(weeks_dir.join(format!("{}.html", report.date)), week_html)?;
+        let week_html =
+            render_week_page(report, &latest_date, &zone, disclaimer, &templates, decimals);
+        write_atomic(weeks_dir.join(format!("{}.html", report.date)), week_html)?;
     }
 
+    let api_dir = site_dir.join("api");
+    let compare_dir = site_dir.join("compare");
+    fs::create_dir_all(&api_dir)?;
+    fs::create_dir_all(&compare_dir)?;
+    let officials_api = officials_api_json(&official_stats, &axes, &latest_date);
+    write_atomic(api_dir.join("officials.json"), serde_json::to_string_pretty(&officials_api)?)?;
+    let compare_html = render_compare_page(&latest_date, disclaimer, &templates, decimals);
+    write_atomic(compare_dir.join("index.html"), compare_html)?;
+
+    let artifacts_html = render_artifacts_index(
+        &load_site_artifacts(&conn)?,
+        site.artifacts_index_limit.unwrap_or(DEFAULT_ARTIFACTS_INDEX_LIMIT),
+        &exported_artifacts,
+        &latest_date,
+        &zone,
+        disclaimer,
+        &templates,
+    );
+    write_atomic(artifacts_dir.join("index.html"), artifacts_html)?;
+
+    if site.sign == Some(true) {
+        let root = write_site_manifest(&site_dir)?;
+        println!("Wrote {SITE_MANIFEST} (manifest hash {root})");
+    }
     println!("Site export completed at {}", site_dir.display());
     Ok(())
 }
 
-fn report_weekly(config_path: PathBuf) -> Result<()> {
-    let config = load_config(&config_path)?;
-    let storage = resolve_storage(Some(&config));
-    let conn = civic_core::db::open(&storage.db_path)?;
-
-    let now = OffsetDateTime::now_utc();
-    let start = now - Duration::days(7);
-    let date_format: &[FormatItem<'_>] = time::macros::format_description!("[year]-[month]-[day]");
-    let date_str = now.format(date_format)?;
-    let window_start = start.format(&Rfc3339)?;
-    let window_end = now.format(&Rfc3339)?;
+/// `sha256sum`-style listing (`<hash>  <path>`) of every file in the site, so
+/// `sha256sum -c manifest.sha256` run inside the site checks it too.
+const SITE_MANIFEST: &str = "manifest.sha256";
+/// Holds the SHA-256 of `SITE_MANIFEST`; archivists record it to detect an edited manifest.
+const SITE_MANIFEST_ROOT: &str = "manifest.root";
+
+/// Site files relative to `site_dir` with `/` separators, sorted, leaving out the manifest.
+fn site_files(site_dir: &Path) -> Result<Vec<String>> {
+    fn walk(dir: &Path, prefix: &str, files: &mut Vec<String>) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let relative = format!("{prefix}{name}");
+            if entry.file_type()?.is_dir() {
+                walk(&entry.path(), &format!("{relative}/"), files)?;
+            } else {
+                files.push(relative);
+            }
+        }
+        Ok(())
+    }
+    let mut files = Vec::new();
+    walk(site_dir, "", &mut files)?;
+    files.retain(|file| file != SITE_MANIFEST && file != SITE_MANIFEST_ROOT);
+    files.sort();
+    Ok(files)
+}
 
-    let mut stmt = conn.prepare(
-        r#"
-        SELECT id, title, retrieved_at, source_value, tags_json
-        FROM artifacts
-        WHERE datetime(retrieved_at) >= datetime(?1)
-          AND datetime(retrieved_at) <= datetime(?2)
-        ORDER BY retrieved_at ASC, id ASC
-        "#,
-    )?;
+/// Hashes every site file into `SITE_MANIFEST` and writes the manifest's own hash to
+/// `SITE_MANIFEST_ROOT`; returns that hash.
+fn write_site_manifest(site_dir: &Path) -> Result<String> {
+    let mut manifest = String::new();
+    for file in site_files(site_dir)? {
+        let hash = civic_core::paths::file_sha256(site_dir.join(&file))?;
+        manifest.push_str(&format!("{hash}  {file}\n"));
+    }
+    write_atomic(site_dir.join(SITE_MANIFEST), &manifest)?;
+    let root = civic_core::paths::file_sha256(site_dir.join(SITE_MANIFEST))?;
+    write_atomic(site_dir.join(SITE_MANIFEST_ROOT), format!("{root}\n"))?;
+    Ok(root)
+}
 
-    let rows = stmt.query_map([window_start.as_str(), window_end.as_str()], |row| {
-        Ok(ReportArtifactRow {
-            id: row.get(0)?,
-            title: row.get(1)?,
-            retrieved_at: row.get(2)?,
-            source_value: row.get(3)?,
-            tags_json: row.get(4)?,
-        })
+/// Everything about the site that no longer matches its manifest: a manifest that does not
+/// match its recorded hash, and files that changed, went missing, or were added.
+fn site_manifest_mismatches(site_dir: &Path) -> Result<Vec<String>> {
+    let manifest_path = site_dir.join(SITE_MANIFEST);
+    let manifest = fs::read_to_string(&manifest_path).map_err(|err| {
+        FailureKind::Validation
+            .error(format!("Failed to read {}: {err}", manifest_path.display()))
     })?;
+    let mut mismatches = Vec::new();
+    match fs::read_to_string(site_dir.join(SITE_MANIFEST_ROOT)) {
+        Ok(root) if root.trim() == civic_core::paths::file_sha256(&manifest_path)? => {}
+        Ok(_) => mismatches.push(format!("{SITE_MANIFEST} does not match {SITE_MANIFEST_ROOT}")),
+        Err(_) => mismatches.push(format!("{SITE_MANIFEST_ROOT} is missing")),
+    }
+    let mut listed = HashSet::new();
+    for line in manifest.lines().filter(|line| !line.trim().is_empty()) {
+        let Some((hash, file)) = line.split_once("  ") else {
+            mismatches.push(format!("malformed manifest line: {line}"));
+            continue;
+        };
+        listed.insert(file);
+        let path = site_dir.join(file);
+        if !path.is_file() {
+            mismatches.push(format!("missing: {file}"));
+        } else if civic_core::paths::file_sha256(&path)? != hash {
+            mismatches.push(format!("changed: {file}"));
+        }
+    }
+    for file in site_files(site_dir)? {
+        if !listed.contains(file.as_str()) {
+            mismatches.push(format!("not in manifest: {file}"));
+        }
+    }
+    Ok(mismatches)
+}
 
-    let mut artifacts = Vec::new();
-    for row in rows {
-        artifacts.push(row?);
+fn verify_site(site_dir: &Path) -> Result<()> {
+    let mismatches = site_manifest_mismatches(site_dir)?;
+    if mismatches.is_empty() {
+        println!("Site {} matches {SITE_MANIFEST}", site_dir.display());
+        return Ok(());
+    }
+    for mismatch in &mismatches {
+        println!("  {mismatch}");
     }
+    Err(FailureKind::Validation.error(format!(
+        "Site {} has {} mismatch(es) against {SITE_MANIFEST}",
+        site_dir.display(),
+        mismatches.len()
+    )))
+}
 
-    let sort_key = |artifact: &&ReportArtifactRow| {
-        (
-            artifact.retrieved_at.clone(),
-            artifact
-                .title
-                .clone()
-                .unwrap_or_else(|| "(untitled)".to_string()),
-        )
+/// Vault notes for officials graded in the latest report week, with deltas against the week
+/// before it. Empty until a weekly report exists.
+fn official_notes(
+    conn: &rusqlite::Connection,
+    reports: &[WeekReport],
+    rubric: Option<&Rubric>,
+    scoring: &ResolvedScoring,
+) -> Result<Vec<obsidian::vault::OfficialNote>> {
+    let Some(latest) = reports.last() else {
+        return Ok(Vec::new());
     };
+    let mut officials = load_official_summaries(
+        conn,
+        &latest.window_start,
+        &latest.window_end,
+        rubric,
+        Some(latest),
+        &latest.date,
+        scoring,
+    )?;
+    if reports.len() > 1 {
+        let previous = &reports[reports.len() - 2];
+        let previous_average = load_official_averages(
+            conn,
+            &previous.window_start,
+            &previous.window_end,
+            rubric,
+            scoring,
+        )?;
+        for summary in &mut officials {
+            if let Some(prior) = previous_average.get(&summary.name) {
+                summary.delta = summary.average_score - prior;
+            }
+        }
+    }
 
-    let report_dir = storage.vault_path.join("Reports").join("Weekly");
-    fs::create_dir_all(&report_dir)?;
+    let axes = rubric.map(|rubric| rubric.axes.clone()).unwrap_or_default();
+    Ok(officials
+        .iter()
+        .map(|official| obsidian::vault::OfficialNote {
+            id: official.id.clone(),
+            name: official.name.clone(),
+            week_date: latest.date.clone(),
+            letter_grade: official.letter_grade.clone(),
+            numeric_grade: official.numeric_grade,
+            delta: official.delta,
+            axes: ordered_by_axes(&official.axis_scores_normalized, &axes)
+                .into_iter()
+                .map(|(axis, score)| {
+                    let (numeric, letter) = score_to_grade(*score);
+                    (axis.to_string(), letter, numeric)
+                })
+                .collect(),
+            drift_flags: official.drift_flags.clone(),
+            insufficient: official.insufficient,
+            provisional: official.provisional,
+            receipts: official
+                .receipts
+                .iter()
+                .map(|receipt| obsidian::vault::OfficialReceipt {
+                    meeting_date: receipt.meeting_date.clone(),
+                    motion_text: receipt.motion_text.clone(),
+                    vote_label: receipt.vote_label.clone(),
+                    artifact_ids: receipt.artifact_ids.clone(),
+                    week_date: receipt.week_date.clone(),
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+fn serve_site(config_path: PathBuf, port: u16, no_export: bool) -> Result<()> {
+    ensure_config_path(&config_path)?;
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    if !no_export {
+        export_site(config_path.clone(), None, None, None)?;
+    }
+    let site_dir = storage.out_dir.join("site");
+    if !site_dir.join("index.html").exists() {
+        return Err(anyhow!(
+            "No exported site at {}; run `larue export-site` first",
+            site_dir.display()
+        ));
+    }
+
+    let listener = std::net::TcpListener::bind(("127.0.0.1", port))?;
+    println!(
+        "Serving {} at http://localhost:{port}/ (Ctrl+C to stop)",
+        site_dir.display()
+    );
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("Connection failed: {err}");
+                continue;
+            }
+        };
+        if let Err(err) = handle_site_request(stream, &site_dir) {
+            eprintln!("Request failed: {err}");
+        }
+    }
+    Ok(())
+}
+
+fn handle_site_request(mut stream: std::net::TcpStream, site_dir: &Path) -> Result<()> {
+    use std::io::{BufRead, BufReader, Write};
+
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let target = parts.next().unwrap_or("/");
+
+    let (status, content_type, body) = if method != "GET" && method != "HEAD" {
+        (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            b"Method not allowed".to_vec(),
+        )
+    } else {
+        let file = resolve_site_path(site_dir, target)
+            .and_then(|path| fs::read(&path).ok().map(|body| (path, body)));
+        match file {
+            Some((path, body)) => ("200 OK", site_content_type(&path), body),
+            None => ("404 Not Found", "text/plain; charset=utf-8", b"Not found".to_vec()),
+        }
+    };
+    println!("{method} {target} -> {status}");
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n",
+        body.len()
+    )?;
+    if method != "HEAD" {
+        stream.write_all(&body)?;
+    }
+    stream.flush()?;
+    Ok(())
+}
+
+/// Map a request target onto a file under the site root; `/` and directories serve index.html.
+/// The path is percent-decoded first, so encoded `..` or separators are caught by the same
+/// checks as literal ones.
+fn resolve_site_path(site_dir: &Path, target: &str) -> Option<PathBuf> {
+    let path = percent_decode(target.split(['?', '#']).next().unwrap_or("/"))?;
+    let mut resolved = site_dir.to_path_buf();
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        if segment == ".." || segment == "." || segment.contains(['\\', '\0']) {
+            return None;
+        }
+        resolved.push(segment);
+    }
+    if resolved.is_dir() {
+        resolved.push("index.html");
+    }
+    resolved.is_file().then_some(resolved)
+}
+
+/// Decodes `%XX` escapes in a request path; `None` for a malformed escape or non-UTF-8 result.
+fn percent_decode(path: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut rest = path.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+fn site_content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js") => "application/javascript; charset=utf-8",
+        Some("json") => "application/json",
+        Some("md") => "text/markdown; charset=utf-8",
+        Some("txt") => "text/plain; charset=utf-8",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("ico") => "image/x-icon",
+        Some("xml") => "application/xml",
+        _ => "application/octet-stream",
+    }
+}
+
+fn report_weekly(
+    config_path: PathBuf,
+    date: Option<String>,
+    max_age: Option<u32>,
+    include_votes: bool,
+) -> Result<()> {
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let zone = resolve_reporting_zone(&config)?;
+    let top_n = resolve_report_top_n(Some(&config))?;
+    let max_staleness_days = resolve_max_staleness_days(Some(&config), max_age)?;
+    let conn = civic_core::db::open(&storage.db_path)?;
+    if let Some(stale) = stale_data(&conn, max_staleness_days, OffsetDateTime::now_utc())? {
+        warn_stale_data(&stale, max_staleness_days);
+    }
+
+    let window = resolve_window(date, &zone)?;
+    let date_str = &window.date;
+    let (window_start, window_end) = window.bounds()?;
+    let categories = resolve_artifact_categories(Some(&config));
+    let languages = resolve_report_languages(Some(&config))?;
+    let rubric = Rubric::load_cached(Path::new("rubric")).ok();
+
+    let mut artifacts = load_report_artifacts(
+        &conn,
+        (&window_start, &window_end),
+        &categories,
+        languages.as_deref(),
+        rubric.as_deref(),
+    )?;
+    let collapse_superseded = config
+        .reporting
+        .as_ref()
+        .and_then(|reporting| reporting.collapse_superseded)
+        .unwrap_or(false);
+    if collapse_superseded {
+        let superseded_by = civic_core::db::artifact_superseded_by(&conn)?;
+        artifacts.retain(|artifact| !superseded_by.contains_key(&artifact.id));
+    }
+
+    let sort_key = |artifact: &&ReportArtifactRow| {
+        (
+            artifact.retrieved_at.clone(),
+            artifact
+                .title
+                .clone()
+                .unwrap_or_else(|| "(untitled)".to_string()),
+        )
+    };
+
+    let layout = resolve_vault_layout(Some(&config));
+    let report_dir = VaultPaths::with_layout(&storage.vault_path, &layout).reports_dir;
+    fs::create_dir_all(&report_dir)?;
     let report_path = report_dir.join(format!("{date_str}.md"));
 
     let mut markdown = String::new();
     markdown.push_str(&format!("# Weekly Report {date_str}\n\n"));
-    markdown.push_str(&format!("Window: {window_start} to {window_end} UTC\n\n"));
+    markdown.push_str(&format!(
+        "Window: {} to {} ({})\n\n",
+        zone.display(&window_start),
+        zone.display(&window_end),
+        zone.name
+    ));
     let (mut high_impact, mut regular): (Vec<_>, Vec<_>) =
         artifacts.iter().partition(|artifact| artifact.is_high_impact());
-    high_impact.sort_by_key(sort_key);
+    high_impact.sort_by(|a, b| {
+        b.importance
+            .partial_cmp(&a.importance)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| sort_key(a).cmp(&sort_key(b)))
+    });
     regular.sort_by_key(sort_key);
 
-    let decisions = load_decisions(&conn, &window_start, &window_end)?;
-    let score_summary = load_score_summary(&conn, &window_start, &window_end)?;
+    let decisions = load_decisions(&conn, &window, rubric.as_deref())?;
+    let score_summary =
+        load_score_summary(&conn, &window, top_n, resolve_exclude_insufficient(Some(&config)))?;
 
     markdown.push_str(&format!("Total artifacts: {}\n\n", artifacts.len()));
     markdown.push_str("## High Impact\n\n");
@@ -1248,26 +3991,23 @@ fn report_weekly(config_path: PathBuf) -> Result<()> {
                 .unwrap_or("(untitled)")
                 .replace('\n', " ");
             markdown.push_str(&format!(
-                "- [{title}]({}) — {}\n",
-                artifact.source_value, artifact.retrieved_at
+                "- [{title}]({}) — {} (importance {:.2})\n",
+                artifact.source_value,
+                zone.display(&artifact.retrieved_at),
+                artifact.importance
             ));
         }
         markdown.push('\n');
     }
 
-    markdown.push_str("## All Artifacts\n\n");
-    for artifact in &regular {
-        let title = artifact
-            .title
-            .as_deref()
-            .unwrap_or("(untitled)")
-            .replace('\n', " ");
-        markdown.push_str(&format!(
-            "- [{title}]({}) — {}\n",
-            artifact.source_value, artifact.retrieved_at
-        ));
-    }
-    markdown.push('\n');
+    markdown.push_str(&render_all_artifacts(&regular, &zone));
+
+    let mut upcoming: Vec<&ReportArtifactRow> = artifacts
+        .iter()
+        .filter(|artifact| artifact.doc_type == DocType::Agenda)
+        .collect();
+    upcoming.sort_by_key(sort_key);
+    markdown.push_str(&render_upcoming_items(&upcoming, &zone));
 
     markdown.push_str("## Decisions This Week\n\n");
     if decisions.is_empty() {
@@ -1276,14 +4016,25 @@ fn report_weekly(config_path: PathBuf) -> Result<()> {
         for meeting in &decisions {
             markdown.push_str(&format!(
                 "- {} — {}\n",
-                meeting.started_at, meeting.body_name
+                zone.display(&meeting.started_at),
+                meeting.body_name
             ));
             for motion in &meeting.motions {
                 let outcome = motion
                     .result
                     .clone()
                     .unwrap_or_else(|| "unknown".to_string());
-                markdown.push_str(&format!("  - {} ({})\n", motion.text, outcome));
+                let tie = motion
+                    .tie_broken_by
+                    .as_ref()
+                    .map(|officer| format!(" — Tie broken by {officer}"))
+                    .unwrap_or_default();
+                markdown.push_str(&format!(
+                    "  - {}{} ({}){tie}\n",
+                    motion.text,
+                    civic_core::schema::amended_marker(motion.amended),
+                    outcome
+                ));
             }
         }
     }
@@ -1293,14 +4044,25 @@ fn report_weekly(config_path: PathBuf) -> Result<()> {
     if score_summary.total_scored == 0 {
         markdown.push_str("_No decision scores available this week._\n");
     } else {
+        let excluded = if score_summary.average_excludes_insufficient
+            && score_summary.insufficient_count > 0
+        {
+            " (excluding insufficient evidence)"
+        } else {
+            ""
+        };
         markdown.push_str(&format!(
-            "- Average score: {:.1}\n",
+            "- Average score: {:.1}{excluded}\n",
             score_summary.average_score
         ));
         markdown.push_str(&format!(
             "- Insufficient evidence: {}\n",
             score_summary.insufficient_count
         ));
+        markdown.push_str(&format!(
+            "- No source artifacts: {}\n",
+            score_summary.no_source_count
+        ));
         if !score_summary.top_positive.is_empty() {
             markdown.push_str("- Top positive decisions:\n");
             for entry in &score_summary.top_positive {
@@ -1326,7 +4088,7 @@ fn report_weekly(config_path: PathBuf) -> Result<()> {
             }
         }
     }
-    fs::write(&report_path, markdown)?;
+    write_atomic(&report_path, markdown)?;
 
     let report_json_dir = storage
         .out_dir
@@ -1342,126 +4104,850 @@ fn report_weekly(config_path: PathBuf) -> Result<()> {
         .count();
     let mut issue_counts: BTreeMap<String, usize> = BTreeMap::new();
     for artifact in &artifacts {
-        for tag in parse_tags_json(&artifact.tags_json) {
-            if is_issue_tag(&tag) {
-                *issue_counts.entry(tag).or_insert(0) += 1;
+        for tag in &artifact.tags {
+            if is_issue_tag(tag) {
+                *issue_counts.entry(tag.clone()).or_insert(0) += 1;
             }
         }
     }
-    let mut issue_counts_vec: Vec<(String, usize)> = issue_counts.into_iter().collect();
-    issue_counts_vec.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
-    let issue_tag_counts = issue_counts_vec
+    // Ranked by importance-weighted count (rubric/tag_importance.yaml); raw counts are kept.
+    let issue_tag_counts = tag_importance()
+        .rank(&issue_counts)
         .into_iter()
         .take(10)
-        .map(|(tag, count)| serde_json::json!({ "tag": tag, "count": count }))
+        .map(|ranked| {
+            serde_json::json!({
+                "tag": ranked.tag,
+                "count": ranked.count,
+                "weighted": ranked.weighted,
+            })
+        })
         .collect::<Vec<_>>();
 
+    let mut category_counts: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut language_counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for artifact in &artifacts {
+        *category_counts.entry(artifact.category.as_str()).or_insert(0) += 1;
+        *language_counts.entry(artifact.language.as_str()).or_insert(0) += 1;
+    }
+
     let json_payload = serde_json::json!({
         "date": date_str,
         "window_start": window_start,
         "window_end": window_end,
         "total": artifacts.len(),
+        "category_counts": category_counts,
+        "language_counts": language_counts,
         "text_extracted_total": extracted_count,
         "issue_tag_counts": issue_tag_counts,
         "rubric_alignment": score_summary.to_json(),
-        "decisions": decisions.iter().map(|meeting| {
-            serde_json::json!({
-                "meeting_id": meeting.id,
-                "body_id": meeting.body_id,
-                "body_name": meeting.body_name,
-                "started_at": meeting.started_at,
-                "motions": meeting.motions.iter().map(|motion| {
-                    serde_json::json!({
-                        "id": motion.id,
-                        "text": motion.text,
-                        "result": motion.result,
-                    })
-                }).collect::<Vec<_>>()
-            })
-        }).collect::<Vec<_>>(),
+        "decisions": report_decisions_json(&decisions, include_votes),
+        "upcoming_items": upcoming.iter().map(|artifact| artifact.id.as_str()).collect::<Vec<_>>(),
         "artifacts": ordered_artifacts.iter().map(|artifact| {
             serde_json::json!({
                 "id": artifact.id,
                 "title": artifact.title,
                 "retrieved_at": artifact.retrieved_at,
                 "source_value": artifact.source_value,
+                "category": artifact.category,
+                "language": artifact.language,
+                "doc_type": artifact.doc_type.as_str(),
                 "extracted": artifact.is_text_extracted(),
+                "importance": artifact.importance,
             })
         }).collect::<Vec<_>>()
     });
-    fs::write(&report_json_path, serde_json::to_string_pretty(&json_payload)?)?;
+    write_atomic(&report_json_path, serde_json::to_string_pretty(&json_payload)?)?;
 
     println!("Weekly report written to {}", report_path.display());
     Ok(())
 }
 
-fn digest_weekly() -> Result<()> {
-    println!("digest-weekly is not implemented yet.");
-    Ok(())
+/// The report's "All Artifacts" section: artifacts grouped under a heading per category (in
+/// name order, with counts), keeping their order within each group.
+fn render_all_artifacts(artifacts: &[&ReportArtifactRow], zone: &ReportingZone) -> String {
+    let mut groups: BTreeMap<&str, Vec<&ReportArtifactRow>> = BTreeMap::new();
+    for artifact in artifacts {
+        groups.entry(artifact.category.as_str()).or_default().push(artifact);
+    }
+    let mut markdown = String::from("## All Artifacts\n\n");
+    if groups.is_empty() {
+        markdown.push_str("_No other artifacts in this window._\n\n");
+    }
+    for (category, group) in groups {
+        markdown.push_str(&format!("### {category} ({})\n\n", group.len()));
+        for artifact in group {
+            let title = artifact
+                .title
+                .as_deref()
+                .unwrap_or("(untitled)")
+                .replace('\n', " ");
+            markdown.push_str(&format!(
+                "- [{title}]({}) — {}\n",
+                artifact.source_value,
+                zone.display(&artifact.retrieved_at)
+            ));
+        }
+        markdown.push('\n');
+    }
+    markdown
 }
 
-fn publish_placeholder() -> Result<()> {
-    println!("publish is not implemented yet.");
-    Ok(())
+/// The report's "Upcoming Items" section: agendas retrieved this week, oldest first. They
+/// announce what a body plans to take up, so they are listed here rather than scored.
+fn render_upcoming_items(agendas: &[&ReportArtifactRow], zone: &ReportingZone) -> String {
+    let mut markdown = String::from("## Upcoming Items\n\n");
+    if agendas.is_empty() {
+        markdown.push_str("_No agendas in this window._\n\n");
+        return markdown;
+    }
+    for artifact in agendas {
+        let title = artifact
+            .title
+            .as_deref()
+            .unwrap_or("(untitled)")
+            .replace('\n', " ");
+        markdown.push_str(&format!(
+            "- [{title}]({}) — {}\n",
+            artifact.source_value,
+            zone.display(&artifact.retrieved_at)
+        ));
+    }
+    markdown.push('\n');
+    markdown
 }
 
-struct ReportArtifactRow {
-    id: String,
-    title: Option<String>,
-    retrieved_at: String,
-    source_value: String,
-    tags_json: String,
-}
+fn digest_weekly(config_path: Option<PathBuf>, email: bool) -> Result<()> {
+    let Some(config_path) = config_path.filter(|_| email) else {
+        println!("digest-weekly is not implemented yet (pass --email for the HTML email digest).");
+        return Ok(());
+    };
+    ensure_config_path(&config_path)?;
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let zone = resolve_reporting_zone(&config)?;
+    let scoring = resolve_scoring(Some(&config));
+    let top_n = resolve_report_top_n(Some(&config))?;
+    let rubric = Rubric::load_cached(Path::new("rubric")).ok();
+
+    let reports = load_week_reports(&storage.out_dir)?;
+    let (date, window_start, window_end) = match reports.last() {
+        Some(report) => (
+            report.date.clone(),
+            report.window_start.clone(),
+            report.window_end.clone(),
+        ),
+        None => {
+            let window = resolve_window(None, &zone)?;
+            let (start, end) = window.bounds()?;
+            (window.date, start, end)
+        }
+    };
 
-struct ReportDecisionMotion {
-    id: String,
-    text: String,
-    result: Option<String>,
-}
+    let conn = civic_core::db::open(&storage.db_path)?;
+    let mut officials = load_official_summaries(
+        &conn,
+        &window_start,
+        &window_end,
+        rubric.as_deref(),
+        reports.last(),
+        &date,
+        &scoring,
+    )?;
+    if reports.len() > 1 {
+        let previous_report = &reports[reports.len() - 2];
+        let previous_average = load_official_averages(
+            &conn,
+            &previous_report.window_start,
+            &previous_report.window_end,
+            rubric.as_deref(),
+            &scoring,
+        )?;
+        for summary in &mut officials {
+            if let Some(prior) = previous_average.get(&summary.name) {
+                summary.delta = summary.average_score - prior;
+            }
+        }
+    }
 
-struct ReportDecisionMeeting {
-    id: String,
-    body_id: String,
-    body_name: String,
-    started_at: String,
-    motions: Vec<ReportDecisionMotion>,
+    let site = resolve_site_config(config.site.as_ref());
+    let disclaimer = site.disclaimer.as_deref().unwrap_or(DEFAULT_DISCLAIMER);
+    let decimals = site.display_decimals.unwrap_or(DEFAULT_DISPLAY_DECIMALS);
+    let html =
+        render_email_digest(reports.last(), &date, &officials, top_n, disclaimer, decimals);
+    let digests_dir = storage.out_dir.join("digests");
+    fs::create_dir_all(&digests_dir)?;
+    let path = digests_dir.join(format!("{date}.html"));
+    write_atomic(&path, html)?;
+    println!("Email digest written to {}", path.display());
+    Ok(())
 }
 
-struct MeetingWindowRow {
-    id: String,
-    body_id: String,
-    started_at: String,
-    artifact_ids_json: String,
-}
+/// Self-contained email body: table layout and inline styles only, since email clients strip
+/// `<style>` blocks and external stylesheets.
+fn render_email_digest(
+    report: Option<&WeekReport>,
+    date: &str,
+    officials: &[OfficialSummary],
+    top_n: usize,
+    disclaimer: &str,
+    decimals: usize,
+) -> String {
+    const CELL: &str = "padding:6px 8px;border-bottom:1px solid #e5e7eb;font-size:14px;";
+    const HEADING: &str = "margin:24px 0 8px;font-size:18px;color:#111827;";
+    const MUTED: &str = "color:#6b7280;font-size:14px;";
 
-struct MotionRow {
-    id: String,
-    text: String,
-}
+    let avg_score = report.map(|report| report.rubric_average).unwrap_or(0.0);
+    let (avg_numeric, avg_grade) = score_to_grade(avg_score);
 
-struct VoteRow {
-    id: String,
-    motion_id: String,
-    ayes: Vec<String>,
-    nays: Vec<String>,
-    abstain: Vec<String>,
-    choices: Vec<(String, VoteChoice)>,
+    let mut movers = officials
+        .iter()
+        .filter(|official| official.delta.abs() > f64::EPSILON)
+        .collect::<Vec<_>>();
+    movers.sort_by(|a, b| {
+        b.delta
+            .abs()
+            .partial_cmp(&a.delta.abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    let movers = if movers.is_empty() {
+        format!("<p style=\"{MUTED}\">No grade changes since last week.</p>")
+    } else {
+        let rows = movers
+            .iter()
+            .take(top_n)
+            .map(|official| {
+                let color = if official.delta > 0.0 { "#15803d" } else { "#b91c1c" };
+                format!(
+                    "<tr><td style=\"{CELL}\">{name}</td>\
+<td style=\"{CELL}\">{grade}</td>\
+<td style=\"{CELL}color:{color};\">{delta:+.2}</td></tr>",
+                    name = official.name,
+                    grade = official.letter_grade,
+                    delta = official.delta,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        email_table(&["Official", "Grade", "Change"], &rows)
+    };
+
+    let drift_rows = officials
+        .iter()
+        .flat_map(|official| {
+            official.drift_details.iter().filter(|detail| !detail.resolved).map(move |detail| {
+                format!(
+                    "<tr><td style=\"{CELL}\">{name}</td>\
+<td style=\"{CELL}\">{axis}</td>\
+<td style=\"{CELL}\">{prior:.2} &rarr; {current:.2}</td></tr>",
+                    name = official.name,
+                    axis = detail.axis,
+                    prior = detail.prior_average,
+                    current = detail.current_average,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    let drift = if drift_rows.is_empty() {
+        format!("<p style=\"{MUTED}\">No drift alerts this week.</p>")
+    } else {
+        email_table(&["Official", "Axis", "Prior &rarr; Current"], &drift_rows.join("\n"))
+    };
+
+    let issue_counts = report
+        .map(|report| report.issue_tag_counts.as_slice())
+        .unwrap_or_default();
+    let issues = if issue_counts.is_empty() {
+        format!("<p style=\"{MUTED}\">No tagged issues this week.</p>")
+    } else {
+        let rows = issue_counts
+            .iter()
+            .take(top_n)
+            .map(|(tag, count)| {
+                format!(
+                    "<tr><td style=\"{CELL}\">{tag}</td><td style=\"{CELL}\">{count}</td></tr>"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        email_table(&["Issue", "Items"], &rows)
+    };
+
+    format!(
+        r#"<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8" />
+<meta name="viewport" content="width=device-width, initial-scale=1" />
+<title>LaRue Civic Intel: week of {date}</title>
+</head>
+<body style="margin:0;padding:0;background:#f3f4f6;">
+<table role="presentation" width="100%" cellpadding="0" cellspacing="0" border="0" style="background:#f3f4f6;">
+<tr><td align="center" style="padding:24px 12px;">
+<table role="presentation" width="600" cellpadding="0" cellspacing="0" border="0" style="max-width:600px;width:100%;background:#ffffff;border-radius:8px;font-family:Arial,Helvetica,sans-serif;color:#111827;">
+<tr><td style="padding:24px;">
+<h1 style="margin:0 0 4px;font-size:22px;">LaRue Civic Intel Weekly Digest</h1>
+<p style="{MUTED}margin:0 0 16px;">Week of {date}</p>
+<table role="presentation" cellpadding="0" cellspacing="0" border="0" style="margin:0 0 8px;">
+<tr><td style="padding:12px 16px;background:#eef2ff;border-radius:6px;">
+<div style="{MUTED}">Average grade</div>
+<div style="font-size:28px;font-weight:bold;">{avg_grade} <span style="{MUTED}">({avg_numeric:.decimals$})</span></div>
+</td></tr>
+</table>
+<h2 style="{HEADING}">Top movers</h2>
+{movers}
+<h2 style="{HEADING}">Drift alerts</h2>
+{drift}
+<h2 style="{HEADING}">Top issues</h2>
+{issues}
+<p style="{MUTED}margin:24px 0 0;">{disclaimer}</p>
+</td></tr>
+</table>
+</td></tr>
+</table>
+</body>
+</html>
+"#
+    )
 }
 
-struct DriftDetectionResult {
-    updated_scores: Vec<DecisionScore>,
-    drift_flags: Vec<String>,
+fn email_table(headers: &[&str], rows: &str) -> String {
+    let header_cells = headers
+        .iter()
+        .map(|header| {
+            format!(
+                "<th align=\"left\" style=\"padding:6px 8px;border-bottom:2px solid #d1d5db;\
+font-size:13px;color:#374151;\">{header}</th>"
+            )
+        })
+        .collect::<String>();
+    format!(
+        "<table role=\"presentation\" width=\"100%\" cellpadding=\"0\" cellspacing=\"0\" \
+border=\"0\">\n<tr>{header_cells}</tr>\n{rows}\n</table>"
+    )
 }
 
-struct ScoreDecisionEntry {
-    text: String,
-    overall_score: f64,
+/// An official whose letter grade differs between two report weeks; scores are the normalized
+/// 0-100 grades.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+struct GradeChange {
+    official: String,
+    old_grade: String,
+    new_grade: String,
+    old_score: f64,
+    new_score: f64,
+    delta: f64,
 }
 
-struct ScoreSummary {
+fn list_grade_changes(config_path: PathBuf, week: Option<String>, json: bool) -> Result<()> {
+    ensure_config_path(&config_path)?;
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let scoring = resolve_scoring(Some(&config));
+    let reports = load_week_reports(&storage.out_dir)?;
+    let week = week
+        .map(|value| parse_flexible_date(&value).map(|date| date.to_string()))
+        .transpose()?;
+    let current_index = match &week {
+        Some(date) => reports
+            .iter()
+            .position(|report| &report.date == date)
+            .ok_or_else(|| {
+                FailureKind::Validation.error(format!("No weekly report found for {date}"))
+            })?,
+        None => reports.len().saturating_sub(1),
+    };
+    if current_index == 0 {
+        println!("Need two weekly reports to compare grades; found {}", reports.len().min(1));
+        return Ok(());
+    }
+    let (previous, current) = (&reports[current_index - 1], &reports[current_index]);
+
+    let rubric = Rubric::load_cached(Path::new("rubric")).ok();
+    let conn = civic_core::db::open(&storage.db_path)?;
+    let previous_averages = load_official_averages(
+        &conn,
+        &previous.window_start,
+        &previous.window_end,
+        rubric.as_deref(),
+        &scoring,
+    )?;
+    let current_averages = load_official_averages(
+        &conn,
+        &current.window_start,
+        &current.window_end,
+        rubric.as_deref(),
+        &scoring,
+    )?;
+    let changes = grade_changes(
+        &previous_averages,
+        &current_averages,
+        rubric.as_ref().map(|rubric| &rubric.config),
+    );
+
+    if json {
+        let payload = serde_json::json!({
+            "week": current.date,
+            "previous_week": previous.date,
+            "changes": changes,
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+        return Ok(());
+    }
+    if changes.is_empty() {
+        println!("No letter-grade changes from {} to {}", previous.date, current.date);
+        return Ok(());
+    }
+    println!("Grade changes from {} to {}:", previous.date, current.date);
+    for change in &changes {
+        println!(
+            "  {}: {} -> {} ({:+.1})",
+            change.official, change.old_grade, change.new_grade, change.delta
+        );
+    }
+    Ok(())
+}
+
+/// Officials graded in both weeks whose letter grade changed, largest movement first.
+fn grade_changes(
+    previous: &HashMap<String, f64>,
+    current: &HashMap<String, f64>,
+    rubric_config: Option<&civic_core::scoring::RubricConfig>,
+) -> Vec<GradeChange> {
+    let mut changes: Vec<GradeChange> = current
+        .iter()
+        .filter_map(|(official, current_average)| {
+            let previous_average = previous.get(official)?;
+            let (old_score, old_grade) =
+                score_to_grade(normalize_score(*previous_average, rubric_config));
+            let (new_score, new_grade) =
+                score_to_grade(normalize_score(*current_average, rubric_config));
+            (old_grade != new_grade).then(|| GradeChange {
+                official: official.clone(),
+                old_grade,
+                new_grade,
+                old_score,
+                new_score,
+                delta: new_score - old_score,
+            })
+        })
+        .collect();
+    changes.sort_by(|a, b| {
+        b.delta
+            .abs()
+            .partial_cmp(&a.delta.abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.official.cmp(&b.official))
+    });
+    changes
+}
+
+/// One issue tag's counts over a range of weekly reports.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+struct IssueTrend {
+    tag: String,
+    count: usize,
+    /// Counts from the earlier and later half of the range's reports; with an odd number of
+    /// reports the middle one falls in the later half.
+    first_half: usize,
+    second_half: usize,
+    /// Rising or Falling when the later half's per-report rate differs from the earlier's.
+    trend: Trend,
+}
+
+fn list_top_issues(
+    config_path: PathBuf,
+    from: Option<String>,
+    to: Option<String>,
+    top: usize,
+    json: bool,
+) -> Result<()> {
+    ensure_config_path(&config_path)?;
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let parse = |value: Option<String>| {
+        value
+            .map(|value| parse_flexible_date(&value).map(|date| date.to_string()))
+            .transpose()
+    };
+    let (from, to) = (parse(from)?, parse(to)?);
+    let reports: Vec<WeekReport> = load_week_reports(&storage.out_dir)?
+        .into_iter()
+        .filter(|report| from.as_ref().is_none_or(|from| &report.date >= from))
+        .filter(|report| to.as_ref().is_none_or(|to| &report.date <= to))
+        .collect();
+    let mut issues = issue_trends(&reports);
+    issues.truncate(top);
+
+    let first = reports.first().map(|report| report.date.as_str()).unwrap_or("");
+    let last = reports.last().map(|report| report.date.as_str()).unwrap_or("");
+    if json {
+        let payload = serde_json::json!({
+            "from": first,
+            "to": last,
+            "reports": reports.len(),
+            "issues": issues,
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+        return Ok(());
+    }
+    if issues.is_empty() {
+        println!("No issue tags in {} weekly report(s)", reports.len());
+        return Ok(());
+    }
+    println!("Top issues from {first} to {last} ({} weekly reports):", reports.len());
+    for issue in &issues {
+        let trend = match issue.trend {
+            Trend::Rising => "rising",
+            Trend::Falling => "falling",
+            Trend::Steady => "steady",
+        };
+        println!(
+            "  {}: {} ({trend}, {} -> {})",
+            issue.tag, issue.count, issue.first_half, issue.second_half
+        );
+    }
+    Ok(())
+}
+
+/// Sums each report's `issue_tag_counts` (issue tags only), most frequent first, ties by tag.
+fn issue_trends(reports: &[WeekReport]) -> Vec<IssueTrend> {
+    let split = reports.len() / 2;
+    let mut halves: BTreeMap<&str, [usize; 2]> = BTreeMap::new();
+    for (index, report) in reports.iter().enumerate() {
+        for (tag, count) in &report.issue_tag_counts {
+            if is_issue_tag(tag) {
+                halves.entry(tag.as_str()).or_default()[usize::from(index >= split)] += count;
+            }
+        }
+    }
+    let mut issues: Vec<IssueTrend> = halves
+        .into_iter()
+        .map(|(tag, [first_half, second_half])| {
+            // Compare per-report rates, since the halves differ in size for odd ranges.
+            let earlier = first_half * (reports.len() - split);
+            let later = second_half * split;
+            let trend = if split == 0 || earlier == later {
+                Trend::Steady
+            } else if later > earlier {
+                Trend::Rising
+            } else {
+                Trend::Falling
+            };
+            IssueTrend {
+                tag: tag.to_string(),
+                count: first_half + second_half,
+                first_half,
+                second_half,
+                trend,
+            }
+        })
+        .collect();
+    issues.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+    issues
+}
+
+/// A stored score carrying a data-quality flag.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+struct FlaggedScore {
+    meeting_id: Option<String>,
+    motion_id: Option<String>,
+    vote_id: Option<String>,
+}
+
+fn list_flags(
+    config_path: PathBuf,
+    flag: Option<String>,
+    week: Option<String>,
+    json: bool,
+) -> Result<()> {
+    ensure_config_path(&config_path)?;
+    let config = load_config(&config_path)?;
+    let storage = resolve_storage(Some(&config));
+    let window = match week {
+        Some(date) => {
+            let zone = resolve_reporting_zone(&config)?;
+            Some(resolve_window(Some(date), &zone)?.bounds()?)
+        }
+        None => None,
+    };
+    let conn = civic_core::db::open(&storage.db_path)?;
+    let rows = load_flagged_scores(&conn, window.as_ref())?;
+    let grouped = group_flags(rows, flag.as_deref());
+
+    if json {
+        let payload = grouped
+            .iter()
+            .map(|(flag, scores)| {
+                (
+                    flag.clone(),
+                    serde_json::json!({ "count": scores.len(), "scores": scores }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+        return Ok(());
+    }
+
+    if grouped.is_empty() {
+        println!("No flagged scores found");
+        return Ok(());
+    }
+    for (flag, scores) in &grouped {
+        println!("{flag}: {}", scores.len());
+        for score in scores {
+            println!(
+                "  meeting={} motion={} vote={}",
+                score.meeting_id.as_deref().unwrap_or("-"),
+                score.motion_id.as_deref().unwrap_or("-"),
+                score.vote_id.as_deref().unwrap_or("-")
+            );
+        }
+    }
+    Ok(())
+}
+
+/// `(flags, score)` for every stored score with at least one flag, optionally limited to
+/// meetings that started inside `window`.
+fn load_flagged_scores(
+    conn: &rusqlite::Connection,
+    window: Option<&(String, String)>,
+) -> Result<Vec<(Vec<String>, FlaggedScore)>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT decision_scores.flags_json, decision_scores.meeting_id,
+               decision_scores.motion_id, decision_scores.vote_id
+        FROM decision_scores
+        LEFT JOIN meetings ON decision_scores.meeting_id = meetings.id
+        WHERE decision_scores.flags_json != '[]'
+          AND (?1 IS NULL OR datetime(meetings.started_at) >= datetime(?1))
+          AND (?2 IS NULL OR datetime(meetings.started_at) <= datetime(?2))
+        ORDER BY decision_scores.meeting_id, decision_scores.motion_id, decision_scores.vote_id
+        "#,
+    )?;
+    let (start, end) = match window {
+        Some((start, end)) => (Some(start.as_str()), Some(end.as_str())),
+        None => (None, None),
+    };
+    let rows = stmt.query_map(rusqlite::params![start, end], |row| {
+        let flags_json: String = row.get(0)?;
+        Ok((
+            flags_json,
+            FlaggedScore {
+                meeting_id: row.get(1)?,
+                motion_id: row.get(2)?,
+                vote_id: row.get(3)?,
+            },
+        ))
+    })?;
+    let mut flagged = Vec::new();
+    for row in rows {
+        let (flags_json, score) = row?;
+        let flags: Vec<String> = serde_json::from_str(&flags_json).unwrap_or_default();
+        if !flags.is_empty() {
+            flagged.push((flags, score));
+        }
+    }
+    Ok(flagged)
+}
+
+/// Groups flagged scores by flag name, keeping only `only` when given.
+fn group_flags(
+    rows: Vec<(Vec<String>, FlaggedScore)>,
+    only: Option<&str>,
+) -> BTreeMap<String, Vec<FlaggedScore>> {
+    let mut grouped: BTreeMap<String, Vec<FlaggedScore>> = BTreeMap::new();
+    for (flags, score) in rows {
+        for flag in flags {
+            if only.is_some_and(|only| only != flag) {
+                continue;
+            }
+            grouped.entry(flag).or_default().push(score.clone());
+        }
+    }
+    grouped
+}
+
+fn doctor(db_path: &str) -> Result<()> {
+    let conn = civic_core::db::open(db_path)?;
+    let dangling = civic_core::db::dangling_votes(&conn)?;
+    for (vote_id, motion_id) in &dangling {
+        eprintln!("Vote {vote_id} references missing motion {motion_id}");
+    }
+    let orphaned = civic_core::db::orphaned_scores(&conn)?;
+    for (score_id, motion_id) in &orphaned {
+        eprintln!("Score {score_id} references missing motion {motion_id}");
+    }
+    let unlinked = civic_core::db::dangling_artifact_refs(&conn)?;
+    for (meeting_id, artifact_id) in &unlinked {
+        eprintln!("Meeting {meeting_id} references missing artifact {artifact_id}");
+    }
+    if !dangling.is_empty() || !orphaned.is_empty() || !unlinked.is_empty() {
+        return Err(FailureKind::Validation.error(format!(
+            "doctor found {} vote(s) and {} score(s) with no matching motion, and {} missing \
+             meeting artifact reference(s)",
+            dangling.len(),
+            orphaned.len(),
+            unlinked.len()
+        )));
+    }
+    println!("doctor: no problems found in {db_path}");
+    Ok(())
+}
+
+fn publish_placeholder() -> Result<()> {
+    println!("publish is not implemented yet.");
+    Ok(())
+}
+
+struct ReportArtifactRow {
+    id: String,
+    title: Option<String>,
+    retrieved_at: String,
+    source_value: String,
+    /// Stored tags with the rubric's tag aliases applied, so synonyms count as one issue.
+    tags: Vec<String>,
+    importance: f64,
+    /// Report grouping derived from `source_kind` (see `resolve_artifact_categories`).
+    category: String,
+    language: String,
+    doc_type: DocType,
+}
+
+/// Artifacts retrieved inside `window`, oldest first, limited to `languages` when given. Doc
+/// types not set explicitly are derived with the rubric's `[source_doc_types]`, if any.
+fn load_report_artifacts(
+    conn: &rusqlite::Connection,
+    (window_start, window_end): (&str, &str),
+    categories: &HashMap<String, String>,
+    languages: Option<&[String]>,
+    rubric: Option<&Rubric>,
+) -> Result<Vec<ReportArtifactRow>> {
+    let no_doc_types = HashMap::new();
+    let source_doc_types =
+        rubric.map_or(&no_doc_types, |rubric| &rubric.config.source_doc_types);
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT id, title, retrieved_at, source_value, tags_json, COALESCE(importance, 0),
+               source_kind, COALESCE(language, ?3), doc_type
+        FROM artifacts
+        WHERE datetime(retrieved_at) >= datetime(?1)
+          AND datetime(retrieved_at) <= datetime(?2)
+        ORDER BY retrieved_at ASC, id ASC
+        "#,
+    )?;
+    let params = [window_start, window_end, civic_core::schema::DEFAULT_ARTIFACT_LANGUAGE];
+    let rows = stmt.query_map(params, |row| {
+        let tags = parse_tags_json(&row.get::<_, String>(4)?, rubric);
+        let source_kind: String = row.get(6)?;
+        let doc_type =
+            civic_core::db::stored_doc_type(row.get(8)?, &tags, &source_kind, source_doc_types);
+        Ok(ReportArtifactRow {
+            id: row.get(0)?,
+            title: row.get(1)?,
+            retrieved_at: row.get(2)?,
+            source_value: row.get(3)?,
+            tags,
+            importance: row.get(5)?,
+            category: categories
+                .get(&source_kind)
+                .cloned()
+                .unwrap_or_else(|| OTHER_ARTIFACT_CATEGORY.to_string()),
+            language: row.get(7)?,
+            doc_type,
+        })
+    })?;
+
+    let mut artifacts = Vec::new();
+    for row in rows {
+        let artifact = row?;
+        if languages.is_none_or(|languages| languages.contains(&artifact.language)) {
+            artifacts.push(artifact);
+        }
+    }
+    Ok(artifacts)
+}
+
+struct ReportDecisionMotion {
+    id: String,
+    text: String,
+    result: Option<String>,
+    amended: bool,
+    amendment_text: Option<String>,
+    vote_label: Option<String>,
+    /// Presiding officer whose vote decided a tie.
+    tie_broken_by: Option<String>,
+    public_comments: Vec<PublicComment>,
+    votes: Vec<VoteRow>,
+}
+
+struct ReportDecisionMeeting {
+    id: String,
+    body_id: String,
+    body_name: String,
+    started_at: String,
+    motions: Vec<ReportDecisionMotion>,
+    /// `None` when the rubric could not be loaded.
+    evidence_coverage: Option<EvidenceCoverage>,
+}
+
+struct MeetingWindowRow {
+    id: String,
+    body_id: String,
+    started_at: String,
+    artifact_ids_json: String,
+    presiding_officer: Option<String>,
+}
+
+struct MotionRow {
+    id: String,
+    text: String,
+    amended: bool,
+    amendment_text: Option<String>,
+    agenda_ref: Option<String>,
+    /// Earlier motion in the same meeting this one reconsiders (see `link_reconsiderations`).
+    reconsiders_motion_id: Option<String>,
+}
+
+impl MotionRow {
+    /// Amended motions are scored on the amendment's wording when the minutes recorded it.
+    fn scored_text(&self) -> &str {
+        civic_core::schema::amended_text(self.amended, self.amendment_text.as_deref())
+            .unwrap_or(&self.text)
+    }
+}
+
+struct VoteRow {
+    id: String,
+    motion_id: String,
+    vote_type: Option<String>,
+    outcome: Option<String>,
+    ayes: Vec<String>,
+    nays: Vec<String>,
+    abstain: Vec<String>,
+    choices: Vec<(String, VoteChoice)>,
+    tally: VoteTally,
+}
+
+struct DriftDetectionResult {
+    updated_scores: Vec<DecisionScore>,
+    /// Earlier drift rows resolved because their axis is back within the threshold.
+    resolved: usize,
+}
+
+struct ScoreDecisionEntry {
+    text: String,
+    overall_score: f64,
+}
+
+struct ScoreSummary {
     average_score: f64,
+    /// Whether `average_score` leaves out the `insufficient_count` scores.
+    average_excludes_insufficient: bool,
     total_scored: usize,
     insufficient_count: usize,
+    no_source_count: usize,
     top_positive: Vec<ScoreDecisionEntry>,
     top_negative: Vec<ScoreDecisionEntry>,
     drift_flags: Vec<String>,
@@ -1471,8 +4957,10 @@ impl ScoreSummary {
     fn to_json(&self) -> serde_json::Value {
         serde_json::json!({
             "average_score": self.average_score,
+            "average_excludes_insufficient": self.average_excludes_insufficient,
             "total_scored": self.total_scored,
             "insufficient_count": self.insufficient_count,
+            "no_source_count": self.no_source_count,
             "top_positive": self.top_positive.iter().map(|entry| {
                 serde_json::json!({
                     "text": entry.text,
@@ -1502,13 +4990,17 @@ struct WeekReport {
 
 struct WeekDecision {
     body_name: String,
-    started_at: String,
     motions: Vec<WeekMotion>,
+    evidence_coverage: Option<EvidenceCoverage>,
 }
 
 struct WeekMotion {
     text: String,
     result: Option<String>,
+    amended: bool,
+    vote_label: Option<String>,
+    tie_broken_by: Option<String>,
+    public_comments: Vec<PublicComment>,
 }
 
 struct WeekArtifact {
@@ -1520,107 +5012,201 @@ struct OfficialSummary {
     id: String,
     name: String,
     average_score: f64,
-    axis_scores: HashMap<String, f64>,
     axis_scores_normalized: HashMap<String, f64>,
+    /// Weighted average of each axis's contribution to the raw overall score.
+    contributions: HashMap<String, f64>,
+    /// Per axis: the average axis score (votes not touching it count as zero) and the
+    /// polarity-signed weight applied to it, from the scores' weight snapshots.
+    weights_applied: HashMap<String, (f64, f64)>,
+    /// `normalize_score` of the sum of `weights_applied` products.
+    weighted_overall: f64,
+    constitution_refs: HashMap<String, Vec<String>>,
     letter_grade: String,
     numeric_grade: f64,
     delta: f64,
+    trend: Trend,
     drift_flags: Vec<String>,
+    drift_details: Vec<DriftDetail>,
     insufficient: bool,
+    /// Scored votes behind the grade this week.
+    vote_count: usize,
+    /// Fewer than `scoring.min_votes_for_grade` scored votes.
+    provisional: bool,
     receipts: Vec<Receipt>,
     top_issue_tags: Vec<String>,
     commentary: Option<String>,
 }
 
+struct DriftDetail {
+    axis: String,
+    prior_average: f64,
+    current_average: f64,
+    deviation: f64,
+    /// Resolved by the window's end; otherwise still active.
+    resolved: bool,
+}
+
 struct Receipt {
     meeting_date: String,
     motion_text: String,
+    vote_label: Option<String>,
     artifact_ids: Vec<String>,
     week_date: String,
+    /// Sentences that triggered the motion's issue tags (see `EvidenceExcerpt`).
+    excerpts: Vec<EvidenceExcerpt>,
 }
 
 impl ReportArtifactRow {
     fn is_high_impact(&self) -> bool {
-        parse_tags_json(&self.tags_json)
-            .iter()
-            .any(|tag| tag == "high_impact")
+        self.tags.iter().any(|tag| tag == "high_impact")
     }
 
     fn is_text_extracted(&self) -> bool {
-        parse_tags_json(&self.tags_json)
-            .iter()
-            .any(|tag| tag == "text_extracted")
+        self.tags.iter().any(|tag| tag == "text_extracted")
+    }
+}
+
+/// Stored tags with the rubric's `tag_aliases.yaml` applied, so synonyms count as one issue.
+/// Without a rubric the tags are returned as stored.
+fn parse_tags_json(tags_json: &str, rubric: Option<&Rubric>) -> Vec<String> {
+    let tags: Vec<String> = serde_json::from_str(tags_json).unwrap_or_default();
+    match rubric {
+        Some(rubric) => rubric.tag_aliases.canonicalize(&tags),
+        None => tags,
     }
 }
 
-fn parse_tags_json(tags_json: &str) -> Vec<String> {
-    serde_json::from_str(tags_json).unwrap_or_default()
+fn tag_importance() -> &'static TagImportance {
+    static TAG_IMPORTANCE: OnceLock<TagImportance> = OnceLock::new();
+    TAG_IMPORTANCE.get_or_init(|| {
+        TagImportance::load_from_dir(Path::new("rubric")).unwrap_or_else(|err| {
+            eprintln!("Warning: failed to load rubric/tag_importance.yaml: {err}");
+            TagImportance::default()
+        })
+    })
+}
+
+/// A reporting week: the report date in the reporting zone plus its window bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WindowSpec {
+    date: String,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+}
+
+impl WindowSpec {
+    /// Window start as the UTC RFC 3339 string bound into SQL comparisons.
+    fn start_rfc3339(&self) -> Result<String> {
+        Ok(self.start.format(&Rfc3339)?)
+    }
+
+    /// Window end as the UTC RFC 3339 string bound into SQL comparisons.
+    fn end_rfc3339(&self) -> Result<String> {
+        Ok(self.end.format(&Rfc3339)?)
+    }
+
+    /// `(start, end)` SQL parameters, in binding order.
+    fn bounds(&self) -> Result<(String, String)> {
+        Ok((self.start_rfc3339()?, self.end_rfc3339()?))
+    }
 }
 
-fn resolve_window(date: Option<String>) -> Result<(String, String, String)> {
+// Window boundaries follow local midnights in the reporting zone but are bound as UTC RFC 3339
+// strings so SQL comparisons against stored UTC timestamps are unchanged.
+fn resolve_window(date: Option<String>, zone: &ReportingZone) -> Result<WindowSpec> {
     let date_format: &[FormatItem<'_>] = time::macros::format_description!("[year]-[month]-[day]");
-    let now = OffsetDateTime::now_utc();
-    if let Some(date_value) = date {
-        let parsed = parse_date_ymd(&date_value)?;
-        let end = parsed.next_day().unwrap_or(parsed);
-        let end_dt = end.with_time(time::Time::MIDNIGHT).assume_utc();
-        let start_dt = end_dt - Duration::days(7);
-        let date_str = parsed.format(date_format)?;
-        let window_start = start_dt.format(&Rfc3339)?;
-        let window_end = end_dt.format(&Rfc3339)?;
-        return Ok((date_str, window_start, window_end));
-    }
-    let date_str = now.format(date_format)?;
-    let window_end = now.format(&Rfc3339)?;
-    let window_start = (now - Duration::days(7)).format(&Rfc3339)?;
-    Ok((date_str, window_start, window_end))
-}
-
-fn parse_date_ymd(date_value: &str) -> Result<time::Date> {
-    let mut parts = date_value.split('-');
-    let year_str = parts.next().unwrap_or("");
-    let month_str = parts.next().unwrap_or("");
-    let day_str = parts.next().unwrap_or("");
-    if parts.next().is_some() || year_str.is_empty() || month_str.is_empty() || day_str.is_empty() {
-        return Err(anyhow!(
-            "Invalid date {date_value}: expected format YYYY-MM-DD"
+    let date = match date {
+        Some(date_value) => parse_flexible_date(&date_value)?,
+        None => zone.to_local(OffsetDateTime::now_utc()).date(),
+    };
+    let end = date.next_day().unwrap_or(date);
+    Ok(WindowSpec {
+        date: date.format(date_format)?,
+        start: zone.local_midnight(end - Duration::days(7)),
+        end: zone.local_midnight(end),
+    })
+}
+
+/// Parses `YYYY-MM-DD` (canonical) or `MM-DD-YYYY`, with `-`, `/`, or `.` as the separator.
+/// Two-digit years, day-first dates, and mixed separators are rejected rather than guessed.
+fn parse_flexible_date(date_value: &str) -> Result<time::Date> {
+    let trimmed = date_value.trim();
+    let separators: Vec<char> = trimmed.chars().filter(|ch| !ch.is_ascii_digit()).collect();
+    let Some(&separator) = separators.first() else {
+        return Err(invalid_date(date_value, "expected YYYY-MM-DD or MM-DD-YYYY"));
+    };
+    if !matches!(separator, '-' | '/' | '.')
+        || separators.len() != 2
+        || separators.iter().any(|ch| *ch != separator)
+    {
+        return Err(invalid_date(
+            date_value,
+            "expected YYYY-MM-DD or MM-DD-YYYY with one kind of separator (-, / or .)",
         ));
     }
+    let parts: Vec<&str> = trimmed.split(separator).collect();
+    if parts.iter().any(|part| part.is_empty()) {
+        return Err(invalid_date(date_value, "expected YYYY-MM-DD or MM-DD-YYYY"));
+    }
+    let (year_str, month_str, day_str) = match (parts[0].len(), parts[2].len()) {
+        (4, 1..=2) => (parts[0], parts[1], parts[2]),
+        (1..=2, 4) => (parts[2], parts[0], parts[1]),
+        _ => {
+            return Err(invalid_date(
+                date_value,
+                "ambiguous date; use a four-digit year as YYYY-MM-DD or MM-DD-YYYY",
+            ));
+        }
+    };
+    if parts[1].len() > 2 {
+        return Err(invalid_date(date_value, "expected YYYY-MM-DD or MM-DD-YYYY"));
+    }
     let year: i32 = year_str
         .parse()
-        .map_err(|err| anyhow!("Invalid date {date_value}: invalid year ({err})"))?;
+        .map_err(|err| invalid_date(date_value, format!("invalid year ({err})")))?;
     let month: u8 = month_str
         .parse()
-        .map_err(|err| anyhow!("Invalid date {date_value}: invalid month ({err})"))?;
+        .map_err(|err| invalid_date(date_value, format!("invalid month ({err})")))?;
     let day: u8 = day_str
         .parse()
-        .map_err(|err| anyhow!("Invalid date {date_value}: invalid day ({err})"))?;
+        .map_err(|err| invalid_date(date_value, format!("invalid day ({err})")))?;
+    if year_str == parts[2] && month > 12 && day <= 12 {
+        return Err(invalid_date(
+            date_value,
+            "day-first dates are not supported; use YYYY-MM-DD or MM-DD-YYYY",
+        ));
+    }
     let month = Month::try_from(month)
-        .map_err(|err| anyhow!("Invalid date {date_value}: invalid month ({err})"))?;
+        .map_err(|err| invalid_date(date_value, format!("invalid month ({err})")))?;
     time::Date::from_calendar_date(year, month, day)
-        .map_err(|err| anyhow!("Invalid date {date_value}: {err}"))
+        .map_err(|err| invalid_date(date_value, err))
+}
+
+fn invalid_date(date_value: &str, reason: impl std::fmt::Display) -> anyhow::Error {
+    FailureKind::Validation.error(format!("Invalid date {date_value}: {reason}"))
 }
 
 fn load_meetings_in_window(
     conn: &rusqlite::Connection,
-    window_start: &str,
-    window_end: &str,
+    window: &WindowSpec,
 ) -> Result<Vec<MeetingWindowRow>> {
+    let (window_start, window_end) = window.bounds()?;
     let mut stmt = conn.prepare(
         r#"
-        SELECT id, body_id, started_at, artifact_ids_json
+        SELECT id, body_id, started_at, artifact_ids_json, presiding_officer
         FROM meetings
         WHERE datetime(started_at) >= datetime(?1)
           AND datetime(started_at) <= datetime(?2)
         ORDER BY started_at ASC, id ASC
         "#,
     )?;
-    let rows = stmt.query_map([window_start, window_end], |row| {
+    let rows = stmt.query_map([window_start.as_str(), window_end.as_str()], |row| {
         Ok(MeetingWindowRow {
             id: row.get(0)?,
             body_id: row.get(1)?,
             started_at: row.get(2)?,
             artifact_ids_json: row.get(3)?,
+            presiding_officer: row.get(4)?,
         })
     })?;
     let mut meetings = Vec::new();
@@ -1633,40 +5219,65 @@ fn load_meetings_in_window(
 fn load_linked_artifacts(
     conn: &rusqlite::Connection,
     meeting: &MeetingWindowRow,
+    rubric: &Rubric,
 ) -> Result<Vec<LinkedArtifact>> {
     let artifact_ids: Vec<String> =
         serde_json::from_str(&meeting.artifact_ids_json).unwrap_or_default();
-    let mut artifacts = Vec::new();
-    for artifact_id in artifact_ids {
-        let mut stmt = conn.prepare(
-            r#"
-            SELECT id, tags_json
-            FROM artifacts
-            WHERE id = ?1
-            "#,
-        )?;
-        let mut rows = stmt.query([artifact_id.as_str()])?;
-        if let Some(row) = rows.next()? {
-            let id: String = row.get(0)?;
-            let tags_json: String = row.get(1)?;
-            artifacts.push(LinkedArtifact {
-                id,
-                tags: parse_tags_json(&tags_json),
-            });
-        }
+    let mut artifacts = civic_core::db::load_linked_artifacts(
+        conn,
+        &artifact_ids,
+        &rubric.config.source_doc_types,
+    )?;
+    for artifact in &mut artifacts {
+        artifact.tags = rubric.tag_aliases.canonicalize(&artifact.tags);
     }
     Ok(artifacts)
 }
 
-fn load_motions_for_meeting(conn: &rusqlite::Connection, meeting_id: &str) -> Result<Vec<MotionRow>> {
-    let order_by = if motions_has_index(conn)? {
-        "ORDER BY motion_index ASC, id ASC"
-    } else {
+/// Drops artifacts first ingested within the last `quiet_hours` before `now`, so evidence that
+/// may still be missing its companion minutes is scored on a later run. Returns the dropped ids.
+fn defer_fresh_artifacts(
+    conn: &rusqlite::Connection,
+    artifacts: &mut Vec<LinkedArtifact>,
+    quiet_hours: u32,
+    now: OffsetDateTime,
+) -> Result<Vec<String>> {
+    let cutoff = (now - Duration::hours(i64::from(quiet_hours))).format(&Rfc3339)?;
+    let mut stmt = conn.prepare(
+        "SELECT 1 FROM artifacts WHERE id = ?1 AND datetime(inserted_at) > datetime(?2)",
+    )?;
+    let mut deferred = Vec::new();
+    let mut kept = Vec::with_capacity(artifacts.len());
+    for artifact in artifacts.drain(..) {
+        if stmt.exists(rusqlite::params![artifact.id, cutoff])? {
+            deferred.push(artifact.id);
+        } else {
+            kept.push(artifact);
+        }
+    }
+    *artifacts = kept;
+    Ok(deferred)
+}
+
+/// Marks artifacts whose source kind is not in `trusted_kinds` as untrusted, so scoring skips
+/// their tags. Kinds compare case-insensitively.
+fn distrust_source_kinds(artifacts: &mut [LinkedArtifact], trusted_kinds: &[String]) {
+    for artifact in artifacts {
+        artifact.trusted = trusted_kinds
+            .iter()
+            .any(|kind| kind.eq_ignore_ascii_case(&artifact.source_kind));
+    }
+}
+
+fn load_motions_for_meeting(conn: &rusqlite::Connection, meeting_id: &str) -> Result<Vec<MotionRow>> {
+    let order_by = if motions_has_index(conn)? {
+        "ORDER BY motion_index ASC, id ASC"
+    } else {
         "ORDER BY id ASC"
     };
     let mut stmt = conn.prepare(&format!(
         r#"
-        SELECT id, text
+        SELECT id, text, amended, amendment_text, reconsiders_motion_id, agenda_ref
         FROM motions
         WHERE meeting_id = ?1
         {order_by}
@@ -1676,6 +5287,10 @@ fn load_motions_for_meeting(conn: &rusqlite::Connection, meeting_id: &str) -> Re
         Ok(MotionRow {
             id: row.get(0)?,
             text: row.get(1)?,
+            amended: row.get(2)?,
+            amendment_text: row.get(3)?,
+            reconsiders_motion_id: row.get(4)?,
+            agenda_ref: row.get(5)?,
         })
     })?;
     let mut motions = Vec::new();
@@ -1699,7 +5314,8 @@ fn motions_has_index(conn: &rusqlite::Connection) -> Result<bool> {
 fn load_votes_for_meeting(conn: &rusqlite::Connection, meeting_id: &str) -> Result<Vec<VoteRow>> {
     let mut stmt = conn.prepare(
         r#"
-        SELECT votes.id, votes.motion_id, votes.ayes_json, votes.nays_json, votes.abstain_json
+        SELECT votes.id, votes.motion_id, votes.ayes_json, votes.nays_json, votes.abstain_json,
+               votes.vote_type, votes.outcome
         FROM votes
         JOIN motions ON votes.motion_id = motions.id
         WHERE motions.meeting_id = ?1
@@ -1716,10 +5332,16 @@ fn load_votes_for_meeting(conn: &rusqlite::Connection, meeting_id: &str) -> Resu
         Ok(VoteRow {
             id: row.get(0)?,
             motion_id: row.get(1)?,
+            vote_type: row.get(5)?,
+            outcome: row.get(6)?,
             ayes: ayes.clone(),
             nays: nays.clone(),
             abstain: abstain.clone(),
             choices: build_vote_choices(&ayes, &nays, &abstain),
+            tally: VoteTally {
+                ayes: ayes.len(),
+                nays: nays.len(),
+            },
         })
     })?;
     let mut votes = Vec::new();
@@ -1760,13 +5382,13 @@ fn slugify(value: &str) -> String {
 fn detect_drift(
     conn: &rusqlite::Connection,
     rubric: &Rubric,
-    window_start: &str,
-    window_end: &str,
+    window: &WindowSpec,
     computed_at: &str,
 ) -> Result<DriftDetectionResult> {
-    let current_scores = load_vote_scores(conn, window_start, window_end)?;
+    let (window_start, window_end) = window.bounds()?;
+    let current_scores = load_vote_scores(conn, &window_start, &window_end)?;
     let mut updated_scores = Vec::new();
-    let mut drift_flags = Vec::new();
+    let mut resolved = 0;
 
     for (official, axis_scores) in current_scores {
         for (axis, current_avg) in axis_scores {
@@ -1774,7 +5396,7 @@ fn detect_drift(
                 conn,
                 &official,
                 &axis,
-                window_start,
+                &window_start,
                 rubric.bias_controls.drift_window,
             )?;
             if prior_scores.len() < rubric.bias_controls.drift_window {
@@ -1782,9 +5404,11 @@ fn detect_drift(
             }
             let prior_avg = average(&prior_scores);
             let deviation = current_avg - prior_avg;
-            if deviation.abs() >= rubric.bias_controls.drift_threshold {
+            if deviation.abs() < rubric.bias_controls.drift_threshold {
+                resolved +=
+                    civic_core::db::resolve_official_drift(conn, &official, &axis, computed_at)?;
+            } else {
                 let flag = format!("drift_detected:{axis}");
-                drift_flags.push(format!("{official}:{flag}"));
                 let drift_id = format!("drift:{}:{}:{}", slugify(&official), axis, window_end);
                 civic_core::db::upsert_official_drift(
                     conn,
@@ -1794,10 +5418,15 @@ fn detect_drift(
                     prior_avg,
                     current_avg,
                     deviation,
-                    &[flag.clone()],
+                    std::slice::from_ref(&flag),
                     computed_at,
                 )?;
-                let scores = load_scores_for_official_in_window(conn, &official, window_start, window_end)?;
+                let scores = load_scores_for_official_in_window(
+                    conn,
+                    &official,
+                    &window_start,
+                    &window_end,
+                )?;
                 for mut score in scores {
                     if !score.flags.contains(&flag) {
                         score.flags.push(flag.clone());
@@ -1810,10 +5439,12 @@ fn detect_drift(
 
     Ok(DriftDetectionResult {
         updated_scores,
-        drift_flags,
+        resolved,
     })
 }
 
+/// Per-official axis averages over the window's vote scores, read from the score history (the
+/// latest computation of each score inside the window) rather than the mutable current rows.
 fn load_vote_scores(
     conn: &rusqlite::Connection,
     window_start: &str,
@@ -1821,11 +5452,18 @@ fn load_vote_scores(
 ) -> Result<HashMap<String, HashMap<String, f64>>> {
     let mut stmt = conn.prepare(
         r#"
-        SELECT decision_scores.axis_json, decision_scores.evidence_json
-        FROM decision_scores
-        WHERE vote_id IS NOT NULL
-          AND datetime(computed_at) >= datetime(?1)
-          AND datetime(computed_at) <= datetime(?2)
+        SELECT history.axis_json, history.evidence_json
+        FROM decision_score_history AS history
+        WHERE history.vote_id IS NOT NULL
+          AND datetime(history.computed_at) >= datetime(?1)
+          AND datetime(history.computed_at) <= datetime(?2)
+          AND history.computed_at = (
+            SELECT MAX(latest.computed_at)
+            FROM decision_score_history AS latest
+            WHERE latest.score_id = history.score_id
+              AND datetime(latest.computed_at) >= datetime(?1)
+              AND datetime(latest.computed_at) <= datetime(?2)
+          )
         "#,
     )?;
     let rows = stmt.query_map([window_start, window_end], |row| {
@@ -1859,6 +5497,9 @@ fn load_vote_scores(
     Ok(averages)
 }
 
+/// The official's most recent axis scores computed before `window_start`, at most one per score
+/// id. History rows keep their original `computed_at`, so rescoring a later, overlapping window
+/// does not pull earlier weeks out of the baseline.
 fn load_prior_vote_scores(
     conn: &rusqlite::Connection,
     official: &str,
@@ -1868,11 +5509,17 @@ fn load_prior_vote_scores(
 ) -> Result<Vec<f64>> {
     let mut stmt = conn.prepare(
         r#"
-        SELECT axis_json, evidence_json
-        FROM decision_scores
-        WHERE vote_id IS NOT NULL
-          AND datetime(computed_at) < datetime(?1)
-        ORDER BY computed_at DESC
+        SELECT history.axis_json, history.evidence_json
+        FROM decision_score_history AS history
+        WHERE history.vote_id IS NOT NULL
+          AND datetime(history.computed_at) < datetime(?1)
+          AND history.computed_at = (
+            SELECT MAX(latest.computed_at)
+            FROM decision_score_history AS latest
+            WHERE latest.score_id = history.score_id
+              AND datetime(latest.computed_at) < datetime(?1)
+          )
+        ORDER BY history.computed_at DESC
         "#,
     )?;
     let rows = stmt.query_map([window_start], |row| {
@@ -1908,7 +5555,8 @@ fn load_scores_for_official_in_window(
     let mut stmt = conn.prepare(
         r#"
         SELECT id, meeting_id, motion_id, vote_id, overall_score, axis_json, refs_json,
-               evidence_json, confidence, flags_json, computed_at
+               evidence_json, confidence, flags_json, computed_at, rubric_version,
+               contributions_json, weights_json
         FROM decision_scores
         WHERE vote_id IS NOT NULL
           AND datetime(computed_at) >= datetime(?1)
@@ -1922,9 +5570,15 @@ fn load_scores_for_official_in_window(
         let flags_json: String = row.get(9)?;
         let axis_scores: HashMap<String, f64> =
             serde_json::from_str(&axis_json).unwrap_or_default();
-        let refs: Vec<String> = serde_json::from_str(&refs_json).unwrap_or_default();
+        let refs: ConstitutionRefs = serde_json::from_str(&refs_json).unwrap_or_default();
         let evidence: Vec<String> = serde_json::from_str(&evidence_json).unwrap_or_default();
         let flags: Vec<String> = serde_json::from_str(&flags_json).unwrap_or_default();
+        let contributions_json: String = row.get(12)?;
+        let contributions: HashMap<String, f64> =
+            serde_json::from_str(&contributions_json).unwrap_or_default();
+        let weights_json: String = row.get(13)?;
+        let axis_weights: HashMap<String, f64> =
+            serde_json::from_str(&weights_json).unwrap_or_default();
         Ok(DecisionScore {
             id: row.get(0)?,
             meeting_id: row.get(1)?,
@@ -1932,11 +5586,14 @@ fn load_scores_for_official_in_window(
             vote_id: row.get(3)?,
             overall_score: row.get(4)?,
             axis_scores,
+            contributions,
+            axis_weights,
             constitutional_refs: refs,
             evidence,
             confidence: row.get(8)?,
             flags,
             computed_at: row.get(10)?,
+            rubric_version: row.get(11)?,
         })
     })?;
     let mut results = Vec::new();
@@ -1956,6 +5613,173 @@ fn extract_official(evidence: &[String]) -> Option<String> {
     })
 }
 
+/// Evidence on the presiding officer's vote score when that vote broke a tie.
+const TIE_BREAKER_TAG: &str = "tie_breaker";
+
+/// The presiding officer's deciding vote on a tie among the other members: their recorded aye
+/// or nay when everyone else split evenly, or, when the minutes list only the tied roll, the
+/// side the vote's outcome implies.
+fn tie_breaker(vote: &VoteRow, presiding_officer: Option<&str>) -> Option<(String, VoteChoice)> {
+    let officer = presiding_officer.map(str::trim).filter(|name| !name.is_empty())?;
+    let (mut ayes, mut nays) = (vote.tally.ayes, vote.tally.nays);
+    let recorded = vote.choices.iter().find(|(name, _)| name == officer);
+    let choice = match recorded.map(|(_, choice)| *choice) {
+        Some(VoteChoice::Aye) => {
+            ayes -= 1;
+            VoteChoice::Aye
+        }
+        Some(VoteChoice::Nay) => {
+            nays -= 1;
+            VoteChoice::Nay
+        }
+        Some(_) => return None,
+        None => outcome_choice(vote.outcome.as_deref()?)?,
+    };
+    (ayes == nays && ayes > 0).then(|| (officer.to_string(), choice))
+}
+
+/// Evidence prefix on a motion's scores when a later motion reconsiders it, followed by the
+/// reconsidering motion's id.
+const RECONSIDERED_BY_PREFIX: &str = "reconsidered_by:";
+
+/// Evidence tag on a motion scored on its agenda item's description because the minutes
+/// recorded no motion text.
+const RESOLVED_FROM_AGENDA_TAG: &str = "resolved_from_agenda";
+
+/// The description of agenda item `agenda_ref` from the linked artifacts' text, checking
+/// artifacts tagged `agenda` first: the rest of the first line that starts with the number
+/// (optionally after "Item"), e.g. "4.2. Award the paving bid" for ref "4.2". "4.21" does not
+/// match "4.2".
+fn agenda_item_text(artifacts: &[LinkedArtifact], agenda_ref: &str) -> Option<String> {
+    let agenda_ref = agenda_ref.trim().trim_end_matches('.');
+    if agenda_ref.is_empty() {
+        return None;
+    }
+    let mut ordered: Vec<&LinkedArtifact> = artifacts.iter().collect();
+    ordered.sort_by_key(|artifact| !artifact.tags.iter().any(|tag| tag == "agenda"));
+    ordered.into_iter().find_map(|artifact| {
+        artifact.body_text.as_deref()?.lines().find_map(|line| {
+            let line = line.trim_start();
+            let line = line
+                .get(..5)
+                .filter(|prefix| prefix.eq_ignore_ascii_case("item "))
+                .map_or(line, |_| line[5..].trim_start());
+            let rest = line.strip_prefix(agenda_ref)?;
+            let continues_number = rest.starts_with(|ch: char| ch.is_ascii_alphanumeric())
+                || rest
+                    .strip_prefix('.')
+                    .is_some_and(|rest| rest.starts_with(|ch: char| ch.is_ascii_digit()));
+            if continues_number {
+                return None;
+            }
+            let description = rest
+                .trim_start_matches(|ch: char| ch.is_whitespace() || ".):-–—".contains(ch))
+                .trim();
+            (!description.is_empty()).then(|| description.to_string())
+        })
+    })
+}
+
+/// Phrases used when `scoring.detect_reconsiderations` is on without
+/// `scoring.reconsideration_patterns`.
+const DEFAULT_RECONSIDERATION_PATTERNS: [&str; 2] = ["reconsider", "rescind"];
+
+/// The agenda item (1-based position among the meeting's motions) a reconsideration motion
+/// names: the first `item <n>` after any of `patterns` in `text`, compared case-insensitively.
+fn reconsidered_item(text: &str, patterns: &[String]) -> Option<usize> {
+    let text = text.to_lowercase();
+    let start = patterns
+        .iter()
+        .filter(|pattern| !pattern.trim().is_empty())
+        .filter_map(|pattern| text.find(&pattern.trim().to_lowercase()))
+        .min()?;
+    let mut words = text[start..].split_whitespace();
+    while let Some(word) = words.next() {
+        if word == "item" {
+            let number = words.find(|word| !matches!(*word, "no." | "no" | "number" | "#"))?;
+            let digits: String = number
+                .trim_start_matches('#')
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            return digits.parse().ok().filter(|item| *item > 0);
+        }
+    }
+    None
+}
+
+/// Re-derives each motion's `reconsiders_motion_id` from its text and stores any change. Only
+/// earlier motions of the same meeting can be reconsidered; with no patterns every link is
+/// cleared. Returns the number of linked motions.
+fn link_reconsiderations(
+    conn: &rusqlite::Connection,
+    motions: &mut [MotionRow],
+    patterns: &[String],
+) -> Result<usize> {
+    let mut linked = 0;
+    for position in 0..motions.len() {
+        let target = reconsidered_item(&motions[position].text, patterns)
+            .filter(|item| *item <= position)
+            .map(|item| motions[item - 1].id.clone());
+        let motion = &mut motions[position];
+        if motion.reconsiders_motion_id != target {
+            civic_core::db::set_motion_reconsiders(conn, &motion.id, target.as_deref())?;
+            motion.reconsiders_motion_id = target;
+        }
+        linked += usize::from(motion.reconsiders_motion_id.is_some());
+    }
+    Ok(linked)
+}
+
+/// Whether a vote score belongs to a reconsidered motion and so gives way to the
+/// reconsideration's votes under `scoring.reconsideration_supersedes`.
+fn superseded_by_reconsideration(evidence: &[String], scoring: &ResolvedScoring) -> bool {
+    scoring.reconsideration_supersedes
+        && evidence.iter().any(|item| item.starts_with(RECONSIDERED_BY_PREFIX))
+}
+
+/// The side a recorded vote outcome came down on, if it names one.
+fn outcome_choice(outcome: &str) -> Option<VoteChoice> {
+    match outcome.trim().to_ascii_lowercase().as_str() {
+        "passed" | "pass" | "carried" | "approved" | "adopted" => Some(VoteChoice::Aye),
+        "failed" | "fail" | "defeated" | "rejected" => Some(VoteChoice::Nay),
+        _ => None,
+    }
+}
+
+/// Evidence entry noting a non-default vote-type weight, e.g. `vote_type_weight:procedural:0.3`.
+/// Display only: grades read the weight from the vote's stored `vote_type` and the current rubric.
+fn vote_type_weight_evidence(vote_type: Option<&str>, weight: f64) -> Option<String> {
+    let vote_type = vote_type?;
+    ((weight - 1.0).abs() > f64::EPSILON).then(|| format!("vote_type_weight:{vote_type}:{weight}"))
+}
+
+/// Weight of a vote score in an official's grade: committee votes count at
+/// `scoring.committee_vote_weight`, times the rubric's weight for the vote's `vote_type` (full
+/// weight without a rubric), times `scoring.tie_breaker_weight` for a presiding officer's
+/// tie-breaking vote.
+fn aggregation_weight(
+    committee: bool,
+    vote_type: Option<&str>,
+    evidence: &[String],
+    rubric: Option<&Rubric>,
+    scoring: &ResolvedScoring,
+) -> f64 {
+    let committee_weight = if committee {
+        scoring.committee_vote_weight
+    } else {
+        1.0
+    };
+    let vote_type_weight =
+        rubric.map_or(1.0, |rubric| rubric.scoring_rules.vote_type_weight(vote_type));
+    let tie_breaker_weight = if evidence.iter().any(|item| item == TIE_BREAKER_TAG) {
+        scoring.tie_breaker_weight
+    } else {
+        1.0
+    };
+    committee_weight * vote_type_weight * tie_breaker_weight
+}
+
 fn average(values: &[f64]) -> f64 {
     if values.is_empty() {
         return 0.0;
@@ -1963,14 +5787,29 @@ fn average(values: &[f64]) -> f64 {
     values.iter().sum::<f64>() / values.len() as f64
 }
 
+fn weighted_average(values: &[f64], weights: &[f64]) -> f64 {
+    let total_weight: f64 = weights.iter().sum();
+    if values.is_empty() || total_weight <= 0.0 {
+        return 0.0;
+    }
+    values
+        .iter()
+        .zip(weights)
+        .map(|(value, weight)| value * weight)
+        .sum::<f64>()
+        / total_weight
+}
+
 fn load_decisions(
     conn: &rusqlite::Connection,
-    window_start: &str,
-    window_end: &str,
+    window: &WindowSpec,
+    rubric: Option<&Rubric>,
 ) -> Result<Vec<ReportDecisionMeeting>> {
+    let (window_start, window_end) = window.bounds()?;
     let mut stmt = conn.prepare(
         r#"
-        SELECT meetings.id, meetings.body_id, meetings.started_at, bodies.name
+        SELECT meetings.id, meetings.body_id, meetings.started_at, bodies.name,
+               meetings.presiding_officer
         FROM meetings
         JOIN bodies ON meetings.body_id = bodies.id
         WHERE datetime(meetings.started_at) >= datetime(?1)
@@ -1979,22 +5818,25 @@ fn load_decisions(
         "#,
     )?;
 
-    let meetings = stmt.query_map([window_start, window_end], |row| {
-        Ok(ReportDecisionMeeting {
+    let meetings = stmt.query_map([window_start.as_str(), window_end.as_str()], |row| {
+        let meeting = ReportDecisionMeeting {
             id: row.get(0)?,
             body_id: row.get(1)?,
             started_at: row.get(2)?,
             body_name: row.get(3)?,
             motions: Vec::new(),
-        })
+            evidence_coverage: None,
+        };
+        Ok((meeting, row.get::<_, Option<String>>(4)?))
     })?;
 
     let mut results = Vec::new();
     for meeting in meetings {
-        let mut meeting = meeting?;
+        let (mut meeting, presiding_officer) = meeting?;
+        let votes = load_votes_for_meeting(conn, &meeting.id)?;
         let mut motion_stmt = conn.prepare(
             r#"
-            SELECT id, COALESCE(text, '') as text, result
+            SELECT id, COALESCE(text, '') as text, result, amended, amendment_text
             FROM motions
             WHERE meeting_id = ?1
             ORDER BY motion_index ASC, id ASC
@@ -2005,19 +5847,179 @@ fn load_decisions(
                 id: row.get(0)?,
                 text: row.get(1)?,
                 result: row.get(2)?,
+                amended: row.get(3)?,
+                amendment_text: row.get(4)?,
+                vote_label: None,
+                tie_broken_by: None,
+                public_comments: Vec::new(),
+                votes: Vec::new(),
             })
         })?;
         meeting.motions = motions.filter_map(|row| row.ok()).collect();
+        for motion in &mut meeting.motions {
+            motion.vote_label =
+                load_motion_tally(conn, &motion.id)?.and_then(|tally| tally.label());
+            motion.public_comments = load_public_comments(conn, &motion.id)?;
+            motion.tie_broken_by = votes
+                .iter()
+                .filter(|vote| vote.motion_id == motion.id)
+                .find_map(|vote| tie_breaker(vote, presiding_officer.as_deref()))
+                .map(|(officer, _)| officer);
+        }
+        for vote in votes {
+            if let Some(motion) =
+                meeting.motions.iter_mut().find(|motion| motion.id == vote.motion_id)
+            {
+                motion.votes.push(vote);
+            }
+        }
+        if let Some(rubric) = rubric {
+            meeting.evidence_coverage =
+                civic_core::db::meeting_evidence_coverage(conn, &meeting.id, rubric)?;
+        }
         results.push(meeting);
     }
     Ok(results)
 }
 
-fn load_score_summary(
+/// The report JSON's `decisions` array. Each motion's recorded votes (name lists and tally) are
+/// only embedded with `include_votes`, since they dominate the payload for long meetings.
+fn report_decisions_json(
+    decisions: &[ReportDecisionMeeting],
+    include_votes: bool,
+) -> Vec<serde_json::Value> {
+    decisions
+        .iter()
+        .map(|meeting| {
+            let motions = meeting
+                .motions
+                .iter()
+                .map(|motion| {
+                    let mut value = serde_json::json!({
+                        "id": motion.id,
+                        "text": motion.text,
+                        "result": motion.result,
+                        "amended": motion.amended,
+                        "amendment_text": motion.amendment_text,
+                        "vote_label": motion.vote_label,
+                        "tie_broken_by": motion.tie_broken_by,
+                        "public_comments": motion.public_comments,
+                    });
+                    if include_votes {
+                        value["votes"] = motion.votes.iter().map(vote_json).collect();
+                    }
+                    value
+                })
+                .collect::<Vec<_>>();
+            serde_json::json!({
+                "meeting_id": meeting.id,
+                "body_id": meeting.body_id,
+                "body_name": meeting.body_name,
+                "started_at": meeting.started_at,
+                "evidence_coverage": meeting.evidence_coverage.map(|coverage| {
+                    serde_json::json!({"covered": coverage.covered, "total": coverage.total})
+                }),
+                "motions": motions,
+            })
+        })
+        .collect()
+}
+
+fn vote_json(vote: &VoteRow) -> serde_json::Value {
+    serde_json::json!({
+        "id": vote.id,
+        "vote_type": vote.vote_type,
+        "outcome": vote.outcome,
+        "ayes": vote.ayes,
+        "nays": vote.nays,
+        "abstain": vote.abstain,
+        "tally": {
+            "ayes": vote.tally.ayes,
+            "nays": vote.tally.nays,
+            "abstain": vote.abstain.len(),
+        },
+    })
+}
+
+fn load_public_comments(
+    conn: &rusqlite::Connection,
+    motion_id: &str,
+) -> Result<Vec<PublicComment>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT id, motion_id, speaker, stance, summary
+        FROM public_comments
+        WHERE motion_id = ?1
+        ORDER BY id ASC
+        "#,
+    )?;
+    let comments = stmt
+        .query_map([motion_id], |row| {
+            Ok(PublicComment {
+                id: row.get(0)?,
+                motion_id: row.get(1)?,
+                speaker: row.get(2)?,
+                stance: row.get(3)?,
+                summary: row.get(4)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(comments)
+}
+
+/// Distinct rubric versions recorded on scores for meetings in the window.
+fn load_rubric_versions(
     conn: &rusqlite::Connection,
     window_start: &str,
     window_end: &str,
+) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT DISTINCT decision_scores.rubric_version
+        FROM decision_scores
+        JOIN meetings ON decision_scores.meeting_id = meetings.id
+        WHERE decision_scores.rubric_version IS NOT NULL
+          AND datetime(meetings.started_at) >= datetime(?1)
+          AND datetime(meetings.started_at) <= datetime(?2)
+        ORDER BY decision_scores.rubric_version
+        "#,
+    )?;
+    let versions = stmt
+        .query_map([window_start, window_end], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+    Ok(versions)
+}
+
+/// Tally of the first recorded vote on a motion, if any.
+fn load_motion_tally(conn: &rusqlite::Connection, motion_id: &str) -> Result<Option<VoteTally>> {
+    let mut stmt = conn.prepare(
+        "SELECT ayes_json, nays_json FROM votes WHERE motion_id = ?1 ORDER BY id ASC LIMIT 1",
+    )?;
+    let mut rows = stmt.query([motion_id])?;
+    let Some(row) = rows.next()? else {
+        return Ok(None);
+    };
+    let ayes_json: String = row.get(0)?;
+    let nays_json: String = row.get(1)?;
+    Ok(Some(tally_from_json(&ayes_json, &nays_json)))
+}
+
+fn tally_from_json(ayes_json: &str, nays_json: &str) -> VoteTally {
+    let ayes: Vec<String> = serde_json::from_str(ayes_json).unwrap_or_default();
+    let nays: Vec<String> = serde_json::from_str(nays_json).unwrap_or_default();
+    VoteTally {
+        ayes: ayes.len(),
+        nays: nays.len(),
+    }
+}
+
+fn load_score_summary(
+    conn: &rusqlite::Connection,
+    window: &WindowSpec,
+    top_n: usize,
+    exclude_insufficient: bool,
 ) -> Result<ScoreSummary> {
+    let (window_start, window_end) = window.bounds()?;
     let mut stmt = conn.prepare(
         r#"
         SELECT decision_scores.overall_score, decision_scores.flags_json, COALESCE(motions.text, '')
@@ -2029,33 +6031,42 @@ fn load_score_summary(
           AND datetime(meetings.started_at) <= datetime(?2)
         "#,
     )?;
-    let rows = stmt.query_map([window_start, window_end], |row| {
+    let rows = stmt.query_map([window_start.as_str(), window_end.as_str()], |row| {
         let flags_json: String = row.get(1)?;
         let flags: Vec<String> = serde_json::from_str(&flags_json).unwrap_or_default();
         Ok((row.get::<_, f64>(0)?, flags, row.get::<_, String>(2)?))
     })?;
 
     let mut scores = Vec::new();
+    let mut averaged = Vec::new();
     let mut insufficient_count = 0usize;
+    let mut no_source_count = 0usize;
     for row in rows {
         let (score, flags, text) = row?;
-        if flags.iter().any(|flag| flag == "insufficient_evidence") {
+        let insufficient = flags.iter().any(|flag| flag == "insufficient_evidence");
+        if insufficient {
             insufficient_count += 1;
         }
+        if !(exclude_insufficient && insufficient) {
+            averaged.push(score);
+        }
+        if flags.iter().any(|flag| flag == "no_source_artifacts") {
+            no_source_count += 1;
+        }
         scores.push((score, text));
     }
 
     let total_scored = scores.len();
-    let average_score = if total_scored == 0 {
+    let average_score = if averaged.is_empty() {
         0.0
     } else {
-        scores.iter().map(|(score, _)| score).sum::<f64>() / total_scored as f64
+        averaged.iter().sum::<f64>() / averaged.len() as f64
     };
 
     scores.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
     let top_negative = scores
         .iter()
-        .take(3)
+        .take(top_n)
         .map(|(score, text)| ScoreDecisionEntry {
             text: text.clone(),
             overall_score: *score,
@@ -2064,63 +6075,194 @@ fn load_score_summary(
     let top_positive = scores
         .iter()
         .rev()
-        .take(3)
+        .take(top_n)
         .map(|(score, text)| ScoreDecisionEntry {
             text: text.clone(),
             overall_score: *score,
         })
         .collect::<Vec<_>>();
 
-    let drift_flags = load_drift_flags(conn, window_start, window_end)?;
+    let drift_flags = load_drift_flags(conn, &window_end)?;
 
     Ok(ScoreSummary {
         average_score,
+        average_excludes_insufficient: exclude_insufficient,
         total_scored,
         insufficient_count,
+        no_source_count,
         top_positive,
         top_negative,
         drift_flags,
     })
 }
 
-fn load_drift_flags(
-    conn: &rusqlite::Connection,
-    window_start: &str,
-    window_end: &str,
-) -> Result<Vec<String>> {
+/// Drift still active at `window_end`: detected by then and not yet resolved, so drift carries
+/// over from earlier windows until `detect_drift` resolves it. One flag per official and axis,
+/// from the latest detection.
+fn load_drift_flags(conn: &rusqlite::Connection, window_end: &str) -> Result<Vec<String>> {
     let mut stmt = conn.prepare(
         r#"
         SELECT official_name, axis, deviation
         FROM official_drift
-        WHERE datetime(computed_at) >= datetime(?1)
-          AND datetime(computed_at) <= datetime(?2)
+        WHERE datetime(computed_at) <= datetime(?1)
+          AND (resolved_at IS NULL OR datetime(resolved_at) > datetime(?1))
         ORDER BY computed_at DESC
         "#,
     )?;
-    let rows = stmt.query_map([window_start, window_end], |row| {
+    let rows = stmt.query_map([window_end], |row| {
         Ok((
             row.get::<_, String>(0)?,
             row.get::<_, String>(1)?,
             row.get::<_, f64>(2)?,
         ))
     })?;
+    let mut seen = HashSet::new();
     let mut flags = Vec::new();
     for row in rows {
         let (official, axis, deviation) = row?;
-        flags.push(format!("{official}: drift_detected:{axis} ({deviation:.2})"));
+        if seen.insert((official.clone(), axis.clone())) {
+            flags.push(format!("{official}: drift_detected:{axis} ({deviation:.2})"));
+        }
     }
     Ok(flags)
 }
 
+fn load_official_drift_detail(
+    conn: &rusqlite::Connection,
+    official: &str,
+    window_start: &str,
+    window_end: &str,
+) -> Result<Vec<DriftDetail>> {
+    // Drift active at any point in the window: detected by its end and not resolved before
+    // its start. Rows resolved inside the window are shown as resolved.
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT axis, prior_average, current_average, deviation,
+               resolved_at IS NOT NULL AND datetime(resolved_at) <= datetime(?3)
+        FROM official_drift
+        WHERE official_name = ?1
+          AND datetime(computed_at) <= datetime(?3)
+          AND (resolved_at IS NULL OR datetime(resolved_at) >= datetime(?2))
+        ORDER BY axis ASC, computed_at DESC
+        "#,
+    )?;
+    let rows = stmt.query_map([official, window_start, window_end], |row| {
+        Ok(DriftDetail {
+            axis: row.get(0)?,
+            prior_average: row.get(1)?,
+            current_average: row.get(2)?,
+            deviation: row.get(3)?,
+            resolved: row.get(4)?,
+        })
+    })?;
+    let mut details: Vec<DriftDetail> = Vec::new();
+    for row in rows {
+        let detail = row?;
+        // Keep only the most recent computation per axis.
+        if details.iter().any(|existing| existing.axis == detail.axis) {
+            continue;
+        }
+        details.push(detail);
+    }
+    Ok(details)
+}
+
 fn resolve_site_config(config: Option<&SiteConfig>) -> SiteConfig {
     SiteConfig {
         enable_commentary: Some(config.and_then(|value| value.enable_commentary).unwrap_or(true)),
         commentary_style: config
             .and_then(|value| value.commentary_style.clone())
             .or(Some("satire".to_string())),
+        disclaimer: config
+            .and_then(|value| value.disclaimer.clone())
+            .or(Some(DEFAULT_DISCLAIMER.to_string())),
+        template_dir: config.and_then(|value| value.template_dir.clone()),
+        display_decimals: Some(
+            config
+                .and_then(|value| value.display_decimals)
+                .unwrap_or(DEFAULT_DISPLAY_DECIMALS),
+        ),
+        commentary_seed_override: config.and_then(|value| value.commentary_seed_override),
+        commentary_sentences: Some(
+            config
+                .and_then(|value| value.commentary_sentences)
+                .unwrap_or(1)
+                .clamp(1, MAX_COMMENTARY_SENTENCES),
+        ),
+        artifacts_index_limit: Some(
+            config
+                .and_then(|value| value.artifacts_index_limit)
+                .unwrap_or(DEFAULT_ARTIFACTS_INDEX_LIMIT)
+                .max(1),
+        ),
+        trend_hysteresis: Some(
+            config
+                .and_then(|value| value.trend_hysteresis)
+                .unwrap_or(0.0)
+                .max(0.0),
+        ),
+        sign: Some(config.and_then(|value| value.sign).unwrap_or(false)),
+    }
+}
+
+const DEFAULT_DISPLAY_DECIMALS: usize = 1;
+
+const MAX_COMMENTARY_SENTENCES: usize = 3;
+
+const DEFAULT_ARTIFACTS_INDEX_LIMIT: usize = 500;
+
+/// Week-over-week grade delta at which an official is badged Rising or Falling.
+const TREND_THRESHOLD: f64 = 5.0;
+
+/// An official's grade trend badge on the site; also an issue's trend in `top-issues`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Trend {
+    #[default]
+    Steady,
+    Rising,
+    Falling,
+}
+
+/// This week's trend given last week's. Entering Rising or Falling takes a delta beyond
+/// `TREND_THRESHOLD + hysteresis`; staying in it only takes `TREND_THRESHOLD - hysteresis`, so
+/// deltas hovering around the threshold do not flip the badge every week.
+fn next_trend(previous: Trend, delta: f64, hysteresis: f64) -> Trend {
+    let enter = TREND_THRESHOLD + hysteresis;
+    let stay = TREND_THRESHOLD - hysteresis;
+    match previous {
+        Trend::Rising if delta >= stay => Trend::Rising,
+        Trend::Falling if delta <= -stay => Trend::Falling,
+        _ if delta >= enter => Trend::Rising,
+        _ if delta <= -enter => Trend::Falling,
+        _ => Trend::Steady,
+    }
+}
+
+/// Each official's trend after replaying `next_trend` over consecutive weekly averages (oldest
+/// first). An official missing from either week of a pair drops back to Steady.
+fn carry_trends(
+    weekly_averages: &[HashMap<String, f64>],
+    hysteresis: f64,
+) -> HashMap<String, Trend> {
+    let mut trends: HashMap<String, Trend> = HashMap::new();
+    for pair in weekly_averages.windows(2) {
+        let (previous, current) = (&pair[0], &pair[1]);
+        trends = current
+            .iter()
+            .filter_map(|(name, average)| {
+                let prior = previous.get(name)?;
+                let trend = trends.get(name).copied().unwrap_or_default();
+                Some((name.clone(), next_trend(trend, average - prior, hysteresis)))
+            })
+            .collect();
     }
+    trends
 }
 
+const DEFAULT_DISCLAIMER: &str =
+    "Rubric-based scoring; commentary is opinion/satire. Always consult primary sources.";
+
 fn load_week_reports(out_dir: &Path) -> Result<Vec<WeekReport>> {
     let reports_dir = out_dir.join("reports").join("weekly");
     if !reports_dir.exists() {
@@ -2174,8 +6316,8 @@ fn load_week_reports(out_dir: &Path) -> Result<Vec<WeekReport>> {
             .map(|items| {
                 items
                     .iter()
-                    .filter_map(|item| {
-                        Some(WeekArtifact {
+                    .map(|item| {
+                        WeekArtifact {
                             title: item
                                 .get("title")
                                 .and_then(|value| value.as_str())
@@ -2186,7 +6328,7 @@ fn load_week_reports(out_dir: &Path) -> Result<Vec<WeekReport>> {
                                 .and_then(|value| value.as_str())
                                 .unwrap_or("")
                                 .to_string(),
-                        })
+                        }
                     })
                     .collect::<Vec<_>>()
             })
@@ -2230,19 +6372,14 @@ fn parse_week_decisions(value: &serde_json::Value) -> Vec<WeekDecision> {
                 .and_then(|value| value.as_str())
                 .unwrap_or("Unknown Body")
                 .to_string();
-            let started_at = decision
-                .get("started_at")
-                .and_then(|value| value.as_str())
-                .unwrap_or("")
-                .to_string();
             let motions = decision
                 .get("motions")
                 .and_then(|value| value.as_array())
                 .map(|items| {
                     items
                         .iter()
-                        .filter_map(|item| {
-                            Some(WeekMotion {
+                        .map(|item| {
+                            WeekMotion {
                                 text: item
                                     .get("text")
                                     .and_then(|value| value.as_str())
@@ -2252,15 +6389,38 @@ fn parse_week_decisions(value: &serde_json::Value) -> Vec<WeekDecision> {
                                     .get("result")
                                     .and_then(|value| value.as_str())
                                     .map(|value| value.to_string()),
-                            })
+                                amended: item
+                                    .get("amended")
+                                    .and_then(|value| value.as_bool())
+                                    .unwrap_or(false),
+                                vote_label: item
+                                    .get("vote_label")
+                                    .and_then(|value| value.as_str())
+                                    .map(|value| value.to_string()),
+                                tie_broken_by: item
+                                    .get("tie_broken_by")
+                                    .and_then(|value| value.as_str())
+                                    .map(|value| value.to_string()),
+                                public_comments: item
+                                    .get("public_comments")
+                                    .cloned()
+                                    .and_then(|value| serde_json::from_value(value).ok())
+                                    .unwrap_or_default(),
+                            }
                         })
                         .collect::<Vec<_>>()
                 })
                 .unwrap_or_default();
+            let evidence_coverage = decision.get("evidence_coverage").and_then(|value| {
+                Some(EvidenceCoverage {
+                    covered: value.get("covered")?.as_u64()? as usize,
+                    total: value.get("total")?.as_u64()? as usize,
+                })
+            });
             WeekDecision {
                 body_name,
-                started_at,
                 motions,
+                evidence_coverage,
             }
         })
         .collect()
@@ -2273,15 +6433,20 @@ fn load_official_summaries(
     rubric: Option<&Rubric>,
     report: Option<&WeekReport>,
     week_date: &str,
+    scoring: &ResolvedScoring,
 ) -> Result<Vec<OfficialSummary>> {
     let mut stmt = conn.prepare(
         r#"
         SELECT decision_scores.overall_score, decision_scores.axis_json,
                decision_scores.flags_json, decision_scores.evidence_json,
-               COALESCE(motions.text, ''), meetings.started_at, meetings.artifact_ids_json
+               COALESCE(motions.text, ''), meetings.started_at, meetings.artifact_ids_json,
+               meetings.parent_meeting_id, votes.ayes_json, votes.nays_json,
+               decision_scores.refs_json, decision_scores.contributions_json,
+               decision_scores.weights_json, votes.vote_type
         FROM decision_scores
         JOIN motions ON decision_scores.motion_id = motions.id
         JOIN meetings ON motions.meeting_id = meetings.id
+        LEFT JOIN votes ON decision_scores.vote_id = votes.id
         WHERE decision_scores.vote_id IS NOT NULL
           AND datetime(meetings.started_at) >= datetime(?1)
           AND datetime(meetings.started_at) <= datetime(?2)
@@ -2296,6 +6461,17 @@ fn load_official_summaries(
         let motion_text: String = row.get(4)?;
         let started_at: String = row.get(5)?;
         let artifact_ids_json: String = row.get(6)?;
+        let parent_meeting_id: Option<String> = row.get(7)?;
+        let ayes_json: Option<String> = row.get(8)?;
+        let nays_json: Option<String> = row.get(9)?;
+        let vote_label = match (ayes_json, nays_json) {
+            (Some(ayes_json), Some(nays_json)) => tally_from_json(&ayes_json, &nays_json).label(),
+            _ => None,
+        };
+        let refs_json: String = row.get(10)?;
+        let contributions_json: String = row.get(11)?;
+        let weights_json: String = row.get(12)?;
+        let vote_type: Option<String> = row.get(13)?;
         Ok((
             overall_score,
             axis_json,
@@ -2304,6 +6480,12 @@ fn load_official_summaries(
             motion_text,
             started_at,
             artifact_ids_json,
+            parent_meeting_id,
+            vote_label,
+            refs_json,
+            contributions_json,
+            weights_json,
+            vote_type,
         ))
     })?;
 
@@ -2317,11 +6499,27 @@ fn load_official_summaries(
             motion_text,
             started_at,
             artifact_ids_json,
+            parent_meeting_id,
+            vote_label,
+            refs_json,
+            contributions_json,
+            weights_json,
+            vote_type,
         ) = row?;
         let evidence: Vec<String> = serde_json::from_str(&evidence_json).unwrap_or_default();
         let Some(official) = extract_official(&evidence) else {
             continue;
         };
+        if superseded_by_reconsideration(&evidence, scoring) {
+            continue;
+        }
+        let weight = aggregation_weight(
+            parent_meeting_id.is_some(),
+            vote_type.as_deref(),
+            &evidence,
+            rubric,
+            scoring,
+        );
         let axis_scores: HashMap<String, f64> =
             serde_json::from_str(&axis_json).unwrap_or_default();
         let flags: Vec<String> = serde_json::from_str(&flags_json).unwrap_or_default();
@@ -2333,23 +6531,54 @@ fn load_official_summaries(
             .or_insert_with(|| OfficialSummaryBuilder::new(&official, report, week_date));
         entry.overall_scores.push(overall_score);
         entry.axis_scores.push(axis_scores);
-        entry.insufficient |= flags.iter().any(|flag| flag == "insufficient_evidence");
+        entry
+            .contributions
+            .push(serde_json::from_str(&contributions_json).unwrap_or_default());
+        entry
+            .axis_weights
+            .push(serde_json::from_str(&weights_json).unwrap_or_default());
+        let refs: ConstitutionRefs = serde_json::from_str(&refs_json).unwrap_or_default();
+        entry.add_constitution_refs(refs);
+        entry.weights.push(weight);
+        entry.insufficient |= flags
+            .iter()
+            .any(|flag| flag == "insufficient_evidence" || flag == "no_source_artifacts");
         entry.receipts.push(Receipt {
             meeting_date: started_at.clone(),
             motion_text: motion_text.clone(),
+            vote_label,
             artifact_ids,
             week_date: report
                 .map(|rep| rep.date.clone())
                 .unwrap_or_else(|| week_date.to_string()),
+            excerpts: evidence
+                .iter()
+                .filter_map(|entry| EvidenceExcerpt::from_evidence(entry))
+                .collect(),
         });
     }
 
-    let drift_flags = load_drift_flags(conn, window_start, window_end)?;
+    if let Some(penalty) = scoring.absence_grade_penalty {
+        for (official, missed) in load_absences(conn, window_start, window_end)? {
+            if let Some(builder) = data.get_mut(&official) {
+                builder.absence_scores = vec![-penalty; missed];
+            }
+        }
+    }
+
+    let drift_flags = load_drift_flags(conn, window_end)?;
     let rubric_config = rubric.map(|value| &value.config);
 
     let mut summaries = Vec::new();
     for (_, builder) in data {
-        summaries.push(builder.build(rubric_config, &drift_flags));
+        let mut summary = builder.build(rubric_config, &drift_flags);
+        summary.provisional = summary.vote_count < scoring.min_votes_for_grade;
+        if summary.provisional && scoring.insufficient_policy == InsufficientPolicy::Withhold {
+            summary.letter_grade = WITHHELD_GRADE.to_string();
+        }
+        summary.drift_details =
+            load_official_drift_detail(conn, &summary.name, window_start, window_end)?;
+        summaries.push(summary);
     }
     summaries.sort_by(|a, b| {
         b.average_score
@@ -2364,39 +6593,120 @@ fn load_official_averages(
     conn: &rusqlite::Connection,
     window_start: &str,
     window_end: &str,
+    rubric: Option<&Rubric>,
+    scoring: &ResolvedScoring,
 ) -> Result<HashMap<String, f64>> {
+    // Same joins as load_official_summaries, so scores whose motion is missing are excluded from
+    // both sides of the delta. The window still applies to when scores were computed.
     let mut stmt = conn.prepare(
         r#"
-        SELECT decision_scores.overall_score, decision_scores.evidence_json
+        SELECT decision_scores.overall_score, decision_scores.evidence_json,
+               meetings.parent_meeting_id, votes.vote_type
         FROM decision_scores
-        WHERE vote_id IS NOT NULL
-          AND datetime(computed_at) >= datetime(?1)
-          AND datetime(computed_at) <= datetime(?2)
+        JOIN motions ON decision_scores.motion_id = motions.id
+        JOIN meetings ON motions.meeting_id = meetings.id
+        LEFT JOIN votes ON decision_scores.vote_id = votes.id
+        WHERE decision_scores.vote_id IS NOT NULL
+          AND datetime(decision_scores.computed_at) >= datetime(?1)
+          AND datetime(decision_scores.computed_at) <= datetime(?2)
         "#,
     )?;
     let rows = stmt.query_map([window_start, window_end], |row| {
         let score: f64 = row.get(0)?;
         let evidence_json: String = row.get(1)?;
-        Ok((score, evidence_json))
+        let parent_meeting_id: Option<String> = row.get(2)?;
+        let vote_type: Option<String> = row.get(3)?;
+        Ok((score, evidence_json, parent_meeting_id, vote_type))
     })?;
-    let mut totals: HashMap<String, Vec<f64>> = HashMap::new();
+    let mut totals: HashMap<String, (Vec<f64>, Vec<f64>)> = HashMap::new();
     for row in rows {
-        let (score, evidence_json) = row?;
+        let (score, evidence_json, parent_meeting_id, vote_type) = row?;
         let evidence: Vec<String> = serde_json::from_str(&evidence_json).unwrap_or_default();
         let Some(official) = extract_official(&evidence) else { continue };
-        totals.entry(official).or_default().push(score);
+        if superseded_by_reconsideration(&evidence, scoring) {
+            continue;
+        }
+        let weight = aggregation_weight(
+            parent_meeting_id.is_some(),
+            vote_type.as_deref(),
+            &evidence,
+            rubric,
+            scoring,
+        );
+        let entry = totals.entry(official).or_default();
+        entry.0.push(score);
+        entry.1.push(weight);
     }
+    let absences = match scoring.absence_grade_penalty {
+        Some(_) => load_absences(conn, window_start, window_end)?,
+        None => HashMap::new(),
+    };
     let mut averages = HashMap::new();
-    for (official, scores) in totals {
-        averages.insert(official, average(&scores));
+    for (official, (scores, weights)) in totals {
+        let missed = absences.get(&official).copied().unwrap_or(0);
+        let absence_scores = vec![-scoring.absence_grade_penalty.unwrap_or(0.0); missed];
+        let (scores, weights) = with_absences(&scores, &weights, &absence_scores);
+        averages.insert(official, weighted_average(&scores, &weights));
     }
     Ok(averages)
 }
 
-fn export_artifact_jsons(out_dir: &Path, dest_dir: &Path) -> Result<()> {
+/// Meetings each official missed in the window. There is no attendance roll, so an official
+/// counts as absent from a meeting with scored votes when they cast no vote there but voted at
+/// another meeting of the same body in the window.
+fn load_absences(
+    conn: &rusqlite::Connection,
+    window_start: &str,
+    window_end: &str,
+) -> Result<HashMap<String, usize>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT meetings.id, meetings.body_id, decision_scores.evidence_json
+        FROM decision_scores
+        JOIN motions ON decision_scores.motion_id = motions.id
+        JOIN meetings ON motions.meeting_id = meetings.id
+        WHERE decision_scores.vote_id IS NOT NULL
+          AND datetime(meetings.started_at) >= datetime(?1)
+          AND datetime(meetings.started_at) <= datetime(?2)
+        "#,
+    )?;
+    let rows = stmt.query_map([window_start, window_end], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+    })?;
+    let mut present: HashMap<String, (String, HashSet<String>)> = HashMap::new();
+    let mut members: HashMap<String, HashSet<String>> = HashMap::new();
+    for row in rows {
+        let (meeting_id, body_id, evidence_json) = row?;
+        let evidence: Vec<String> = serde_json::from_str(&evidence_json).unwrap_or_default();
+        let Some(official) = extract_official(&evidence) else { continue };
+        members.entry(body_id.clone()).or_default().insert(official.clone());
+        let (_, attended) = present.entry(meeting_id).or_insert_with(|| (body_id, HashSet::new()));
+        attended.insert(official);
+    }
+    let mut absences: HashMap<String, usize> = HashMap::new();
+    for (body_id, attended) in present.values() {
+        for official in members[body_id].difference(attended) {
+            *absences.entry(official.clone()).or_default() += 1;
+        }
+    }
+    Ok(absences)
+}
+
+/// Vote scores and weights with one unit-weight entry appended per absence pseudo-score.
+fn with_absences(scores: &[f64], weights: &[f64], absence_scores: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut scores = scores.to_vec();
+    let mut weights = weights.to_vec();
+    scores.extend_from_slice(absence_scores);
+    weights.extend(absence_scores.iter().map(|_| 1.0));
+    (scores, weights)
+}
+
+/// Copies collector artifact JSON into the site, returning the ids written.
+fn export_artifact_jsons(out_dir: &Path, dest_dir: &Path) -> Result<HashSet<String>> {
+    let mut exported = HashSet::new();
     let artifacts_dir = out_dir.join("artifacts");
     if !artifacts_dir.exists() {
-        return Ok(());
+        return Ok(exported);
     }
     for entry in fs::read_dir(&artifacts_dir)? {
         let entry = entry?;
@@ -2409,10 +6719,18 @@ fn export_artifact_jsons(out_dir: &Path, dest_dir: &Path) -> Result<()> {
         let Some(id) = value.get("id").and_then(|value| value.as_str()) else {
             continue;
         };
+        let id = match safe_filename(id) {
+            Ok(id) => id,
+            Err(err) => {
+                eprintln!("Skipping artifact export from {}: {err}", path.display());
+                continue;
+            }
+        };
         let dest = dest_dir.join(format!("{id}.json"));
-        fs::write(dest, serde_json::to_string_pretty(&value)?)?;
+        write_atomic(dest, serde_json::to_string_pretty(&value)?)?;
+        exported.insert(id);
     }
-    Ok(())
+    Ok(exported)
 }
 
 fn copy_report_jsons(out_dir: &Path, dest_dir: &Path) -> Result<()> {
@@ -2427,7 +6745,7 @@ fn copy_report_jsons(out_dir: &Path, dest_dir: &Path) -> Result<()> {
             continue;
         }
         let filename = path.file_name().and_then(|value| value.to_str()).unwrap_or("");
-        fs::copy(&path, dest_dir.join(filename))?;
+        write_atomic(dest_dir.join(filename), fs::read(&path)?)?;
     }
     Ok(())
 }
@@ -2464,6 +6782,8 @@ a:hover { color: #c2ddff; }
 .badge { padding: 0.2rem 0.6rem; border-radius: 999px; font-size: 0.75rem; font-weight: 600; display: inline-flex; align-items: center; gap: 0.3rem; }
 .badge.rising { background: rgba(68, 171, 99, 0.18); color: #7de7a5; border: 1px solid rgba(68, 171, 99, 0.4); }
 .badge.falling { background: rgba(196, 69, 69, 0.18); color: #ff9c9c; border: 1px solid rgba(196, 69, 69, 0.4); }
+.mover.rising .mover-delta { color: #7de7a5; }
+.mover.falling .mover-delta { color: #ff9c9c; }
 .badge.drift { background: rgba(210, 140, 46, 0.2); color: #ffd18b; border: 1px solid rgba(210, 140, 46, 0.4); }
 .badge.insufficient { background: rgba(115, 129, 148, 0.2); color: #b6c2d3; border: 1px solid rgba(115, 129, 148, 0.4); }
 .badge.grade-a { background: rgba(78, 197, 139, 0.18); color: #7ff0b0; border: 1px solid rgba(78, 197, 139, 0.4); }
@@ -2471,6 +6791,11 @@ a:hover { color: #c2ddff; }
 .badge.grade-c { background: rgba(240, 190, 78, 0.2); color: #ffd38a; border: 1px solid rgba(240, 190, 78, 0.4); }
 .badge.grade-d { background: rgba(255, 140, 84, 0.18); color: #ffc2a3; border: 1px solid rgba(255, 140, 84, 0.4); }
 .badge.grade-f { background: rgba(217, 80, 80, 0.18); color: #ffb3b3; border: 1px solid rgba(217, 80, 80, 0.4); }
+.badge.grade-withheld, .badge.provisional { background: rgba(115, 129, 148, 0.2); color: #b6c2d3; border: 1px dashed rgba(115, 129, 148, 0.6); }
+.stale-banner { background: rgba(210, 140, 46, 0.2); color: #ffd18b; border: 1px solid rgba(210, 140, 46, 0.6); border-radius: 12px; padding: 0.75rem 1rem; margin-bottom: 1rem; font-weight: 600; }
+.grade-dist { display: grid; gap: 0.4rem; margin-top: 1rem; max-width: 520px; }
+.grade-dist-row { display: grid; grid-template-columns: 2.5rem 1fr 2.5rem; align-items: center; gap: 0.6rem; }
+.badge.grade-bar { display: block; height: 0.9rem; padding: 0; }
 .table-wrap { overflow-x: auto; border: 1px solid #1d2836; border-radius: 14px; margin-top: 1rem; }
 table { width: 100%; border-collapse: collapse; font-size: 0.95rem; }
 thead th { position: sticky; top: 0; background: #0f1620; color: #c4d2e3; text-align: left; padding: 0.75rem; border-bottom: 1px solid #1d2836; }
@@ -2481,7 +6806,10 @@ td { padding: 0.75rem; border-bottom: 1px solid #1d2836; vertical-align: top; }
 .score-card { background: #121a25; border: 1px solid #1f2b3a; border-radius: 14px; padding: 1rem; }
 .receipts details { background: #111923; border: 1px solid #1d2836; border-radius: 12px; padding: 0.75rem 1rem; margin-bottom: 0.6rem; }
 .receipts summary { cursor: pointer; font-weight: 600; }
+.receipts .excerpt { margin: 0.5rem 0 0; padding-left: 0.75rem; border-left: 3px solid #1d2836; }
 .clean-list { list-style: none; padding-left: 0; margin: 0; display: grid; gap: 0.6rem; }
+.compare-controls { display: flex; gap: 1rem; flex-wrap: wrap; }
+.compare-controls select, .filter-controls select { margin-left: 0.5rem; background: #0f1620; color: #ecf1f6; border: 1px solid #1d2836; border-radius: 8px; padding: 0.35rem 0.5rem; }
 .footer { border-top: 1px solid #1d2836; padding: 2rem 1.5rem; background: #0f1620; color: #9fb0c4; }
 .footer-inner { max-width: 1200px; margin: 0 auto; display: flex; flex-direction: column; gap: 0.8rem; }
 .footer-links { display: flex; flex-wrap: wrap; gap: 1rem; align-items: center; }
@@ -2516,16 +6844,167 @@ document.querySelectorAll('th[data-sort]').forEach((header) => {
     rows.forEach((row) => tbody.appendChild(row));
   });
 });
+
+const compare = document.getElementById('compare');
+if (compare) {
+  const decimals = Number(compare.dataset.decimals || 1);
+  const [left, right] = compare.querySelectorAll('select');
+  const result = compare.querySelector('.compare-result');
+  const cell = (tag, text, className) => {
+    const element = document.createElement(tag);
+    element.textContent = text;
+    if (className) element.className = className;
+    return element;
+  };
+  const score = (value) => (typeof value === 'number' ? value.toFixed(decimals) : '—');
+  fetch(compare.dataset.src)
+    .then((response) => response.json())
+    .then((data) => {
+      const officials = data.officials || [];
+      const render = () => {
+        const picked = [left.value, right.value]
+          .map((id) => officials.find((official) => official.id === id))
+          .filter(Boolean);
+        if (picked.length === 0) {
+          result.replaceChildren(cell('p', 'No graded officials yet.', 'subtitle'));
+          return;
+        }
+        const same = picked.length === 2 && picked[0].id === picked[1].id;
+        const columns = same ? [picked[0]] : picked;
+        const table = document.createElement('table');
+        const head = table.createTHead().insertRow();
+        head.appendChild(cell('th', 'Axis'));
+        columns.forEach((official) => head.appendChild(cell('th', official.name)));
+        const body = table.createTBody();
+        const overall = body.insertRow();
+        overall.appendChild(cell('td', 'Overall'));
+        columns.forEach((official) => {
+          const grade = `${official.letter_grade} (${score(official.numeric_grade)})`;
+          overall.appendChild(cell('td', grade));
+        });
+        (data.axes || []).forEach((axis) => {
+          const row = body.insertRow();
+          row.appendChild(cell('td', axis));
+          columns.forEach((official) => {
+            const grade = official.axes[axis];
+            row.appendChild(cell('td', grade ? `${grade.letter} (${score(grade.score)})` : '—'));
+          });
+        });
+        const wrap = cell('div', '', 'table-wrap');
+        wrap.appendChild(table);
+        const sameNote = 'Both selections are the same official; pick another to compare.';
+        const notice = same ? [cell('p', sameNote, 'subtitle')] : [];
+        result.replaceChildren(...notice, wrap);
+      };
+      [left, right].forEach((select, index) => {
+        officials.forEach((official) => select.appendChild(new Option(official.name, official.id)));
+        select.selectedIndex = Math.min(index, officials.length - 1);
+        select.addEventListener('change', render);
+      });
+      render();
+    })
+    .catch(() => {
+      result.replaceChildren(cell('p', 'Official data could not be loaded.', 'subtitle'));
+    });
+}
+
+const tagFilter = document.getElementById('tag-filter');
+if (tagFilter) {
+  const rows = document.querySelectorAll('tr[data-tags]');
+  tagFilter.addEventListener('change', () => {
+    const tag = tagFilter.value;
+    rows.forEach((row) => {
+      row.hidden = tag !== '' && !row.dataset.tags.split(' ').includes(tag);
+    });
+  });
+}
     "#;
-    fs::write(assets_dir.join("style.css"), css.trim())?;
-    fs::write(assets_dir.join("app.js"), js.trim())?;
+    write_atomic(assets_dir.join("style.css"), css.trim())?;
+    write_atomic(assets_dir.join("app.js"), js.trim())?;
     Ok(())
 }
 
-fn render_home_page(
-    latest_report: Option<&WeekReport>,
-    week_date: &str,
+/// The newest artifact is older than the staleness threshold, which usually means a collector
+/// stopped producing data while the rest of the pipeline kept running.
+struct StaleData {
+    last_retrieved_at: String,
+    age_days: i64,
+}
+
+/// `None` when data is fresh, nothing is ingested yet, or the newest timestamp is unparseable.
+fn stale_data(
+    conn: &rusqlite::Connection,
+    max_staleness_days: u32,
+    now: OffsetDateTime,
+) -> Result<Option<StaleData>> {
+    let Some(last_retrieved_at) = civic_core::db::latest_artifact_retrieved_at(conn)? else {
+        return Ok(None);
+    };
+    let Ok(retrieved) = OffsetDateTime::parse(&last_retrieved_at, &Rfc3339) else {
+        return Ok(None);
+    };
+    let age = now - retrieved;
+    if age <= Duration::days(i64::from(max_staleness_days)) {
+        return Ok(None);
+    }
+    Ok(Some(StaleData {
+        last_retrieved_at,
+        age_days: age.whole_days(),
+    }))
+}
+
+fn warn_stale_data(stale: &StaleData, max_staleness_days: u32) {
+    eprintln!(
+        "Warning: data may be stale: newest artifact was retrieved {} ({} days ago, threshold \
+         {max_staleness_days}); check that the collectors are still running",
+        stale.last_retrieved_at, stale.age_days
+    );
+}
+
+/// The officials whose grades rose and fell the most since the previous report week.
+#[derive(Default)]
+struct WeekMovers<'a> {
+    improvement: Option<&'a OfficialSummary>,
+    decline: Option<&'a OfficialSummary>,
+}
+
+/// Picks the largest positive and negative `delta` among officials also graded in the previous
+/// week (`previous_average`), so the first week and newcomers never count as movers. Withheld
+/// grades are skipped; equal deltas go to the alphabetically first name.
+fn biggest_movers<'a>(
+    officials: &'a [OfficialSummary],
+    previous_average: &HashMap<String, f64>,
+) -> WeekMovers<'a> {
+    let candidates = officials.iter().filter(|official| {
+        official.letter_grade != WITHHELD_GRADE && previous_average.contains_key(&official.name)
+    });
+    // `sign` flips declines so both directions pick the largest magnitude.
+    let strongest = |sign: f64| {
+        candidates
+            .clone()
+            .filter(|official| official.delta * sign > 0.0)
+            .max_by(|a, b| {
+                (a.delta * sign)
+                    .total_cmp(&(b.delta * sign))
+                    .then_with(|| b.name.cmp(&a.name))
+            })
+    };
+    WeekMovers {
+        improvement: strongest(1.0),
+        decline: strongest(-1.0),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_home_page(
+    latest_report: Option<&WeekReport>,
+    week_date: &str,
     officials: &[OfficialSummary],
+    movers: &WeekMovers,
+    stale_since: Option<&str>,
+    disclaimer: &str,
+    templates: &SiteTemplates,
+    decimals: usize,
 ) -> String {
     let avg_score = latest_report.map(|report| report.rubric_average).unwrap_or(0.0);
     let drift_count = officials.iter().filter(|official| !official.drift_flags.is_empty()).count();
@@ -2554,6 +7033,15 @@ fn render_home_page(
             .join("")
     };
 
+    let stale_banner = stale_since
+        .map(|last_updated| {
+            format!(
+                "<div class=\"stale-banner\">\u{26a0} Data may be stale (last updated \
+                 {last_updated})</div>"
+            )
+        })
+        .unwrap_or_default();
+
     let (avg_numeric, avg_grade) = score_to_grade(avg_score);
     let hero = format!(
         r#"
@@ -2579,18 +7067,21 @@ fn render_home_page(
       <div class="stat-value">{flagged_count}</div>
     </div>
   </div>
+{movers_row}
 </section>
 "#,
         week_date = week_date,
         artifact_count = latest_report.map(|report| report.artifacts.len()).unwrap_or(0),
         decision_count = latest_report.map(|report| report.decisions.len()).unwrap_or(0),
-        flagged_count = flagged_count
+        flagged_count = flagged_count,
+        movers_row = render_movers_row(movers, decimals)
     );
 
     let body = format!(
         r#"
 {nav}
 <main class="container">
+  {stale_banner}
   {hero}
   <section>
     <h2>Governing body dashboards</h2>
@@ -2599,7 +7090,7 @@ fn render_home_page(
         <div class="card-title">{icon_court} Fiscal Court</div>
         <div>
           <span class="badge grade-{grade_class}">{avg_grade}</span>
-          <span class="subtitle">Avg score {avg_numeric:.1}</span>
+          <span class="subtitle">Avg score {avg_numeric:.decimals$}</span>
         </div>
         <div class="chip-row">
           <span class="chip">Drift alerts: {drift_count}</span>
@@ -2620,8 +7111,9 @@ fn render_home_page(
 </main>
 {footer}
 "#,
-        nav = nav_html(week_date),
-        footer = footer_html(week_date),
+        nav = templates.nav(week_date),
+        footer = templates.footer(week_date, disclaimer),
+        stale_banner = stale_banner,
         hero = hero,
         icon_court = icon_court(),
         icon_cap = icon_cap(),
@@ -2632,25 +7124,71 @@ fn render_home_page(
         drift_count = drift_count,
         tag_chips = tag_chips
     );
-    html_page("LaRue Civic Intel", &body)
+    templates.page("LaRue Civic Intel", &body)
+}
+
+/// The hero's "Biggest improvement / Biggest decline this week" callout; empty without movers.
+fn render_movers_row(movers: &WeekMovers, decimals: usize) -> String {
+    let cards = [
+        ("Biggest improvement this week", "rising", movers.improvement),
+        ("Biggest decline this week", "falling", movers.decline),
+    ]
+    .into_iter()
+    .filter_map(|(label, class, official)| {
+        let official = official?;
+        Some(format!(
+            r#"    <div class="stat mover {class}">
+      <div class="stat-label">{label}</div>
+      <div class="stat-value"><a href="/officials/{id}.html">{name}</a> <span class="mover-delta">{delta:+.decimals$}</span></div>
+    </div>"#,
+            id = official.id,
+            name = official.name,
+            delta = official.delta,
+        ))
+    })
+    .collect::<Vec<_>>();
+    if cards.is_empty() {
+        return String::new();
+    }
+    format!("  <div class=\"stats-row movers\">\n{}\n  </div>", cards.join("\n"))
 }
 
-fn render_stockade_page(officials: &[OfficialSummary], week_date: &str) -> String {
+fn render_stockade_page(
+    officials: &[OfficialSummary],
+    week_date: &str,
+    rubric_versions: &[String],
+    disclaimer: &str,
+    templates: &SiteTemplates,
+    decimals: usize,
+) -> String {
+    let rubric_note = if rubric_versions.is_empty() {
+        String::new()
+    } else {
+        let versions = rubric_versions
+            .iter()
+            .map(|hash| {
+                format!(
+                    "<code title=\"{hash}\">{}</code>",
+                    civic_core::scoring::short_rubric_version(hash)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("<p class=\"subtitle\">Scored under rubric {versions}.</p>")
+    };
     let rows = officials
         .iter()
         .map(|official| {
-            let trend_badge = if official.delta >= 5.0 {
-                format!(
+            let trend_badge = match official.trend {
+                Trend::Rising => format!(
                     "<span class=\"badge rising\">{} Rising</span>",
                     icon_trend_up()
-                )
-            } else if official.delta <= -5.0 {
-                format!(
+                ),
+                Trend::Falling => format!(
                     "<span class=\"badge falling\">{} Falling</span>",
                     icon_trend_down()
-                )
-            } else {
-                String::new()
+                ),
+                Trend::Steady => String::new(),
             };
             let drift_badge = if !official.drift_flags.is_empty() {
                 format!("<span class=\"badge drift\">{} Drift</span>", icon_alert())
@@ -2665,6 +7203,11 @@ fn render_stockade_page(officials: &[OfficialSummary], week_date: &str) -> Strin
             } else {
                 String::new()
             };
+            let provisional_badge = if official.provisional {
+                format!("<span class=\"badge provisional\">{} Provisional</span>", icon_info())
+            } else {
+                String::new()
+            };
             let tags = if official.top_issue_tags.is_empty() {
                 "-".to_string()
             } else {
@@ -2679,10 +7222,10 @@ fn render_stockade_page(officials: &[OfficialSummary], week_date: &str) -> Strin
             format!(
                 r#"<tr>
 <td><a href="/officials/{id}.html">{name}</a></td>
-<td data-value="{numeric:.1}">{numeric:.1}</td>
+<td data-value="{numeric:.decimals$}">{numeric:.decimals$}</td>
 <td><span class="badge grade-{grade_class}">{grade}</span></td>
-<td data-value="{delta:.1}">{delta:.1}</td>
-<td><div class="chip-row">{trend}{drift}{insufficient}</div></td>
+<td data-value="{delta:.decimals$}">{delta:.decimals$}</td>
+<td><div class="chip-row">{trend}{drift}{insufficient}{provisional}</div></td>
 <td><div class="chip-row">{tags}</div></td>
 </tr>"#,
                 id = official.id,
@@ -2694,6 +7237,7 @@ fn render_stockade_page(officials: &[OfficialSummary], week_date: &str) -> Strin
                 trend = trend_badge,
                 drift = drift_badge,
                 insufficient = insufficient_badge,
+                provisional = provisional_badge,
                 tags = tags
             )
         })
@@ -2706,6 +7250,8 @@ fn render_stockade_page(officials: &[OfficialSummary], week_date: &str) -> Strin
 <main class="container">
   <h2>Public Stockade</h2>
   <p class="subtitle">Leaderboard sorted by current score. Click headers to sort.</p>
+  {rubric_note}
+  {distribution}
   <div class="table-wrap">
     <table>
       <thead>
@@ -2729,19 +7275,71 @@ fn render_stockade_page(officials: &[OfficialSummary], week_date: &str) -> Strin
 <script src="/assets/app.js"></script>
     "#
     ,
-        nav = nav_html(week_date),
-        footer = footer_html(week_date)
+        nav = templates.nav(week_date),
+        footer = templates.footer(week_date, disclaimer),
+        distribution = render_grade_distribution(officials)
     );
-    html_page("Public Stockade", &body)
+    templates.page("Public Stockade", &body)
+}
+
+/// Inline bar chart of how many officials fall in each letter-grade band.
+fn render_grade_distribution(officials: &[OfficialSummary]) -> String {
+    if officials.is_empty() {
+        return "<p class=\"subtitle\">No graded officials yet.</p>".to_string();
+    }
+    let bands = ["A", "B", "C", "D", "F"];
+    let counts = bands
+        .iter()
+        .map(|band| {
+            let class = grade_class(band);
+            officials
+                .iter()
+                .filter(|official| grade_class(&official.letter_grade) == class)
+                .count()
+        })
+        .collect::<Vec<_>>();
+    let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+    let rows = bands
+        .iter()
+        .zip(&counts)
+        .map(|(band, count)| {
+            let class = grade_class(band);
+            let bar = if *count == 0 {
+                String::new()
+            } else {
+                format!(
+                    "<span class=\"badge grade-{class} grade-bar\" style=\"width: {:.0}%\"></span>",
+                    *count as f64 / max_count as f64 * 100.0
+                )
+            };
+            format!(
+                r#"<div class="grade-dist-row">
+  <span class="badge grade-{class}">{band}</span>
+  <div>{bar}</div>
+  <span class="subtitle">{count}</span>
+</div>"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "<section class=\"grade-dist\" aria-label=\"Grade distribution\">\n{rows}\n</section>"
+    )
 }
 
-fn render_officials_index(officials: &[OfficialSummary], week_date: &str) -> String {
+fn render_officials_index(
+    officials: &[OfficialSummary],
+    week_date: &str,
+    disclaimer: &str,
+    templates: &SiteTemplates,
+    decimals: usize,
+) -> String {
     let list = officials
         .iter()
         .map(|official| {
             let grade_class = grade_class(&official.letter_grade);
             format!(
-                "<li><a href=\"/officials/{id}.html\">{name}</a> <span class=\"badge grade-{grade_class}\">{grade}</span> <span class=\"subtitle\">{score:.1}</span></li>",
+                "<li><a href=\"/officials/{id}.html\">{name}</a> <span class=\"badge grade-{grade_class}\">{grade}</span> <span class=\"subtitle\">{score:.decimals$}</span></li>",
                 id = official.id,
                 name = official.name,
                 grade = official.letter_grade,
@@ -2765,31 +7363,351 @@ fn render_officials_index(officials: &[OfficialSummary], week_date: &str) -> Str
 {footer}
     "#
     ,
-        nav = nav_html(week_date),
-        footer = footer_html(week_date)
+        nav = templates.nav(week_date),
+        footer = templates.footer(week_date, disclaimer)
+    );
+    templates.page("Officials", &body)
+}
+
+/// `api/officials.json`: the latest week's grades with normalized per-axis scores, which the
+/// compare page reads client-side. `axes` lists every axis any official has, in display order.
+fn officials_api_json(
+    officials: &[OfficialSummary],
+    axes: &[String],
+    week_date: &str,
+) -> serde_json::Value {
+    let mut all_axes: Vec<String> = Vec::new();
+    for official in officials {
+        for (axis, _) in ordered_by_axes(&official.axis_scores_normalized, axes) {
+            if !all_axes.iter().any(|known| known == axis) {
+                all_axes.push(axis.to_string());
+            }
+        }
+    }
+    let order = |axis: &String| axes.iter().position(|known| known == axis).unwrap_or(axes.len());
+    all_axes.sort_by(|a, b| order(a).cmp(&order(b)).then_with(|| a.cmp(b)));
+
+    let entries = officials
+        .iter()
+        .map(|official| {
+            let axis_grades = official
+                .axis_scores_normalized
+                .iter()
+                .map(|(axis, score)| {
+                    let (numeric, letter) = score_to_grade(*score);
+                    (axis.clone(), serde_json::json!({"score": numeric, "letter": letter}))
+                })
+                .collect::<BTreeMap<_, _>>();
+            serde_json::json!({
+                "id": official.id,
+                "name": official.name,
+                "letter_grade": official.letter_grade,
+                "numeric_grade": official.numeric_grade,
+                "provisional": official.provisional,
+                "axes": axis_grades,
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({
+        "week_date": week_date,
+        "axes": all_axes,
+        "officials": entries,
+    })
+}
+
+/// Side-by-side comparison of two officials. The page is static; `app.js` fills the selectors
+/// and table from `api/officials.json`.
+fn render_compare_page(
+    week_date: &str,
+    disclaimer: &str,
+    templates: &SiteTemplates,
+    decimals: usize,
+) -> String {
+    let body = format!(
+        r#"
+{nav}
+<main class="container">
+  <h2>Compare officials</h2>
+  <div class="card" id="compare" data-src="/api/officials.json" data-decimals="{decimals}">
+    <div class="compare-controls">
+      <label>Official <select name="left" aria-label="First official"></select></label>
+      <label>Official <select name="right" aria-label="Second official"></select></label>
+    </div>
+    <div class="compare-result"><p class="subtitle">Loading officials…</p></div>
+  </div>
+  <noscript><p class="subtitle">Comparing officials needs JavaScript; see the
+    <a href="/officials/index.html">officials list</a> instead.</p></noscript>
+</main>
+{footer}
+<script src="/assets/app.js"></script>
+    "#,
+        nav = templates.nav(week_date),
+        footer = templates.footer(week_date, disclaimer)
+    );
+    templates.page("Compare officials", &body)
+}
+
+/// Ingested artifacts for the site index, newest first. Body text is dropped; the index never
+/// shows it.
+fn load_site_artifacts(conn: &rusqlite::Connection) -> Result<Vec<civic_core::schema::Artifact>> {
+    let mut artifacts = Vec::new();
+    civic_core::db::for_each_artifact(conn, |mut artifact| {
+        artifact.body_text = None;
+        artifacts.push(artifact);
+        Ok(())
+    })?;
+    artifacts.sort_by(|a, b| {
+        b.source
+            .retrieved_at
+            .cmp(&a.source.retrieved_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    Ok(artifacts)
+}
+
+/// `artifacts/index.html`: a sortable table of the newest `limit` artifacts with a tag filter.
+/// Titles link to the artifact JSON when it was exported with the site.
+fn render_artifacts_index(
+    artifacts: &[civic_core::schema::Artifact],
+    limit: usize,
+    exported: &HashSet<String>,
+    week_date: &str,
+    zone: &ReportingZone,
+    disclaimer: &str,
+    templates: &SiteTemplates,
+) -> String {
+    let shown = &artifacts[..artifacts.len().min(limit)];
+    let rows = shown
+        .iter()
+        .map(|artifact| {
+            let title = escape_html(artifact.title.as_deref().unwrap_or(&artifact.id));
+            let title = if exported.contains(&artifact.id) {
+                format!("<a href=\"/artifacts/{}.json\">{title}</a>", escape_html(&artifact.id))
+            } else {
+                title
+            };
+            let retrieved_at = &artifact.source.retrieved_at;
+            let sort_key = OffsetDateTime::parse(retrieved_at, &Rfc3339)
+                .map(|value| value.unix_timestamp())
+                .unwrap_or(0);
+            let local = zone.display(retrieved_at);
+            let date = local.get(..10).unwrap_or(&local);
+            let value = escape_html(&artifact.source.value);
+            let source = if value.starts_with("http://") || value.starts_with("https://") {
+                format!("<a href=\"{value}\">{}</a>", escape_html(&artifact.source.kind))
+            } else {
+                value
+            };
+            let tags = artifact
+                .tags
+                .iter()
+                .map(|tag| format!("<span class=\"chip\">{}</span>", escape_html(tag)))
+                .collect::<String>();
+            format!(
+                r#"<tr data-tags="{data_tags}">
+<td>{title}</td>
+<td data-value="{sort_key}">{date}</td>
+<td>{source}</td>
+<td><div class="chip-row">{tags}</div></td>
+</tr>"#,
+                data_tags = escape_html(&artifact.tags.join(" ")),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let all_tags = shown
+        .iter()
+        .flat_map(|artifact| artifact.tags.iter())
+        .collect::<std::collections::BTreeSet<_>>();
+    let options = all_tags
+        .iter()
+        .map(|tag| format!("<option value=\"{tag}\">{tag}</option>", tag = escape_html(tag)))
+        .collect::<Vec<_>>()
+        .join("");
+    let capped = if artifacts.len() > shown.len() {
+        format!(
+            "<p class=\"subtitle\">Showing the newest {} of {} artifacts. Raise \
+             <code>site.artifacts_index_limit</code> to list more.</p>",
+            shown.len(),
+            artifacts.len()
+        )
+    } else {
+        String::new()
+    };
+    let table = if shown.is_empty() {
+        "<p class=\"subtitle\">No artifacts ingested yet.</p>".to_string()
+    } else {
+        format!(
+            r#"<div class="filter-controls">
+    <label>Tag <select id="tag-filter" aria-label="Filter by tag">
+      <option value="">All tags</option>{options}
+    </select></label>
+  </div>
+  <div class="table-wrap">
+    <table>
+      <thead>
+        <tr>
+          <th data-sort>Title</th>
+          <th data-sort>Date</th>
+          <th data-sort>Source</th>
+          <th>Tags</th>
+        </tr>
+      </thead>
+      <tbody>
+        {rows}
+      </tbody>
+    </table>
+  </div>
+  <div class="sort-hint">Tip: click column headers to sort.</div>"#
+        )
+    };
+    let body = format!(
+        r#"
+{nav}
+<main class="container">
+  <h2>Artifacts</h2>
+  <p class="subtitle">Source documents behind the scores, newest first.</p>
+  {capped}
+  {table}
+</main>
+{footer}
+<script src="/assets/app.js"></script>
+    "#,
+        nav = templates.nav(week_date),
+        footer = templates.footer(week_date, disclaimer)
     );
-    html_page("Officials", &body)
+    templates.page("Artifacts", &body)
+}
+
+/// Excerpts are artifact text, which may contain markup characters.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
 }
 
-fn render_official_detail(official: &OfficialSummary, week_date: &str) -> String {
-    let axis_rows = official
-        .axis_scores_normalized
+/// Per-axis entries: rubric axes first in canonical order, then any stored axes the current
+/// rubric no longer lists, sorted.
+fn ordered_by_axes<'a, V>(
+    values: &'a HashMap<String, V>,
+    axes: &'a [String],
+) -> Vec<(&'a str, &'a V)> {
+    let mut ordered: Vec<(&str, &V)> = axes
+        .iter()
+        .filter_map(|axis| values.get(axis).map(|value| (axis.as_str(), value)))
+        .collect();
+    let mut extra: Vec<(&str, &V)> = values
         .iter()
+        .filter(|(axis, _)| !axes.contains(axis))
+        .map(|(axis, value)| (axis.as_str(), value))
+        .collect();
+    extra.sort_by(|a, b| a.0.cmp(b.0));
+    ordered.extend(extra);
+    ordered
+}
+
+fn render_official_detail(
+    official: &OfficialSummary,
+    axes: &[String],
+    week_date: &str,
+    disclaimer: &str,
+    templates: &SiteTemplates,
+    decimals: usize,
+) -> String {
+    let axis_rows = ordered_by_axes(&official.axis_scores_normalized, axes)
+        .into_iter()
         .map(|(axis, score)| {
             let (numeric, letter) = score_to_grade(*score);
             format!(
-                "<tr><td>{axis}</td><td>{letter}</td><td>{numeric:.1}</td></tr>"
+                "<tr><td>{axis}</td><td>{letter}</td><td>{numeric:.decimals$}</td></tr>"
             )
         })
         .collect::<Vec<_>>()
         .join("\n");
 
-    let trend = if official.delta >= 5.0 {
-        format!("{} Rising", icon_trend_up())
-    } else if official.delta <= -5.0 {
-        format!("{} Falling", icon_trend_down())
+    let drivers_section = if official.contributions.is_empty() {
+        "<p class=\"subtitle\">No axis moved this score this week.</p>".to_string()
+    } else {
+        let mut drivers: Vec<(&String, &f64)> = official.contributions.iter().collect();
+        drivers.sort_by(|a, b| {
+            b.1.abs()
+                .partial_cmp(&a.1.abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(b.0))
+        });
+        let rows = drivers
+            .into_iter()
+            .map(|(axis, value)| format!("<tr><td>{axis}</td><td>{value:+.2}</td></tr>"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            r#"<div class="table-wrap">
+      <table>
+        <thead><tr><th>Axis</th><th>Contribution</th></tr></thead>
+        <tbody>{rows}</tbody>
+      </table>
+    </div>
+    <p class="subtitle">Weighted, polarity-signed axis scores behind the raw average of
+      {average:+.2}; scores outside the rubric's range are clamped before averaging.</p>"#,
+            average = official.average_score
+        )
+    };
+
+    let weights_section = if official.weights_applied.is_empty() {
+        "<p class=\"subtitle\">No weight snapshot was recorded for these scores.</p>".to_string()
+    } else {
+        let mut weighted_sum = 0.0;
+        let rows = ordered_by_axes(&official.weights_applied, axes)
+            .into_iter()
+            .map(|(axis, (score, weight))| {
+                weighted_sum += score * weight;
+                format!(
+                    "<tr><td>{axis}</td><td>{score:+.2}</td><td>{weight:+.2}</td>\
+                     <td>{product:+.2}</td></tr>",
+                    product = score * weight
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            r#"<div class="table-wrap">
+      <table>
+        <thead>
+          <tr><th>Axis</th><th>Avg axis score</th><th>Weight</th><th>Axis × weight</th></tr>
+        </thead>
+        <tbody>{rows}</tbody>
+      </table>
+    </div>
+    <p class="subtitle">Weighted overall: {weighted_sum:+.2} raw, normalized to
+      <span class="weighted-overall">{overall:.decimals$}</span>. Weights are the rubric's, signed
+      by axis polarity; votes that did not touch an axis count as zero.</p>"#,
+            overall = official.weighted_overall
+        )
+    };
+
+    let refs_section = if official.constitution_refs.is_empty() {
+        "<p class=\"subtitle\">No constitutional references this week.</p>".to_string()
     } else {
-        format!("{} Stable", icon_info())
+        let rows = ordered_by_axes(&official.constitution_refs, axes)
+            .into_iter()
+            .map(|(axis, refs)| format!("<tr><td>{axis}</td><td>{}</td></tr>", refs.join(", ")))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            r#"<div class="table-wrap">
+      <table>
+        <thead><tr><th>Axis</th><th>References</th></tr></thead>
+        <tbody>{rows}</tbody>
+      </table>
+    </div>"#
+        )
+    };
+
+    let trend = match official.trend {
+        Trend::Rising => format!("{} Rising", icon_trend_up()),
+        Trend::Falling => format!("{} Falling", icon_trend_down()),
+        Trend::Steady => format!("{} Stable", icon_info()),
     };
 
     let mut flags = Vec::new();
@@ -2802,6 +7720,14 @@ fn render_official_detail(official: &OfficialSummary, week_date: &str) -> String
             icon_info()
         ));
     }
+    if official.provisional {
+        flags.push(format!(
+            "<span class=\"badge provisional\">{} Provisional ({} scored vote{})</span>",
+            icon_info(),
+            official.vote_count,
+            if official.vote_count == 1 { "" } else { "s" }
+        ));
+    }
     let flags = if flags.is_empty() {
         "<span class=\"subtitle\">No flags</span>".to_string()
     } else {
@@ -2825,11 +7751,29 @@ fn render_official_detail(official: &OfficialSummary, week_date: &str) -> String
                         .collect::<Vec<_>>()
                         .join(", ")
                 };
+                let vote = receipt
+                    .vote_label
+                    .as_ref()
+                    .map(|label| format!(" <span class=\"badge\">{label}</span>"))
+                    .unwrap_or_default();
+                let excerpts = receipt
+                    .excerpts
+                    .iter()
+                    .map(|excerpt| {
+                        let id = escape_html(&excerpt.artifact_id);
+                        format!(
+                            "\n  <blockquote class=\"excerpt\">“{}” <span class=\"subtitle\">— {}, \
+                             <a href=\"/artifacts/{id}.json\">{id}</a></span></blockquote>",
+                            escape_html(&excerpt.text),
+                            excerpt.tag
+                        )
+                    })
+                    .collect::<String>();
                 format!(
                     r#"<details>
-  <summary>{date}: {text}</summary>
+  <summary>{date}: {text}{vote}</summary>
   <div class="subtitle">Week: <a href="/weeks/{week}.html">{week}</a></div>
-  <div class="subtitle">Artifacts: {artifacts}</div>
+  <div class="subtitle">Artifacts: {artifacts}</div>{excerpts}
 </details>"#,
                     date = receipt.meeting_date,
                     text = receipt.motion_text,
@@ -2841,10 +7785,47 @@ fn render_official_detail(official: &OfficialSummary, week_date: &str) -> String
             .join("\n")
     };
 
-    let commentary = official
-        .commentary
-        .as_deref()
-        .unwrap_or("No commentary generated.");
+    let drift_section = if official.drift_details.is_empty() {
+        "<p class=\"subtitle\">No drift detected this week.</p>".to_string()
+    } else {
+        let rows = official
+            .drift_details
+            .iter()
+            .map(|detail| {
+                let status = if detail.resolved {
+                    "<span class=\"badge\">Resolved</span>".to_string()
+                } else {
+                    format!("<span class=\"badge drift\">{} Active</span>", icon_alert())
+                };
+                format!(
+                    "<tr><td>{axis}</td><td>{prior:.2}</td><td>{current:.2}</td>\
+                     <td>{deviation:+.2}</td><td>{status}</td></tr>",
+                    axis = detail.axis,
+                    prior = detail.prior_average,
+                    current = detail.current_average,
+                    deviation = detail.deviation
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            r#"<div class="table-wrap">
+      <table>
+        <thead><tr><th>Axis</th><th>Prior avg</th><th>Current avg</th><th>Deviation</th><th>Status</th></tr></thead>
+        <tbody>{rows}</tbody>
+      </table>
+    </div>"#
+        )
+    };
+
+    // Commentary must point readers at primary sources, so it is only shown alongside receipts.
+    let commentary = match official.commentary.as_deref() {
+        Some(line) if !official.receipts.is_empty() => format!(
+            "{line} <a href=\"#receipts\">Primary sources: see receipts</a>"
+        ),
+        Some(_) => "Commentary withheld: no primary-source receipts this week.".to_string(),
+        None => "No commentary generated.".to_string(),
+    };
 
     let grade_class = grade_class(&official.letter_grade);
     let body = format!(
@@ -2855,12 +7836,12 @@ fn render_official_detail(official: &OfficialSummary, week_date: &str) -> String
   <div class="score-grid">
     <div class="score-card">
       <div class="subtitle">Overall score</div>
-      <div class="stat-value">{numeric:.1}</div>
+      <div class="stat-value">{numeric:.decimals$}</div>
       <span class="badge grade-{grade_class}">{grade}</span>
     </div>
     <div class="score-card">
       <div class="subtitle">Trend</div>
-      <div class="stat-value">{delta:.1}</div>
+      <div class="stat-value">{delta:.decimals$}</div>
       <span class="badge">{trend}</span>
     </div>
     <div class="score-card">
@@ -2880,6 +7861,26 @@ fn render_official_detail(official: &OfficialSummary, week_date: &str) -> String
   </section>
 
   <section>
+    <h3>What drove this score</h3>
+    {drivers_section}
+  </section>
+
+  <section>
+    <h3>Weights applied</h3>
+    {weights_section}
+  </section>
+
+  <section>
+    <h3>Constitutional references</h3>
+    {refs_section}
+  </section>
+
+  <section>
+    <h3>Drift by axis</h3>
+    {drift_section}
+  </section>
+
+  <section id="receipts">
     <h3>Receipts</h3>
     <div class="receipts">{receipts}</div>
   </section>
@@ -2887,28 +7888,39 @@ fn render_official_detail(official: &OfficialSummary, week_date: &str) -> String
   <section class="card">
     <h3>Commentary</h3>
     <p>{commentary}</p>
-    <p class="subtitle">Satire/opinion based on this project’s rubric scoring.</p>
+    <p class="subtitle">{disclaimer}</p>
   </section>
 </main>
 {footer}
     "#,
-        nav = nav_html(week_date),
-        footer = footer_html(week_date),
+        nav = templates.nav(week_date),
+        footer = templates.footer(week_date, disclaimer),
         name = official.name,
         grade = official.letter_grade,
         grade_class = grade_class,
         numeric = official.numeric_grade,
         axis_rows = axis_rows,
+        drivers_section = drivers_section,
+        weights_section = weights_section,
+        refs_section = refs_section,
+        drift_section = drift_section,
         receipts = receipts,
         commentary = commentary,
         delta = official.delta,
         trend = trend,
         flags = flags
     );
-    html_page(&format!("Official {}", official.name), &body)
+    templates.page(&format!("Official {}", official.name), &body)
 }
 
-fn render_week_page(report: &WeekReport, week_date: &str) -> String {
+fn render_week_page(
+    report: &WeekReport,
+    week_date: &str,
+    zone: &ReportingZone,
+    disclaimer: &str,
+    templates: &SiteTemplates,
+    decimals: usize,
+) -> String {
     let issue_tags = if report.issue_tag_counts.is_empty() {
         "_No issue tags._".to_string()
     } else {
@@ -2934,13 +7946,63 @@ fn render_week_page(report: &WeekReport, week_date: &str) -> String {
                             .result
                             .clone()
                             .unwrap_or_else(|| "unknown".to_string());
-                        format!("<li>{} ({})</li>", motion.text, outcome)
+                        let amended = civic_core::schema::amended_marker(motion.amended);
+                        let tie = motion
+                            .tie_broken_by
+                            .as_ref()
+                            .map(|officer| {
+                                format!(" <span class=\"subtitle\">Tie broken by {officer}</span>")
+                            })
+                            .unwrap_or_default();
+                        match &motion.vote_label {
+                            Some(label) => format!(
+                                "<li>{}{} ({}) <span class=\"badge\">{}</span>{tie}</li>",
+                                motion.text, amended, outcome, label
+                            ),
+                            None => {
+                                format!("<li>{}{} ({}){tie}</li>", motion.text, amended, outcome)
+                            }
+                        }
                     })
                     .collect::<Vec<_>>()
                     .join("\n");
+                let comments = decision
+                    .motions
+                    .iter()
+                    .flat_map(|motion| {
+                        motion.public_comments.iter().map(move |comment| {
+                            let speaker = escape_html(
+                                comment.speaker.as_deref().unwrap_or("Unnamed speaker"),
+                            );
+                            let stance = comment
+                                .stance
+                                .as_deref()
+                                .map(|stance| {
+                                    format!(" <span class=\"badge\">{}</span>", escape_html(stance))
+                                })
+                                .unwrap_or_default();
+                            format!(
+                                "<li>{speaker}{stance}: {summary} <span class=\"subtitle\">on \
+                                 {motion}</span></li>",
+                                summary = escape_html(&comment.summary),
+                                motion = motion.text
+                            )
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let comments = if comments.is_empty() {
+                    String::new()
+                } else {
+                    format!("<h5>Public Comment</h5><ul>{}</ul>", comments.join("\n"))
+                };
+                let coverage = decision
+                    .evidence_coverage
+                    .and_then(|coverage| coverage.label())
+                    .map(|label| format!("<p class=\"subtitle\">{label}</p>"))
+                    .unwrap_or_default();
                 format!(
-                    "<div class=\"card\"><h4>{}</h4><ul>{}</ul></div>",
-                    decision.body_name, motions
+                    "<div class=\"card\"><h4>{}</h4>{coverage}<ul>{}</ul>{}</div>",
+                    decision.body_name, motions, comments
                 )
             })
             .collect::<Vec<_>>()
@@ -2967,7 +8029,7 @@ fn render_week_page(report: &WeekReport, week_date: &str) -> String {
 {nav}
 <main class="container">
   <h2>Week of {date}</h2>
-  <p class="subtitle">Window: {start} to {end}</p>
+  <p class="subtitle">Window: {start} to {end} ({zone})</p>
   <section class="card">
     <h3>High-impact artifacts</h3>
     <ul>{artifacts}</ul>
@@ -2978,88 +8040,119 @@ fn render_week_page(report: &WeekReport, week_date: &str) -> String {
   </section>
   <section class="card">
     <h3>Rubric Alignment</h3>
-    <p>Average score: {avg:.1}</p>
+    <p>Average score: {avg:.decimals$}</p>
     <p>Issue tags: {issue_tags}</p>
     <p><a href="/reports/weekly/{date}.json">Raw report JSON</a></p>
   </section>
 </main>
 {footer}
     "#,
-        nav = nav_html(week_date),
-        footer = footer_html(week_date),
+        nav = templates.nav(week_date),
+        footer = templates.footer(week_date, disclaimer),
         date = report.date,
-        start = report.window_start,
-        end = report.window_end,
+        start = zone.display(&report.window_start),
+        end = zone.display(&report.window_end),
         artifacts = artifacts,
         decisions = decisions,
         avg = report.rubric_average,
-        issue_tags = issue_tags
+        issue_tags = issue_tags,
+        zone = zone.name
     );
-    html_page(&format!("Week {}", report.date), &body)
+    templates.page(&format!("Week {}", report.date), &body)
 }
 
-fn html_page(title: &str, body: &str) -> String {
-    format!(
-        r#"<!doctype html>
-<html lang="en">
-<head>
-  <meta charset="utf-8" />
-  <meta name="viewport" content="width=device-width, initial-scale=1" />
-  <title>{title}</title>
-  <link rel="stylesheet" href="/assets/style.css" />
-</head>
-<body>
-{body}
-</body>
-</html>
-"#
-    )
+/// Site page skeletons: the HTML shell, header nav, and footer. Each is read from
+/// `<template_dir>/{page,nav,footer}.html` when present, else the built-in default, and filled
+/// by `{{placeholder}}` substitution.
+struct SiteTemplates {
+    page: String,
+    nav: String,
+    footer: String,
 }
 
-fn nav_html(week_date: &str) -> String {
-    format!(
-        r#"
-<header class="site-header">
-  <div class="nav-inner">
-    <div class="nav-brand">
-      {icon_logo}
-      <span>LaRue Civic Intel</span>
-    </div>
-    <nav class="nav-links">
-      <a href="/">Home</a>
-      <a href="/stockade/index.html">Stockade</a>
-      <a href="/officials/index.html">Officials</a>
-      <a href="/weeks/{week_date}.html">Latest Week</a>
-    </nav>
-    <div class="nav-search" aria-disabled="true">
-      {icon_search}
-      <input type="text" placeholder="Search (coming soon)" disabled />
-    </div>
-  </div>
-</header>
-"#,
-        week_date = week_date,
-        icon_logo = icon_court(),
-        icon_search = icon_search()
-    )
+impl Default for SiteTemplates {
+    fn default() -> Self {
+        Self {
+            page: include_str!("../templates/page.html").to_string(),
+            nav: include_str!("../templates/nav.html").to_string(),
+            footer: include_str!("../templates/footer.html").to_string(),
+        }
+    }
 }
 
-fn footer_html(week_date: &str) -> String {
-    format!(
-        r#"
-<footer class="footer">
-  <div class="footer-inner">
-    <div class="footer-links">
-      <a class="btn" href="https://github.com/sponsors/Yisonco-Stellargold">Sponsor</a>
-      <a href="https://github.com/Yisonco-Stellargold/larue-civic-intel">Repository</a>
-      <a href="/reports/weekly/{week_date}.json">Latest report JSON</a>
-    </div>
-    <div class="subtitle">Rubric-based scoring; commentary is opinion/satire. Always consult primary sources.</div>
-  </div>
-</footer>
-"#,
-        week_date = week_date
-    )
+impl SiteTemplates {
+    fn load(template_dir: Option<&Path>) -> Result<Self> {
+        let mut templates = Self::default();
+        let Some(dir) = template_dir else {
+            return Ok(templates);
+        };
+        if !dir.is_dir() {
+            return Err(FailureKind::Config.error(format!(
+                "Template directory not found: {}",
+                dir.display()
+            )));
+        }
+        for (name, template) in [
+            ("page.html", &mut templates.page),
+            ("nav.html", &mut templates.nav),
+            ("footer.html", &mut templates.footer),
+        ] {
+            let path = dir.join(name);
+            if path.exists() {
+                *template = fs::read_to_string(&path)
+                    .map_err(|err| anyhow!("Cannot read template {}: {err}", path.display()))?;
+            }
+        }
+        Ok(templates)
+    }
+
+    /// Placeholders: `title`, `body`.
+    fn page(&self, title: &str, body: &str) -> String {
+        fill_template(&self.page, &[("title", title), ("body", body)])
+    }
+
+    /// Placeholders: `week_date`, `icon_logo`, `icon_search`.
+    fn nav(&self, week_date: &str) -> String {
+        fill_template(
+            &self.nav,
+            &[
+                ("week_date", week_date),
+                ("icon_logo", icon_court()),
+                ("icon_search", icon_search()),
+            ],
+        )
+    }
+
+    /// Placeholders: `week_date`, `disclaimer`.
+    fn footer(&self, week_date: &str, disclaimer: &str) -> String {
+        fill_template(
+            &self.footer,
+            &[("week_date", week_date), ("disclaimer", disclaimer)],
+        )
+    }
+}
+
+/// Replaces each `{{name}}` with its value in one pass, so substituted text is never rescanned.
+/// Unknown placeholders are left as-is.
+fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(len) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        let name = after[..len].trim();
+        match values.iter().find(|(key, _)| *key == name) {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(&rest[start..start + 2 + len + 2]),
+        }
+        rest = &after[len + 2..];
+    }
+    out.push_str(rest);
+    out
 }
 
 fn icon_court() -> &'static str {
@@ -3094,16 +8187,7 @@ fn icon_info() -> &'static str {
     r#"<svg class="icon" viewBox="0 0 24 24" aria-hidden="true"><circle cx="12" cy="12" r="9"/><path d="M12 10v6"/><path d="M12 7h.01"/></svg>"#
 }
 
-fn grade_class(grade: &str) -> &'static str {
-    match grade.chars().next().unwrap_or('F') {
-        'A' => "a",
-        'B' => "b",
-        'C' => "c",
-        'D' => "d",
-        _ => "f",
-    }
-}
-
+#[allow(clippy::too_many_arguments)]
 fn build_commentary_line(
     official_id: &str,
     week_date: &str,
@@ -3118,33 +8202,32 @@ fn build_commentary_line(
         return None;
     }
     let style = site.commentary_style.clone().unwrap_or_else(|| "satire".to_string());
-    let seed = format!("{official_id}:{week_date}:{style}");
-    let grade_drop = grade_rank(prior_grade) - grade_rank(grade);
-    let grade_rise = grade_rank(grade) - grade_rank(prior_grade);
-    let templates = if delta <= -10.0 || grade_drop >= 1 {
-        vec![
-            "This week’s voting record earned a {grade}—not exactly a masterclass in restraint.",
-            "A {grade} this week. The numbers did the talking.",
-            "Scores slid to {grade}; the rubric isn’t feeling inspired.",
-        ]
-    } else if delta >= 10.0 || grade_rise >= 1 {
-        vec![
-            "Solid climb to a {grade}; keep it up and the trend becomes a pattern.",
-            "A jump to {grade}. Momentum looks real this week.",
-            "Score gains landed at {grade}; credit where it’s due.",
-        ]
-    } else {
-        vec![
-            "Steady at {grade}; the next votes will decide the direction.",
-            "Holding at {grade}. Consistency is the story for now.",
-            "No major shifts: {grade} with room to move.",
-        ]
-    };
-    let mut template = templates[stable_hash(&seed) as usize % templates.len()];
+    let seed = site
+        .commentary_seed_override
+        .unwrap_or_else(|| stable_hash(&format!("{official_id}:{week_date}:{style}")));
+    let templates = commentary_templates(grade, prior_grade, delta);
+    let mut template = templates[(seed % templates.len() as u64) as usize];
     if style == "neutral" {
         template = "Current grade is {grade}; see the weekly report for details.";
     }
     let mut line = template.replace("{grade}", grade);
+    // Later sentences draw from their own pools, stepping past any template already used so a
+    // pinned seed still yields distinct sentences.
+    let mut used = vec![template];
+    let sentences = site.commentary_sentences.unwrap_or(1).min(MAX_COMMENTARY_SENTENCES);
+    let decimals = site.display_decimals.unwrap_or(DEFAULT_DISPLAY_DECIMALS);
+    let extras = [
+        (COMMENTARY_TREND_DETAIL, Some(format!("{delta:+.decimals$}"))),
+        (COMMENTARY_TOP_ISSUE, tags.first().cloned()),
+    ];
+    for (pool, value) in extras.into_iter().take(sentences.saturating_sub(1)) {
+        let Some(value) = value else {
+            continue;
+        };
+        let template = pick_unused_template(pool, seed, &mut used);
+        line.push(' ');
+        line.push_str(&template.replace("{delta}", &value).replace("{issue}", &value));
+    }
     if has_drift {
         line.push_str(" Drift alerts are active.");
     }
@@ -3154,6 +8237,65 @@ fn build_commentary_line(
     Some(line)
 }
 
+const COMMENTARY_DECLINE: &[&str] = &[
+    "This week’s voting record earned a {grade}—not exactly a masterclass in restraint.",
+    "A {grade} this week. The numbers did the talking.",
+    "Scores slid to {grade}; the rubric isn’t feeling inspired.",
+];
+
+const COMMENTARY_RISE: &[&str] = &[
+    "Solid climb to a {grade}; keep it up and the trend becomes a pattern.",
+    "A jump to {grade}. Momentum looks real this week.",
+    "Score gains landed at {grade}; credit where it’s due.",
+];
+
+const COMMENTARY_STEADY: &[&str] = &[
+    "Steady at {grade}; the next votes will decide the direction.",
+    "Holding at {grade}. Consistency is the story for now.",
+    "No major shifts: {grade} with room to move.",
+];
+
+const COMMENTARY_TREND_DETAIL: &[&str] = &[
+    "That is a {delta}-point move from last week.",
+    "Week over week, the score moved {delta} points.",
+    "Compared with the prior report: {delta} points.",
+];
+
+const COMMENTARY_TOP_ISSUE: &[&str] = &[
+    "Most of the scored votes touched {issue}.",
+    "{issue} carried the most weight in this week's record.",
+    "Watch {issue}; it led this week's scored votes.",
+];
+
+/// Template at `seed` (modulo the pool size), or the next one along that `used` doesn't hold yet.
+fn pick_unused_template(
+    pool: &'static [&'static str],
+    seed: u64,
+    used: &mut Vec<&'static str>,
+) -> &'static str {
+    let start = (seed % pool.len() as u64) as usize;
+    let template = (0..pool.len())
+        .map(|offset| pool[(start + offset) % pool.len()])
+        .find(|template| !used.contains(template))
+        .unwrap_or(pool[start]);
+    used.push(template);
+    template
+}
+
+/// A drop of 10+ points or a lower letter reads as a decline, a matching rise as a climb;
+/// anything else is steady.
+fn commentary_templates(grade: &str, prior_grade: &str, delta: f64) -> &'static [&'static str] {
+    let grade_drop = grade_rank(prior_grade) - grade_rank(grade);
+    let grade_rise = grade_rank(grade) - grade_rank(prior_grade);
+    if delta <= -10.0 || grade_drop >= 1 {
+        COMMENTARY_DECLINE
+    } else if delta >= 10.0 || grade_rise >= 1 {
+        COMMENTARY_RISE
+    } else {
+        COMMENTARY_STEADY
+    }
+}
+
 fn stable_hash(value: &str) -> u64 {
     let mut hash: u64 = 14695981039346656037;
     for byte in value.as_bytes() {
@@ -3163,49 +8305,18 @@ fn stable_hash(value: &str) -> u64 {
     hash
 }
 
-fn score_to_grade(score: f64) -> (f64, String) {
-    let numeric = score.clamp(0.0, 100.0);
-    let grade = match numeric {
-        n if n >= 97.0 => "A+",
-        n if n >= 93.0 => "A",
-        n if n >= 90.0 => "A-",
-        n if n >= 87.0 => "B+",
-        n if n >= 83.0 => "B",
-        n if n >= 80.0 => "B-",
-        n if n >= 77.0 => "C+",
-        n if n >= 73.0 => "C",
-        n if n >= 70.0 => "C-",
-        n if n >= 67.0 => "D+",
-        n if n >= 63.0 => "D",
-        n if n >= 60.0 => "D-",
-        _ => "F",
-    };
-    (numeric, grade.to_string())
-}
-
-fn grade_rank(grade: &str) -> i32 {
-    match grade {
-        "A+" => 12,
-        "A" => 11,
-        "A-" => 10,
-        "B+" => 9,
-        "B" => 8,
-        "B-" => 7,
-        "C+" => 6,
-        "C" => 5,
-        "C-" => 4,
-        "D+" => 3,
-        "D" => 2,
-        "D-" => 1,
-        _ => 0,
-    }
-}
-
 struct OfficialSummaryBuilder {
     id: String,
     name: String,
     overall_scores: Vec<f64>,
     axis_scores: Vec<HashMap<String, f64>>,
+    contributions: Vec<HashMap<String, f64>>,
+    /// Each score's `axis_weights` snapshot.
+    axis_weights: Vec<HashMap<String, f64>>,
+    constitution_refs: HashMap<String, Vec<String>>,
+    weights: Vec<f64>,
+    /// Pseudo-scores for missed meetings under `scoring.absence_grade_penalty`.
+    absence_scores: Vec<f64>,
     receipts: Vec<Receipt>,
     insufficient: bool,
     top_issue_tags: Vec<String>,
@@ -3229,19 +8340,48 @@ impl OfficialSummaryBuilder {
             name: name.to_string(),
             overall_scores: Vec::new(),
             axis_scores: Vec::new(),
+            contributions: Vec::new(),
+            axis_weights: Vec::new(),
+            constitution_refs: HashMap::new(),
+            weights: Vec::new(),
+            absence_scores: Vec::new(),
             receipts: Vec::new(),
             insufficient: false,
             top_issue_tags,
         }
     }
 
+    fn add_constitution_refs(&mut self, refs: ConstitutionRefs) {
+        for (axis, axis_refs) in refs {
+            let merged = self.constitution_refs.entry(axis).or_default();
+            for value in axis_refs {
+                if !merged.contains(&value) {
+                    merged.push(value);
+                }
+            }
+        }
+    }
+
     fn build(
         self,
         rubric_config: Option<&civic_core::scoring::RubricConfig>,
         drift_flags: &[String],
     ) -> OfficialSummary {
-        let average_score = average(&self.overall_scores);
-        let axis_scores = average_axis_scores(&self.axis_scores);
+        let (scores, weights) =
+            with_absences(&self.overall_scores, &self.weights, &self.absence_scores);
+        let average_score = weighted_average(&scores, &weights);
+        let axis_scores = average_axis_scores(&self.axis_scores, &self.weights);
+        let contributions = average_contributions(&self.contributions, &self.weights);
+        let applied_scores = average_contributions(&self.axis_scores, &self.weights);
+        let weights_applied = average_axis_scores(&self.axis_weights, &self.weights)
+            .into_iter()
+            .map(|(axis, weight)| {
+                let score = applied_scores.get(&axis).copied().unwrap_or(0.0);
+                (axis, (score, weight))
+            })
+            .collect::<HashMap<_, _>>();
+        let weighted_sum = weights_applied.values().map(|(score, weight)| score * weight).sum();
+        let weighted_overall = normalize_score(weighted_sum, rubric_config);
         let axis_scores_normalized = axis_scores
             .iter()
             .map(|(axis, score)| (axis.clone(), normalize_score(*score, rubric_config)))
@@ -3257,13 +8397,20 @@ impl OfficialSummaryBuilder {
             id: self.id,
             name: self.name,
             average_score,
-            axis_scores,
             axis_scores_normalized,
+            contributions,
+            weights_applied,
+            weighted_overall,
+            constitution_refs: self.constitution_refs,
             letter_grade,
             numeric_grade,
             delta: 0.0,
+            trend: Trend::Steady,
             drift_flags: drift,
+            drift_details: Vec::new(),
             insufficient: self.insufficient,
+            vote_count: self.overall_scores.len(),
+            provisional: false,
             receipts: self.receipts,
             top_issue_tags: self.top_issue_tags,
             commentary: None,
@@ -3271,58 +8418,3085 @@ impl OfficialSummaryBuilder {
     }
 }
 
-fn normalize_score(score: f64, rubric_config: Option<&civic_core::scoring::RubricConfig>) -> f64 {
-    let Some(config) = rubric_config else {
-        return score.clamp(0.0, 100.0);
-    };
-    let floor = config.general.score_floor;
-    let ceiling = config.general.score_ceiling;
-    if (ceiling - floor).abs() < f64::EPSILON {
-        return config.general.neutral_score;
+fn average_axis_scores(values: &[HashMap<String, f64>], weights: &[f64]) -> HashMap<String, f64> {
+    let mut totals: HashMap<String, (Vec<f64>, Vec<f64>)> = HashMap::new();
+    for (map, weight) in values.iter().zip(weights) {
+        for (axis, value) in map {
+            let entry = totals.entry(axis.clone()).or_default();
+            entry.0.push(*value);
+            entry.1.push(*weight);
+        }
+    }
+    let mut averages = HashMap::new();
+    for (axis, (scores, axis_weights)) in totals {
+        averages.insert(axis, weighted_average(&scores, &axis_weights));
     }
-    let normalized = ((score - floor) / (ceiling - floor)) * 100.0;
-    normalized.clamp(0.0, 100.0)
+    averages
 }
 
-fn average_axis_scores(values: &[HashMap<String, f64>]) -> HashMap<String, f64> {
-    let mut totals: HashMap<String, Vec<f64>> = HashMap::new();
-    for map in values {
+/// Unlike `average_axis_scores`, a vote that did not touch an axis counts as a zero
+/// contribution, so the averages still sum to the weighted average overall score.
+fn average_contributions(
+    values: &[HashMap<String, f64>],
+    weights: &[f64],
+) -> HashMap<String, f64> {
+    let total_weight: f64 = weights.iter().sum();
+    if total_weight <= 0.0 {
+        return HashMap::new();
+    }
+    let mut totals: HashMap<String, f64> = HashMap::new();
+    for (map, weight) in values.iter().zip(weights) {
         for (axis, value) in map {
-            totals.entry(axis.clone()).or_default().push(*value);
+            *totals.entry(axis.clone()).or_default() += value * weight;
         }
     }
-    let mut averages = HashMap::new();
-    for (axis, scores) in totals {
-        averages.insert(axis, average(&scores));
+    for value in totals.values_mut() {
+        *value /= total_weight;
     }
-    averages
+    totals
 }
 
-fn is_issue_tag(tag: &str) -> bool {
-    const ISSUE_TAGS: &[&str] = &[
-        "zoning",
-        "rezoning",
-        "variance",
-        "planning_commission",
-        "budget",
-        "tax",
-        "bond",
-        "appropriation",
-        "contract",
-        "bid",
-        "procurement",
-        "election",
-        "clerk",
-        "ballot",
-        "school_board",
-        "curriculum",
-        "policy",
-        "lawsuit",
-        "settlement",
-        "ordinance",
-        "public_safety",
-        "land_sale",
-        "eminent_domain",
-    ];
-    ISSUE_TAGS.iter().any(|issue| *issue == tag)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn novel_rubric_axis_reaches_official_detail_page() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let dir = std::env::temp_dir().join(format!("larue-novel-axis-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for entry in fs::read_dir(&repo).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_file() {
+                fs::copy(&path, dir.join(path.file_name().unwrap())).unwrap();
+            }
+        }
+        let weights = fs::read_to_string(dir.join("weights.yaml")).unwrap();
+        let weights = weights
+            .replace("axis_weights:\n", "axis_weights:\n  water_quality: 1.0\n")
+            .replace("axis_tags:\n", "axis_tags:\n  water_quality: [water_main]\n");
+        fs::write(dir.join("weights.yaml"), weights).unwrap();
+        let rubric = Rubric::load_from_dir(&dir).unwrap();
+        assert!(rubric.axes.iter().any(|axis| axis == "water_quality"));
+
+        let linked = [LinkedArtifact {
+            id: "a1".to_string(),
+            tags: vec!["water_main".to_string()],
+            retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            content_type: None,
+            body_text: None,
+            source_kind: "url".to_string(),
+            trusted: true,
+            doc_type: DocType::Other,
+        }];
+        let score = civic_core::scoring::compute_motion_score(
+            "Replace the Main Street water main",
+            &linked,
+            "2024-03-04T18:00:00Z",
+            &rubric,
+        );
+        assert!(score.axis_scores.contains_key("water_quality"));
+
+        let mut builder = OfficialSummaryBuilder::new("Jane Smith", None, "2024-03-04");
+        builder.overall_scores.push(score.overall_score);
+        builder.axis_scores.push(score.axis_scores);
+        builder.weights.push(1.0);
+        let official = builder.build(Some(&rubric.config), &[]);
+        let html = render_official_detail(
+            &official,
+            &rubric.axes,
+            "2024-03-04",
+            DEFAULT_DISCLAIMER,
+            &SiteTemplates::default(),
+            1,
+        );
+        assert!(html.contains("<td>water_quality</td>"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn link_minutes_creates_and_links_minutes_artifact() {
+        let dir = std::env::temp_dir().join(format!("larue-link-minutes-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let meeting_path = dir.join("meeting.json");
+        let meeting = serde_json::json!({
+            "id": "fc-2024-03-04",
+            "body_id": "larue-fiscal-court",
+            "started_at": "2024-03-04T18:00:00Z",
+            "artifact_ids": ["agenda-1"],
+            "motions": [],
+            "parent_meeting_id": null,
+            "source": {
+                "kind": "url",
+                "value": "https://example.com/minutes/2024-03-04.pdf",
+                "retrieved_at": "2024-03-06T12:00:00Z"
+            }
+        });
+        fs::write(&meeting_path, meeting.to_string()).unwrap();
+        let db_path = dir.join("civic.db");
+        let db = db_path.to_str().unwrap();
+
+        let storage = ArtifactStorage {
+            store_raw_json: false,
+            ..ArtifactStorage::default()
+        };
+
+        ingest_meeting(meeting_path, db, storage, true, false).unwrap();
+
+        let conn = civic_core::db::open(db).unwrap();
+        assert!(civic_core::db::artifact_exists(&conn, "fc-2024-03-04-minutes").unwrap());
+        // The minutes artifact honors the configured storage settings.
+        let raw_json: String = conn
+            .query_row(
+                "SELECT raw_json FROM artifacts WHERE id = 'fc-2024-03-04-minutes'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(raw_json, "");
+        let artifact_ids_json: String = conn
+            .query_row(
+                "SELECT artifact_ids_json FROM meetings WHERE id = 'fc-2024-03-04'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        let artifact_ids: Vec<String> = serde_json::from_str(&artifact_ids_json).unwrap();
+        assert_eq!(artifact_ids, vec!["agenda-1", "fc-2024-03-04-minutes"]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn native_tagger_is_idempotent_and_honors_force() {
+        use civic_core::scoring::artifact_importance;
+
+        let path = std::env::temp_dir().join(format!("larue-tag-native-{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let conn = civic_core::db::open(path.to_str().unwrap()).unwrap();
+        let artifact = serde_json::json!({
+            "id": "notice-1",
+            "source": {
+                "kind": "url",
+                "value": "https://example.com/notice-1",
+                "retrieved_at": "2024-03-01T00:00:00Z"
+            },
+            "title": "Invitation to Bid",
+            "body_text": "Sealed bids for the road contract are due Friday.",
+            "tags": ["public_notice", "contract"]
+        });
+        ingest_artifact_json(&conn, artifact, ArtifactStorage::default(), None).unwrap();
+        let stored_tags = || -> Vec<String> {
+            let tags_json: String = conn
+                .query_row("SELECT tags_json FROM artifacts WHERE id = 'notice-1'", [], |row| {
+                    row.get(0)
+                })
+                .unwrap();
+            serde_json::from_str(&tags_json).unwrap()
+        };
+        let rules = |tags: &[&str]| {
+            TagRules::from_map(
+                tags.iter()
+                    .map(|tag| (tag.to_string(), vec![tag.to_string()]))
+                    .collect(),
+            )
+            .unwrap()
+        };
+
+        let importance = || -> f64 {
+            conn.query_row("SELECT importance FROM artifacts WHERE id = 'notice-1'", [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+        };
+        let body = "Sealed bids for the road contract are due Friday.";
+
+        let first = retag_artifacts(&conn, &rules(&["bid", "tax"]), false).unwrap();
+        assert_eq!((first.processed, first.tagged), (1, 1));
+        assert_eq!(stored_tags(), vec!["public_notice", "contract", "bid", ISSUE_TAG_MARKER]);
+        assert_eq!(importance(), artifact_importance(&stored_tags(), Some(body)));
+
+        let changed = rules(&["contract", "tax"]);
+        let again = retag_artifacts(&conn, &changed, false).unwrap();
+        assert_eq!((again.processed, again.skipped), (0, 1));
+        assert_eq!(stored_tags(), vec!["public_notice", "contract", "bid", ISSUE_TAG_MARKER]);
+
+        // --force replaces the earlier run's `bid`; the collector's `contract` stays either way.
+        let forced = retag_artifacts(&conn, &changed, true).unwrap();
+        assert_eq!((forced.processed, forced.forced), (1, 1));
+        assert_eq!(stored_tags(), vec!["public_notice", "contract", ISSUE_TAG_MARKER]);
+        assert_eq!(importance(), artifact_importance(&stored_tags(), Some(body)));
+        retag_artifacts(&conn, &rules(&["tax"]), true).unwrap();
+        assert_eq!(stored_tags(), vec!["public_notice", "contract", ISSUE_TAG_MARKER]);
+
+        drop(conn);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn strict_links_rejects_meeting_with_unknown_artifact() {
+        let dir = std::env::temp_dir().join(format!("larue-strict-links-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let meeting_path = dir.join("meeting.json");
+        let meeting = serde_json::json!({
+            "id": "fc-2024-03-04",
+            "body_id": "larue-fiscal-court",
+            "started_at": "2024-03-04T18:00:00Z",
+            "artifact_ids": ["agenda-missing"],
+            "motions": [],
+            "parent_meeting_id": null
+        });
+        fs::write(&meeting_path, meeting.to_string()).unwrap();
+        let db_path = dir.join("civic.db");
+        let db = db_path.to_str().unwrap();
+
+        let err = ingest_meeting(meeting_path.clone(), db, ArtifactStorage::default(), false, true)
+            .unwrap_err();
+        assert!(err.to_string().contains("agenda-missing"));
+        let conn = civic_core::db::open(db).unwrap();
+        assert!(!civic_core::db::meeting_exists(&conn, "fc-2024-03-04").unwrap());
+
+        ingest_meeting(meeting_path, db, ArtifactStorage::default(), false, false).unwrap();
+        assert_eq!(
+            civic_core::db::dangling_artifact_refs(&conn).unwrap(),
+            vec![("fc-2024-03-04".to_string(), "agenda-missing".to_string())]
+        );
+        assert!(doctor(db).is_err());
+
+        drop(conn);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn flags_are_grouped_by_name_and_filtered() {
+        let score = |vote: &str| FlaggedScore {
+            meeting_id: Some("m1".to_string()),
+            motion_id: Some("m1-a".to_string()),
+            vote_id: Some(vote.to_string()),
+        };
+        let rows = vec![
+            (vec!["insufficient_evidence".to_string()], score("v1")),
+            (
+                vec!["insufficient_evidence".to_string(), "votes_missing".to_string()],
+                score("v2"),
+            ),
+        ];
+
+        let grouped = group_flags(rows.clone(), None);
+        let names: Vec<&str> = grouped.keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["insufficient_evidence", "votes_missing"]);
+        assert_eq!(grouped["insufficient_evidence"], vec![score("v1"), score("v2")]);
+
+        let only = group_flags(rows, Some("votes_missing"));
+        assert_eq!(only.len(), 1);
+        assert_eq!(only["votes_missing"], vec![score("v2")]);
+    }
+
+    /// DB with meeting `m1` (2024-03-04) and its motion `m1-a`.
+    fn meeting_db(name: &str) -> (PathBuf, rusqlite::Connection) {
+        use civic_core::schema::{DecisionMeeting, DecisionMotion};
+
+        let path = std::env::temp_dir().join(format!("larue-{name}-{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let conn = civic_core::db::open(path.to_str().unwrap()).unwrap();
+        let motion = DecisionMotion {
+            id: "m1-a".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 0,
+            text: "Approve the road contract".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        };
+        let meeting = DecisionMeeting {
+            id: "m1".to_string(),
+            body_id: "larue-fiscal-court".to_string(),
+            body_name: None,
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: Vec::new(),
+            parent_meeting_id: None,
+            presiding_officer: None,
+        };
+        civic_core::db::upsert_decision_meeting(
+            &conn,
+            &meeting,
+            &serde_json::json!({}),
+            std::slice::from_ref(&motion),
+        )
+        .unwrap();
+        civic_core::db::upsert_motion(&conn, &motion, &serde_json::json!({})).unwrap();
+        (path, conn)
+    }
+
+    fn store_vote_score(
+        conn: &rusqlite::Connection,
+        id: &str,
+        motion_id: &str,
+        overall_score: f64,
+        evidence: Vec<String>,
+    ) {
+        let score = DecisionScore {
+            id: id.to_string(),
+            meeting_id: Some("m1".to_string()),
+            motion_id: Some(motion_id.to_string()),
+            vote_id: Some(format!("vote-{id}")),
+            overall_score,
+            axis_scores: HashMap::new(),
+            contributions: HashMap::new(),
+            axis_weights: HashMap::new(),
+            constitutional_refs: ConstitutionRefs::new(),
+            evidence,
+            confidence: 0.5,
+            flags: Vec::new(),
+            computed_at: "2024-03-05T00:00:00Z".to_string(),
+            rubric_version: None,
+        };
+        civic_core::db::upsert_decision_score(conn, &score).unwrap();
+    }
+
+    const WINDOW: (&str, &str) = ("2024-02-27T00:00:00Z", "2024-03-06T00:00:00Z");
+
+    /// A config in a fresh temp dir whose `out_dir/decisions` holds each bundle as
+    /// `<file>.json`, for driving `ingest_decisions`. Returns the dir and the config path.
+    fn decisions_config(name: &str, bundles: &[(&str, serde_json::Value)]) -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("larue-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let decisions = dir.join("out").join("decisions");
+        fs::create_dir_all(&decisions).unwrap();
+        for (file, bundle) in bundles {
+            fs::write(decisions.join(format!("{file}.json")), bundle.to_string()).unwrap();
+        }
+        let config_path = dir.join("config.toml");
+        fs::write(
+            &config_path,
+            format!(
+                "[storage]\ndb_path = {:?}\nout_dir = {:?}\n",
+                dir.join("civic.db").display().to_string(),
+                dir.join("out").display().to_string(),
+            ),
+        )
+        .unwrap();
+        (dir, config_path)
+    }
+
+    fn decision_meeting_json(id: &str, started_at: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "body_id": "larue-fiscal-court",
+            "started_at": started_at,
+            "artifact_ids": [],
+        })
+    }
+
+    fn decision_vote_json(id: &str, motion_id: &str, ayes: &[&str]) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "motion_id": motion_id,
+            "ayes": ayes,
+            "nays": [],
+            "abstain": [],
+        })
+    }
+
+    #[test]
+    fn ingested_public_comments_render_on_the_meeting_note() {
+        let with_comments = serde_json::json!({
+            "meeting": decision_meeting_json("full-1", "2024-03-04T18:00:00Z"),
+            "motions": [{
+                "id": "full-1-motion-0", "meeting_id": "full-1", "index": 0,
+                "text": "Approve road paving contract", "result": "passed",
+            }],
+            "votes": [],
+            "public_comments": [{
+                "id": "full-1-comment-0",
+                "motion_id": "full-1-motion-0",
+                "speaker": "Resident",
+                "stance": "against",
+                "summary": "Asked for a second bid",
+            }],
+        });
+        // Bundles from collectors that predate public comments omit the key entirely.
+        let without_comments = serde_json::json!({
+            "meeting": decision_meeting_json("full-2", "2024-03-11T18:00:00Z"),
+            "motions": [],
+            "votes": [],
+        });
+        let (dir, config_path) = decisions_config(
+            "public-comments",
+            &[("full-1", with_comments), ("full-2", without_comments)],
+        );
+
+        ingest_decisions(config_path, false).unwrap();
+
+        let conn = civic_core::db::open(dir.join("civic.db").to_str().unwrap()).unwrap();
+        assert!(civic_core::db::meeting_exists(&conn, "full-2").unwrap());
+        let vault_root = dir.join("vault");
+        obsidian::vault::build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, None)
+            .unwrap();
+        let note = fs::read_to_string(
+            vault_root.join("Meetings/2024-03-04-larue-fiscal-court.md"),
+        )
+        .unwrap();
+        assert!(
+            note.contains(
+                "## Public Comment\n- Resident (against): Asked for a second bid \
+                 — on \"Approve road paving contract\"\n"
+            ),
+            "{note}"
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn votes_on_motions_stored_by_an_earlier_bundle_are_kept() {
+        let first = serde_json::json!({
+            "meeting": decision_meeting_json("m1", "2024-03-04T18:00:00Z"),
+            "motions": [{
+                "id": "m1-a", "meeting_id": "m1", "index": 0,
+                "text": "Approve the road contract",
+            }],
+            "votes": [],
+            "public_comments": [],
+        });
+        // A later bundle records a roll call on m1-a plus a vote whose motion exists nowhere.
+        let second = serde_json::json!({
+            "meeting": decision_meeting_json("m2", "2024-03-11T18:00:00Z"),
+            "motions": [],
+            "votes": [
+                decision_vote_json("v-late", "m1-a", &["Smith"]),
+                decision_vote_json("v-lost", "m1-missing", &["Smith"]),
+            ],
+            "public_comments": [],
+        });
+        let (dir, config_path) =
+            decisions_config("late-votes", &[("a-first", first), ("b-second", second)]);
+
+        ingest_decisions(config_path, false).unwrap();
+        let conn = civic_core::db::open(dir.join("civic.db").to_str().unwrap()).unwrap();
+        let votes = conn
+            .prepare("SELECT id FROM votes ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get::<_, String>(0))
+            .unwrap()
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(votes, vec!["v-late".to_string()]);
+
+        drop(conn);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn average_can_leave_out_insufficient_evidence_scores() {
+        let (path, conn) = meeting_db("exclude-insufficient");
+        let second = civic_core::schema::DecisionMotion {
+            id: "m1-b".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 1,
+            text: "Accept the donation".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        };
+        civic_core::db::upsert_motion(&conn, &second, &serde_json::json!({})).unwrap();
+        for (motion_id, overall_score, flags) in
+            [("m1-a", 80.0, vec![]), ("m1-b", 50.0, vec!["insufficient_evidence".to_string()])]
+        {
+            let score = DecisionScore {
+                id: format!("motion:{motion_id}"),
+                meeting_id: Some("m1".to_string()),
+                motion_id: Some(motion_id.to_string()),
+                vote_id: None,
+                overall_score,
+                axis_scores: HashMap::new(),
+                contributions: HashMap::new(),
+                axis_weights: HashMap::new(),
+                constitutional_refs: ConstitutionRefs::new(),
+                evidence: Vec::new(),
+                confidence: 0.0,
+                flags,
+                computed_at: "2024-03-05T00:00:00Z".to_string(),
+                rubric_version: None,
+            };
+            civic_core::db::upsert_decision_score(&conn, &score).unwrap();
+        }
+        let window = resolve_window(Some("2024-03-05".into()), &ReportingZone::utc()).unwrap();
+
+        let all = load_score_summary(&conn, &window, 3, false).unwrap();
+        assert_eq!(all.average_score, 65.0);
+        let excluded = load_score_summary(&conn, &window, 3, true).unwrap();
+        assert_eq!(excluded.average_score, 80.0);
+        for summary in [&all, &excluded] {
+            assert_eq!(summary.total_scored, 2);
+            assert_eq!(summary.insufficient_count, 1);
+        }
+        assert_eq!(excluded.to_json()["average_excludes_insufficient"], true);
+
+        let config: Config =
+            toml::from_str("[reporting]\nexclude_insufficient_from_average = true\n").unwrap();
+        assert!(resolve_exclude_insufficient(Some(&config)));
+        assert!(!resolve_exclude_insufficient(None));
+        drop(conn);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn ndjson_export_round_trips_typed_records() {
+        let (path, conn) = meeting_db("ndjson");
+        let score = DecisionScore {
+            id: "s1".to_string(),
+            meeting_id: Some("m1".to_string()),
+            motion_id: Some("m1-a".to_string()),
+            vote_id: None,
+            overall_score: -1.5,
+            axis_scores: HashMap::from([("fiscal_restraint".to_string(), -1.5)]),
+            contributions: HashMap::from([("fiscal_restraint".to_string(), -1.5)]),
+            axis_weights: HashMap::from([("fiscal_restraint".to_string(), 1.0)]),
+            constitutional_refs: vec![(
+                "fiscal_restraint".to_string(),
+                vec!["US Amendment 16".to_string()],
+            )],
+            evidence: vec!["tag:contract".to_string(), "official:Smith".to_string()],
+            confidence: 0.6,
+            flags: vec!["close_vote".to_string()],
+            computed_at: "2024-03-05T00:00:00Z".to_string(),
+            rubric_version: Some("abc123".to_string()),
+        };
+        civic_core::db::upsert_decision_score(&conn, &score).unwrap();
+        let artifact: civic_core::schema::Artifact = serde_json::from_value(serde_json::json!({
+            "id": "a1",
+            "source": {"kind": "url", "value": "https://example.org/a1",
+                       "retrieved_at": "2024-03-01T00:00:00Z"},
+            "title": "Agenda",
+            "body_text": "Road contract",
+            "content_type": "text/html",
+            "tags": ["contract", "roads"],
+            "supersedes": ["a0"],
+            "language": "es",
+            "doc_type": "agenda",
+        }))
+        .unwrap();
+        civic_core::db::upsert_artifact(&conn, &artifact, &serde_json::json!({})).unwrap();
+
+        let export = |table| {
+            let mut out = Vec::new();
+            let written = write_ndjson(&conn, table, &mut out).unwrap();
+            let text = String::from_utf8(out).unwrap();
+            assert_eq!(text.lines().count(), written);
+            assert!(text.ends_with('\n'));
+            text
+        };
+        let scores = export(NdjsonTable::Scores)
+            .lines()
+            .map(|line| serde_json::from_str::<DecisionScore>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(scores, vec![score]);
+
+        let artifacts = export(NdjsonTable::Artifacts);
+        let parsed: civic_core::schema::Artifact = serde_json::from_str(artifacts.trim()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&artifact).unwrap()
+        );
+
+        let meetings = export(NdjsonTable::Meetings)
+            .lines()
+            .map(|line| serde_json::from_str::<civic_core::schema::Meeting>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].id, "m1");
+        assert_eq!(meetings[0].motions[0].text, "Approve the road contract");
+        assert_eq!(meetings[0].motions[0].result.as_deref(), Some("passed"));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn relationship_graph_links_bodies_through_officials_and_artifacts() {
+        let (path, conn) = meeting_db("graph");
+        let artifact = |id: &str, title: &str| {
+            serde_json::json!({
+                "id": id,
+                "source": {"kind": "url", "value": format!("https://example.org/{id}"),
+                           "retrieved_at": "2024-03-01T00:00:00Z"},
+                "title": title,
+                "tags": []
+            })
+        };
+        for (id, title) in [("a1", "Agenda"), ("a2", "Unlinked \"notice\"")] {
+            ingest_artifact_json(&conn, artifact(id, title), ArtifactStorage::default(), None)
+                .unwrap();
+        }
+        let meeting = civic_core::schema::DecisionMeeting {
+            id: "m1".to_string(),
+            body_id: "larue-fiscal-court".to_string(),
+            body_name: None,
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: vec!["a1".to_string(), "missing".to_string()],
+            parent_meeting_id: None,
+            presiding_officer: None,
+        };
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &serde_json::json!({}), &[])
+            .unwrap();
+        let vote = civic_core::schema::DecisionVote {
+            id: "v1".to_string(),
+            motion_id: "m1-a".to_string(),
+            vote_type: None,
+            outcome: Some("passed".to_string()),
+            ayes: vec!["Adams".to_string(), "Baker".to_string()],
+            nays: vec!["Clark".to_string()],
+            abstain: Vec::new(),
+        };
+        civic_core::db::upsert_vote(&conn, &vote, &serde_json::json!({})).unwrap();
+
+        let graph = build_relationship_graph(&conn).unwrap();
+        // 1 body, 1 meeting, 1 motion, 1 vote, 3 officials, 2 artifacts.
+        assert_eq!(graph.nodes.len(), 9);
+        // held, considered, voted, 3 choices, and the one ingested artifact link.
+        assert_eq!(graph.edges.len(), 7);
+        let kinds = |kind: &str| graph.edges.iter().filter(|edge| edge.kind == kind).count();
+        assert_eq!((kinds("aye"), kinds("nay"), kinds("documents")), (2, 1, 1));
+
+        let json = serde_json::to_value(&graph).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 9);
+        assert_eq!(json["edges"].as_array().unwrap().len(), 7);
+        assert!(json.get("seen").is_none());
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph larue {\n") && dot.ends_with("}\n"));
+        assert_eq!(dot.matches(" -> ").count(), 7);
+        assert!(dot.contains("\"vote:v1\" -> \"official:clark\" [label=\"nay\"];"));
+        assert!(dot.contains("[label=\"Unlinked \\\"notice\\\"\", shape=folder];"));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn orphaned_scores_are_excluded_from_summaries_and_averages_alike() {
+        let (path, conn) = meeting_db("orphan");
+        let smith = || vec!["official:Smith".to_string()];
+        store_vote_score(&conn, "s1", "m1-a", 2.0, smith());
+        store_vote_score(&conn, "s2", "gone", -4.0, smith());
+        let scoring = ResolvedScoring::default();
+        let (start, end) = WINDOW;
+
+        let summaries =
+            load_official_summaries(&conn, start, end, None, None, "2024-03-05", &scoring).unwrap();
+        let averages = load_official_averages(&conn, start, end, None, &scoring).unwrap();
+
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].average_score, 2.0);
+        assert_eq!(averages.get("Smith"), Some(&2.0));
+        assert_eq!(
+            civic_core::db::orphaned_scores(&conn).unwrap(),
+            vec![("s2".to_string(), "gone".to_string())]
+        );
+
+        drop(conn);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn procedural_votes_weigh_less_in_official_grades() {
+        let (path, conn) = meeting_db("vote-type");
+        let rules_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_cached(&rules_dir).unwrap();
+        let evidence = |vote_type: &str| {
+            let weight = rubric.scoring_rules.vote_type_weight(Some(vote_type));
+            let mut evidence = vec!["official:Smith".to_string()];
+            evidence.extend(vote_type_weight_evidence(Some(vote_type), weight));
+            evidence
+        };
+        assert_eq!(evidence("final"), vec!["official:Smith"]);
+        assert_eq!(
+            evidence("procedural"),
+            vec!["official:Smith", "vote_type_weight:procedural:0.3"]
+        );
+        // The weight comes from the stored vote_type, not the evidence, so scores written
+        // without the display entry are weighted too.
+        for (id, vote_type, overall) in
+            [("final", "final", 2.0), ("table", "procedural", -4.0), ("voice", "voice", 1.0)]
+        {
+            let vote = civic_core::schema::DecisionVote {
+                id: format!("vote-{id}"),
+                motion_id: "m1-a".to_string(),
+                vote_type: Some(vote_type.to_string()),
+                outcome: None,
+                ayes: vec!["Smith".to_string()],
+                nays: Vec::new(),
+                abstain: Vec::new(),
+            };
+            civic_core::db::upsert_vote(&conn, &vote, &serde_json::json!({})).unwrap();
+            store_vote_score(&conn, id, "m1-a", overall, vec!["official:Smith".to_string()]);
+        }
+        let scoring = ResolvedScoring::default();
+        let (start, end) = WINDOW;
+        let rubric = Some(rubric.as_ref());
+
+        let summaries =
+            load_official_summaries(&conn, start, end, rubric, None, "2024-03-05", &scoring)
+                .unwrap();
+        let averages = load_official_averages(&conn, start, end, rubric, &scoring).unwrap();
+
+        // (2.0 * 1.0 + -4.0 * 0.3 + 1.0 * 1.0) / 2.3
+        let expected = 1.8 / 2.3;
+        assert!((summaries[0].average_score - expected).abs() < 1e-9);
+        assert!((averages["Smith"] - expected).abs() < 1e-9);
+
+        // Without a rubric every vote type counts fully.
+        let unweighted = load_official_averages(&conn, start, end, None, &scoring).unwrap();
+        assert!((unweighted["Smith"] - (-1.0 / 3.0)).abs() < 1e-9);
+
+        drop(conn);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn officials_below_min_votes_are_provisional() {
+        let (path, conn) = meeting_db("min-votes");
+        let evidence = |name: &str| vec![format!("official:{name}")];
+        store_vote_score(&conn, "s1", "m1-a", 2.0, evidence("Smith"));
+        store_vote_score(&conn, "s2", "m1-a", 2.0, evidence("Smith"));
+        store_vote_score(&conn, "j1", "m1-a", 2.0, evidence("Jones"));
+        let (start, end) = WINDOW;
+        let load = |scoring: &ResolvedScoring| {
+            load_official_summaries(&conn, start, end, None, None, "2024-03-05", scoring).unwrap()
+        };
+
+        let badge = ResolvedScoring {
+            min_votes_for_grade: 2,
+            ..ResolvedScoring::default()
+        };
+        let officials = load(&badge);
+        let jones = officials.iter().find(|official| official.name == "Jones").unwrap();
+        let smith = officials.iter().find(|official| official.name == "Smith").unwrap();
+        assert_eq!((jones.vote_count, jones.provisional), (1, true));
+        assert_eq!((smith.vote_count, smith.provisional), (2, false));
+        assert_ne!(jones.letter_grade, WITHHELD_GRADE);
+        let templates = SiteTemplates::default();
+        let html =
+            render_official_detail(jones, &[], "2024-03-05", DEFAULT_DISCLAIMER, &templates, 1);
+        assert!(html.contains("Provisional (1 scored vote)"));
+
+        let withhold = ResolvedScoring {
+            min_votes_for_grade: 2,
+            insufficient_policy: InsufficientPolicy::Withhold,
+            ..ResolvedScoring::default()
+        };
+        let officials = load(&withhold);
+        let jones = officials.iter().find(|official| official.name == "Jones").unwrap();
+        let smith = officials.iter().find(|official| official.name == "Smith").unwrap();
+        assert_eq!(jones.letter_grade, WITHHELD_GRADE);
+        assert_ne!(smith.letter_grade, WITHHELD_GRADE);
+
+        drop(conn);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn absence_penalty_lowers_frequently_absent_officials() {
+        use civic_core::schema::{DecisionMeeting, DecisionMotion};
+
+        let (path, conn) = meeting_db("absences");
+        for day in 2..=3 {
+            let meeting = DecisionMeeting {
+                id: format!("m{day}"),
+                body_id: "larue-fiscal-court".to_string(),
+                body_name: None,
+                started_at: format!("2024-03-0{}T18:00:00Z", day + 2),
+                meeting_type: None,
+                artifact_ids: Vec::new(),
+                parent_meeting_id: None,
+                presiding_officer: None,
+            };
+            let motion = DecisionMotion {
+                id: format!("m{day}-a"),
+                meeting_id: meeting.id.clone(),
+                index: 0,
+                text: "Approve the minutes".to_string(),
+                moved_by: None,
+                seconded_by: None,
+                result: Some("passed".to_string()),
+                amended: false,
+                amendment_text: None,
+                agenda_ref: None,
+            };
+            let raw = serde_json::json!({});
+            civic_core::db::upsert_decision_meeting(&conn, &meeting, &raw, &[]).unwrap();
+            civic_core::db::upsert_motion(&conn, &motion, &raw).unwrap();
+        }
+        let evidence = |name: &str| vec![format!("official:{name}")];
+        for motion in ["m1-a", "m2-a", "m3-a"] {
+            store_vote_score(&conn, &format!("p-{motion}"), motion, 40.0, evidence("Present"));
+        }
+        store_vote_score(&conn, "a-m1-a", "m1-a", 40.0, evidence("Absent"));
+        let (start, end) = WINDOW;
+        let load = |scoring: &ResolvedScoring| {
+            load_official_summaries(&conn, start, end, None, None, "2024-03-05", scoring)
+                .unwrap()
+                .into_iter()
+                .map(|official| (official.name, official.average_score, official.vote_count))
+                .collect::<Vec<_>>()
+        };
+
+        // Off by default: the absent official is graded on the one vote they cast.
+        assert_eq!(
+            load(&ResolvedScoring::default()),
+            vec![("Absent".to_string(), 40.0, 1), ("Present".to_string(), 40.0, 3)]
+        );
+        let penalized = ResolvedScoring {
+            absence_grade_penalty: Some(20.0),
+            ..ResolvedScoring::default()
+        };
+        assert_eq!(
+            load(&penalized),
+            vec![("Present".to_string(), 40.0, 3), ("Absent".to_string(), 0.0, 1)]
+        );
+        let averages = load_official_averages(&conn, start, end, None, &penalized).unwrap();
+        assert_eq!(averages["Absent"], 0.0);
+        assert_eq!(averages["Present"], 40.0);
+
+        drop(conn);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn scored_official_gets_a_vault_note() {
+        let (path, conn) = meeting_db("official-notes");
+        store_vote_score(&conn, "s1", "m1-a", 2.0, vec!["official:Smith".to_string()]);
+        let scoring = ResolvedScoring::default();
+        let (start, end) = WINDOW;
+        let reports = vec![build_placeholder_report("2024-03-04", start, end)];
+
+        let notes = official_notes(&conn, &reports, None, &scoring).unwrap();
+        let vault = std::env::temp_dir()
+            .join(format!("larue-official-notes-vault-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&vault);
+        obsidian::vault::write_official_notes(&vault, &VaultLayout::default(), &notes).unwrap();
+
+        let note = fs::read_to_string(vault.join("Officials/smith.md")).unwrap();
+        assert!(note.contains("# Smith\n"));
+        assert!(note.contains(&format!("- Grade: **{}**", notes[0].letter_grade)));
+        assert!(note.contains("- 2024-03-04T18:00:00Z: Approve the road contract"));
+        let moc = fs::read_to_string(vault.join("00_Index/MOC - Officials.md")).unwrap();
+        assert!(moc.contains("- [[Officials/smith|Smith]]"));
+
+        drop(conn);
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_dir_all(&vault);
+    }
+
+    #[test]
+    fn window_spec_binds_utc_bounds_of_local_week() {
+        let zone = ReportingZone::from_name("America/Chicago").expect("zone");
+        let window = resolve_window(Some("2024-03-04".to_string()), &zone).expect("window");
+
+        assert_eq!(window.date, "2024-03-04");
+        assert_eq!(
+            window.bounds().expect("bounds"),
+            (
+                "2024-02-27T06:00:00Z".to_string(),
+                "2024-03-05T06:00:00Z".to_string()
+            )
+        );
+
+        // Without --date the window is the local week ending today, not a rolling 7x24h span.
+        let current = resolve_window(None, &zone).expect("window");
+        let dated = resolve_window(Some(current.date.clone()), &zone).expect("window");
+        assert_eq!(current.bounds().expect("bounds"), dated.bounds().expect("bounds"));
+        assert_eq!(zone.to_local(current.end).time(), time::Time::MIDNIGHT);
+    }
+
+    #[test]
+    fn week_page_escapes_public_comment_markup() {
+        let mut report = build_placeholder_report("2024-03-04", WINDOW.0, WINDOW.1);
+        report.decisions.push(WeekDecision {
+            body_name: "LaRue Fiscal Court".to_string(),
+            motions: vec![WeekMotion {
+                text: "Approve the road contract".to_string(),
+                result: Some("passed".to_string()),
+                amended: false,
+                vote_label: None,
+                tie_broken_by: None,
+                public_comments: vec![PublicComment {
+                    id: "m1-comment-0".to_string(),
+                    motion_id: "m1-a".to_string(),
+                    speaker: Some("<b>Resident</b>".to_string()),
+                    stance: Some("against & opposed".to_string()),
+                    summary: "<script>alert(1)</script>".to_string(),
+                }],
+            }],
+            evidence_coverage: None,
+        });
+        let zone = ReportingZone::from_name("America/Chicago").expect("zone");
+
+        let html =
+            render_week_page(&report, "2024-03-04", &zone, "", &SiteTemplates::default(), 2);
+
+        assert!(html.contains(
+            "<li>&lt;b&gt;Resident&lt;/b&gt; <span class=\"badge\">against &amp; opposed</span>: \
+             &lt;script&gt;alert(1)&lt;/script&gt;"
+        ));
+        assert!(!html.contains("<script>"));
+    }
+
+    #[test]
+    fn signed_site_export_verifies_until_a_file_is_tampered_with() {
+        let dir = std::env::temp_dir().join(format!("larue-sign-site-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.toml");
+        fs::write(
+            &config_path,
+            format!(
+                "[storage]\ndb_path = {:?}\nout_dir = {:?}\n\n[site]\nsign = true\n",
+                dir.join("civic.db").display().to_string(),
+                dir.join("out").display().to_string(),
+            ),
+        )
+        .unwrap();
+
+        export_site(config_path, None, None, None).unwrap();
+        let site_dir = dir.join("out").join("site");
+        let manifest = fs::read_to_string(site_dir.join(SITE_MANIFEST)).unwrap();
+        assert!(manifest.lines().any(|line| line.ends_with("  index.html")));
+        assert!(manifest.lines().any(|line| line.ends_with("  api/officials.json")));
+        assert!(site_manifest_mismatches(&site_dir).unwrap().is_empty());
+        verify_site(&site_dir).unwrap();
+
+        fs::write(site_dir.join("index.html"), "<p>edited</p>").unwrap();
+        fs::write(site_dir.join("extra.html"), "<p>planted</p>").unwrap();
+        fs::remove_file(site_dir.join("api").join("officials.json")).unwrap();
+        assert_eq!(
+            site_manifest_mismatches(&site_dir).unwrap(),
+            vec![
+                "missing: api/officials.json".to_string(),
+                "changed: index.html".to_string(),
+                "not in manifest: extra.html".to_string(),
+            ]
+        );
+        let err = verify_site(&site_dir).unwrap_err();
+        assert_eq!(exit_code_for(&err), 5);
+
+        fs::write(site_dir.join(SITE_MANIFEST), manifest.replace("index.html", "x.html")).unwrap();
+        assert!(
+            site_manifest_mismatches(&site_dir)
+                .unwrap()
+                .contains(&format!("{SITE_MANIFEST} does not match {SITE_MANIFEST_ROOT}"))
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn issue_trends_sum_weekly_reports_and_compare_halves() {
+        let out_dir =
+            std::env::temp_dir().join(format!("larue-top-issues-{}", std::process::id()));
+        let weekly = out_dir.join("reports").join("weekly");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&weekly).unwrap();
+        let weeks: [(&str, &[(&str, usize)]); 4] = [
+            ("2024-03-05", &[("zoning", 4), ("budget", 1), ("tax", 2), ("agenda", 9)]),
+            ("2024-03-12", &[("zoning", 2), ("budget", 1), ("tax", 2)]),
+            ("2024-03-19", &[("budget", 3), ("tax", 2)]),
+            ("2024-03-26", &[("budget", 3), ("tax", 2)]),
+        ];
+        for (date, counts) in weeks {
+            let counts: Vec<_> = counts
+                .iter()
+                .map(|(tag, count)| serde_json::json!({ "tag": tag, "count": count }))
+                .collect();
+            let report = serde_json::json!({ "date": date, "issue_tag_counts": counts });
+            fs::write(weekly.join(format!("{date}.json")), report.to_string()).unwrap();
+        }
+
+        let reports = load_week_reports(&out_dir).unwrap();
+        let issues = issue_trends(&reports);
+        let summary: Vec<_> = issues
+            .iter()
+            .map(|issue| (issue.tag.as_str(), issue.count, issue.trend))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("budget", 8, Trend::Rising),
+                ("tax", 8, Trend::Steady),
+                ("zoning", 6, Trend::Falling),
+            ]
+        );
+        assert_eq!((issues[0].first_half, issues[0].second_half), (2, 6));
+        assert_eq!(serde_json::to_value(&issues[2]).unwrap()["trend"], "falling");
+
+        // Three reports: the middle one counts toward the later half, compared per report.
+        let issues = issue_trends(&reports[1..]);
+        assert_eq!(issues[0].tag, "budget");
+        assert_eq!((issues[0].first_half, issues[0].second_half), (1, 6));
+        assert_eq!(issues[0].trend, Trend::Rising);
+        assert_eq!(issue_trends(&reports[..1])[0].trend, Trend::Steady);
+
+        let _ = fs::remove_dir_all(&out_dir);
+    }
+
+    #[test]
+    fn grade_changes_list_only_letter_changes_by_magnitude() {
+        let previous = HashMap::from([
+            ("Smith".to_string(), 85.0),
+            ("Jones".to_string(), 72.0),
+            ("Brown".to_string(), 91.0),
+            ("Gone".to_string(), 50.0),
+        ]);
+        let current = HashMap::from([
+            ("Smith".to_string(), 78.0),
+            ("Jones".to_string(), 95.0),
+            ("Brown".to_string(), 92.0),
+            ("New".to_string(), 40.0),
+        ]);
+
+        let changes = grade_changes(&previous, &current, None);
+
+        let summary: Vec<(&str, &str, &str)> = changes
+            .iter()
+            .map(|change| {
+                (
+                    change.official.as_str(),
+                    change.old_grade.as_str(),
+                    change.new_grade.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(summary, vec![("Jones", "C-", "A"), ("Smith", "B", "C+")]);
+        assert_eq!(changes[0].delta, 23.0);
+        assert_eq!(changes[1].delta, -7.0);
+    }
+
+    #[test]
+    fn replay_runs_pipeline_against_snapshot_without_touching_it() {
+        let root = std::env::temp_dir().join(format!("larue-replay-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let snapshot = root.join("snapshot");
+        fs::create_dir_all(snapshot.join("artifacts")).unwrap();
+        fs::create_dir_all(snapshot.join("decisions")).unwrap();
+        let artifact = serde_json::json!({
+            "id": "agenda-1",
+            "source": {
+                "kind": "url",
+                "value": "https://example.com/agenda.pdf",
+                "retrieved_at": "2024-03-02T12:00:00Z"
+            },
+            "title": "Fiscal Court agenda",
+            "body_text": "Road contract",
+            "content_type": "application/pdf",
+            "tags": ["contract"]
+        });
+        fs::write(snapshot.join("artifacts/agenda-1.json"), artifact.to_string()).unwrap();
+        let decision = serde_json::json!({
+            "meeting": {
+                "id": "fc-2024-03-04",
+                "body_id": "larue-fiscal-court",
+                "started_at": "2024-03-04T18:00:00Z",
+                "artifact_ids": ["agenda-1"]
+            },
+            "motions": [{
+                "id": "fc-2024-03-04-m0",
+                "meeting_id": "fc-2024-03-04",
+                "index": 0,
+                "text": "Approve the road contract",
+                "result": "passed"
+            }],
+            "votes": [{
+                "id": "fc-2024-03-04-v0",
+                "motion_id": "fc-2024-03-04-m0",
+                "vote_type": "roll_call",
+                "outcome": "passed",
+                "ayes": ["Smith", "Jones"],
+                "nays": [],
+                "abstain": []
+            }]
+        });
+        fs::write(snapshot.join("decisions/fc-2024-03-04.json"), decision.to_string()).unwrap();
+        let config_path = root.join("config.toml");
+        fs::write(&config_path, "[storage]\nout_dir = \"does-not-matter\"\n").unwrap();
+        let work_dir = root.join("work");
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+
+        replay(
+            config_path,
+            snapshot.clone(),
+            None,
+            Some(work_dir.clone()),
+            &rubric_dir,
+            Some("2024-03-05".to_string()),
+        )
+        .unwrap();
+
+        let report: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(work_dir.join("out/reports/weekly/2024-03-05.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(report["total"], 1);
+        assert!(work_dir.join("out/site/index.html").exists());
+        let conn = civic_core::db::open(work_dir.join("civic.db").to_str().unwrap()).unwrap();
+        assert!(civic_core::db::meeting_exists(&conn, "fc-2024-03-04").unwrap());
+        let scores: i64 = conn
+            .query_row("SELECT COUNT(*) FROM decision_scores", [], |row| row.get(0))
+            .unwrap();
+        assert!(scores > 0);
+        let snapshot_entries: Vec<_> = fs::read_dir(&snapshot).unwrap().collect();
+        assert_eq!(snapshot_entries.len(), 2);
+
+        let err = replay(
+            root.join("config.toml"),
+            snapshot,
+            None,
+            Some(work_dir),
+            &rubric_dir,
+            Some("2024-03-05".to_string()),
+        )
+        .unwrap_err();
+        assert_eq!(exit_code_for(&err), 2);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn stale_artifacts_put_a_banner_on_the_home_page() {
+        let path = std::env::temp_dir().join(format!("larue-stale-{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let conn = civic_core::db::open(path.to_str().unwrap()).unwrap();
+        let now = OffsetDateTime::parse("2024-03-20T00:00:00Z", &Rfc3339).unwrap();
+        assert!(stale_data(&conn, 14, now).unwrap().is_none());
+
+        let artifact = civic_core::schema::Artifact {
+            id: "a1".to_string(),
+            source: civic_core::schema::SourceRef {
+                kind: "url".to_string(),
+                value: "https://example.com/a1".to_string(),
+                retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            },
+            title: None,
+            body_text: None,
+            content_type: None,
+            tags: Vec::new(),
+            supersedes: Vec::new(),
+            language: None,
+            doc_type: None,
+        };
+        civic_core::db::upsert_artifact(&conn, &artifact, &serde_json::json!({})).unwrap();
+        assert!(stale_data(&conn, 30, now).unwrap().is_none());
+
+        let stale = stale_data(&conn, 14, now).unwrap().unwrap();
+        assert_eq!(stale.age_days, 19);
+        let templates = SiteTemplates::default();
+        let movers = WeekMovers::default();
+        let last_updated = Some(stale.last_retrieved_at.as_str());
+        let html =
+            render_home_page(None, "2024-03-19", &[], &movers, last_updated, "x", &templates, 1);
+        assert!(html.contains("Data may be stale (last updated 2024-03-01T00:00:00Z)"));
+        let fresh = render_home_page(None, "2024-03-19", &[], &movers, None, "x", &templates, 1);
+        assert!(!fresh.contains("stale-banner"));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn biggest_movers_are_chosen_from_officials_graded_last_week() {
+        let official = |name: &str, delta: f64| {
+            let mut summary =
+                OfficialSummaryBuilder::new(name, None, "2024-03-18").build(None, &[]);
+            summary.delta = delta;
+            summary
+        };
+        let mut withheld = official("Ford", -30.0);
+        withheld.letter_grade = WITHHELD_GRADE.to_string();
+        let officials = vec![
+            official("Baker", 9.0),
+            official("Adams", 4.0),
+            official("Allen", 9.0),
+            official("Clark", -3.0),
+            official("Davis", -12.0),
+            official("Evans", 20.0),
+            withheld,
+        ];
+        // Evans was not graded last week, so their delta is not a week-over-week move.
+        let previous_average = ["Adams", "Allen", "Baker", "Clark", "Davis", "Ford"]
+            .into_iter()
+            .map(|name| (name.to_string(), 50.0))
+            .collect::<HashMap<_, _>>();
+
+        let movers = biggest_movers(&officials, &previous_average);
+        assert_eq!(movers.improvement.map(|official| official.name.as_str()), Some("Allen"));
+        assert_eq!(movers.decline.map(|official| official.name.as_str()), Some("Davis"));
+        let templates = SiteTemplates::default();
+        let home = |movers| {
+            render_home_page(None, "2024-03-18", &officials, movers, None, "x", &templates, 1)
+        };
+        let html = home(&movers);
+        assert!(html.contains("Biggest improvement this week"));
+        assert!(html.contains("<a href=\"/officials/allen.html\">Allen</a>"));
+        assert!(html.contains("<span class=\"mover-delta\">-12.0</span>"));
+
+        // The first week has no prior grades, so the callout is hidden.
+        let first_week = biggest_movers(&officials, &HashMap::new());
+        assert!(first_week.improvement.is_none() && first_week.decline.is_none());
+        let html = home(&first_week);
+        assert!(!html.contains("Biggest"));
+    }
+
+    #[test]
+    fn flexible_dates_accept_common_forms_and_reject_ambiguous_ones() {
+        let expected = time::Date::from_calendar_date(2024, Month::March, 5).unwrap();
+        for value in [
+            "2024-03-05",
+            "2024/03/05",
+            "2024.3.5",
+            "03-05-2024",
+            "03/05/2024",
+            "3.5.2024",
+            " 2024-03-05 ",
+        ] {
+            assert_eq!(parse_flexible_date(value).unwrap(), expected, "{value}");
+        }
+        assert_eq!(parse_flexible_date("03/05/2024").unwrap().to_string(), "2024-03-05");
+
+        for value in ["03/05/24", "25/03/2024", "2024/03-05", "20240305", "2024-02-30", ""] {
+            let err = parse_flexible_date(value).unwrap_err();
+            assert_eq!(exit_code_for(&err), 5, "{value}");
+        }
+        let err = parse_flexible_date("03/05/24").unwrap_err();
+        assert!(err.to_string().contains("ambiguous"), "{err}");
+    }
+
+    #[test]
+    fn all_artifacts_are_grouped_by_source_kind_category() {
+        let config: Config =
+            toml::from_str("[reporting.artifact_categories]\nfile = \"Minutes & Packets\"\n")
+                .unwrap();
+        let categories = resolve_artifact_categories(Some(&config));
+        let row = |id: &str, kind: &str| ReportArtifactRow {
+            id: id.to_string(),
+            title: Some(format!("Artifact {id}")),
+            retrieved_at: "2024-03-04T12:00:00Z".to_string(),
+            source_value: format!("https://example.org/{id}"),
+            tags: Vec::new(),
+            importance: 0.0,
+            category: categories
+                .get(kind)
+                .cloned()
+                .unwrap_or_else(|| OTHER_ARTIFACT_CATEGORY.to_string()),
+            language: "en".to_string(),
+            doc_type: DocType::Other,
+        };
+        let rows = [
+            row("n1", "public_notice"),
+            row("w1", "url"),
+            row("f1", "file"),
+            row("n2", "public_notice"),
+            row("w2", "rss"),
+            row("x1", "fax"),
+        ];
+        let refs = rows.iter().collect::<Vec<_>>();
+
+        let markdown = render_all_artifacts(&refs, &ReportingZone::utc());
+        let headings = markdown
+            .lines()
+            .filter(|line| line.starts_with("### "))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            headings,
+            vec![
+                "### Legal Notices (2)",
+                "### Minutes & Packets (1)",
+                "### Other (1)",
+                "### Web (2)",
+            ]
+        );
+        let notices = markdown.split("### Legal Notices (2)\n\n").nth(1).unwrap();
+        assert!(notices.starts_with(
+            "- [Artifact n1](https://example.org/n1) — 2024-03-04T12:00:00Z\n\
+             - [Artifact n2](https://example.org/n2)"
+        ));
+        assert!(render_all_artifacts(&[], &ReportingZone::utc()).contains("_No other artifacts"));
+    }
+
+    #[test]
+    fn report_artifacts_filter_by_language() {
+        let dir = std::env::temp_dir().join(format!("larue-languages-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("larue.db");
+        for (id, language) in [("notice-en", None), ("notice-es", Some("es"))] {
+            let artifact = serde_json::json!({
+                "id": id,
+                "source": {
+                    "kind": "public_notice",
+                    "value": format!("https://example.org/{id}"),
+                    "retrieved_at": "2024-03-04T12:00:00Z",
+                },
+                "title": id,
+                "body_text": null,
+                "content_type": null,
+                "tags": [],
+                "language": language,
+            });
+            fs::write(dir.join(format!("{id}.json")), artifact.to_string()).unwrap();
+        }
+        ingest_dir(dir.clone(), db_path.to_str().unwrap(), ArtifactStorage::default()).unwrap();
+        let conn = civic_core::db::open(db_path.to_str().unwrap()).unwrap();
+        let window = ("2024-03-04T00:00:00Z", "2024-03-05T00:00:00Z");
+        let categories = resolve_artifact_categories(None);
+        let ids = |languages: Option<&[String]>| {
+            load_report_artifacts(&conn, window, &categories, languages, None)
+                .unwrap()
+                .into_iter()
+                .map(|artifact| format!("{}:{}", artifact.id, artifact.language))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(None), vec!["notice-en:en", "notice-es:es"]);
+        let config: Config = toml::from_str("[reporting]\nlanguages = [\"ES\"]\n").unwrap();
+        let spanish = resolve_report_languages(Some(&config)).unwrap();
+        assert_eq!(ids(spanish.as_deref()), vec!["notice-es:es"]);
+        assert_eq!(ids(Some(&["en".to_string()])), vec!["notice-en:en"]);
+
+        let config: Config = toml::from_str("[reporting]\nlanguages = [\"eng\"]\n").unwrap();
+        let err = resolve_report_languages(Some(&config)).unwrap_err();
+        assert_eq!(exit_code_for(&err), 2);
+        let mut artifact: civic_core::schema::Artifact =
+            serde_json::from_str(&fs::read_to_string(dir.join("notice-es.json")).unwrap())
+                .unwrap();
+        artifact.language = Some("Spanish".to_string());
+        assert_eq!(exit_code_for(&validate_artifact(&artifact).unwrap_err()), 5);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn ingest_reads_artifact_json_from_stdin() {
+        let path = std::env::temp_dir().join(format!("larue-stdin-{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let db_path = path.to_str().unwrap();
+        let artifact = serde_json::json!({
+            "id": "notice-stdin",
+            "source": {
+                "kind": "public_notice",
+                "value": "https://example.org/notice-stdin",
+                "retrieved_at": "2024-03-04T12:00:00Z",
+            },
+            "title": "Piped notice",
+            "body_text": null,
+            "content_type": null,
+            "tags": [],
+        });
+        let stdin = std::io::Cursor::new(artifact.to_string());
+
+        let storage = ArtifactStorage::default();
+        let id = ingest_artifact_from(stdin, "stdin", db_path, storage).unwrap();
+        assert_eq!(id, "notice-stdin");
+        let conn = civic_core::db::open(db_path).unwrap();
+        let title: String = conn
+            .query_row("SELECT title FROM artifacts WHERE id = ?1", [&id], |row| row.get(0))
+            .unwrap();
+        assert_eq!(title, "Piped notice");
+
+        let capped = ArtifactStorage {
+            max_body_text_bytes: Some(8),
+            store_raw_json: true,
+        };
+        let mut long = artifact.clone();
+        long["id"] = "notice-long".into();
+        long["body_text"] = "A notice longer than eight bytes".into();
+        ingest_artifact_from(std::io::Cursor::new(long.to_string()), "stdin", db_path, capped)
+            .unwrap();
+        let (tags, body_text_len): (String, i64) = conn
+            .query_row(
+                "SELECT tags_json, body_text_len FROM artifacts WHERE id = 'notice-long'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert!(tags.contains("truncated"), "{tags}");
+        assert_eq!(body_text_len, 32);
+
+        let garbled = std::io::Cursor::new("{not json");
+        let err = ingest_artifact_from(garbled, "stdin", db_path, storage).unwrap_err();
+        assert_eq!(exit_code_for(&err), 5);
+        assert!(Cli::try_parse_from(["larue", "ingest", "--stdin"]).is_ok());
+        assert!(Cli::try_parse_from(["larue", "ingest", "a.json", "--stdin"]).is_err());
+        assert!(Cli::try_parse_from(["larue", "ingest"]).is_err());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn run_lock_is_exclusive_breaks_only_stale_locks_and_releases_on_drop() {
+        let root = std::env::temp_dir().join(format!("larue-run-lock-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let lock_path = root.join("out/.larue.lock");
+
+        let lock = RunLock::acquire(&lock_path, false, 360).unwrap();
+        let holder = fs::read_to_string(&lock_path).unwrap();
+        assert!(holder.starts_with(&format!("pid={} started_at=", std::process::id())));
+        assert!(RunLock::acquire(&lock_path, false, 360).is_err());
+        // --force only breaks a lock at least `stale_minutes` old.
+        assert!(RunLock::acquire(&lock_path, true, 360).is_err());
+        drop(lock);
+        assert!(!lock_path.exists());
+
+        fs::write(&lock_path, "pid=1 started_at=2024-03-04T00:00:00Z\n").unwrap();
+        assert!(RunLock::acquire(&lock_path, false, 0).is_err());
+        let broken = RunLock::acquire(&lock_path, true, 0).unwrap();
+        assert!(!fs::read_to_string(&lock_path).unwrap().starts_with("pid=1 "));
+        drop(broken);
+        assert!(!lock_path.exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn import_legacy_relocates_flat_fields_into_nested_artifact_keys() {
+        let root = std::env::temp_dir().join(format!("larue-legacy-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let legacy_dir = root.join("legacy");
+        fs::create_dir_all(&legacy_dir).unwrap();
+        let legacy = serde_json::json!({
+            "id": "legacy-1",
+            "url": "https://example.com/old-agenda.pdf",
+            "fetched_at": "2019-05-06T12:00:00Z",
+            "title": "Old agenda",
+            "body_text": null,
+            "content_type": null
+        });
+        fs::write(legacy_dir.join("legacy-1.json"), legacy.to_string()).unwrap();
+        let mapping = root.join("map.toml");
+        fs::write(
+            &mapping,
+            "[rename]\nurl = \"source.value\"\nfetched_at = \"source.retrieved_at\"\n\n\
+             [defaults]\n\"source.kind\" = \"url\"\ntags = []\n",
+        )
+        .unwrap();
+        let db_path = root.join("civic.db");
+
+        import_legacy(
+            legacy_dir,
+            db_path.to_str().unwrap(),
+            ArtifactStorage::default(),
+            mapping,
+        )
+        .unwrap();
+
+        let conn = civic_core::db::open(db_path.to_str().unwrap()).unwrap();
+        let (kind, value, retrieved_at, raw_json): (String, String, String, String) = conn
+            .query_row(
+                "SELECT source_kind, source_value, retrieved_at, raw_json
+                 FROM artifacts WHERE id = 'legacy-1'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .unwrap();
+        assert_eq!(kind, "url");
+        assert_eq!(value, "https://example.com/old-agenda.pdf");
+        assert_eq!(retrieved_at, "2019-05-06T12:00:00Z");
+        let raw_json: serde_json::Value = serde_json::from_str(&raw_json).unwrap();
+        assert_eq!(raw_json["source"]["value"], "https://example.com/old-agenda.pdf");
+        assert!(raw_json.get("url").is_none());
+        assert!(raw_json.get("fetched_at").is_none());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn held_run_lock_and_missing_legacy_dir_exit_with_their_own_codes() {
+        let root = std::env::temp_dir().join(format!("larue-exit-codes-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let lock_path = root.join(".larue.lock");
+        let held = RunLock::acquire(&lock_path, false, 360).unwrap();
+        let err = RunLock::acquire(&lock_path, false, 360).err().unwrap();
+        assert_eq!(exit_code_for(&err), 6);
+
+        let mapping = root.join("mapping.toml");
+        fs::write(&mapping, "[rename]\nurl = \"source.value\"\n").unwrap();
+        let db_path = root.join("civic.db");
+        let err = import_legacy(
+            root.join("missing"),
+            db_path.to_str().unwrap(),
+            ArtifactStorage::default(),
+            mapping,
+        )
+        .unwrap_err();
+        assert_eq!(exit_code_for(&err), 2);
+
+        drop(held);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn site_paths_are_percent_decoded_and_kept_inside_the_site_dir() {
+        let root = std::env::temp_dir().join(format!("larue-serve-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let site_dir = root.join("site");
+        fs::create_dir_all(site_dir.join("officials")).unwrap();
+        fs::write(site_dir.join("index.html"), "home").unwrap();
+        fs::write(site_dir.join("officials/Jane Smith.html"), "jane").unwrap();
+        fs::write(root.join("secret.txt"), "secret").unwrap();
+
+        assert_eq!(resolve_site_path(&site_dir, "/"), Some(site_dir.join("index.html")));
+        assert_eq!(
+            resolve_site_path(&site_dir, "/officials/Jane%20Smith.html?x=1"),
+            Some(site_dir.join("officials/Jane Smith.html"))
+        );
+        for target in [
+            "/../secret.txt",
+            "/%2e%2e/secret.txt",
+            "/officials/%2E%2E%2F..%2Fsecret.txt",
+            "/..%5Csecret.txt",
+            "/bad%zz",
+            "/trailing%2",
+        ] {
+            assert_eq!(resolve_site_path(&site_dir, target), None, "{target}");
+        }
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn reingesting_changed_text_records_a_revision() {
+        let conn = civic_core::db::open_memory().unwrap();
+        let artifact = |retrieved_at: &str, body: &str| {
+            serde_json::json!({
+                "id": "notice-edit",
+                "source": {
+                    "kind": "public_notice",
+                    "value": "https://example.org/notice-edit",
+                    "retrieved_at": retrieved_at,
+                },
+                "title": "Edited notice",
+                "body_text": body,
+                "content_type": null,
+                "tags": [],
+            })
+        };
+        let ingest =
+            |json| ingest_artifact_json(&conn, json, ArtifactStorage::default(), None).unwrap();
+
+        ingest(artifact("2024-03-04T12:00:00Z", "Hearing on March 5.\nBring comments.\n"));
+        ingest(artifact("2024-03-05T12:00:00Z", "Hearing on March 5.\nBring comments.\n"));
+        assert!(civic_core::db::load_artifact_revisions(&conn, "notice-edit").unwrap().is_empty());
+
+        ingest(artifact("2024-03-06T12:00:00Z", "Hearing on March 12.\nBring comments.\n"));
+        let revisions = civic_core::db::load_artifact_revisions(&conn, "notice-edit").unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].revision, 1);
+        assert_eq!(revisions[0].retrieved_at, "2024-03-05T12:00:00Z");
+        assert_eq!(
+            revisions[0].body_text.as_deref(),
+            Some("Hearing on March 5.\nBring comments.\n")
+        );
+
+        let history = render_artifact_history(&conn, "notice-edit").unwrap();
+        assert!(history.starts_with("Artifact notice-edit: 1 revision(s)\n"));
+        assert!(history.contains("  current (retrieved 2024-03-06T12:00:00Z)\n"));
+        assert!(history.contains(
+            "--- notice-edit@2024-03-05T12:00:00Z\n+++ notice-edit@2024-03-06T12:00:00Z\n"
+        ));
+        assert!(history.contains("\n-Hearing on March 5.\n+Hearing on March 12.\n Bring"));
+        assert!(render_artifact_history(&conn, "missing").is_err());
+    }
+
+    #[test]
+    fn ingest_uses_config_db_path_when_db_flag_is_absent() {
+        let dir = std::env::temp_dir().join(format!("larue-ingest-cfg-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("configured.db");
+        let config_path = dir.join("config.toml");
+        fs::write(
+            &config_path,
+            format!("[storage]\ndb_path = {:?}\n", db_path.display().to_string()),
+        )
+        .unwrap();
+        let artifact_path = dir.join("notice.json");
+        let artifact = serde_json::json!({
+            "id": "notice-cfg",
+            "source": {
+                "kind": "public_notice",
+                "value": "https://example.org/notice-cfg",
+                "retrieved_at": "2024-03-04T12:00:00Z",
+            },
+            "title": "Configured notice",
+            "body_text": null,
+            "content_type": null,
+            "tags": [],
+        });
+        fs::write(&artifact_path, artifact.to_string()).unwrap();
+        let args = |extra: &[&str]| {
+            let mut args = vec!["larue", "ingest", artifact_path.to_str().unwrap()];
+            args.extend(["--config", config_path.to_str().unwrap()]);
+            args.extend(extra);
+            Cli::try_parse_from(args.into_iter().map(str::to_string).collect::<Vec<_>>())
+                .unwrap()
+        };
+
+        run(args(&[])).unwrap();
+        let conn = civic_core::db::open(db_path.to_str().unwrap()).unwrap();
+        let count = |conn: &rusqlite::Connection| -> i64 {
+            conn.query_row("SELECT COUNT(*) FROM artifacts", [], |row| row.get(0)).unwrap()
+        };
+        assert_eq!(count(&conn), 1);
+
+        let flag_db = dir.join("flag.db");
+        run(args(&["--db", flag_db.to_str().unwrap()])).unwrap();
+        assert_eq!(count(&civic_core::db::open(flag_db.to_str().unwrap()).unwrap()), 1);
+        assert_eq!(count(&conn), 1);
+
+        // An unsupported reporting zone fails at config load, even for commands that never use it.
+        fs::write(&config_path, "[reporting]\ntimezone = \"Europe/Berlin\"\n").unwrap();
+        let err = run(args(&[])).unwrap_err();
+        assert_eq!(exit_code_for(&err), 2);
+        assert!(err.to_string().contains("Unsupported time zone Europe/Berlin"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn ingest_dir_decodes_invalid_body_text_lossily() {
+        let dir = std::env::temp_dir().join(format!("larue-lossy-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let artifact = |id: &str, title: &[u8], body: &[u8]| {
+            let mut raw = format!(
+                r#"{{"id":"{id}","source":{{"kind":"pdf","value":"https://example.org/{id}.pdf",
+                "retrieved_at":"2024-03-04T12:00:00Z"}},"content_type":"application/pdf",
+                "tags":["budget"],"title":""#
+            )
+            .into_bytes();
+            raw.extend_from_slice(title);
+            raw.extend_from_slice(br#"","body_text":""#);
+            raw.extend_from_slice(body);
+            raw.extend_from_slice(br#""}"#);
+            raw
+        };
+        // Latin-1 "café" and "résumé": 0xE9 is not valid UTF-8 on its own.
+        let latin1 = artifact("latin1", b"Minutes", b"caf\xe9 r\xe9sum\xe9");
+        fs::write(dir.join("a-latin1.json"), latin1).unwrap();
+        fs::write(dir.join("b-title.json"), artifact("bad-title", b"Caf\xe9", b"fine")).unwrap();
+        fs::write(dir.join("c-clean.json"), artifact("clean", b"Agenda", "café".as_bytes()))
+            .unwrap();
+        let db_path = dir.join("civic.db");
+        let db_path = db_path.to_str().unwrap();
+
+        ingest_dir(dir.clone(), db_path, ArtifactStorage::default()).unwrap();
+
+        let conn = civic_core::db::open(db_path).unwrap();
+        let stored = |id: &str| {
+            conn.query_row(
+                "SELECT body_text, tags_json FROM artifacts WHERE id = ?1",
+                [id],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+            )
+        };
+        let (body, tags) = stored("latin1").unwrap();
+        assert_eq!(body, "caf\u{FFFD} r\u{FFFD}sum\u{FFFD}");
+        assert_eq!(tags, r#"["budget","lossy_decode"]"#);
+        let (body, tags) = stored("clean").unwrap();
+        assert_eq!(body, "café");
+        assert_eq!(tags, r#"["budget"]"#);
+        assert!(stored("bad-title").is_err());
+
+        let err = decode_artifact_json(&artifact("x", b"Caf\xe9", b""), "x.json").unwrap_err();
+        assert_eq!(exit_code_for(&err), 5);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn config_check_reports_unknown_keys_bad_values_and_missing_source_fields() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let example = fs::read_to_string(root.join("config.example.toml")).unwrap();
+        let report = check_config(&example, &root);
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+
+        let raw = r#"
+            [storage]
+            db_path = "civic.db"
+            out_dir = "out"
+            vault_pth = "vault"
+
+            [sources.wayback]
+            enabled = true
+
+            [reporting]
+            timezone = "Mars/Olympus_Mons"
+
+            [tagging.tag_min_hits]
+            anything_goes = 2
+        "#;
+        let report = check_config(raw, &root);
+        assert_eq!(
+            report.warnings,
+            vec![
+                "unknown key `storage.vault_pth` is ignored".to_string(),
+                "storage.vault_path is not set; the default will be used".to_string(),
+            ]
+        );
+        assert_eq!(report.errors.len(), 2, "{:?}", report.errors);
+        assert!(report.errors[0].starts_with("reporting.timezone:"));
+        assert_eq!(report.errors[1], "sources.wayback is enabled but has no urls");
+
+        let report = check_config("[reporting]\ntop_n = \"three\"\n", &root);
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].starts_with("invalid value:"), "{:?}", report.errors);
+
+        let report = check_config("", &root.join("crates"));
+        assert!(report.errors.iter().any(|err| err.starts_with("rubric directory")));
+        let missing_script = "script not found: workers/parsers/extract_text.py".to_string();
+        assert!(report.errors.contains(&missing_script));
+    }
+
+    #[test]
+    fn rescoring_an_overlapping_window_keeps_earlier_computations_for_drift() {
+        let (path, conn) = meeting_db("score-history");
+        let score = |axis_score: f64, computed_at: &str| DecisionScore {
+            id: "vote:v1:smith".to_string(),
+            meeting_id: Some("m1".to_string()),
+            motion_id: Some("m1-a".to_string()),
+            vote_id: Some("v1".to_string()),
+            overall_score: axis_score,
+            axis_scores: HashMap::from([("fiscal".to_string(), axis_score)]),
+            contributions: HashMap::new(),
+            axis_weights: HashMap::new(),
+            constitutional_refs: ConstitutionRefs::new(),
+            evidence: vec!["official:Smith".to_string()],
+            confidence: 0.5,
+            flags: Vec::new(),
+            computed_at: computed_at.to_string(),
+            rubric_version: None,
+        };
+        // Week ending 03-10, then a window ending 03-13 that overlaps it, then a rerun of it.
+        civic_core::db::upsert_decision_score(&conn, &score(2.0, "2024-03-10T00:00:00Z")).unwrap();
+        civic_core::db::upsert_decision_score(&conn, &score(3.0, "2024-03-13T00:00:00Z")).unwrap();
+        civic_core::db::upsert_decision_score(&conn, &score(4.0, "2024-03-13T00:00:00Z")).unwrap();
+
+        let current: (i64, String) = conn
+            .query_row("SELECT COUNT(*), MAX(computed_at) FROM decision_scores", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(current, (1, "2024-03-13T00:00:00Z".to_string()));
+        let history: i64 = conn
+            .query_row("SELECT COUNT(*) FROM decision_score_history", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(history, 2);
+
+        let prior =
+            load_prior_vote_scores(&conn, "Smith", "fiscal", "2024-03-11T00:00:00Z", 5).unwrap();
+        assert_eq!(prior, vec![2.0]);
+        // Both computations fall inside this window; only the latest one counts.
+        let current =
+            load_vote_scores(&conn, "2024-03-07T00:00:00Z", "2024-03-13T00:00:00Z").unwrap();
+        assert_eq!(current["Smith"]["fiscal"], 4.0);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn drift_resolves_once_the_axis_returns_within_threshold() {
+        let (path, conn) = meeting_db("drift-resolve");
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let mut rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        rubric.bias_controls.drift_window = 5;
+        rubric.bias_controls.drift_threshold = 5.0;
+        let score = |vote: &str, axis_score: f64, computed_at: &str| DecisionScore {
+            id: format!("vote:{vote}:smith"),
+            meeting_id: Some("m1".to_string()),
+            motion_id: Some("m1-a".to_string()),
+            vote_id: Some(vote.to_string()),
+            overall_score: axis_score,
+            axis_scores: HashMap::from([("fiscal".to_string(), axis_score)]),
+            contributions: HashMap::new(),
+            axis_weights: HashMap::new(),
+            constitutional_refs: ConstitutionRefs::new(),
+            evidence: vec!["official:Smith".to_string()],
+            confidence: 0.5,
+            flags: Vec::new(),
+            computed_at: computed_at.to_string(),
+            rubric_version: None,
+        };
+        let run_week = |date: &str, vote: &str, axis_score: f64| {
+            let window = resolve_window(Some(date.to_string()), &ReportingZone::utc()).unwrap();
+            let computed_at = window.end_rfc3339().unwrap();
+            civic_core::db::upsert_decision_score(&conn, &score(vote, axis_score, &computed_at))
+                .unwrap();
+            let result = detect_drift(&conn, &rubric, &window, &computed_at).unwrap();
+            (window.start_rfc3339().unwrap(), computed_at, result)
+        };
+        for day in 1..=5 {
+            let baseline = score(&format!("va{day}"), 2.0, &format!("2024-02-2{day}T00:00:00Z"));
+            civic_core::db::upsert_decision_score(&conn, &baseline).unwrap();
+        }
+
+        // A jump from the 2.0 baseline flags drift...
+        let (start, end, result) = run_week("2024-03-10", "vb", 20.0);
+        assert_eq!(result.resolved, 0);
+        let flags = load_drift_flags(&conn, &end).unwrap();
+        assert_eq!(flags, ["Smith: drift_detected:fiscal (18.00)"]);
+        let details = load_official_drift_detail(&conn, "Smith", &start, &end).unwrap();
+        assert!(!details[0].resolved);
+        // ...which stays active into a week with no new evaluation of the axis...
+        assert_eq!(load_drift_flags(&conn, "2024-03-12T00:00:00Z").unwrap().len(), 1);
+
+        // ...until the axis reverts: 2.5 is within 5.0 of the prior average of 5.6.
+        let (start, end, result) = run_week("2024-03-18", "vc", 2.5);
+        assert_eq!(result.resolved, 1);
+        assert!(load_drift_flags(&conn, &end).unwrap().is_empty());
+        assert_eq!(load_drift_flags(&conn, "2024-03-11T00:00:00Z").unwrap().len(), 1);
+        let status: (String, String) = conn
+            .query_row("SELECT status, resolved_at FROM official_drift", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(status, ("resolved".to_string(), end.clone()));
+
+        let mut builder = OfficialSummaryBuilder::new("Smith", None, "2024-03-18");
+        builder.overall_scores.push(2.5);
+        builder.weights.push(1.0);
+        let mut official = builder.build(None, &load_drift_flags(&conn, &end).unwrap());
+        official.drift_details = load_official_drift_detail(&conn, "Smith", &start, &end).unwrap();
+        assert!(official.drift_flags.is_empty());
+        assert!(official.drift_details[0].resolved);
+        let templates = SiteTemplates::default();
+        let html = render_official_detail(&official, &[], "2024-03-18", "x", &templates, 1);
+        assert!(html.contains("<td>fiscal</td>"));
+        assert!(html.contains("<span class=\"badge\">Resolved</span>"));
+        assert!(!html.contains("badge drift"));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn official_page_renders_disclaimer_and_links_commentary_to_receipts() {
+        let mut builder = OfficialSummaryBuilder::new("Jane Smith", None, "2024-03-05");
+        builder.overall_scores.push(2.5);
+        builder.weights.push(1.0);
+        let mut official = builder.build(None, &[]);
+        official.commentary = Some("Paved the road.".to_string());
+        official.receipts.push(Receipt {
+            meeting_date: "2024-03-04".to_string(),
+            motion_text: "Approve the road contract".to_string(),
+            vote_label: Some("Aye".to_string()),
+            artifact_ids: vec!["minutes-1".to_string()],
+            week_date: "2024-03-05".to_string(),
+            excerpts: Vec::new(),
+        });
+        let templates = SiteTemplates::default();
+        let disclaimer = "Opinion based on the rubric; read the minutes.";
+
+        let html = render_official_detail(&official, &[], "2024-03-05", disclaimer, &templates, 1);
+        assert!(html.contains("<section id=\"receipts\">"));
+        assert!(html.contains(
+            "<p>Paved the road. <a href=\"#receipts\">Primary sources: see receipts</a></p>"
+        ));
+        assert!(html.contains(&format!("<p class=\"subtitle\">{disclaimer}</p>")));
+        assert!(!html.contains(DEFAULT_DISCLAIMER));
+
+        official.receipts.clear();
+        let html = render_official_detail(&official, &[], "2024-03-05", disclaimer, &templates, 1);
+        assert!(html.contains("Commentary withheld: no primary-source receipts this week."));
+        assert!(!html.contains("Paved the road."));
+
+        let site = resolve_site_config(None);
+        assert_eq!(site.disclaimer.as_deref(), Some(DEFAULT_DISCLAIMER));
+    }
+
+    #[test]
+    fn parallel_scoring_matches_sequential() {
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        let inputs = (0..7)
+            .map(|index| {
+                let meeting_id = format!("m{index}");
+                let motion_id = format!("{meeting_id}-a");
+                // Alternate scorable and untagged evidence so flags differ between meetings.
+                let tags = if index % 2 == 0 { vec!["contract".to_string()] } else { Vec::new() };
+                MeetingScoringInput {
+                    body_rubric: None,
+                    meeting: MeetingWindowRow {
+                        id: meeting_id.clone(),
+                        body_id: "larue-fiscal-court".to_string(),
+                        started_at: format!("2024-03-0{}T18:00:00Z", index + 1),
+                        artifact_ids_json: "[]".to_string(),
+                        presiding_officer: None,
+                    },
+                    artifacts: vec![LinkedArtifact {
+                        id: format!("a{index}"),
+                        tags,
+                        retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+                        content_type: None,
+                        body_text: None,
+                        source_kind: "url".to_string(),
+                        trusted: true,
+                        doc_type: DocType::Other,
+                    }],
+                    motions: vec![MotionRow {
+                        id: motion_id.clone(),
+                        text: format!("Approve road contract {index}"),
+                        amended: false,
+                        amendment_text: None,
+                        agenda_ref: None,
+                        reconsiders_motion_id: None,
+                    }],
+                    votes: vec![VoteRow {
+                        id: format!("{motion_id}-vote"),
+                        motion_id,
+                        vote_type: None,
+                        outcome: None,
+                        ayes: Vec::new(),
+                        nays: Vec::new(),
+                        abstain: Vec::new(),
+                        choices: vec![
+                            ("Smith".to_string(), VoteChoice::Aye),
+                            ("Jones".to_string(), VoteChoice::Nay),
+                        ],
+                        tally: VoteTally { ayes: 1, nays: 1 },
+                    }],
+                }
+            })
+            .collect::<Vec<_>>();
+        let computed_at = "2024-03-10T00:00:00Z";
+        let flatten = |meetings: Vec<MeetingScores>| {
+            let mut tally = ScoreTally::default();
+            let mut scores = Vec::new();
+            let mut audit = Vec::new();
+            for meeting in meetings {
+                tally.add(&meeting.tally);
+                audit.extend(meeting.audit_records);
+                scores.extend(meeting.scores.into_iter().map(|score| {
+                    (
+                        score.id,
+                        score.overall_score,
+                        score.axis_scores,
+                        score.confidence,
+                        score.flags,
+                    )
+                }));
+            }
+            (tally, scores, audit)
+        };
+
+        let sequential = flatten(score_meetings(&inputs, &rubric, computed_at, Some("h"), 1));
+        assert_eq!(sequential.0.motions_scored, 7);
+        assert_eq!(sequential.0.votes_scored, 14);
+        assert_eq!(sequential.1[0].0, "motion:m0-a");
+        for parallel in [2, 3, 16] {
+            let result = score_meetings(&inputs, &rubric, computed_at, Some("h"), parallel);
+            assert_eq!(flatten(result), sequential, "parallel={parallel}");
+        }
+    }
+
+    #[cfg(not(feature = "python-collectors"))]
+    #[test]
+    fn python_commands_fail_clearly_without_the_feature() {
+        let err = tag_artifacts(PathBuf::from("config.toml"), false).unwrap_err();
+        assert_eq!(exit_code_for(&err), 3);
+        assert!(err.to_string().contains("use tag-native instead"));
+        let err = extract_text(PathBuf::from("config.toml")).unwrap_err();
+        assert!(err.to_string().contains("without the python-collectors feature"));
+    }
+
+    #[test]
+    fn fresh_artifacts_are_deferred_from_scoring() {
+        let (path, conn) = meeting_db("quiet-period");
+        for id in ["settled", "fresh"] {
+            let artifact: civic_core::schema::Artifact = serde_json::from_value(serde_json::json!({
+                "id": id,
+                "source": {
+                    "kind": "public_notice",
+                    "value": format!("https://example.org/{id}"),
+                    "retrieved_at": "2024-03-01T00:00:00Z",
+                },
+                "title": id,
+                "body_text": null,
+                "content_type": null,
+                "tags": ["contract"],
+            }))
+            .unwrap();
+            civic_core::db::upsert_artifact(&conn, &artifact, &serde_json::json!({})).unwrap();
+        }
+        conn.execute(
+            "UPDATE artifacts SET inserted_at = '2024-03-01T00:00:00.000Z' WHERE id = 'settled'",
+            [],
+        )
+        .unwrap();
+        let linked = || {
+            ["settled", "fresh"]
+                .map(|id| LinkedArtifact {
+                    id: id.to_string(),
+                    tags: vec!["contract".to_string()],
+                    retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+                    content_type: None,
+                    body_text: None,
+                    source_kind: "url".to_string(),
+                    trusted: true,
+                    doc_type: DocType::Other,
+                })
+                .to_vec()
+        };
+        let now = OffsetDateTime::now_utc();
+
+        let mut artifacts = linked();
+        let deferred = defer_fresh_artifacts(&conn, &mut artifacts, 24, now).unwrap();
+        assert_eq!(deferred, vec!["fresh".to_string()]);
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(artifacts[0].id, "settled");
+
+        // Once the quiet period has passed, the next run scores it.
+        let mut artifacts = linked();
+        let later = now + Duration::hours(25);
+        assert!(defer_fresh_artifacts(&conn, &mut artifacts, 24, later).unwrap().is_empty());
+        assert_eq!(artifacts.len(), 2);
+
+        let config: Config = toml::from_str("[scoring]\nmin_evidence_age_hours = 6\n").unwrap();
+        assert_eq!(resolve_scoring(Some(&config)).min_evidence_age_hours, 6);
+        assert_eq!(resolve_scoring(None).min_evidence_age_hours, 0);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn untrusted_source_tags_are_left_out_of_scoring() {
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        let linked = |id: &str, kind: &str, tags: &[&str]| LinkedArtifact {
+            id: id.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            content_type: None,
+            body_text: None,
+            source_kind: kind.to_string(),
+            trusted: true,
+            doc_type: DocType::Other,
+        };
+        let mut artifacts = vec![
+            linked("minutes", "url", &[]),
+            linked("post", "social_media", &["contract"]),
+        ];
+        let score = |artifacts: &[LinkedArtifact]| {
+            civic_core::scoring::compute_motion_score(
+                "Approve road contract",
+                artifacts,
+                "2024-03-04T18:00:00Z",
+                &rubric,
+            )
+        };
+
+        let trusting = score(&artifacts);
+        assert!(trusting.evidence.contains(&"tag:contract".to_string()));
+        assert!(!trusting.flags.contains(&"insufficient_evidence".to_string()));
+
+        let config: Config =
+            toml::from_str("[scoring]\ntrusted_source_kinds = [\"URL\", \"file\"]\n").unwrap();
+        let trusted_kinds = resolve_scoring(Some(&config)).trusted_source_kinds.unwrap();
+        assert!(resolve_scoring(None).trusted_source_kinds.is_none());
+        distrust_source_kinds(&mut artifacts, &trusted_kinds);
+        assert!(artifacts[0].trusted);
+        assert!(!artifacts[1].trusted);
+        let strict = score(&artifacts);
+        assert!(!strict.evidence.contains(&"tag:contract".to_string()));
+        assert!(strict.evidence.contains(&"untrusted_source:social_media:post".to_string()));
+        assert!(strict.flags.contains(&"insufficient_evidence".to_string()));
+        assert_eq!(strict.overall_score, rubric.config.general.neutral_score);
+    }
+
+    #[test]
+    fn amended_motion_is_scored_on_amendment_text() {
+        let (path, conn) = meeting_db("amended");
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        let mut motion = civic_core::schema::DecisionMotion {
+            id: "m1-a".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 0,
+            text: "Approve the road plan".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: Some("Approve the road plan and award the paving contract".into()),
+            agenda_ref: None,
+        };
+        let score = |motion: &civic_core::schema::DecisionMotion| {
+            civic_core::db::upsert_motion(&conn, motion, &serde_json::json!({})).unwrap();
+            let input = MeetingScoringInput {
+                body_rubric: None,
+                meeting: MeetingWindowRow {
+                    id: "m1".to_string(),
+                    body_id: "larue-fiscal-court".to_string(),
+                    started_at: "2024-03-04T18:00:00Z".to_string(),
+                    artifact_ids_json: "[]".to_string(),
+                    presiding_officer: None,
+                },
+                artifacts: vec![LinkedArtifact {
+                    id: "a1".to_string(),
+                    tags: vec!["contract".to_string()],
+                    retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+                    content_type: None,
+                    body_text: None,
+                    source_kind: "url".to_string(),
+                    trusted: true,
+                    doc_type: DocType::Other,
+                }],
+                motions: load_motions_for_meeting(&conn, "m1").unwrap(),
+                votes: Vec::new(),
+            };
+            let mut meetings = score_meetings(&[input], &rubric, "2024-03-05T00:00:00Z", None, 1);
+            meetings.remove(0).scores.remove(0)
+        };
+
+        // Recorded amendment text is ignored until the motion is marked amended.
+        let original = score(&motion);
+        assert!(!original.evidence.iter().any(|item| item.starts_with("spending_bias:")));
+        assert!(!original.evidence.contains(&"scored_amended".to_string()));
+
+        motion.amended = true;
+        let amended = score(&motion);
+        assert!(amended.evidence.contains(&"spending_bias:contract".to_string()));
+        assert!(amended.evidence.contains(&"scored_amended".to_string()));
+        assert_ne!(amended.axis_scores, original.axis_scores);
+        assert_eq!(civic_core::schema::amended_marker(motion.amended), " (amended)");
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn blank_motion_is_scored_on_its_agenda_item() {
+        let conn = civic_core::db::open_memory().unwrap();
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        let mut motion = civic_core::schema::DecisionMotion {
+            id: "m1-a".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 0,
+            text: String::new(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: Some("4.2".to_string()),
+        };
+        civic_core::db::upsert_motion(&conn, &motion, &serde_json::json!({})).unwrap();
+        let agenda = LinkedArtifact {
+            id: "agenda-1".to_string(),
+            tags: vec!["agenda".to_string(), "bond".to_string()],
+            retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            content_type: None,
+            body_text: Some(
+                "4. New business\n4.1 Approve minutes\n4.21 Set a hearing date\n\
+                 Item 4.2. Award the paving contract to Smith Paving\n"
+                    .to_string(),
+            ),
+            source_kind: "url".to_string(),
+            trusted: true,
+            doc_type: DocType::Agenda,
+        };
+        let minutes = LinkedArtifact {
+            id: "minutes-1".to_string(),
+            tags: vec!["minutes".to_string(), "contract".to_string()],
+            body_text: None,
+            doc_type: DocType::Minutes,
+            ..agenda.clone()
+        };
+        assert_eq!(
+            agenda_item_text(std::slice::from_ref(&agenda), "4.2").as_deref(),
+            Some("Award the paving contract to Smith Paving")
+        );
+        assert_eq!(
+            agenda_item_text(std::slice::from_ref(&agenda), "4").as_deref(),
+            Some("New business")
+        );
+        assert_eq!(agenda_item_text(std::slice::from_ref(&agenda), "7"), None);
+
+        let score = |conn: &rusqlite::Connection| {
+            let input = MeetingScoringInput {
+                meeting: MeetingWindowRow {
+                    id: "m1".to_string(),
+                    body_id: "larue-fiscal-court".to_string(),
+                    started_at: "2024-03-04T18:00:00Z".to_string(),
+                    artifact_ids_json: "[]".to_string(),
+                    presiding_officer: None,
+                },
+                artifacts: vec![agenda.clone(), minutes.clone()],
+                motions: load_motions_for_meeting(conn, "m1").unwrap(),
+                votes: Vec::new(),
+                body_rubric: None,
+            };
+            let mut meetings = score_meetings(&[input], &rubric, "2024-03-05T00:00:00Z", None, 1);
+            meetings.remove(0).scores.remove(0)
+        };
+        let resolved = score(&conn);
+        assert!(resolved.evidence.contains(&RESOLVED_FROM_AGENDA_TAG.to_string()));
+        assert!(resolved.evidence.contains(&"spending_bias:contract".to_string()));
+        assert!(resolved.evidence.contains(&"not_decision_record:agenda:agenda-1".to_string()));
+        assert!(!resolved.evidence.contains(&"tag:bond".to_string()));
+        assert!(!resolved.flags.contains(&"empty_motion_text".to_string()));
+
+        motion.agenda_ref = None;
+        civic_core::db::upsert_motion(&conn, &motion, &serde_json::json!({})).unwrap();
+        let unresolved = score(&conn);
+        assert!(!unresolved.evidence.contains(&RESOLVED_FROM_AGENDA_TAG.to_string()));
+        assert!(unresolved.flags.contains(&"empty_motion_text".to_string()));
+    }
+
+    #[test]
+    fn in_memory_database_round_trips_ingest_and_scoring() {
+        use civic_core::schema::{DecisionMeeting, DecisionMotion, DecisionVote};
+
+        let conn = civic_core::db::open_memory().unwrap();
+        let artifact = serde_json::json!({
+            "id": "a1",
+            "source": {"kind": "url", "value": "https://example.org/a1",
+                       "retrieved_at": "2024-03-01T00:00:00Z"},
+            "title": "Agenda",
+            "body_text": "Approve the road contract",
+            "tags": ["contract"]
+        });
+        ingest_artifact_json(&conn, artifact, ArtifactStorage::default(), None).unwrap();
+        let meeting = DecisionMeeting {
+            id: "m1".to_string(),
+            body_id: "larue-fiscal-court".to_string(),
+            body_name: None,
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: vec!["a1".to_string()],
+            parent_meeting_id: None,
+            presiding_officer: None,
+        };
+        let motion = DecisionMotion {
+            id: "m1-a".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 0,
+            text: "Approve the road contract".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        };
+        let vote = DecisionVote {
+            id: "v1".to_string(),
+            motion_id: "m1-a".to_string(),
+            vote_type: None,
+            outcome: Some("passed".to_string()),
+            ayes: vec!["Adams".to_string(), "Baker".to_string()],
+            nays: vec!["Clark".to_string()],
+            abstain: Vec::new(),
+        };
+        let empty = serde_json::json!({});
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &empty, &[]).unwrap();
+        civic_core::db::upsert_motion(&conn, &motion, &empty).unwrap();
+        civic_core::db::upsert_vote(&conn, &vote, &empty).unwrap();
+
+        let window = resolve_window(Some("2024-03-05".into()), &ReportingZone::utc()).unwrap();
+        let meeting = load_meetings_in_window(&conn, &window).unwrap().remove(0);
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        let input = MeetingScoringInput {
+            artifacts: load_linked_artifacts(&conn, &meeting, &rubric).unwrap(),
+            motions: load_motions_for_meeting(&conn, "m1").unwrap(),
+            votes: load_votes_for_meeting(&conn, "m1").unwrap(),
+            body_rubric: None,
+            meeting,
+        };
+        assert_eq!(input.artifacts.len(), 1);
+        let scores = score_meetings(&[input], &rubric, "2024-03-05T00:00:00Z", None, 1)
+            .remove(0)
+            .scores;
+        assert!(!scores.is_empty());
+        for score in &scores {
+            civic_core::db::upsert_decision_score(&conn, score).unwrap();
+        }
+
+        let mut stored = Vec::new();
+        civic_core::db::for_each_decision_score(&conn, |score| {
+            stored.push(score.id);
+            Ok(())
+        })
+        .unwrap();
+        stored.sort();
+        let mut expected: Vec<String> = scores.into_iter().map(|score| score.id).collect();
+        expected.sort();
+        assert_eq!(stored, expected);
+        assert!(stored.contains(&"vote:v1:adams".to_string()));
+    }
+
+    #[test]
+    fn agenda_artifacts_are_not_decision_evidence() {
+        use civic_core::schema::{DecisionMeeting, DecisionMotion};
+
+        let conn = civic_core::db::open_memory().unwrap();
+        let artifact = |id: &str, tags: &[&str], doc_type: Option<&str>| {
+            serde_json::json!({
+                "id": id,
+                "source": {"kind": "url", "value": format!("https://example.org/{id}"),
+                           "retrieved_at": "2024-03-01T00:00:00Z"},
+                "title": id,
+                "body_text": "Approve the road contract",
+                "tags": tags,
+                "doc_type": doc_type,
+            })
+        };
+        let ingest =
+            |json| ingest_artifact_json(&conn, json, ArtifactStorage::default(), None).unwrap();
+        ingest(artifact("agenda", &["agenda", "contract"], None));
+        ingest(artifact("hearing", &["contract"], Some("notice")));
+        ingest(artifact("minutes", &["minutes", "contract"], None));
+        let mut meeting = DecisionMeeting {
+            id: "m1".to_string(),
+            body_id: "larue-fiscal-court".to_string(),
+            body_name: None,
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: vec!["agenda".to_string(), "hearing".to_string()],
+            parent_meeting_id: None,
+            presiding_officer: None,
+        };
+        let motion = DecisionMotion {
+            id: "m1-a".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 0,
+            text: "Approve the road contract".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        };
+        let empty = serde_json::json!({});
+        civic_core::db::upsert_motion(&conn, &motion, &empty).unwrap();
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        let score = |meeting: &DecisionMeeting| {
+            civic_core::db::upsert_decision_meeting(&conn, meeting, &empty, &[]).unwrap();
+            let window = resolve_window(Some("2024-03-05".into()), &ReportingZone::utc()).unwrap();
+            let meeting = load_meetings_in_window(&conn, &window).unwrap().remove(0);
+            let input = MeetingScoringInput {
+                artifacts: load_linked_artifacts(&conn, &meeting, &rubric).unwrap(),
+                motions: load_motions_for_meeting(&conn, "m1").unwrap(),
+                votes: Vec::new(),
+                body_rubric: None,
+                meeting,
+            };
+            score_meetings(&[input], &rubric, "2024-03-05T00:00:00Z", None, 1)
+                .remove(0)
+                .scores
+                .remove(0)
+        };
+
+        let agenda_only = score(&meeting);
+        assert_eq!(agenda_only.flags, vec!["no_source_artifacts".to_string()]);
+        assert_eq!(agenda_only.overall_score, rubric.config.general.neutral_score);
+        assert!(agenda_only.contributions.is_empty());
+        assert!(!agenda_only.evidence.contains(&"tag:contract".to_string()));
+        assert!(agenda_only.evidence.contains(&"not_decision_record:agenda:agenda".to_string()));
+        assert!(agenda_only.evidence.contains(&"not_decision_record:notice:hearing".to_string()));
+
+        meeting.artifact_ids.push("minutes".to_string());
+        let with_minutes = score(&meeting);
+        assert!(with_minutes.evidence.contains(&"tag:contract".to_string()));
+        assert!(!with_minutes.flags.contains(&"no_source_artifacts".to_string()));
+
+        // Only explicit types are stored: retagging reclassifies an artifact, and the rubric's
+        // [source_doc_types] classifies untagged ones by source kind.
+        ingest(artifact("untagged", &["contract"], None));
+        civic_core::db::update_artifact_tags(&conn, "agenda", &["minutes".to_string()]).unwrap();
+        let ids = ["agenda", "hearing", "untagged"].map(String::from);
+        let doc_types = |rubric: &Rubric| {
+            civic_core::db::load_linked_artifacts(&conn, &ids, &rubric.config.source_doc_types)
+                .unwrap()
+                .into_iter()
+                .map(|artifact| artifact.doc_type)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(doc_types(&rubric), [DocType::Minutes, DocType::Notice, DocType::Other]);
+        let mut rubric = rubric;
+        rubric.config.source_doc_types.insert("url".to_string(), DocType::Agenda);
+        assert_eq!(doc_types(&rubric), [DocType::Minutes, DocType::Notice, DocType::Agenda]);
+    }
+
+    #[test]
+    fn mostly_unextracted_artifacts_trip_the_extraction_warning() {
+        let artifact = |id: &str, tags: &[&str], body_text: Option<&str>| LinkedArtifact {
+            id: id.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            content_type: Some("application/pdf".to_string()),
+            body_text: body_text.map(str::to_string),
+            source_kind: "url".to_string(),
+            trusted: true,
+            doc_type: DocType::Other,
+        };
+        let input = |id: &str, artifacts: Vec<LinkedArtifact>| MeetingScoringInput {
+            meeting: MeetingWindowRow {
+                id: id.to_string(),
+                body_id: "larue-fiscal-court".to_string(),
+                started_at: "2024-03-04T18:00:00Z".to_string(),
+                artifact_ids_json: "[]".to_string(),
+                presiding_officer: None,
+            },
+            artifacts,
+            motions: Vec::new(),
+            votes: Vec::new(),
+            body_rubric: None,
+        };
+        let shared = artifact("agenda", &[], None);
+        let minutes = artifact("minutes", &["text_extracted"], None);
+        let blank = artifact("notice", &["budget"], Some("  "));
+        let inputs = vec![
+            input("m1", vec![minutes, shared.clone()]),
+            input("m2", vec![shared, blank, artifact("scan", &[], None)]),
+        ];
+
+        let message = extraction_shortfall(&inputs, 0.5).unwrap();
+        assert!(message.starts_with("only 1 of 4 linked artifact(s) have extracted text"));
+        assert!(extraction_shortfall(&inputs, 0.25).is_none());
+        assert!(extraction_shortfall(&inputs[..1], 0.5).is_none());
+        assert!(extraction_shortfall(&[], 1.0).is_none());
+        assert_eq!(ResolvedScoring::default().min_extracted_fraction, 0.5);
+    }
+
+    #[test]
+    fn meetings_are_scored_under_their_body_rubric() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let base = std::env::temp_dir().join(format!("larue-body-rubric-{}", std::process::id()));
+        let school = base.join("larue-school-board");
+        let _ = fs::remove_dir_all(&base);
+        fs::create_dir_all(&school).unwrap();
+        for entry in fs::read_dir(&repo).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_file() {
+                fs::copy(&path, base.join(path.file_name().unwrap())).unwrap();
+                fs::copy(&path, school.join(path.file_name().unwrap())).unwrap();
+            }
+        }
+        let weights = fs::read_to_string(school.join("weights.yaml")).unwrap();
+        fs::write(
+            school.join("weights.yaml"),
+            weights.replace("transparency: 1.4", "transparency: 2.0"),
+        )
+        .unwrap();
+
+        let (path, conn) = meeting_db("body-rubric");
+        let meeting = civic_core::schema::DecisionMeeting {
+            id: "m2".to_string(),
+            body_id: "larue-school-board".to_string(),
+            body_name: None,
+            started_at: "2024-03-04T19:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: Vec::new(),
+            parent_meeting_id: None,
+            presiding_officer: None,
+        };
+        let motion = civic_core::schema::DecisionMotion {
+            id: "m2-a".to_string(),
+            meeting_id: "m2".to_string(),
+            index: 0,
+            text: "Adopt the open records policy".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        };
+        let empty = serde_json::json!({});
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &empty, &[]).unwrap();
+        civic_core::db::upsert_motion(&conn, &motion, &empty).unwrap();
+
+        let rubric = Rubric::load_from_dir(&base).unwrap();
+        let window = resolve_window(Some("2024-03-05".into()), &ReportingZone::utc()).unwrap();
+        let inputs = load_meetings_in_window(&conn, &window)
+            .unwrap()
+            .into_iter()
+            .map(|meeting| MeetingScoringInput {
+                artifacts: load_linked_artifacts(&conn, &meeting, &rubric).unwrap(),
+                motions: load_motions_for_meeting(&conn, &meeting.id).unwrap(),
+                votes: load_votes_for_meeting(&conn, &meeting.id).unwrap(),
+                body_rubric: Rubric::load_for_body(&base, &meeting.body_id).unwrap(),
+                meeting,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(inputs.len(), 2);
+        assert!(inputs[0].body_rubric.is_none());
+        let school_rubric = inputs[1].body_rubric.clone().unwrap();
+        assert_eq!(school_rubric.axis_weights["transparency"], 2.0);
+        assert_ne!(school_rubric.version_hash, rubric.version_hash);
+
+        let scored = score_meetings(&inputs, &rubric, "2024-03-05T00:00:00Z", Some("h"), 1);
+        let versions = |index: usize| {
+            scored[index]
+                .scores
+                .iter()
+                .map(|score| score.rubric_version.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(versions(0), vec![rubric.version_hash.clone()]);
+        assert_eq!(versions(1), vec![school_rubric.version_hash.clone()]);
+        assert_eq!(
+            scored[1].audit_records[0]["inputs"]["rubric_hash"],
+            school_rubric.version_hash
+        );
+        assert!(Rubric::load_for_body(&base, "../larue-school-board").unwrap().is_none());
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn report_json_embeds_votes_only_when_requested() {
+        let (path, conn) = meeting_db("report-votes");
+        let vote = civic_core::schema::DecisionVote {
+            id: "v1".to_string(),
+            motion_id: "m1-a".to_string(),
+            vote_type: Some("roll_call".to_string()),
+            outcome: Some("passed".to_string()),
+            ayes: vec!["Adams".to_string(), "Baker".to_string()],
+            nays: vec!["Clark".to_string()],
+            abstain: vec!["Davis".to_string()],
+        };
+        civic_core::db::upsert_vote(&conn, &vote, &serde_json::json!({})).unwrap();
+        let window = resolve_window(Some("2024-03-05".into()), &ReportingZone::utc()).unwrap();
+        let decisions = load_decisions(&conn, &window, None).unwrap();
+
+        let lean = report_decisions_json(&decisions, false);
+        assert!(lean[0]["motions"][0].get("votes").is_none());
+
+        let full = report_decisions_json(&decisions, true);
+        let votes = full[0]["motions"][0]["votes"].as_array().unwrap();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0]["id"], "v1");
+        assert_eq!(votes[0]["ayes"], serde_json::json!(["Adams", "Baker"]));
+        assert_eq!(votes[0]["nays"], serde_json::json!(["Clark"]));
+        assert_eq!(votes[0]["abstain"], serde_json::json!(["Davis"]));
+        assert_eq!(votes[0]["tally"], serde_json::json!({"ayes": 2, "nays": 1, "abstain": 1}));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn reconsideration_supersedes_the_original_votes_in_grades() {
+        use civic_core::schema::{DecisionMeeting, DecisionMotion, DecisionVote};
+
+        let (path, conn) = meeting_db("reconsider");
+        let artifact = serde_json::json!({
+            "id": "a1",
+            "source": {"kind": "url", "value": "https://example.org/a1",
+                       "retrieved_at": "2024-03-01T00:00:00Z"},
+            "title": "Agenda",
+            "tags": ["contract"]
+        });
+        ingest_artifact_json(&conn, artifact, ArtifactStorage::default(), None).unwrap();
+        let meeting = DecisionMeeting {
+            id: "m1".to_string(),
+            body_id: "larue-fiscal-court".to_string(),
+            body_name: None,
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: vec!["a1".to_string()],
+            parent_meeting_id: None,
+            presiding_officer: None,
+        };
+        let empty = serde_json::json!({});
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &empty, &[]).unwrap();
+        let motion = DecisionMotion {
+            id: "m1-b".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 1,
+            text: "Motion to reconsider the vote on Item #1".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        };
+        civic_core::db::upsert_motion(&conn, &motion, &empty).unwrap();
+        let vote = |id: &str, motion_id: &str, ayes: &[&str], nays: &[&str]| DecisionVote {
+            id: id.to_string(),
+            motion_id: motion_id.to_string(),
+            vote_type: None,
+            outcome: None,
+            ayes: ayes.iter().map(|name| name.to_string()).collect(),
+            nays: nays.iter().map(|name| name.to_string()).collect(),
+            abstain: Vec::new(),
+        };
+        // Adams backs the contract, then votes it down on reconsideration.
+        for vote in [
+            vote("v1", "m1-a", &["Adams", "Baker"], &["Clark"]),
+            vote("v2", "m1-b", &["Baker"], &["Adams", "Clark"]),
+        ] {
+            civic_core::db::upsert_vote(&conn, &vote, &empty).unwrap();
+        }
+
+        let patterns = vec!["reconsider".to_string()];
+        let item = |text: &str| reconsidered_item(text, &patterns);
+        assert_eq!(item("Motion to reconsider the vote on Item #1"), Some(1));
+        assert_eq!(item("Reconsider item no. 12."), Some(12));
+        assert_eq!(item("Approve item 3"), None);
+        assert_eq!(item("Reconsider the last motion"), None);
+
+        let window = resolve_window(Some("2024-03-05".into()), &ReportingZone::utc()).unwrap();
+        let meeting = load_meetings_in_window(&conn, &window).unwrap().remove(0);
+        let mut motions = load_motions_for_meeting(&conn, "m1").unwrap();
+        // Off by default: no patterns, no links.
+        assert!(resolve_scoring(None).reconsideration_patterns.is_empty());
+        assert_eq!(link_reconsiderations(&conn, &mut motions, &[]).unwrap(), 0);
+        assert_eq!(link_reconsiderations(&conn, &mut motions, &patterns).unwrap(), 1);
+        let stored = load_motions_for_meeting(&conn, "m1").unwrap();
+        assert_eq!(stored[1].reconsiders_motion_id.as_deref(), Some("m1-a"));
+
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        let input = MeetingScoringInput {
+            artifacts: load_linked_artifacts(&conn, &meeting, &rubric).unwrap(),
+            motions,
+            votes: load_votes_for_meeting(&conn, "m1").unwrap(),
+            body_rubric: None,
+            meeting,
+        };
+        let scores = score_meetings(&[input], &rubric, "2024-03-05T00:00:00Z", None, 1)
+            .remove(0)
+            .scores;
+        for score in &scores {
+            civic_core::db::upsert_decision_score(&conn, score).unwrap();
+        }
+        let score_of = |id: &str| scores.iter().find(|score| score.id == id).unwrap();
+        let original = score_of("vote:v1:adams");
+        let reconsidered = score_of("vote:v2:adams");
+        assert!(original.evidence.contains(&"reconsidered_by:m1-b".to_string()));
+        assert!(!reconsidered.evidence.iter().any(|item| item.starts_with("reconsidered_by:")));
+        assert_ne!(original.overall_score, reconsidered.overall_score);
+
+        let (start, end) = window.bounds().unwrap();
+        let config: Config = toml::from_str(
+            "[scoring]\ndetect_reconsiderations = true\n\
+             reconsideration_patterns = [\"reconsider\"]\n",
+        )
+        .unwrap();
+        let superseding = resolve_scoring(Some(&config));
+        assert_eq!(superseding.reconsideration_patterns, patterns);
+        let averages = load_official_averages(&conn, &start, &end, None, &superseding).unwrap();
+        assert_eq!(averages["Adams"], reconsidered.overall_score);
+        let keeping = ResolvedScoring {
+            reconsideration_supersedes: false,
+            ..superseding
+        };
+        let averages = load_official_averages(&conn, &start, &end, None, &keeping).unwrap();
+        let both = (original.overall_score + reconsidered.overall_score) / 2.0;
+        assert!((averages["Adams"] - both).abs() < 1e-9);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn presiding_officer_breaks_tied_votes() {
+        let (path, conn) = meeting_db("tie-breaker");
+        let names = |list: &[&str]| list.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+        let meeting = civic_core::schema::DecisionMeeting {
+            id: "m1".to_string(),
+            body_id: "larue-fiscal-court".to_string(),
+            body_name: None,
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: Vec::new(),
+            parent_meeting_id: None,
+            presiding_officer: Some("Judge Brown".to_string()),
+        };
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &serde_json::json!({}), &[])
+            .unwrap();
+        let vote = civic_core::schema::DecisionVote {
+            id: "v1".to_string(),
+            motion_id: "m1-a".to_string(),
+            vote_type: None,
+            outcome: Some("passed".to_string()),
+            ayes: names(&["Adams", "Baker"]),
+            nays: names(&["Clark", "Davis"]),
+            abstain: Vec::new(),
+        };
+        civic_core::db::upsert_vote(&conn, &vote, &serde_json::json!({})).unwrap();
+
+        let window = resolve_window(Some("2024-03-05".into()), &ReportingZone::utc()).unwrap();
+        let meeting = load_meetings_in_window(&conn, &window).unwrap().remove(0);
+        assert_eq!(meeting.presiding_officer.as_deref(), Some("Judge Brown"));
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        let input = MeetingScoringInput {
+            motions: load_motions_for_meeting(&conn, "m1").unwrap(),
+            votes: load_votes_for_meeting(&conn, "m1").unwrap(),
+            body_rubric: None,
+            meeting,
+            artifacts: Vec::new(),
+        };
+        let scores = score_meetings(&[input], &rubric, "2024-03-05T00:00:00Z", None, 1)
+            .remove(0)
+            .scores;
+        // The 2-2 roll plus the chair's unrecorded deciding aye.
+        assert_eq!(scores.iter().filter(|score| score.vote_id.is_some()).count(), 5);
+        let chair = scores.iter().find(|score| score.id == "vote:v1:judge_brown").unwrap();
+        assert!(chair.evidence.contains(&"official:Judge Brown".to_string()));
+        assert!(chair.evidence.contains(&TIE_BREAKER_TAG.to_string()));
+        let adams = scores.iter().find(|score| score.id == "vote:v1:adams").unwrap();
+        assert!(!adams.evidence.contains(&TIE_BREAKER_TAG.to_string()));
+
+        let scoring = ResolvedScoring {
+            tie_breaker_weight: 2.0,
+            ..ResolvedScoring::default()
+        };
+        assert_eq!(aggregation_weight(false, None, &chair.evidence, None, &scoring), 2.0);
+        assert_eq!(aggregation_weight(false, None, &adams.evidence, None, &scoring), 1.0);
+
+        let decisions = load_decisions(&conn, &window, None).unwrap();
+        assert_eq!(decisions[0].motions[0].tie_broken_by.as_deref(), Some("Judge Brown"));
+
+        // A recorded chair vote on an otherwise even split also breaks the tie; a clear
+        // majority does not.
+        let roll = |ayes: &[&str], nays: &[&str]| VoteRow {
+            id: "v2".to_string(),
+            motion_id: "m1-a".to_string(),
+            vote_type: None,
+            outcome: None,
+            ayes: names(ayes),
+            nays: names(nays),
+            abstain: Vec::new(),
+            choices: build_vote_choices(&names(ayes), &names(nays), &[]),
+            tally: VoteTally { ayes: ayes.len(), nays: nays.len() },
+        };
+        let tied = roll(&["Adams"], &["Clark", "Judge Brown"]);
+        let (officer, choice) = tie_breaker(&tied, Some("Judge Brown")).unwrap();
+        assert_eq!((officer.as_str(), choice.to_string()), ("Judge Brown", "nay".to_string()));
+        let majority = roll(&["Adams", "Baker", "Judge Brown"], &["Clark"]);
+        assert!(tie_breaker(&majority, Some("Judge Brown")).is_none());
+        assert!(tie_breaker(&tied, None).is_none());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn weights_applied_reproduce_the_displayed_overall() {
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let mut rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+        rubric.axis_polarity.insert(
+            "fiscal_restraint".to_string(),
+            civic_core::scoring::AxisPolarity::Negative,
+        );
+        let votes = [
+            HashMap::from([
+                ("transparency".to_string(), 10.0),
+                ("fiscal_restraint".to_string(), 5.0),
+            ]),
+            HashMap::from([("transparency".to_string(), 20.0)]),
+        ];
+        let mut builder = OfficialSummaryBuilder::new("Jane Smith", None, "2024-03-04");
+        for axis_scores in votes {
+            let axis_weights = applied_axis_weights(&axis_scores, &rubric);
+            let overall = axis_scores.iter().map(|(axis, score)| score * axis_weights[axis]).sum();
+            builder.overall_scores.push(overall);
+            builder.axis_weights.push(axis_weights);
+            builder.axis_scores.push(axis_scores);
+            builder.weights.push(1.0);
+        }
+        let official = builder.build(Some(&rubric.config), &[]);
+
+        let (score, weight) = official.weights_applied["fiscal_restraint"];
+        assert_eq!((score, weight), (2.5, -1.2));
+        let weighted_sum: f64 =
+            official.weights_applied.values().map(|(score, weight)| score * weight).sum();
+        let expected = normalize_score(weighted_sum, Some(&rubric.config));
+        assert!((weighted_sum - 18.0).abs() < 1e-9);
+        assert!((official.weighted_overall - expected).abs() < 1e-9);
+        assert!((official.numeric_grade - expected).abs() < 1e-9);
+
+        let templates = SiteTemplates::default();
+        let html =
+            render_official_detail(&official, &rubric.axes, "2024-03-05", "x", &templates, 2);
+        assert!(html.contains("<h3>Weights applied</h3>"));
+        assert!(html.contains(
+            "<tr><td>fiscal_restraint</td><td>+2.50</td><td>-1.20</td><td>-3.00</td></tr>"
+        ));
+        assert!(html.contains(&format!("<span class=\"weighted-overall\">{expected:.2}</span>")));
+        assert!(html.contains("<div class=\"stat-value\">59.00</div>"));
+    }
+
+    #[test]
+    fn display_decimals_controls_normalized_score_precision() {
+        let mut builder = OfficialSummaryBuilder::new("Jane Smith", None, "2024-03-04");
+        builder.overall_scores.push(83.46);
+        builder.axis_scores.push(HashMap::from([("budget".to_string(), 83.46)]));
+        builder.weights.push(1.0);
+        let official = builder.build(None, &[]);
+        let templates = SiteTemplates::default();
+        let site = resolve_site_config(None);
+        assert_eq!(site.display_decimals, Some(1));
+
+        let officials = std::slice::from_ref(&official);
+
+        let index = render_officials_index(officials, "2024-03-05", "x", &templates, 1);
+        assert!(index.contains("<span class=\"subtitle\">83.5</span>"));
+        let index = render_officials_index(officials, "2024-03-05", "x", &templates, 0);
+        assert!(index.contains("<span class=\"subtitle\">83</span>"));
+        let detail = render_official_detail(&official, &[], "2024-03-05", "x", &templates, 0);
+        assert!(detail.contains("<div class=\"stat-value\">83</div>"));
+        assert!(detail.contains("<td>budget</td><td>B</td><td>83</td>"));
+        assert!(!detail.contains("83.5"));
+    }
+
+    #[test]
+    fn trend_hysteresis_stops_badges_flipping_on_oscillating_deltas() {
+        let replay = |deltas: &[f64], hysteresis: f64| {
+            let mut trend = Trend::Steady;
+            deltas
+                .iter()
+                .map(|delta| {
+                    trend = next_trend(trend, *delta, hysteresis);
+                    trend
+                })
+                .collect::<Vec<_>>()
+        };
+        let (rising, falling, steady) = (Trend::Rising, Trend::Falling, Trend::Steady);
+
+        // Hovering just around the threshold flips the badge weekly without hysteresis...
+        let hovering = [5.1, 4.9, 5.1, 4.9, -5.1, -4.9];
+        assert_eq!(
+            replay(&hovering, 0.0),
+            [rising, steady, rising, steady, falling, steady]
+        );
+        // ...and never earns one with it.
+        assert_eq!(replay(&hovering, 1.0), [steady; 6]);
+
+        // A clear move starts a trend, which then holds until the delta drops below 4.
+        let settling = [6.5, 4.5, 5.1, 4.1, 3.9, -6.0, -4.2, 2.0];
+        assert_eq!(
+            replay(&settling, 1.0),
+            [rising, rising, rising, rising, steady, falling, falling, steady]
+        );
+
+        // Carried over weekly averages, Smith keeps rising on a 4.5 gain while Jones, whose
+        // earlier move was too small to start a trend, stays steady on the same gain.
+        let week = |smith: f64, jones: f64| {
+            HashMap::from([("Smith".to_string(), smith), ("Jones".to_string(), jones)])
+        };
+        let weeks = [week(50.0, 50.0), week(57.0, 55.5), week(61.5, 60.0)];
+        let trends = carry_trends(&weeks, 1.0);
+        assert_eq!(trends["Smith"], rising);
+        assert_eq!(trends["Jones"], steady);
+        // Officials absent from the latest week are dropped.
+        let trends = carry_trends(&[week(50.0, 50.0), HashMap::new()], 1.0);
+        assert!(trends.is_empty());
+
+        let config: Config = toml::from_str("[site]\ntrend_hysteresis = 1.5\n").unwrap();
+        assert_eq!(resolve_site_config(config.site.as_ref()).trend_hysteresis, Some(1.5));
+        assert_eq!(resolve_site_config(None).trend_hysteresis, Some(0.0));
+    }
+
+    #[test]
+    fn commentary_seed_override_selects_template_per_branch() {
+        let render = |grade: &str, prior: &str, delta: f64, seed: u64| {
+            let site = SiteConfig {
+                commentary_seed_override: Some(seed),
+                ..resolve_site_config(None)
+            };
+            build_commentary_line("jane", "2024-03-05", grade, prior, delta, false, &[], &site)
+                .unwrap()
+        };
+        let cases: [(&str, &str, f64, &[&str]); 6] = [
+            ("C", "B", 0.0, COMMENTARY_DECLINE),
+            ("B", "B", -12.0, COMMENTARY_DECLINE),
+            ("A", "B", 0.0, COMMENTARY_RISE),
+            ("B", "B", 10.0, COMMENTARY_RISE),
+            ("B", "B", 4.0, COMMENTARY_STEADY),
+            ("B", "B", -9.9, COMMENTARY_STEADY),
+        ];
+        for (grade, prior, delta, expected) in cases {
+            for (index, template) in expected.iter().enumerate() {
+                let seed = index as u64 + expected.len() as u64;
+                assert_eq!(
+                    render(grade, prior, delta, seed),
+                    template.replace("{grade}", grade),
+                    "{prior} -> {grade} ({delta})"
+                );
+            }
+        }
+
+        let site = resolve_site_config(None);
+        assert_eq!(site.commentary_seed_override, None);
+        let hashed = build_commentary_line("jane", "2024-03-05", "B", "B", 0.0, false, &[], &site);
+        let index = stable_hash("jane:2024-03-05:satire") % COMMENTARY_STEADY.len() as u64;
+        assert_eq!(hashed.unwrap(), COMMENTARY_STEADY[index as usize].replace("{grade}", "B"));
+    }
+
+    #[test]
+    fn multi_sentence_commentary_is_distinct_and_deterministic() {
+        let config: Config = toml::from_str("[site]\ncommentary_sentences = 9\n").unwrap();
+        let site = resolve_site_config(config.site.as_ref());
+        assert_eq!(site.commentary_sentences, Some(MAX_COMMENTARY_SENTENCES));
+        assert_eq!(resolve_site_config(None).commentary_sentences, Some(1));
+
+        let tags = vec!["zoning".to_string(), "roads".to_string()];
+        let render = |site: &SiteConfig| {
+            build_commentary_line("jane", "2024-03-05", "B", "C", 6.0, false, &tags, site)
+                .unwrap()
+        };
+        let hashed = render(&site);
+        assert_eq!(hashed, render(&site));
+        let pinned = SiteConfig {
+            commentary_seed_override: Some(0),
+            ..site
+        };
+        assert_eq!(
+            render(&pinned),
+            format!(
+                "{} {} {} Top issues: zoning, roads.",
+                COMMENTARY_RISE[0].replace("{grade}", "B"),
+                COMMENTARY_TREND_DETAIL[0].replace("{delta}", "+6.0"),
+                COMMENTARY_TOP_ISSUE[0].replace("{issue}", "zoning"),
+            )
+        );
+
+        // A template shared by two pools is only used once per commentary.
+        let mut used = vec![COMMENTARY_TREND_DETAIL[1]];
+        let pick = |used: &mut Vec<&'static str>| {
+            pick_unused_template(COMMENTARY_TREND_DETAIL, 4, used)
+        };
+        assert_eq!(pick(&mut used), COMMENTARY_TREND_DETAIL[2]);
+        assert_eq!(pick(&mut used), COMMENTARY_TREND_DETAIL[0]);
+        assert_eq!(used.len(), 3);
+    }
+
+    #[test]
+    fn compare_page_reads_officials_api_json() {
+        let mut builder = OfficialSummaryBuilder::new("Jane Smith", None, "2024-03-04");
+        builder.overall_scores.push(83.46);
+        builder.axis_scores.push(HashMap::from([
+            ("legacy".to_string(), 70.0),
+            ("budget".to_string(), 83.46),
+        ]));
+        builder.weights.push(1.0);
+        let official = builder.build(None, &[]);
+        let axes = vec!["transparency".to_string(), "budget".to_string()];
+
+        let api = officials_api_json(std::slice::from_ref(&official), &axes, "2024-03-05");
+        assert_eq!(api["week_date"], "2024-03-05");
+        assert_eq!(api["axes"], serde_json::json!(["budget", "legacy"]));
+        let entry = &api["officials"][0];
+        assert_eq!(entry["id"], official.id.as_str());
+        assert_eq!(entry["name"], "Jane Smith");
+        assert_eq!(entry["letter_grade"], "B");
+        assert_eq!(entry["axes"]["budget"]["letter"], "B");
+        assert_eq!(entry["axes"]["legacy"]["letter"], "C-");
+
+        let html = render_compare_page("2024-03-05", "x", &SiteTemplates::default(), 0);
+        assert!(html.contains("<title>Compare officials</title>"));
+        assert!(html.contains(
+            "id=\"compare\" data-src=\"/api/officials.json\" data-decimals=\"0\""
+        ));
+        assert_eq!(html.matches("<select").count(), 2);
+        assert!(html.contains("<script src=\"/assets/app.js\"></script>"));
+        assert!(html.contains("<a href=\"/compare/index.html\">Compare</a>"));
+    }
+
+    #[test]
+    fn artifacts_index_is_capped_and_filterable() {
+        let artifact = |id: &str, day: u32, tags: &[&str]| {
+            serde_json::from_value::<civic_core::schema::Artifact>(serde_json::json!({
+                "id": id,
+                "source": {
+                    "kind": "url",
+                    "value": format!("https://example.org/{id}"),
+                    "retrieved_at": format!("2024-03-0{day}T12:00:00Z"),
+                },
+                "title": format!("Notice <{id}>"),
+                "body_text": null,
+                "content_type": null,
+                "tags": tags,
+            }))
+            .unwrap()
+        };
+        let artifacts = vec![
+            artifact("new", 3, &["budget", "roads"]),
+            artifact("mid", 2, &["zoning"]),
+            artifact("old", 1, &["budget"]),
+        ];
+        let exported = HashSet::from(["new".to_string()]);
+        let render = |limit| {
+            render_artifacts_index(
+                &artifacts,
+                limit,
+                &exported,
+                "2024-03-05",
+                &ReportingZone::utc(),
+                "x",
+                &SiteTemplates::default(),
+            )
+        };
+
+        let html = render(2);
+        assert!(html.contains("<title>Artifacts</title>"));
+        assert!(html.contains("<a href=\"/artifacts/index.html\">Artifacts</a>"));
+        assert!(html.contains("Showing the newest 2 of 3 artifacts."));
+        assert!(html.contains("<tr data-tags=\"budget roads\">"));
+        assert!(html.contains("<a href=\"/artifacts/new.json\">Notice &lt;new&gt;</a>"));
+        assert!(html.contains("<td>Notice &lt;mid&gt;</td>"));
+        assert!(!html.contains("Notice &lt;old&gt;"));
+        assert!(html.contains("<td data-value=\"1709467200\">2024-03-03</td>"));
+        assert!(html.contains("<a href=\"https://example.org/new\">url</a>"));
+        assert_eq!(html.matches("<th data-sort>").count(), 3);
+        assert!(html.contains("<option value=\"zoning\">zoning</option>"));
+        assert_eq!(html.matches("<option value=\"budget\">").count(), 1);
+
+        assert!(!render(500).contains("Showing the newest"));
+    }
+
+    #[test]
+    fn template_dir_overrides_page_chrome() {
+        let dir = std::env::temp_dir().join(format!("larue-templates-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("nav.html"),
+            "<nav class=\"county\">Hodgenville Watch {{ week_date }} {{unknown}}</nav>",
+        )
+        .unwrap();
+
+        let templates = SiteTemplates::load(Some(&dir)).unwrap();
+        let html = render_officials_index(&[], "2024-03-05", "Opinion {{body}}", &templates, 1);
+
+        let nav = "<nav class=\"county\">Hodgenville Watch 2024-03-05 {{unknown}}</nav>";
+        assert!(html.contains(nav));
+        assert!(!html.contains("LaRue Civic Intel</span>"));
+        // Defaults still cover the templates the directory does not override.
+        assert!(html.starts_with("<!doctype html>"));
+        assert!(html.contains("<title>Officials</title>"));
+        assert!(html.contains("<div class=\"subtitle\">Opinion {{body}}</div>"));
+
+        let default =
+            render_officials_index(&[], "2024-03-05", "x", &SiteTemplates::default(), 1);
+        assert!(default.contains("<span>LaRue Civic Intel</span>"));
+        assert!(SiteTemplates::load(Some(&dir.join("missing"))).is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,285 @@
+use crate::vault::{
+    OfficialNote, VaultLayout, VaultPaths, render_decision_meeting_notes, render_official_note,
+    render_score_report,
+};
+use anyhow::Result;
+use civic_core::scoring::Rubric;
+use rusqlite::Connection;
+use std::collections::{BTreeMap, HashMap};
+
+/// One vault note placed in the bundle: its wiki-link target (`<dir>/<stem>`) and body with
+/// frontmatter stripped and headings demoted under the bundle's section headings.
+struct BundleNote {
+    target: String,
+    body: String,
+}
+
+/// Renders the weekly score report, decision meeting notes, and official notes into a single
+/// Markdown document with a table of contents. Wiki-links to notes in the bundle become anchor
+/// links; links to anything else (artifacts, report weeks) are reduced to their label.
+pub fn build_vault_bundle(
+    conn: &Connection,
+    layout: &VaultLayout,
+    top_n: usize,
+    exclude_insufficient: bool,
+    rubric: Option<&Rubric>,
+    officials: &[OfficialNote],
+) -> Result<String> {
+    // Normalized the same way as the vault, so link targets match what the notes emit.
+    let layout = VaultPaths::with_layout("", layout).layout;
+
+    let (report_stem, report_md) = render_score_report(conn, top_n, exclude_insufficient)?;
+    let report = vec![BundleNote {
+        target: format!("{}/{report_stem}", layout.reports_dir),
+        body: demote_note(&report_md),
+    }];
+    let decisions = render_decision_meeting_notes(conn, &layout, rubric)?
+        .into_iter()
+        .map(|(stem, md)| BundleNote {
+            target: format!("{}/{stem}", layout.meetings_dir),
+            body: demote_note(&md),
+        })
+        .collect::<Vec<_>>();
+    let mut sorted: Vec<&OfficialNote> = officials.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+    let officials = sorted
+        .into_iter()
+        .map(|official| BundleNote {
+            target: format!("{}/{}", layout.officials_dir, official.id),
+            body: demote_note(&render_official_note(&layout, official)),
+        })
+        .collect::<Vec<_>>();
+    let sections = [
+        ("Weekly Report", report),
+        ("Decisions", decisions),
+        ("Officials", officials),
+    ];
+
+    // Anchors follow GitHub's heading slugs, which number repeated headings in document order,
+    // so every heading is slugged in the order it will be written.
+    let mut slugger = HeadingSlugger::default();
+    slugger.slug("Contents");
+    let mut anchors: HashMap<String, (String, String)> = HashMap::new();
+    let mut toc = vec!["## Contents".to_string(), String::new()];
+    for (title, notes) in &sections {
+        toc.push(format!("- [{title}](#{})", slugger.slug(title)));
+        for note in notes {
+            let mut headings = note.body.lines().filter_map(heading_text);
+            if let Some(heading) = headings.next() {
+                let anchor = slugger.slug(heading);
+                toc.push(format!("  - [{heading}](#{anchor})"));
+                anchors.insert(note.target.clone(), (heading.to_string(), anchor));
+            }
+            for heading in headings {
+                slugger.slug(heading);
+            }
+        }
+    }
+
+    let mut md = String::new();
+    md.push_str("# LaRue Civic Intel Vault Bundle\n\n");
+    md.push_str("This document is generated. Do not edit manually.\n\n");
+    md.push_str(&toc.join("\n"));
+    md.push('\n');
+    for (title, notes) in &sections {
+        md.push_str(&format!("\n## {title}\n\n"));
+        if notes.is_empty() {
+            md.push_str("_Nothing recorded._\n");
+        }
+        for note in notes {
+            md.push_str(&resolve_wikilinks(&note.body, &anchors));
+            md.push('\n');
+        }
+    }
+    Ok(md)
+}
+
+/// Drops YAML frontmatter and pushes every heading down two levels, so a note's `#` title sits
+/// under a bundle `##` section.
+fn demote_note(md: &str) -> String {
+    let body = md
+        .strip_prefix("---\n")
+        .and_then(|rest| rest.split_once("\n---\n"))
+        .map(|(_, body)| body)
+        .unwrap_or(md);
+    body.trim_start_matches('\n')
+        .lines()
+        .map(|line| {
+            if heading_text(line).is_some() {
+                format!("##{line}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+fn heading_text(line: &str) -> Option<&str> {
+    let text = line.trim_start_matches('#');
+    if text.len() == line.len() {
+        return None;
+    }
+    text.strip_prefix(' ').map(str::trim)
+}
+
+/// `[[target|label]]` becomes `[label](#anchor)` when the target is in the bundle, otherwise
+/// just `label`. Unterminated `[[` is left as-is.
+fn resolve_wikilinks(md: &str, anchors: &HashMap<String, (String, String)>) -> String {
+    let mut out = String::with_capacity(md.len());
+    let mut rest = md;
+    while let Some(start) = rest.find("[[") {
+        let Some(len) = rest[start + 2..].find("]]") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let inner = &rest[start + 2..start + 2 + len];
+        let (target, label) = inner.split_once('|').unwrap_or((inner, ""));
+        let target = target.trim();
+        match anchors.get(target) {
+            Some((heading, anchor)) => {
+                let label = if label.is_empty() { heading.as_str() } else { label };
+                out.push_str(&format!("[{label}](#{anchor})"));
+            }
+            None if label.is_empty() => {
+                out.push_str(target.rsplit('/').next().unwrap_or(target));
+            }
+            None => out.push_str(label),
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[derive(Default)]
+struct HeadingSlugger {
+    seen: BTreeMap<String, usize>,
+}
+
+impl HeadingSlugger {
+    fn slug(&mut self, heading: &str) -> String {
+        let base: String = heading
+            .to_lowercase()
+            .chars()
+            .filter_map(|ch| match ch {
+                ' ' => Some('-'),
+                ch if ch.is_alphanumeric() || ch == '-' || ch == '_' => Some(ch),
+                _ => None,
+            })
+            .collect();
+        let count = self.seen.entry(base.clone()).or_insert(0);
+        let slug = if *count == 0 {
+            base
+        } else {
+            format!("{base}-{count}")
+        };
+        *count += 1;
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault::OfficialReceipt;
+    use civic_core::schema::{DecisionMeeting, DecisionMotion};
+    use serde_json::json;
+
+    #[test]
+    fn bundle_has_contents_sections_and_anchor_links() {
+        let conn = civic_core::db::open_memory().unwrap();
+        let meeting = DecisionMeeting {
+            id: "m1".to_string(),
+            body_id: "larue-fiscal-court".to_string(),
+            body_name: None,
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: vec!["a1".to_string()],
+            parent_meeting_id: None,
+            presiding_officer: None,
+        };
+        let motion = DecisionMotion {
+            id: "m1-a".to_string(),
+            meeting_id: "m1".to_string(),
+            index: 0,
+            text: "Approve the road contract".to_string(),
+            moved_by: None,
+            seconded_by: None,
+            result: Some("passed".to_string()),
+            amended: false,
+            amendment_text: None,
+            agenda_ref: None,
+        };
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &json!({}), &[]).unwrap();
+        civic_core::db::upsert_motion(&conn, &motion, &json!({})).unwrap();
+        let reconsider = DecisionMotion {
+            id: "m1-b".to_string(),
+            index: 1,
+            text: "Reconsider item 1".to_string(),
+            ..motion
+        };
+        civic_core::db::upsert_motion(&conn, &reconsider, &json!({})).unwrap();
+        civic_core::db::set_motion_reconsiders(&conn, "m1-b", Some("m1-a")).unwrap();
+        let official = OfficialNote {
+            id: "jane-doe".to_string(),
+            name: "Jane Doe".to_string(),
+            week_date: "2024-03-05".to_string(),
+            letter_grade: "B".to_string(),
+            numeric_grade: 84.0,
+            delta: 0.0,
+            axes: Vec::new(),
+            drift_flags: Vec::new(),
+            insufficient: false,
+            provisional: false,
+            receipts: vec![OfficialReceipt {
+                meeting_date: "2024-03-04".to_string(),
+                motion_text: "Approve the road contract".to_string(),
+                vote_label: None,
+                artifact_ids: vec!["a1".to_string()],
+                week_date: "2024-03-05".to_string(),
+            }],
+        };
+
+        let layout = VaultLayout::default();
+        let bundle = build_vault_bundle(&conn, &layout, 3, false, None, &[official]).unwrap();
+
+        assert!(bundle.starts_with("# LaRue Civic Intel Vault Bundle\n"));
+        for section in ["## Contents", "## Weekly Report", "## Decisions", "## Officials"] {
+            assert!(bundle.contains(&format!("\n{section}\n")), "missing {section}");
+        }
+        assert!(bundle.contains("### LaRue County Fiscal Court — 2024-03-04"));
+        assert!(bundle.contains(
+            "  - [LaRue County Fiscal Court — 2024-03-04](#larue-county-fiscal-court--2024-03-04)"
+        ));
+        assert!(bundle.contains("- [Jane Doe](#jane-doe)"));
+        assert!(bundle.contains(
+            "#### Motions\n\
+             - Approve the road contract (passed) — reconsidered by \"Reconsider item 1\"\n\
+             - Reconsider item 1 (passed) — reconsiders \"Approve the road contract\"\n"
+        ));
+        assert!(bundle.contains("- a1\n"));
+        assert!(!bundle.contains("[["));
+        assert!(!bundle.contains("\nid: m1\n"));
+    }
+
+    #[test]
+    fn wikilinks_to_bundled_notes_become_anchors() {
+        let anchors = HashMap::from([(
+            "Meetings/2024-03-04-finance".to_string(),
+            ("Finance — 2024-03-04".to_string(), "finance--2024-03-04".to_string()),
+        )]);
+        let md = "See [[Meetings/2024-03-04-finance|2024-03-04]], [[Meetings/2024-03-04-finance]] \
+                  and [[Artifacts/a1|a1]] or [[Artifacts/a2]] [[open";
+        assert_eq!(
+            resolve_wikilinks(md, &anchors),
+            "See [2024-03-04](#finance--2024-03-04), [Finance — 2024-03-04](#finance--2024-03-04) \
+             and a1 or a2 [[open"
+        );
+
+        let mut slugger = HeadingSlugger::default();
+        assert_eq!(slugger.slug("Motions"), "motions");
+        assert_eq!(slugger.slug("Motions"), "motions-1");
+    }
+}
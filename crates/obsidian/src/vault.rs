@@ -1,4 +1,8 @@
 use anyhow::Result;
+use civic_core::paths::{safe_filename, write_atomic};
+use civic_core::progress::Progress;
+use civic_core::scoring::Rubric;
+use civic_core::tags::is_issue_tag;
 use rusqlite::Connection;
 use serde_json;
 use std::collections::BTreeMap;
@@ -8,21 +12,61 @@ use time::{Duration, OffsetDateTime};
 use time::format_description::FormatItem;
 use time::format_description::well_known::Rfc3339;
 
+/// Vault subdirectory names relative to the vault root; `/` separates nested folders.
+/// The same names are used as wiki-link prefixes in generated notes.
+#[derive(Debug, Clone)]
+pub struct VaultLayout {
+    pub index_dir: String,
+    pub artifacts_dir: String,
+    pub meetings_dir: String,
+    pub reports_dir: String,
+    pub officials_dir: String,
+}
+
+impl Default for VaultLayout {
+    fn default() -> Self {
+        Self {
+            index_dir: "00_Index".to_string(),
+            artifacts_dir: "Artifacts".to_string(),
+            meetings_dir: "Meetings".to_string(),
+            reports_dir: "Reports/Weekly".to_string(),
+            officials_dir: "Officials".to_string(),
+        }
+    }
+}
+
 pub struct VaultPaths {
     pub root: PathBuf,
     pub index_dir: PathBuf,
     pub artifacts_dir: PathBuf,
     pub meetings_dir: PathBuf,
+    pub reports_dir: PathBuf,
+    pub officials_dir: PathBuf,
+    pub layout: VaultLayout,
 }
 
 impl VaultPaths {
     pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self::with_layout(root, &VaultLayout::default())
+    }
+
+    pub fn with_layout(root: impl Into<PathBuf>, layout: &VaultLayout) -> Self {
         let root = root.into();
+        let layout = VaultLayout {
+            index_dir: link_prefix(&layout.index_dir),
+            artifacts_dir: link_prefix(&layout.artifacts_dir),
+            meetings_dir: link_prefix(&layout.meetings_dir),
+            reports_dir: link_prefix(&layout.reports_dir),
+            officials_dir: link_prefix(&layout.officials_dir),
+        };
         Self {
-            index_dir: root.join("00_Index"),
-            artifacts_dir: root.join("Artifacts"),
-            meetings_dir: root.join("Meetings"),
+            index_dir: root.join(&layout.index_dir),
+            artifacts_dir: root.join(&layout.artifacts_dir),
+            meetings_dir: root.join(&layout.meetings_dir),
+            reports_dir: root.join(&layout.reports_dir),
+            officials_dir: root.join(&layout.officials_dir),
             root,
+            layout,
         }
     }
 
@@ -34,14 +78,39 @@ impl VaultPaths {
     }
 }
 
-pub fn build_vault(conn: &Connection, vault_root: &Path) -> Result<()> {
-    let paths = VaultPaths::new(vault_root);
+fn link_prefix(dir: &str) -> String {
+    dir.trim_matches('/').to_string()
+}
+
+/// `top_n` caps the positive/negative decision lists in the weekly score report, and
+/// `exclude_insufficient` leaves insufficient-evidence scores out of its average. With a rubric,
+/// decision meeting notes also show their evidence coverage. Every note's frontmatter records
+/// when and from which database it was generated (see `Provenance`).
+pub fn build_vault(
+    conn: &Connection,
+    vault_root: &Path,
+    layout: &VaultLayout,
+    top_n: usize,
+    exclude_insufficient: bool,
+    rubric: Option<&Rubric>,
+) -> Result<()> {
+    let paths = VaultPaths::with_layout(vault_root, layout);
     paths.ensure()?;
+    let provenance = Provenance {
+        generated_at: OffsetDateTime::now_utc().format(&Rfc3339)?,
+        source_db: conn
+            .path()
+            .filter(|path| !path.is_empty())
+            .unwrap_or(":memory:")
+            .to_string(),
+        rubric_version: rubric.map(|rubric| rubric.version_hash.clone()),
+    };
 
     // 1) Write artifact notes
     let mut stmt = conn.prepare(
         r#"
-        SELECT id, source_kind, source_value, retrieved_at, title, content_type, body_text, tags_json
+        SELECT id, source_kind, source_value, retrieved_at, title, content_type, body_text, tags_json,
+               COALESCE(importance, 0), COALESCE(supersedes_json, '[]')
         FROM artifacts
         ORDER BY retrieved_at DESC
         "#,
@@ -57,27 +126,45 @@ pub fn build_vault(conn: &Connection, vault_root: &Path) -> Result<()> {
             content_type: row.get(5)?,
             body_text: row.get(6)?,
             tags_json: row.get(7)?,
+            importance: row.get(8)?,
+            supersedes: serde_json::from_str(&row.get::<_, String>(9)?).unwrap_or_default(),
         })
     })?;
-
-    let mut index_lines: Vec<String> = Vec::new();
-    index_lines.push("# MOC - Artifacts".to_string());
-    index_lines.push(String::new());
-    index_lines.push("This index is generated. Do not edit manually.".to_string());
-    index_lines.push(String::new());
+    let superseded_by = civic_core::db::artifact_superseded_by(conn)?;
+    let artifact_count: i64 =
+        conn.query_row("SELECT COUNT(*) FROM artifacts", [], |row| row.get(0))?;
+
+    let mut index_lines: Vec<String> = vec![
+        "# MOC - Artifacts".to_string(),
+        String::new(),
+        "This index is generated. Do not edit manually.".to_string(),
+        String::new(),
+    ];
 
     let mut issue_counts: BTreeMap<String, usize> = BTreeMap::new();
 
+    let mut progress = Progress::new("build-vault", artifact_count as usize);
     for r in rows {
+        progress.tick();
         let a = r?;
-        write_artifact_note(&paths, &a)?;
-        index_lines.push(format!("- [[Artifacts/{}|{}]]", a.id, a.index_title()));
-        update_issue_counts(&a.tags_json, &mut issue_counts);
+        if let Err(err) = safe_filename(&a.id) {
+            eprintln!("Skipping artifact note: {err}");
+            continue;
+        }
+        let newer = superseded_by.get(&a.id).map(Vec::as_slice).unwrap_or_default();
+        write_artifact_note(&paths, &a, newer, &provenance)?;
+        index_lines.push(format!(
+            "- [[{}/{}|{}]]",
+            paths.layout.artifacts_dir,
+            a.id,
+            a.index_title()
+        ));
+        update_issue_counts(&a.tags_json, rubric, &mut issue_counts);
     }
 
     // 2) Write MOC
     let moc_path = paths.index_dir.join("MOC - Artifacts.md");
-    fs::write(moc_path, index_lines.join("\n"))?;
+    write_atomic(moc_path, provenance.stamp(&index_lines.join("\n"), false))?;
 
     // 3) Write meeting notes
     let mut stmt = conn.prepare(
@@ -98,46 +185,53 @@ pub fn build_vault(conn: &Connection, vault_root: &Path) -> Result<()> {
         })
     })?;
 
-    let mut meeting_index: Vec<String> = Vec::new();
-    meeting_index.push("# MOC - Meetings".to_string());
-    meeting_index.push(String::new());
-    meeting_index.push("This index is generated. Do not edit manually.".to_string());
-    meeting_index.push(String::new());
+    let mut meeting_index: Vec<String> = vec![
+        "# MOC - Meetings".to_string(),
+        String::new(),
+        "This index is generated. Do not edit manually.".to_string(),
+        String::new(),
+    ];
 
     for r in rows {
         let m = r?;
-        write_meeting_note(&paths, &m)?;
+        if let Err(err) = safe_filename(&m.id) {
+            eprintln!("Skipping meeting note: {err}");
+            continue;
+        }
+        write_meeting_note(&paths, &m, &provenance)?;
         meeting_index.push(format!(
-            "- [[Meetings/{}|{}]]",
+            "- [[{}/{}|{}]]",
+            paths.layout.meetings_dir,
             m.id,
             m.index_title()
         ));
     }
 
     let meeting_moc_path = paths.index_dir.join("MOC - Meetings.md");
-    fs::write(meeting_moc_path, meeting_index.join("\n"))?;
+    write_atomic(meeting_moc_path, provenance.stamp(&meeting_index.join("\n"), false))?;
 
     // 4) Write decision meeting notes
-    write_decision_meeting_notes(conn, &paths)?;
+    write_decision_meeting_notes(conn, &paths, rubric, &provenance)?;
 
     // 5) Write weekly score report
-    write_score_report(conn, &paths)?;
+    write_score_report(conn, &paths, top_n, exclude_insufficient, &provenance)?;
 
     // 6) Write reports MOC
-    write_reports_moc(&paths)?;
+    write_reports_moc(&paths, &provenance)?;
 
     // 7) Write issue MOC
-    let mut issue_lines: Vec<String> = Vec::new();
-    issue_lines.push("# MOC - Issues".to_string());
-    issue_lines.push(String::new());
-    issue_lines.push("This index is generated. Do not edit manually.".to_string());
-    issue_lines.push(String::new());
+    let mut issue_lines: Vec<String> = vec![
+        "# MOC - Issues".to_string(),
+        String::new(),
+        "This index is generated. Do not edit manually.".to_string(),
+        String::new(),
+    ];
     issue_lines.push("## Weekly Reports".to_string());
     issue_lines.push(String::new());
 
-    let reports_dir = paths.root.join("Reports").join("Weekly");
+    let reports_dir = &paths.reports_dir;
     if reports_dir.exists() {
-        let mut report_links: Vec<String> = fs::read_dir(&reports_dir)?
+        let mut report_links: Vec<String> = fs::read_dir(reports_dir)?
             .filter_map(|entry| entry.ok())
             .filter_map(|entry| {
                 let path = entry.path();
@@ -145,7 +239,7 @@ pub fn build_vault(conn: &Connection, vault_root: &Path) -> Result<()> {
                     return None;
                 }
                 let stem = path.file_stem()?.to_str()?.to_string();
-                Some(format!("- [[Reports/Weekly/{stem}|{stem}]]"))
+                Some(format!("- [[{}/{stem}|{stem}]]", paths.layout.reports_dir))
             })
             .collect();
         report_links.sort();
@@ -173,11 +267,42 @@ pub fn build_vault(conn: &Connection, vault_root: &Path) -> Result<()> {
     }
 
     let issue_moc_path = paths.index_dir.join("MOC - Issues.md");
-    fs::write(issue_moc_path, issue_lines.join("\n"))?;
+    write_atomic(issue_moc_path, provenance.stamp(&issue_lines.join("\n"), false))?;
 
     Ok(())
 }
 
+/// When and from what `build_vault` produced its notes, captured once per build so every note
+/// from one run carries the same `generated_at`. Dataview can compare it against the
+/// database's latest ingest to surface stale notes.
+struct Provenance {
+    generated_at: String,
+    source_db: String,
+    rubric_version: Option<String>,
+}
+
+impl Provenance {
+    /// Appends `generated_at`, `source_db`, and for score notes `rubric_version` to the note's
+    /// frontmatter, adding a frontmatter block to notes without one.
+    fn stamp(&self, md: &str, scored: bool) -> String {
+        // JSON strings are valid YAML, so paths and hashes need no further escaping.
+        let quote = |value: &str| serde_json::to_string(value).unwrap_or_default();
+        let mut fields = format!(
+            "generated_at: {}\nsource_db: {}\n",
+            self.generated_at,
+            quote(&self.source_db)
+        );
+        if scored && let Some(version) = &self.rubric_version {
+            fields.push_str(&format!("rubric_version: {}\n", quote(version)));
+        }
+        let (frontmatter, body) = split_frontmatter(md);
+        match frontmatter.strip_suffix("---\n") {
+            Some(open) => format!("{open}{fields}---\n{body}"),
+            None => format!("---\n{fields}---\n\n{md}"),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct ArtifactRow {
     id: String,
@@ -188,6 +313,8 @@ struct ArtifactRow {
     content_type: Option<String>,
     body_text: Option<String>,
     tags_json: String,
+    importance: f64,
+    supersedes: Vec<String>,
 }
 
 impl ArtifactRow {
@@ -196,8 +323,13 @@ impl ArtifactRow {
     }
 }
 
-fn write_artifact_note(paths: &VaultPaths, a: &ArtifactRow) -> Result<()> {
-    let note_path = paths.artifacts_dir.join(format!("{}.md", a.id));
+fn write_artifact_note(
+    paths: &VaultPaths,
+    a: &ArtifactRow,
+    superseded_by: &[String],
+    provenance: &Provenance,
+) -> Result<()> {
+    let note_path = paths.artifacts_dir.join(format!("{}.md", safe_filename(&a.id)?));
 
     // Minimal frontmatter for later search/sorting
     let mut md = String::new();
@@ -212,6 +344,7 @@ fn write_artifact_note(paths: &VaultPaths, a: &ArtifactRow) -> Result<()> {
     }
     md.push_str("tags_json: |\n");
     md.push_str(&indent_yaml_block(&a.tags_json));
+    md.push_str(&format!("importance: {:.2}\n", a.importance));
     md.push_str("---\n\n");
 
     md.push_str(&format!("# {}\n\n", a.title.clone().unwrap_or_else(|| a.id.clone())));
@@ -219,7 +352,25 @@ fn write_artifact_note(paths: &VaultPaths, a: &ArtifactRow) -> Result<()> {
     md.push_str("## Source\n");
     md.push_str(&format!("- Kind: `{}`\n", a.source_kind));
     md.push_str(&format!("- Value: {}\n", a.source_value));
-    md.push_str(&format!("- Retrieved: `{}`\n\n", a.retrieved_at));
+    md.push_str(&format!("- Retrieved: `{}`\n", a.retrieved_at));
+    md.push_str(&format!("- Importance: `{:.2}`\n\n", a.importance));
+
+    if !a.supersedes.is_empty() || !superseded_by.is_empty() {
+        let links = |ids: &[String]| {
+            ids.iter()
+                .map(|id| format!("[[{}/{id}|{id}]]", paths.layout.artifacts_dir))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        md.push_str("## Supersedes / Superseded by\n");
+        if !a.supersedes.is_empty() {
+            md.push_str(&format!("- Supersedes: {}\n", links(&a.supersedes)));
+        }
+        if !superseded_by.is_empty() {
+            md.push_str(&format!("- Superseded by: {}\n", links(superseded_by)));
+        }
+        md.push('\n');
+    }
 
     md.push_str("## Extracted Text\n");
     match &a.body_text {
@@ -232,8 +383,7 @@ fn write_artifact_note(paths: &VaultPaths, a: &ArtifactRow) -> Result<()> {
         }
     }
 
-    fs::write(note_path, md)?;
-    Ok(())
+    write_generated_note(&note_path, &provenance.stamp(&md, false))
 }
 
 #[derive(Debug)]
@@ -251,8 +401,12 @@ impl MeetingRow {
     }
 }
 
-fn write_meeting_note(paths: &VaultPaths, meeting: &MeetingRow) -> Result<()> {
-    let note_path = paths.meetings_dir.join(format!("{}.md", meeting.id));
+fn write_meeting_note(
+    paths: &VaultPaths,
+    meeting: &MeetingRow,
+    provenance: &Provenance,
+) -> Result<()> {
+    let note_path = paths.meetings_dir.join(format!("{}.md", safe_filename(&meeting.id)?));
 
     let mut md = String::new();
     md.push_str("---\n");
@@ -269,8 +423,58 @@ fn write_meeting_note(paths: &VaultPaths, meeting: &MeetingRow) -> Result<()> {
     md.push_str(&format!("- Body: `{}`\n", meeting.body_id));
     md.push_str(&format!("- Started: `{}`\n", meeting.started_at));
 
-    fs::write(note_path, md)?;
-    Ok(())
+    write_generated_note(&note_path, &provenance.stamp(&md, false))
+}
+
+/// Delimits the generated part of an artifact or meeting note; anything outside it is the
+/// user's and survives rebuilds.
+const GENERATED_BEGIN: &str = "<!-- LARUE:BEGIN -->";
+const GENERATED_END: &str = "<!-- LARUE:END -->";
+
+/// Writes a generated note without clobbering manual edits. The frontmatter is always
+/// regenerated (Obsidian only reads it at the top of the file) and the body goes between
+/// `GENERATED_BEGIN` and `GENERATED_END`; text the user added before or after that region is
+/// kept. An existing note with no markers predates the convention and is fully generated, so
+/// it is replaced and gains the markers. A note with only one marker is left alone, since its
+/// region can no longer be told apart from the user's text.
+fn write_generated_note(path: &Path, md: &str) -> Result<()> {
+    let (frontmatter, body) = split_frontmatter(md);
+    let body = body.trim_start_matches('\n');
+    let existing = match fs::read_to_string(path) {
+        Ok(existing) => Some(existing),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+    let (before, after) = match existing.as_deref().map(|note| split_frontmatter(note).1) {
+        Some(rest) => match (rest.find(GENERATED_BEGIN), rest.find(GENERATED_END)) {
+            (Some(begin), Some(end)) if begin < end => {
+                (&rest[..begin], &rest[end + GENERATED_END.len()..])
+            }
+            (None, None) => ("", "\n"),
+            _ => {
+                eprintln!(
+                    "Skipping {}: unbalanced {GENERATED_BEGIN} / {GENERATED_END} markers",
+                    path.display()
+                );
+                return Ok(());
+            }
+        },
+        None => ("", "\n"),
+    };
+    let newline = if body.ends_with('\n') { "" } else { "\n" };
+    write_atomic(
+        path,
+        format!("{frontmatter}{before}{GENERATED_BEGIN}\n{body}{newline}{GENERATED_END}{after}"),
+    )
+}
+
+/// Splits a note into its frontmatter (through the closing `---` line) and the rest; the
+/// frontmatter is empty when the note has none.
+fn split_frontmatter(md: &str) -> (&str, &str) {
+    md.strip_prefix("---\n")
+        .and_then(|rest| rest.find("\n---\n"))
+        .map(|end| md.split_at(4 + end + 5))
+        .unwrap_or(("", md))
 }
 
 fn indent_yaml_block(s: &str) -> String {
@@ -281,7 +485,7 @@ fn indent_yaml_block(s: &str) -> String {
         out.push_str(line);
         out.push('\n');
     }
-    if s.ends_with('\n') == false {
+    if !s.ends_with('\n') {
         // ensure trailing newline inside block
         // already added per line; this is fine
     }
@@ -295,11 +499,11 @@ struct DecisionMeetingRow {
     body_name: String,
     started_at: String,
     artifact_ids_json: String,
+    parent_meeting_id: Option<String>,
 }
 
 #[derive(Debug)]
 struct DecisionMotionRow {
-    #[allow(dead_code)]
     id: String,
     #[allow(dead_code)]
     meeting_id: String,
@@ -307,12 +511,35 @@ struct DecisionMotionRow {
     result: Option<String>,
     #[allow(dead_code)]
     index: i64,
+    amended: bool,
+    reconsiders_motion_id: Option<String>,
+}
+
+fn write_decision_meeting_notes(
+    conn: &Connection,
+    paths: &VaultPaths,
+    rubric: Option<&Rubric>,
+    provenance: &Provenance,
+) -> Result<()> {
+    for (stem, md) in render_decision_meeting_notes(conn, &paths.layout, rubric)? {
+        let path = paths.meetings_dir.join(format!("{stem}.md"));
+        write_generated_note(&path, &provenance.stamp(&md, true))?;
+    }
+    Ok(())
 }
 
-fn write_decision_meeting_notes(conn: &Connection, paths: &VaultPaths) -> Result<()> {
+/// `(note stem, markdown)` for each decision meeting, newest first. `layout` supplies the
+/// wiki-link prefixes and must already be normalized (see `VaultPaths::with_layout`).
+pub(crate) fn render_decision_meeting_notes(
+    conn: &Connection,
+    layout: &VaultLayout,
+    rubric: Option<&Rubric>,
+) -> Result<Vec<(String, String)>> {
+    let mut notes = Vec::new();
     let mut stmt = conn.prepare(
         r#"
-        SELECT meetings.id, meetings.body_id, meetings.started_at, meetings.artifact_ids_json, bodies.name
+        SELECT meetings.id, meetings.body_id, meetings.started_at, meetings.artifact_ids_json,
+               bodies.name, meetings.parent_meeting_id
         FROM meetings
         JOIN bodies ON meetings.body_id = bodies.id
         ORDER BY meetings.started_at DESC, meetings.id DESC
@@ -325,6 +552,7 @@ fn write_decision_meeting_notes(conn: &Connection, paths: &VaultPaths) -> Result
             started_at: row.get(2)?,
             artifact_ids_json: row.get(3)?,
             body_name: row.get(4)?,
+            parent_meeting_id: row.get(5)?,
         })
     })?;
 
@@ -335,26 +563,41 @@ fn write_decision_meeting_notes(conn: &Connection, paths: &VaultPaths) -> Result
             .split('T')
             .next()
             .unwrap_or(&meeting.started_at);
-        let filename = format!("{date}-{}.md", meeting.body_id);
-        let note_path = paths.meetings_dir.join(filename);
+        let stem = match safe_filename(&format!("{date}-{}", meeting.body_id)) {
+            Ok(stem) => stem,
+            Err(err) => {
+                eprintln!("Skipping decision meeting note: {err}");
+                continue;
+            }
+        };
 
         let mut motion_stmt = conn.prepare(
             r#"
-            SELECT id, meeting_id, text, result, motion_index
+            SELECT id, meeting_id, text, result, motion_index, amended, reconsiders_motion_id
             FROM motions
             WHERE meeting_id = ?1
             ORDER BY motion_index ASC, id ASC
             "#,
         )?;
-        let motions = motion_stmt.query_map([meeting.id.as_str()], |row| {
-            Ok(DecisionMotionRow {
-                id: row.get(0)?,
-                meeting_id: row.get(1)?,
-                text: row.get(2)?,
-                result: row.get(3)?,
-                index: row.get(4)?,
-            })
-        })?;
+        let motions = motion_stmt
+            .query_map([meeting.id.as_str()], |row| {
+                Ok(DecisionMotionRow {
+                    id: row.get(0)?,
+                    meeting_id: row.get(1)?,
+                    text: row.get(2)?,
+                    result: row.get(3)?,
+                    index: row.get(4)?,
+                    amended: row.get(5)?,
+                    reconsiders_motion_id: row.get(6)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        let motion_text = |id: &str| {
+            motions
+                .iter()
+                .find(|motion| motion.id == id)
+                .map(|motion| motion.text.trim().to_string())
+        };
 
         let mut md = String::new();
         md.push_str("---\n");
@@ -367,22 +610,78 @@ fn write_decision_meeting_notes(conn: &Connection, paths: &VaultPaths) -> Result
         md.push_str("---\n\n");
 
         md.push_str(&format!("# {} — {}\n\n", meeting.body_name, date));
+        if let Some(parent_id) = meeting.parent_meeting_id.as_deref()
+            && let Some((parent_note, parent_date)) = meeting_note_link(conn, parent_id)?
+        {
+            md.push_str(&format!(
+                "Committee meeting feeding [[{}/{parent_note}|{parent_date}]].\n\n",
+                layout.meetings_dir
+            ));
+        }
         md.push_str("## Motions\n");
 
-        let mut has_motions = false;
-        for motion in motions {
-            let motion = motion?;
-            has_motions = true;
-            let result = motion.result.unwrap_or_else(|| "unknown".to_string());
+        for motion in &motions {
+            let result = motion.result.as_deref().unwrap_or("unknown");
+            let mut links = String::new();
+            if let Some(original) = motion.reconsiders_motion_id.as_deref().and_then(motion_text) {
+                links.push_str(&format!(" — reconsiders \"{original}\""));
+            }
+            for later in motions
+                .iter()
+                .filter(|later| later.reconsiders_motion_id.as_deref() == Some(&motion.id))
+            {
+                links.push_str(&format!(" — reconsidered by \"{}\"", later.text.trim()));
+            }
             md.push_str(&format!(
-                "- {} ({})\n",
+                "- {}{} ({result}){links}\n",
                 motion.text.trim(),
-                result
+                civic_core::schema::amended_marker(motion.amended),
             ));
         }
-        if !has_motions {
+        if motions.is_empty() {
             md.push_str("_No motions recorded._\n");
         }
+        if let Some(rubric) = rubric
+            && let Some(coverage) =
+                civic_core::db::meeting_evidence_coverage(conn, &meeting.id, rubric)?
+            && let Some(label) = coverage.label()
+        {
+            md.push_str(&format!("\n{label}\n"));
+        }
+
+        let mut comment_stmt = conn.prepare(
+            r#"
+            SELECT COALESCE(public_comments.speaker, 'Unnamed speaker'), public_comments.stance,
+                   public_comments.summary, COALESCE(motions.text, '')
+            FROM public_comments
+            JOIN motions ON public_comments.motion_id = motions.id
+            WHERE motions.meeting_id = ?1
+            ORDER BY motions.motion_index ASC, public_comments.id ASC
+            "#,
+        )?;
+        let comments = comment_stmt.query_map([meeting.id.as_str()], |row| {
+            let speaker: String = row.get(0)?;
+            let stance: Option<String> = row.get(1)?;
+            let summary: String = row.get(2)?;
+            let motion_text: String = row.get(3)?;
+            Ok((speaker, stance, summary, motion_text))
+        })?;
+        let mut comment_lines = Vec::new();
+        for comment in comments {
+            let (speaker, stance, summary, motion_text) = comment?;
+            let stance = stance.map(|stance| format!(" ({stance})")).unwrap_or_default();
+            comment_lines.push(format!(
+                "- {speaker}{stance}: {} — on \"{}\"\n",
+                summary.trim(),
+                motion_text.trim()
+            ));
+        }
+        if !comment_lines.is_empty() {
+            md.push_str("\n## Public Comment\n");
+            for line in comment_lines {
+                md.push_str(&line);
+            }
+        }
 
         md.push_str("\n## Source Artifacts\n");
         let artifact_ids: Vec<String> = serde_json::from_str(&meeting.artifact_ids_json)
@@ -391,17 +690,88 @@ fn write_decision_meeting_notes(conn: &Connection, paths: &VaultPaths) -> Result
             md.push_str("_No source artifacts recorded._\n");
         } else {
             for artifact_id in artifact_ids {
-                md.push_str(&format!("- [[Artifacts/{artifact_id}|{artifact_id}]]\n"));
+                md.push_str(&format!(
+                    "- [[{}/{artifact_id}|{artifact_id}]]\n",
+                    layout.artifacts_dir
+                ));
+            }
+        }
+
+        let mut committee_stmt = conn.prepare(
+            r#"
+            SELECT meetings.body_id, meetings.started_at, bodies.name
+            FROM meetings
+            JOIN bodies ON meetings.body_id = bodies.id
+            WHERE meetings.parent_meeting_id = ?1
+            ORDER BY meetings.started_at ASC, meetings.id ASC
+            "#,
+        )?;
+        let committees = committee_stmt.query_map([meeting.id.as_str()], |row| {
+            let body_id: String = row.get(0)?;
+            let started_at: String = row.get(1)?;
+            let body_name: String = row.get(2)?;
+            Ok((body_id, started_at, body_name))
+        })?;
+        let mut committee_lines = Vec::new();
+        for committee in committees {
+            let (body_id, started_at, body_name) = committee?;
+            let committee_date = started_at.split('T').next().unwrap_or(&started_at);
+            let link = format!(
+                "[[{}/{committee_date}-{body_id}|{committee_date}]]",
+                layout.meetings_dir
+            );
+            committee_lines.push(format!("- Recommended by committee {body_name} — {link}\n"));
+        }
+        if !committee_lines.is_empty() {
+            md.push_str("\n## Committee Recommendations\n");
+            for line in committee_lines {
+                md.push_str(&line);
             }
         }
 
-        fs::write(note_path, md)?;
+        notes.push((stem.to_string(), md));
     }
 
+    Ok(notes)
+}
+
+fn meeting_note_link(conn: &Connection, meeting_id: &str) -> Result<Option<(String, String)>> {
+    let mut stmt = conn.prepare("SELECT body_id, started_at FROM meetings WHERE id = ?1")?;
+    let mut rows = stmt.query([meeting_id])?;
+    let Some(row) = rows.next()? else {
+        return Ok(None);
+    };
+    let body_id: String = row.get(0)?;
+    let started_at: String = row.get(1)?;
+    let date = started_at
+        .split('T')
+        .next()
+        .unwrap_or(&started_at)
+        .to_string();
+    Ok(Some((format!("{date}-{body_id}"), date)))
+}
+
+fn write_score_report(
+    conn: &Connection,
+    paths: &VaultPaths,
+    top_n: usize,
+    exclude_insufficient: bool,
+    provenance: &Provenance,
+) -> Result<()> {
+    let (stem, md) = render_score_report(conn, top_n, exclude_insufficient)?;
+    fs::create_dir_all(&paths.reports_dir)?;
+    write_atomic(paths.reports_dir.join(format!("{stem}.md")), provenance.stamp(&md, true))?;
     Ok(())
 }
 
-fn write_score_report(conn: &Connection, paths: &VaultPaths) -> Result<()> {
+/// `(note stem, markdown)` for the rubric score report over the last seven days. With
+/// `exclude_insufficient`, scores flagged `insufficient_evidence` still count toward the totals
+/// but not the average.
+pub(crate) fn render_score_report(
+    conn: &Connection,
+    top_n: usize,
+    exclude_insufficient: bool,
+) -> Result<(String, String)> {
     let now = OffsetDateTime::now_utc();
     let start = now - Duration::days(7);
     let date_format: &[FormatItem<'_>] = time::macros::format_description!("[year]-[month]-[day]");
@@ -427,39 +797,49 @@ fn write_score_report(conn: &Connection, paths: &VaultPaths) -> Result<()> {
     })?;
 
     let mut scores = Vec::new();
+    let mut averaged = Vec::new();
     let mut insufficient = 0usize;
+    let mut no_sources = 0usize;
     for row in rows {
         let (score, flags, text) = row?;
-        if flags.iter().any(|flag| flag == "insufficient_evidence") {
+        let is_insufficient = flags.iter().any(|flag| flag == "insufficient_evidence");
+        if is_insufficient {
             insufficient += 1;
         }
+        if !(exclude_insufficient && is_insufficient) {
+            averaged.push(score);
+        }
+        if flags.iter().any(|flag| flag == "no_source_artifacts") {
+            no_sources += 1;
+        }
         scores.push((score, text));
     }
     let total_scored = scores.len();
-    let average_score = if total_scored == 0 {
+    let average_score = if averaged.is_empty() {
         0.0
     } else {
-        scores.iter().map(|(score, _)| score).sum::<f64>() / total_scored as f64
+        averaged.iter().sum::<f64>() / averaged.len() as f64
     };
 
     scores.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
-    let top_negative = scores.iter().take(3).collect::<Vec<_>>();
-    let top_positive = scores.iter().rev().take(3).collect::<Vec<_>>();
+    let top_negative = scores.iter().take(top_n).collect::<Vec<_>>();
+    let top_positive = scores.iter().rev().take(top_n).collect::<Vec<_>>();
 
     let drift_flags = load_drift_flags(conn, &window_start, &window_end)?;
 
-    let report_dir = paths.root.join("Reports").join("Weekly");
-    fs::create_dir_all(&report_dir)?;
-    let report_path = report_dir.join(format!("{date_str}-scores.md"));
-
     let mut md = String::new();
     md.push_str(&format!("# Rubric Scores {date_str}\n\n"));
     md.push_str(&format!("Window: {window_start} to {window_end} UTC\n\n"));
     if total_scored == 0 {
         md.push_str("_No decision scores available this week._\n");
     } else {
-        md.push_str(&format!("- Average score: {:.1}\n", average_score));
+        md.push_str(&format!("- Average score: {:.1}", average_score));
+        if exclude_insufficient && insufficient > 0 {
+            md.push_str(" (excluding insufficient evidence)");
+        }
+        md.push('\n');
         md.push_str(&format!("- Insufficient evidence: {insufficient}\n"));
+        md.push_str(&format!("- No source artifacts: {no_sources}\n"));
         if !top_positive.is_empty() {
             md.push_str("\n## Top Positive\n");
             for (score, text) in top_positive {
@@ -480,20 +860,20 @@ fn write_score_report(conn: &Connection, paths: &VaultPaths) -> Result<()> {
         }
     }
 
-    fs::write(report_path, md)?;
-    Ok(())
+    Ok((format!("{date_str}-scores"), md))
 }
 
-fn write_reports_moc(paths: &VaultPaths) -> Result<()> {
-    let mut report_lines = Vec::new();
-    report_lines.push("# MOC - Reports".to_string());
-    report_lines.push(String::new());
-    report_lines.push("This index is generated. Do not edit manually.".to_string());
-    report_lines.push(String::new());
+fn write_reports_moc(paths: &VaultPaths, provenance: &Provenance) -> Result<()> {
+    let mut report_lines = vec![
+        "# MOC - Reports".to_string(),
+        String::new(),
+        "This index is generated. Do not edit manually.".to_string(),
+        String::new(),
+    ];
 
-    let reports_dir = paths.root.join("Reports").join("Weekly");
+    let reports_dir = &paths.reports_dir;
     if reports_dir.exists() {
-        let mut report_links: Vec<String> = fs::read_dir(&reports_dir)?
+        let mut report_links: Vec<String> = fs::read_dir(reports_dir)?
             .filter_map(|entry| entry.ok())
             .filter_map(|entry| {
                 let path = entry.path();
@@ -501,7 +881,7 @@ fn write_reports_moc(paths: &VaultPaths) -> Result<()> {
                     return None;
                 }
                 let stem = path.file_stem()?.to_str()?.to_string();
-                Some(format!("- [[Reports/Weekly/{stem}|{stem}]]"))
+                Some(format!("- [[{}/{stem}|{stem}]]", paths.layout.reports_dir))
             })
             .collect();
         report_links.sort();
@@ -515,10 +895,160 @@ fn write_reports_moc(paths: &VaultPaths) -> Result<()> {
     }
 
     let moc_path = paths.index_dir.join("MOC - Reports.md");
-    fs::write(moc_path, report_lines.join("\n"))?;
+    write_atomic(moc_path, provenance.stamp(&report_lines.join("\n"), false))?;
+    Ok(())
+}
+
+/// One official's grades for a report week, as shown on the site's official page.
+#[derive(Debug, Clone)]
+pub struct OfficialNote {
+    pub id: String,
+    pub name: String,
+    pub week_date: String,
+    pub letter_grade: String,
+    pub numeric_grade: f64,
+    /// Change in average score against the previous report week.
+    pub delta: f64,
+    /// `(axis, letter, numeric)` in rubric axis order.
+    pub axes: Vec<(String, String, f64)>,
+    pub drift_flags: Vec<String>,
+    pub insufficient: bool,
+    /// Graded on fewer votes than the configured minimum.
+    pub provisional: bool,
+    pub receipts: Vec<OfficialReceipt>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OfficialReceipt {
+    pub meeting_date: String,
+    pub motion_text: String,
+    pub vote_label: Option<String>,
+    pub artifact_ids: Vec<String>,
+    pub week_date: String,
+}
+
+/// Writes `<officials_dir>/<id>.md` for each official plus `MOC - Officials.md`.
+pub fn write_official_notes(
+    vault_root: &Path,
+    layout: &VaultLayout,
+    officials: &[OfficialNote],
+) -> Result<()> {
+    let paths = VaultPaths::with_layout(vault_root, layout);
+    paths.ensure()?;
+    fs::create_dir_all(&paths.officials_dir)?;
+
+    let mut index_lines: Vec<String> = vec![
+        "# MOC - Officials".to_string(),
+        String::new(),
+        "This index is generated. Do not edit manually.".to_string(),
+        String::new(),
+    ];
+    let mut sorted: Vec<&OfficialNote> = officials.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+    for official in &sorted {
+        if let Err(err) = safe_filename(&official.id) {
+            eprintln!("Skipping official note: {err}");
+            continue;
+        }
+        write_official_note(&paths, official)?;
+        index_lines.push(format!(
+            "- [[{}/{}|{}]] ({})",
+            paths.layout.officials_dir, official.id, official.name, official.letter_grade
+        ));
+    }
+    if sorted.is_empty() {
+        index_lines.push("_No graded officials found._".to_string());
+    }
+
+    let moc_path = paths.index_dir.join("MOC - Officials.md");
+    write_atomic(moc_path, index_lines.join("\n"))?;
+    Ok(())
+}
+
+fn write_official_note(paths: &VaultPaths, official: &OfficialNote) -> Result<()> {
+    let note_path = paths
+        .officials_dir
+        .join(format!("{}.md", safe_filename(&official.id)?));
+    write_atomic(note_path, render_official_note(&paths.layout, official))?;
     Ok(())
 }
 
+pub(crate) fn render_official_note(layout: &VaultLayout, official: &OfficialNote) -> String {
+    let week_link = |week: &str| format!("[[{}/{week}|{week}]]", layout.reports_dir);
+
+    let mut md = String::new();
+    md.push_str("---\n");
+    md.push_str(&format!("id: {}\n", official.id));
+    md.push_str(&format!("name: {}\n", official.name));
+    md.push_str(&format!("week: {}\n", official.week_date));
+    md.push_str(&format!("grade: {}\n", official.letter_grade));
+    md.push_str(&format!("numeric_grade: {:.1}\n", official.numeric_grade));
+    md.push_str("---\n\n");
+
+    md.push_str(&format!("# {}\n\n", official.name));
+    md.push_str(&format!(
+        "- Grade: **{}** ({:.1})\n",
+        official.letter_grade, official.numeric_grade
+    ));
+    md.push_str(&format!("- Change vs prior week: {:+.1}\n", official.delta));
+    md.push_str(&format!("- Week: {}\n", week_link(&official.week_date)));
+    if official.insufficient {
+        md.push_str("- Insufficient evidence\n");
+    }
+    if official.provisional {
+        md.push_str("- Provisional: too few scored votes for a firm grade\n");
+    }
+
+    md.push_str("\n## Axes\n");
+    if official.axes.is_empty() {
+        md.push_str("_No axis scores this week._\n");
+    } else {
+        md.push_str("| Axis | Grade | Score |\n|---|---|---|\n");
+        for (axis, letter, numeric) in &official.axes {
+            md.push_str(&format!("| {axis} | {letter} | {numeric:.1} |\n"));
+        }
+    }
+
+    md.push_str("\n## Drift Flags\n");
+    if official.drift_flags.is_empty() {
+        md.push_str("_No drift detected this week._\n");
+    } else {
+        for flag in &official.drift_flags {
+            md.push_str(&format!("- {flag}\n"));
+        }
+    }
+
+    md.push_str("\n## Receipts\n");
+    if official.receipts.is_empty() {
+        md.push_str("_No receipts recorded._\n");
+    }
+    for receipt in &official.receipts {
+        let vote = receipt
+            .vote_label
+            .as_ref()
+            .map(|label| format!(" ({label})"))
+            .unwrap_or_default();
+        let artifacts = if receipt.artifact_ids.is_empty() {
+            "_No artifacts_".to_string()
+        } else {
+            receipt
+                .artifact_ids
+                .iter()
+                .map(|id| format!("[[{}/{id}|{id}]]", layout.artifacts_dir))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        md.push_str(&format!(
+            "- {}: {}{vote} — week {}; artifacts: {artifacts}\n",
+            receipt.meeting_date,
+            receipt.motion_text.replace('\n', " "),
+            week_link(&receipt.week_date)
+        ));
+    }
+
+    md
+}
+
 fn load_drift_flags(conn: &Connection, window_start: &str, window_end: &str) -> Result<Vec<String>> {
     let mut stmt = conn.prepare(
         r#"
@@ -544,8 +1074,17 @@ fn load_drift_flags(conn: &Connection, window_start: &str, window_end: &str) ->
     Ok(flags)
 }
 
-fn update_issue_counts(tags_json: &str, issue_counts: &mut BTreeMap<String, usize>) {
-    let tags: Vec<String> = serde_json::from_str(tags_json).unwrap_or_default();
+/// Counts issue tags with the rubric's aliases applied, so rows stored before an alias was added
+/// count under the canonical tag.
+fn update_issue_counts(
+    tags_json: &str,
+    rubric: Option<&Rubric>,
+    issue_counts: &mut BTreeMap<String, usize>,
+) {
+    let mut tags: Vec<String> = serde_json::from_str(tags_json).unwrap_or_default();
+    if let Some(rubric) = rubric {
+        tags = rubric.tag_aliases.canonicalize(&tags);
+    }
     for tag in tags {
         if is_issue_tag(&tag) {
             *issue_counts.entry(tag).or_insert(0) += 1;
@@ -553,31 +1092,443 @@ fn update_issue_counts(tags_json: &str, issue_counts: &mut BTreeMap<String, usiz
     }
 }
 
-fn is_issue_tag(tag: &str) -> bool {
-    const ISSUE_TAGS: &[&str] = &[
-        "zoning",
-        "rezoning",
-        "variance",
-        "planning_commission",
-        "budget",
-        "tax",
-        "bond",
-        "appropriation",
-        "contract",
-        "bid",
-        "procurement",
-        "election",
-        "clerk",
-        "ballot",
-        "school_board",
-        "curriculum",
-        "policy",
-        "lawsuit",
-        "settlement",
-        "ordinance",
-        "public_safety",
-        "land_sale",
-        "eminent_domain",
-    ];
-    ISSUE_TAGS.iter().any(|issue| *issue == tag)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use civic_core::schema::DecisionMeeting;
+    use serde_json::json;
+
+    fn decision_meeting(id: &str, body_id: &str, parent: Option<&str>) -> DecisionMeeting {
+        DecisionMeeting {
+            id: id.to_string(),
+            body_id: body_id.to_string(),
+            body_name: None,
+            started_at: "2024-03-04T18:00:00Z".to_string(),
+            meeting_type: None,
+            artifact_ids: Vec::new(),
+            parent_meeting_id: parent.map(str::to_string),
+            presiding_officer: None,
+        }
+    }
+
+    fn temp_db(name: &str) -> (PathBuf, Connection) {
+        let root = std::env::temp_dir()
+            .join(format!("larue-vault-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let conn = civic_core::db::open(root.join("civic.db").to_str().unwrap()).unwrap();
+        (root, conn)
+    }
+
+    #[test]
+    fn committee_meetings_link_to_parent() {
+        let (root, conn) = temp_db("committee");
+        conn.execute(
+            "INSERT INTO bodies (id, name, kind, jurisdiction) VALUES (?1, ?2, ?3, ?4)",
+            ["finance", "Finance Committee", "committee", "larue_county"],
+        )
+        .unwrap();
+
+        let parent = decision_meeting("full-1", "larue-fiscal-court", None);
+        let child = decision_meeting("finance-1", "finance", Some("full-1"));
+        civic_core::db::upsert_decision_meeting(&conn, &parent, &json!({}), &[]).unwrap();
+        civic_core::db::upsert_decision_meeting(&conn, &child, &json!({}), &[]).unwrap();
+
+        let vault_root = root.join("vault");
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, None).unwrap();
+        let meetings_dir = vault_root.join("Meetings");
+        let parent_note =
+            fs::read_to_string(meetings_dir.join("2024-03-04-larue-fiscal-court.md")).unwrap();
+        let child_note = fs::read_to_string(meetings_dir.join("2024-03-04-finance.md")).unwrap();
+
+        assert!(parent_note.contains("## Committee Recommendations"));
+        assert!(parent_note.contains(
+            "- Recommended by committee Finance Committee — [[Meetings/2024-03-04-finance|2024-03-04]]"
+        ));
+        assert!(child_note.contains("[[Meetings/2024-03-04-larue-fiscal-court|2024-03-04]]"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn score_report_honors_top_n() {
+        let (root, conn) = temp_db("top-n");
+        let started_at = (OffsetDateTime::now_utc() - Duration::days(1))
+            .format(&Rfc3339)
+            .unwrap();
+        conn.execute(
+            r#"
+            INSERT INTO meetings (id, body_id, started_at, artifact_ids_json, motions_json, raw_json)
+            VALUES ('m1', 'larue-fiscal-court', ?1, '[]', '[]', '{}')
+            "#,
+            [started_at.as_str()],
+        )
+        .unwrap();
+        for index in 0..6 {
+            let motion_id = format!("m1-motion-{index}");
+            conn.execute(
+                r#"
+                INSERT INTO motions (id, meeting_id, motion_index, text, raw_json)
+                VALUES (?1, 'm1', ?2, ?3, '{}')
+                "#,
+                rusqlite::params![motion_id, index, format!("Motion {index}")],
+            )
+            .unwrap();
+            let score = civic_core::scoring::DecisionScore {
+                id: format!("motion:{motion_id}"),
+                meeting_id: Some("m1".to_string()),
+                motion_id: Some(motion_id.clone()),
+                vote_id: None,
+                overall_score: index as f64,
+                axis_scores: Default::default(),
+                contributions: Default::default(),
+                axis_weights: Default::default(),
+                constitutional_refs: Vec::new(),
+                evidence: Vec::new(),
+                confidence: 1.0,
+                flags: Vec::new(),
+                computed_at: started_at.clone(),
+                rubric_version: None,
+            };
+            civic_core::db::upsert_decision_score(&conn, &score).unwrap();
+        }
+
+        let vault_root = root.join("vault");
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 5, false, None).unwrap();
+        let report_dir = vault_root.join("Reports").join("Weekly");
+        let report_path = fs::read_dir(&report_dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .find(|path| path.to_string_lossy().ends_with("-scores.md"))
+            .unwrap();
+        let report = fs::read_to_string(report_path).unwrap();
+        let section = |heading: &str| {
+            report
+                .split(heading)
+                .nth(1)
+                .unwrap()
+                .split("\n\n")
+                .next()
+                .unwrap()
+                .lines()
+                .filter(|line| line.starts_with("- "))
+                .count()
+        };
+        assert_eq!(section("## Top Positive\n"), 5);
+        assert_eq!(section("## Top Negative\n"), 5);
+        assert!(report.contains("## Top Positive\n- Motion 5 (5.0)\n"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn manual_edits_outside_generated_region_survive_rebuild() {
+        let (root, conn) = temp_db("manual-edits");
+        let insert = |title: &str| {
+            conn.execute(
+                r#"
+                INSERT INTO artifacts (
+                  id, source_kind, source_value, retrieved_at, title, tags_json, raw_json
+                )
+                VALUES ('a1', 'url', 'https://example.com', '2024-03-01T00:00:00Z', ?1, '[]', '{}')
+                ON CONFLICT(id) DO UPDATE SET title=excluded.title
+                "#,
+                [title],
+            )
+            .unwrap();
+        };
+        insert("Road bids");
+        let vault_root = root.join("vault");
+        let note_path = vault_root.join("Artifacts/a1.md");
+
+        // A note from before the markers existed is fully generated and gets migrated.
+        fs::create_dir_all(note_path.parent().unwrap()).unwrap();
+        fs::write(&note_path, "---\nid: a1\n---\n\n# Old generated title\n").unwrap();
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, None).unwrap();
+        let note = fs::read_to_string(&note_path).unwrap();
+        assert!(note.starts_with("---\nid: a1\n"));
+        assert!(note.contains("---\n<!-- LARUE:BEGIN -->\n# Road bids\n"));
+        assert!(note.ends_with("<!-- LARUE:END -->\n"));
+        assert!(!note.contains("Old generated title"));
+
+        let edited = note
+            .replace("<!-- LARUE:BEGIN -->", "Reviewed by the clerk.\n\n<!-- LARUE:BEGIN -->")
+            + "\n## My notes\n- Ask about the second bid.\n";
+        fs::write(&note_path, &edited).unwrap();
+        insert("Road bids (amended)");
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, None).unwrap();
+
+        let rebuilt = fs::read_to_string(&note_path).unwrap();
+        assert!(rebuilt.contains("Reviewed by the clerk.\n\n<!-- LARUE:BEGIN -->\n"));
+        assert!(rebuilt.contains("# Road bids (amended)\n"));
+        let user_notes = "<!-- LARUE:END -->\n\n## My notes\n- Ask about the second bid.\n";
+        assert!(rebuilt.ends_with(user_notes));
+        assert_eq!(rebuilt.matches("<!-- LARUE:BEGIN -->").count(), 1);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn custom_layout_places_notes_and_links() {
+        let (root, conn) = temp_db("layout");
+        conn.execute(
+            r#"
+            INSERT INTO artifacts (id, source_kind, source_value, retrieved_at, tags_json, raw_json)
+            VALUES ('a1', 'url', 'https://example.com', '2024-03-01T00:00:00Z', '[]', '{}')
+            "#,
+            [],
+        )
+        .unwrap();
+        let mut meeting = decision_meeting("full-1", "larue-fiscal-court", None);
+        meeting.artifact_ids = vec!["a1".to_string()];
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &json!({}), &[]).unwrap();
+
+        let layout = VaultLayout {
+            index_dir: "Index".to_string(),
+            artifacts_dir: "Sources/Docs".to_string(),
+            meetings_dir: "Gov/Meetings".to_string(),
+            reports_dir: "Reports/Scores/".to_string(),
+            officials_dir: "People".to_string(),
+        };
+        let vault_root = root.join("vault");
+        build_vault(&conn, &vault_root, &layout, 3, false, None).unwrap();
+
+        assert!(vault_root.join("Sources/Docs/a1.md").is_file());
+        assert!(vault_root.join("Gov/Meetings/2024-03-04-larue-fiscal-court.md").is_file());
+        assert!(vault_root.join("Index/MOC - Reports.md").is_file());
+        assert!(!vault_root.join("00_Index").exists());
+        let artifact_moc = fs::read_to_string(vault_root.join("Index/MOC - Artifacts.md")).unwrap();
+        assert!(artifact_moc.contains("- [[Sources/Docs/a1|"));
+        let meeting_note =
+            fs::read_to_string(vault_root.join("Gov/Meetings/2024-03-04-larue-fiscal-court.md"))
+                .unwrap();
+        assert!(meeting_note.contains("- [[Sources/Docs/a1|a1]]"));
+        let reports_moc = fs::read_to_string(vault_root.join("Index/MOC - Reports.md")).unwrap();
+        assert!(reports_moc.contains("[[Reports/Scores/"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn traversal_artifact_ids_are_skipped() {
+        let (root, conn) = temp_db("traversal");
+        for id in ["a1", "../../escape"] {
+            conn.execute(
+                r#"
+                INSERT INTO artifacts
+                    (id, source_kind, source_value, retrieved_at, tags_json, raw_json)
+                VALUES (?1, 'url', 'https://example.com', '2024-03-01T00:00:00Z', '[]', '{}')
+                "#,
+                [id],
+            )
+            .unwrap();
+        }
+
+        let vault_root = root.join("vault");
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, None).unwrap();
+
+        assert!(vault_root.join("Artifacts/a1.md").is_file());
+        assert!(!root.join("escape.md").exists());
+        assert!(!vault_root.join("escape.md").exists());
+        let artifact_moc =
+            fs::read_to_string(vault_root.join("00_Index/MOC - Artifacts.md")).unwrap();
+        assert!(!artifact_moc.contains("escape"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn superseding_chain_links_both_directions() {
+        let (root, conn) = temp_db("supersedes");
+        let chain = [
+            ("ord-v1", vec![]),
+            ("ord-v2", vec!["ord-v1"]),
+            ("ord-v3", vec!["ord-v2"]),
+        ];
+        for (id, supersedes) in chain {
+            let artifact = civic_core::schema::Artifact {
+                id: id.to_string(),
+                source: civic_core::schema::SourceRef {
+                    kind: "url".to_string(),
+                    value: format!("https://example.com/{id}"),
+                    retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+                },
+                title: None,
+                body_text: None,
+                content_type: None,
+                tags: Vec::new(),
+                supersedes: supersedes.into_iter().map(str::to_string).collect(),
+                language: None,
+                doc_type: None,
+            };
+            civic_core::db::upsert_artifact(&conn, &artifact, &json!({})).unwrap();
+        }
+
+        let vault_root = root.join("vault");
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, None).unwrap();
+        let note =
+            |id: &str| fs::read_to_string(vault_root.join(format!("Artifacts/{id}.md"))).unwrap();
+
+        let first = note("ord-v1");
+        assert!(first.contains("- Superseded by: [[Artifacts/ord-v2|ord-v2]]\n"));
+        assert!(!first.contains("- Supersedes:"));
+        let middle = note("ord-v2");
+        assert!(middle.contains("## Supersedes / Superseded by\n"));
+        assert!(middle.contains("- Supersedes: [[Artifacts/ord-v1|ord-v1]]\n"));
+        assert!(middle.contains("- Superseded by: [[Artifacts/ord-v3|ord-v3]]\n"));
+        let latest = note("ord-v3");
+        assert!(latest.contains("- Supersedes: [[Artifacts/ord-v2|ord-v2]]\n"));
+        assert!(!latest.contains("- Superseded by:"));
+
+        let superseded = civic_core::db::artifact_superseded_by(&conn).unwrap();
+        assert_eq!(superseded.keys().collect::<Vec<_>>(), vec!["ord-v1", "ord-v2"]);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn decision_note_reports_evidence_coverage() {
+        let (root, conn) = temp_db("coverage");
+        let artifact = civic_core::schema::Artifact {
+            id: "minutes-1".to_string(),
+            source: civic_core::schema::SourceRef {
+                kind: "url".to_string(),
+                value: "https://example.com/minutes-1".to_string(),
+                retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            },
+            title: None,
+            body_text: None,
+            content_type: None,
+            tags: vec!["contract".to_string()],
+            supersedes: Vec::new(),
+            language: None,
+            doc_type: None,
+        };
+        civic_core::db::upsert_artifact(&conn, &artifact, &json!({})).unwrap();
+        let mut meeting = decision_meeting("m1", "larue-fiscal-court", None);
+        meeting.artifact_ids = vec!["minutes-1".to_string()];
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &json!({}), &[]).unwrap();
+        for (index, text) in ["Approve the road contract", " "].into_iter().enumerate() {
+            let motion = civic_core::schema::DecisionMotion {
+                id: format!("m1-{index}"),
+                meeting_id: "m1".to_string(),
+                index,
+                text: text.to_string(),
+                moved_by: None,
+                seconded_by: None,
+                result: Some("passed".to_string()),
+                amended: false,
+                amendment_text: None,
+                agenda_ref: None,
+            };
+            civic_core::db::upsert_motion(&conn, &motion, &json!({})).unwrap();
+        }
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+
+        let coverage = civic_core::db::meeting_evidence_coverage(&conn, "m1", &rubric)
+            .unwrap()
+            .unwrap();
+        assert_eq!((coverage.covered, coverage.total), (1, 2));
+
+        let vault_root = root.join("vault");
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, Some(&rubric)).unwrap();
+        let note_path = vault_root.join("Meetings/2024-03-04-larue-fiscal-court.md");
+        let note = fs::read_to_string(note_path).unwrap();
+        assert!(note.contains("\nEvidence coverage: 50% (1 of 2 motions)\n"), "{note}");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn issue_moc_counts_aliased_tags_under_the_canonical_tag() {
+        let (root, conn) = temp_db("issue-aliases");
+        for (id, tag) in [("a1", "contract"), ("a2", "contracts")] {
+            conn.execute(
+                r#"
+                INSERT INTO artifacts (id, source_kind, source_value, retrieved_at, tags_json, raw_json)
+                VALUES (?1, 'url', 'https://example.com', '2024-03-01T00:00:00Z', ?2, '{}')
+                "#,
+                rusqlite::params![id, format!("[\"{tag}\"]")],
+            )
+            .unwrap();
+        }
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+
+        let vault_root = root.join("vault");
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, Some(&rubric)).unwrap();
+        let moc = fs::read_to_string(vault_root.join("00_Index/MOC - Issues.md")).unwrap();
+        assert!(moc.contains("- contract (2)"), "{moc}");
+        assert!(!moc.contains("- contracts"), "{moc}");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn every_note_records_build_provenance() {
+        let (root, conn) = temp_db("provenance");
+        let artifact = civic_core::schema::Artifact {
+            id: "minutes-1".to_string(),
+            source: civic_core::schema::SourceRef {
+                kind: "url".to_string(),
+                value: "https://example.com/minutes-1".to_string(),
+                retrieved_at: "2024-03-01T00:00:00Z".to_string(),
+            },
+            title: None,
+            body_text: None,
+            content_type: None,
+            tags: vec!["contract".to_string()],
+            supersedes: Vec::new(),
+            language: None,
+            doc_type: None,
+        };
+        civic_core::db::upsert_artifact(&conn, &artifact, &json!({})).unwrap();
+        let mut meeting = decision_meeting("m1", "larue-fiscal-court", None);
+        meeting.artifact_ids = vec!["minutes-1".to_string()];
+        civic_core::db::upsert_decision_meeting(&conn, &meeting, &json!({}), &[]).unwrap();
+        let rubric_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../rubric");
+        let rubric = Rubric::load_from_dir(&rubric_dir).unwrap();
+
+        let vault_root = root.join("vault");
+        build_vault(&conn, &vault_root, &VaultLayout::default(), 3, false, Some(&rubric)).unwrap();
+
+        let mut pending = vec![vault_root.clone()];
+        let mut notes = Vec::new();
+        while let Some(dir) = pending.pop() {
+            for entry in fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else if path.extension().is_some_and(|ext| ext == "md") {
+                    notes.push(path);
+                }
+            }
+        }
+        assert!(notes.len() >= 6, "{notes:?}");
+        let mut generated_at = Vec::new();
+        for path in &notes {
+            let note = fs::read_to_string(path).unwrap();
+            let (frontmatter, _) = split_frontmatter(&note);
+            let yaml = frontmatter
+                .strip_prefix("---\n")
+                .and_then(|rest| rest.strip_suffix("---\n"))
+                .unwrap_or_else(|| panic!("{} has no frontmatter", path.display()));
+            let fields: serde_yaml::Mapping = serde_yaml::from_str(yaml).unwrap();
+            let field = |key: &str| fields.get(key).and_then(|value| value.as_str());
+            let stamp = field("generated_at").unwrap();
+            OffsetDateTime::parse(stamp, &Rfc3339).unwrap();
+            generated_at.push(stamp.to_string());
+            assert_eq!(field("source_db"), conn.path(), "{}", path.display());
+            let scored = path.ends_with("Meetings/2024-03-04-larue-fiscal-court.md")
+                || path.to_string_lossy().ends_with("-scores.md");
+            let expected_version = scored.then_some(rubric.version_hash.as_str());
+            assert_eq!(field("rubric_version"), expected_version, "{}", path.display());
+        }
+        generated_at.dedup();
+        assert_eq!(generated_at.len(), 1);
+
+        let _ = fs::remove_dir_all(&root);
+    }
 }